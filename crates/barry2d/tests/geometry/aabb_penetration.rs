@@ -0,0 +1,50 @@
+use barry2d::bounding_volume::{Aabb, BoundingVolume};
+use barry2d::math::Vector2;
+
+fn unit_box(center: Vector2) -> Aabb {
+    Aabb::from_half_extents(center, Vector2::splat(1.0))
+}
+
+#[test]
+fn penetration_along_x() {
+    let a = unit_box(Vector2::new(0.0, 0.0));
+    let b = unit_box(Vector2::new(1.5, 0.2));
+
+    let mtv = a.penetration(&b).unwrap();
+    assert_relative_eq!(mtv, Vector2::new(-0.5, 0.0));
+    assert_relative_eq!(b.penetration(&a).unwrap(), Vector2::new(0.5, 0.0));
+
+    // Moving `a` by the penetration vector leaves it touching `b`.
+    let separated = Aabb::new(a.mins + mtv, a.maxs + mtv);
+    assert_relative_eq!(separated.penetration(&b).unwrap(), Vector2::ZERO);
+}
+
+#[test]
+fn penetration_along_y() {
+    let a = unit_box(Vector2::new(0.0, 0.0));
+    let b = unit_box(Vector2::new(-0.3, -1.75));
+
+    assert_relative_eq!(a.penetration(&b).unwrap(), Vector2::new(0.0, 0.25));
+}
+
+#[test]
+fn penetration_diagonal_corner_overlap() {
+    let a = unit_box(Vector2::new(0.0, 0.0));
+    let b = unit_box(Vector2::new(1.6, 1.8));
+
+    // The corners overlap by 0.4 along X and 0.2 along Y.
+    assert_relative_eq!(
+        a.penetration(&b).unwrap(),
+        Vector2::new(0.0, -0.2),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn penetration_disjoint() {
+    let a = unit_box(Vector2::new(0.0, 0.0));
+    let b = unit_box(Vector2::new(2.5, 0.0));
+
+    assert!(!a.intersects(&b));
+    assert!(a.penetration(&b).is_none());
+}
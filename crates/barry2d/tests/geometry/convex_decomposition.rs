@@ -0,0 +1,71 @@
+use barry2d::math::{Real, Vector2};
+use barry2d::shape::ConvexPolygon;
+use barry2d::transformation;
+use barry2d::utils::point_in_poly2d;
+
+fn signed_area(poly: &[Vector2]) -> Real {
+    let mut area = 0.0;
+    for i in 0..poly.len() {
+        let a = poly[i];
+        let b = poly[(i + 1) % poly.len()];
+        area += a.perp_dot(b);
+    }
+    area / 2.0
+}
+
+fn is_convex_ccw(poly: &[Vector2]) -> bool {
+    (0..poly.len()).all(|i| {
+        let a = poly[i];
+        let b = poly[(i + 1) % poly.len()];
+        let c = poly[(i + 2) % poly.len()];
+        (b - a).perp_dot(c - b) >= -1.0e-6
+    })
+}
+
+#[test]
+fn convex_decomposition_l_shape() {
+    let l_shape = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 1.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(1.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ];
+
+    let pieces = transformation::convex_decomposition(&l_shape).unwrap();
+    assert!(pieces.len() == 2 || pieces.len() == 3);
+
+    for piece in &pieces {
+        assert!(is_convex_ccw(piece));
+        assert!(ConvexPolygon::from_convex_polyline(piece.clone()).is_some());
+    }
+
+    // The pieces don’t overlap and cover the whole L-shape.
+    let total_area: Real = pieces.iter().map(|piece| signed_area(piece)).sum();
+    assert_relative_eq!(total_area, signed_area(&l_shape), epsilon = 1.0e-5);
+
+    for i in 0..20 {
+        for j in 0..20 {
+            let pt = Vector2::new(0.05 + i as Real * 0.1, 0.05 + j as Real * 0.1);
+
+            if point_in_poly2d(pt, &l_shape) {
+                assert!(pieces.iter().any(|piece| point_in_poly2d(pt, piece)));
+            }
+        }
+    }
+}
+
+#[test]
+fn convex_decomposition_convex_input() {
+    let square = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(0.0, 1.0),
+    ];
+
+    let pieces = transformation::convex_decomposition(&square).unwrap();
+    assert_eq!(pieces.len(), 1);
+    assert_eq!(pieces[0].len(), 4);
+}
@@ -1,4 +1,4 @@
-use barry2d::math::{Isometry2, UnitVector2, Vector2};
+use barry2d::math::{Isometry2, Real, UnitVector2, Vector2};
 use barry2d::query::{self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
 use barry2d::shape::Cuboid;
 
@@ -50,3 +50,46 @@ fn cuboids_large_size_ratio_issue_181() {
         }
     }
 }
+
+fn check_scaled_cuboid_cuboid_epa(scale: Real) {
+    let c = Cuboid::new(Vector2::new(2.0, 1.0) * scale);
+    let m1 = Isometry2::from_xy(3.5 * scale, 0.3 * scale);
+    let m2 = Isometry2::IDENTITY;
+
+    let res = query::details::contact_support_map_support_map(m1.inv_mul(m2), &c, &c, 0.0)
+        .expect("Penetration not found.");
+    assert_relative_eq!(res.dist, -0.5 * scale, epsilon = 1.0e-4 * scale);
+    assert_relative_eq!(*res.normal1, -Vector2::X, epsilon = 1.0e-4);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn large_cuboid_cuboid_EPA() {
+    check_scaled_cuboid_cuboid_epa(1000.0);
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn tiny_cuboid_cuboid_EPA() {
+    check_scaled_cuboid_cuboid_epa(0.001);
+}
+
+#[test]
+fn thin_nearly_parallel_cuboids_epa_normal() {
+    // Near-degenerate CSO: two thin, long, and almost parallel cuboids with a shallow overlap.
+    // Rounding errors used to make EPA stop early with the normal of an ill-conditioned face.
+    let c = Cuboid::new(Vector2::new(2.0, 1.0e-3));
+
+    for i in 0..100 {
+        let angle = i as Real * 1.0e-7;
+        let dx = 0.5 + i as Real * 1.0e-3;
+        let pos12 = Isometry2::new(Vector2::new(dx, 5.0e-4), angle);
+
+        let res = query::details::contact_support_map_support_map(pos12, &c, &c, 0.0)
+            .expect("Penetration not found.");
+
+        // The minimal penetration direction is the `Y` axis of either cuboid.
+        assert!(res.normal1.dot(Vector2::Y) >= angle.cos() - 1.0e-3);
+        assert_relative_eq!(res.dist, -1.5e-3, epsilon = 1.0e-4);
+    }
+}
@@ -0,0 +1,77 @@
+use barry2d::math::{real_consts, Isometry2, Real, Vector2};
+use barry2d::query::{directed_hausdorff_distance_2d, hausdorff_distance_2d};
+use barry2d::shape::ConvexPolygon;
+
+fn rectangle(hx: Real, hy: Real) -> ConvexPolygon {
+    ConvexPolygon::from_convex_polyline(vec![
+        Vector2::new(-hx, -hy),
+        Vector2::new(hx, -hy),
+        Vector2::new(hx, hy),
+        Vector2::new(-hx, hy),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn offset_squares() {
+    // The square [-1, 1]² lies inside of the square [-1.5, 2.5] x [-2, 2].
+    let small = rectangle(1.0, 1.0);
+    let large = rectangle(2.0, 2.0);
+    let pos12 = Isometry2::from_xy(0.5, 0.0);
+
+    // The middle of the right edge of the small square is 1.5 away from the large square.
+    assert_relative_eq!(
+        directed_hausdorff_distance_2d(pos12, &small, &large),
+        1.5,
+        epsilon = 1.0e-6
+    );
+    // The corner (2.5, 2) of the large square is the farthest from the corner (1, 1).
+    let expected = Real::sqrt(1.5 * 1.5 + 1.0);
+    assert_relative_eq!(
+        directed_hausdorff_distance_2d(pos12.inverse(), &large, &small),
+        expected,
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        hausdorff_distance_2d(pos12, &small, &large),
+        expected,
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        hausdorff_distance_2d(pos12.inverse(), &large, &small),
+        expected,
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn farthest_point_in_the_middle_of_an_edge() {
+    // The farthest points of the thin rectangle are in the middle of its long edges, not at
+    // its vertices.
+    let thin = rectangle(1.5, 0.1);
+    let square = rectangle(2.0, 2.0);
+
+    assert_relative_eq!(
+        directed_hausdorff_distance_2d(Isometry2::IDENTITY, &thin, &square),
+        1.9,
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn identical_and_rotated_polygons() {
+    let square = rectangle(1.0, 1.0);
+    assert_relative_eq!(
+        hausdorff_distance_2d(Isometry2::IDENTITY, &square, &square),
+        0.0,
+        epsilon = 1.0e-6
+    );
+
+    // A square rotated by 45 degrees around its center: its corners stick out by sqrt(2) - 1.
+    let pos12 = Isometry2::new(Vector2::ZERO, real_consts::FRAC_PI_4);
+    assert_relative_eq!(
+        hausdorff_distance_2d(pos12, &square, &square),
+        Real::sqrt(2.0) - 1.0,
+        epsilon = 1.0e-5
+    );
+}
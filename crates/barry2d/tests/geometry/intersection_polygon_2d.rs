@@ -0,0 +1,71 @@
+use barry2d::math::{Isometry2, Real, Vector2};
+use barry2d::query::intersection_polygon_2d;
+use barry2d::shape::ConvexPolygon;
+
+fn square(half_extent: Real) -> ConvexPolygon {
+    ConvexPolygon::from_convex_polyline(vec![
+        Vector2::new(-half_extent, -half_extent),
+        Vector2::new(half_extent, -half_extent),
+        Vector2::new(half_extent, half_extent),
+        Vector2::new(-half_extent, half_extent),
+    ])
+    .unwrap()
+}
+
+fn signed_area(points: &[Vector2]) -> Real {
+    let mut area = 0.0;
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        area += a.perp_dot(b);
+    }
+    area / 2.0
+}
+
+#[test]
+fn overlapping_squares() {
+    let square = square(1.0);
+    let pos12 = Isometry2::new(Vector2::new(1.0, 0.5), 0.0);
+
+    let polygon = intersection_polygon_2d(pos12, &square, &square);
+    assert_eq!(polygon.len(), 4);
+    assert_relative_eq!(signed_area(&polygon), 1.5, epsilon = 1.0e-5);
+
+    // The overlap is the rectangle `[0, 1] x [-0.5, 1]`.
+    for pt in &polygon {
+        assert!(pt.x >= -1.0e-5 && pt.x <= 1.0 + 1.0e-5);
+        assert!(pt.y >= -0.5 - 1.0e-5 && pt.y <= 1.0 + 1.0e-5);
+    }
+}
+
+#[test]
+fn rotated_square_inside_square() {
+    let big = square(2.0);
+    let small = square(1.0);
+    let pos12 = Isometry2::new(Vector2::ZERO, 0.3);
+
+    let polygon = intersection_polygon_2d(pos12, &big, &small);
+    assert_eq!(polygon.len(), 4);
+    assert_relative_eq!(signed_area(&polygon), 4.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn disjoint_squares() {
+    let square = square(1.0);
+    let pos12 = Isometry2::new(Vector2::new(3.0, 0.5), 0.2);
+    assert!(intersection_polygon_2d(pos12, &square, &square).is_empty());
+}
+
+#[test]
+fn touching_squares() {
+    let square = square(1.0);
+
+    // The squares share an edge, or part of it.
+    let pos12 = Isometry2::new(Vector2::new(2.0, 0.0), 0.0);
+    assert!(intersection_polygon_2d(pos12, &square, &square).is_empty());
+    let pos12 = Isometry2::new(Vector2::new(2.0, 0.5), 0.0);
+    assert!(intersection_polygon_2d(pos12, &square, &square).is_empty());
+
+    // The squares share a vertex.
+    let pos12 = Isometry2::new(Vector2::new(2.0, 2.0), 0.0);
+    assert!(intersection_polygon_2d(pos12, &square, &square).is_empty());
+}
@@ -1,5 +1,19 @@
+mod aabb_penetration;
 mod ball_ball_toi;
 mod ball_cuboid_contact;
+mod convex_decomposition;
 mod epa2;
+mod hausdorff_distance_2d;
+mod intersection_polygon_2d;
+mod perp_products;
+mod perpendicular;
+mod polygon_centroid;
+mod polygon_polygon_sat;
+mod polyline;
 mod ray_cast;
+mod ray_polygon_boundary;
+mod segment_split;
+mod shape_aabb;
+mod shape_outline;
+mod support_map_aabb;
 mod time_of_impact2;
@@ -0,0 +1,28 @@
+use barry2d::math::{cross2, perp, perp_dot, Vector2};
+
+#[test]
+fn perp_products_are_consistent() {
+    let vectors = [
+        Vector2::X,
+        Vector2::Y,
+        Vector2::new(1.0, 1.0),
+        Vector2::new(-0.3, 2.0),
+        Vector2::new(-4.0, -1.5),
+    ];
+
+    assert_eq!(perp(Vector2::X), Vector2::Y);
+    assert_eq!(cross2(Vector2::X, Vector2::Y), 1.0);
+
+    for a in vectors {
+        assert_eq!(perp(perp(a)), -a);
+        assert_eq!(perp(a).dot(a), 0.0);
+        // Must agree with the convention used by `glam`.
+        assert_eq!(perp(a), a.perp());
+
+        for b in vectors {
+            assert_relative_eq!(perp_dot(a, b), cross2(a, b), epsilon = 1.0e-6);
+            assert_relative_eq!(perp_dot(a, b), a.perp_dot(b), epsilon = 1.0e-6);
+            assert_relative_eq!(cross2(a, b), -cross2(b, a), epsilon = 1.0e-6);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use barry2d::math::{UnitVector, Vector2};
+use barry2d::utils::perpendicular;
+
+#[test]
+fn perpendicular_is_orthogonal_and_right_handed() {
+    let dirs = [
+        Vector2::X,
+        Vector2::Y,
+        Vector2::new(1.0, 1.0),
+        Vector2::new(-0.3, 2.0),
+        Vector2::new(-4.0, -1.5),
+    ];
+
+    for dir in dirs {
+        let dir = UnitVector::new(dir).unwrap();
+        let perp = perpendicular(dir);
+
+        assert_relative_eq!(perp.length(), 1.0, epsilon = 1.0e-6);
+        assert_relative_eq!(dir.dot(*perp), 0.0, epsilon = 1.0e-6);
+        // Counter-clockwise rotation: the 2D cross product is positive.
+        assert_relative_eq!(dir.perp_dot(*perp), 1.0, epsilon = 1.0e-6);
+    }
+
+    assert_eq!(*perpendicular(UnitVector::X), Vector2::Y);
+}
@@ -0,0 +1,69 @@
+use barry2d::math::Vector2;
+use barry2d::utils::polygon_centroid;
+
+#[test]
+fn right_triangle_centroid() {
+    let ccw = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(3.0, 0.0),
+        Vector2::new(0.0, 3.0),
+    ];
+    let cw = [ccw[0], ccw[2], ccw[1]];
+
+    assert_relative_eq!(
+        polygon_centroid(&ccw).unwrap(),
+        Vector2::new(1.0, 1.0),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        polygon_centroid(&cw).unwrap(),
+        Vector2::new(1.0, 1.0),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn centroid_ignores_vertex_density() {
+    // A unit square with many extra vertices along its right edge. Their average is biased
+    // toward that edge but the area centroid is still the square's center.
+    let vertices = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 0.25),
+        Vector2::new(1.0, 0.5),
+        Vector2::new(1.0, 0.75),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(0.0, 1.0),
+    ];
+
+    assert_relative_eq!(
+        polygon_centroid(&vertices).unwrap(),
+        Vector2::new(0.5, 0.5),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn non_convex_polygon_centroid() {
+    // An L-shape made of a 2x1 rectangle and a 1x1 square on top of its left half.
+    let vertices = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(2.0, 1.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(1.0, 2.0),
+        Vector2::new(0.0, 2.0),
+    ];
+
+    // Area-weighted: (2 * (1, 0.5) + 1 * (0.5, 1.5)) / 3.
+    assert_relative_eq!(
+        polygon_centroid(&vertices).unwrap(),
+        Vector2::new(2.5 / 3.0, 2.5 / 3.0),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn empty_polygon_centroid() {
+    assert_eq!(polygon_centroid(&[]), None);
+}
@@ -0,0 +1,57 @@
+use barry2d::math::{Isometry2, Real, Vector2};
+use barry2d::query::{sat, PointQuery};
+use barry2d::shape::ConvexPolygon;
+
+fn polygon(points: &[(Real, Real)]) -> ConvexPolygon {
+    ConvexPolygon::from_convex_polyline(points.iter().map(|p| Vector2::new(p.0, p.1)).collect())
+        .unwrap()
+}
+
+#[test]
+fn box_triangle_overlap() {
+    let square = polygon(&[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]);
+    let triangle = polygon(&[(-0.5, 0.0), (0.5, 0.0), (0.0, 1.0)]);
+    let pos12 = Isometry2::new(Vector2::new(0.0, 0.8), 0.0);
+
+    let (axis, depth, points) = sat::polygon_polygon_sat(&square, &triangle, pos12).unwrap();
+    assert_relative_eq!(axis, Vector2::Y, epsilon = 1.0e-5);
+    assert_relative_eq!(depth, 0.2, epsilon = 1.0e-5);
+
+    // The contact points span the flat bottom of the triangle resting in the square's top face.
+    assert_relative_eq!(points[0].x.abs(), 0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(points[1].x.abs(), 0.5, epsilon = 1.0e-5);
+    assert!(points[0].x * points[1].x < 0.0);
+    for pt in points {
+        assert!(pt.y >= 0.8 - 1.0e-5 && pt.y <= 1.0 + 1.0e-5);
+    }
+
+    let disjoint = Isometry2::new(Vector2::new(0.0, 1.1), 0.0);
+    assert!(sat::polygon_polygon_sat(&square, &triangle, disjoint).is_none());
+}
+
+#[test]
+fn triangle_triangle_overlap() {
+    let triangle1 = polygon(&[(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)]);
+    let triangle2 = polygon(&[(0.0, 0.0), (2.0, 0.0), (0.0, 2.0)]);
+    let angle = 3.24;
+    let pos12 = Isometry2::new(Vector2::new(1.5, 1.5), angle);
+
+    let (axis, depth, points) = sat::polygon_polygon_sat(&triangle1, &triangle2, pos12).unwrap();
+    assert!(depth > 0.0);
+    assert_relative_eq!(axis.length(), 1.0, epsilon = 1.0e-5);
+
+    // Moving the second triangle by the penetration along the axis separates the shapes.
+    let eps = 1.0e-3;
+    let separated = Isometry2::new(pos12.translation + axis * (depth + eps), angle);
+    let touching = Isometry2::new(pos12.translation + axis * (depth - eps), angle);
+    assert!(sat::polygon_polygon_sat(&triangle1, &triangle2, separated).is_none());
+    assert!(sat::polygon_polygon_sat(&triangle1, &triangle2, touching).is_some());
+
+    // The contact points lie in the overlap region, up to the penetration depth.
+    for pt in points {
+        let dist1 = triangle1.distance_to_point(Isometry2::IDENTITY, pt, true);
+        let dist2 = triangle2.distance_to_point(pos12, pt, true);
+        assert!(dist1 <= depth + 1.0e-4);
+        assert!(dist2 <= depth + 1.0e-4);
+    }
+}
@@ -0,0 +1,68 @@
+use barry2d::math::{Isometry2, Vector2};
+use barry2d::query::{PointQuery, Ray, RayCast};
+use barry2d::shape::{Polyline, Shape};
+
+fn zig_zag() -> Polyline {
+    let vertices = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(2.0, 0.0),
+        Vector2::new(3.0, 1.0),
+        Vector2::new(4.0, 0.0),
+    ];
+    Polyline::new(vertices, None)
+}
+
+#[test]
+fn polyline_aabb() {
+    let polyline = zig_zag();
+    let aabb = polyline.compute_aabb(Isometry2::from_xy(1.0, 2.0));
+
+    assert_relative_eq!(aabb.mins, Vector2::new(1.0, 2.0));
+    assert_relative_eq!(aabb.maxs, Vector2::new(5.0, 3.0));
+}
+
+#[test]
+fn polyline_ray_cast() {
+    let polyline = zig_zag();
+    let m = Isometry2::IDENTITY;
+
+    // Hits the middle of the second segment.
+    let ray = Ray::new(Vector2::new(1.5, 5.0), -Vector2::Y);
+    let hit = polyline
+        .cast_ray_and_get_normal(m, &ray, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.5);
+    assert_relative_eq!(ray.point_at(hit.toi), Vector2::new(1.5, 0.5));
+
+    // Hits the vertex shared by the second and third segments from below.
+    let ray = Ray::new(Vector2::new(2.0, -1.0), Vector2::Y);
+    assert_relative_eq!(polyline.cast_ray(m, &ray, 10.0, true).unwrap(), 1.0);
+
+    // Passes beyond the end of the polyline.
+    let ray = Ray::new(Vector2::new(5.0, 5.0), -Vector2::Y);
+    assert!(polyline.cast_ray(m, &ray, 10.0, true).is_none());
+
+    // Stops before reaching the polyline.
+    let ray = Ray::new(Vector2::new(1.5, 5.0), -Vector2::Y);
+    assert!(polyline.cast_ray(m, &ray, 4.0, true).is_none());
+}
+
+#[test]
+fn polyline_project_point() {
+    let polyline = zig_zag();
+    let m = Isometry2::IDENTITY;
+
+    // Closest to the vertex at the top of the third segment.
+    let proj = polyline.project_point(m, Vector2::new(3.0, 2.0), false);
+    assert_relative_eq!(proj.point, Vector2::new(3.0, 1.0), epsilon = 1.0e-5);
+
+    // Closest to the interior of the first segment.
+    let proj = polyline.project_point(m, Vector2::new(0.5, 0.0), false);
+    assert_relative_eq!(proj.point, Vector2::new(0.25, 0.25), epsilon = 1.0e-5);
+    assert_relative_eq!(
+        polyline.distance_to_point(m, Vector2::new(0.5, 0.0), false),
+        0.125f32.sqrt(),
+        epsilon = 1.0e-5
+    );
+}
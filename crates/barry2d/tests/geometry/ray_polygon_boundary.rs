@@ -0,0 +1,55 @@
+use barry2d::math::Vector2;
+use barry2d::query::{self, Ray};
+
+fn square() -> [Vector2; 4] {
+    [
+        Vector2::new(-1.0, -1.0),
+        Vector2::new(1.0, -1.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(-1.0, 1.0),
+    ]
+}
+
+#[test]
+fn ray_through_polygon_hits_twice() {
+    let ray = Ray::new(Vector2::new(-3.0, 0.5), Vector2::new(1.0, 0.0));
+    let hits = query::ray_polygon_boundary_intersections(&ray, &square());
+    assert_eq!(hits.as_slice(), &[2.0, 4.0]);
+
+    // Same result with clockwise vertices.
+    let mut cw = square();
+    cw.reverse();
+    let hits = query::ray_polygon_boundary_intersections(&ray, &cw);
+    assert_eq!(hits.as_slice(), &[2.0, 4.0]);
+}
+
+#[test]
+fn ray_from_inside_polygon_hits_once() {
+    let ray = Ray::new(Vector2::new(0.0, 0.0), Vector2::new(0.0, 2.0));
+    let hits = query::ray_polygon_boundary_intersections(&ray, &square());
+    assert_eq!(hits.as_slice(), &[0.5]);
+}
+
+#[test]
+fn ray_grazing_vertex_hits_once() {
+    let diamond = [
+        Vector2::new(0.0, -1.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+        Vector2::new(-1.0, 0.0),
+    ];
+    let ray = Ray::new(Vector2::new(-2.0, 1.0), Vector2::new(1.0, 0.0));
+    let hits = query::ray_polygon_boundary_intersections(&ray, &diamond);
+    assert_eq!(hits.len(), 1);
+    assert_relative_eq!(hits[0], 2.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn ray_missing_polygon_hits_nothing() {
+    let ray = Ray::new(Vector2::new(-3.0, 2.0), Vector2::new(1.0, 0.0));
+    assert!(query::ray_polygon_boundary_intersections(&ray, &square()).is_empty());
+
+    // Pointing away from the polygon.
+    let ray = Ray::new(Vector2::new(-3.0, 0.0), Vector2::new(-1.0, 0.0));
+    assert!(query::ray_polygon_boundary_intersections(&ray, &square()).is_empty());
+}
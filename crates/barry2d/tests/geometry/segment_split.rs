@@ -0,0 +1,38 @@
+use barry2d::math::Vector2;
+use barry2d::shape::Segment;
+
+fn segment() -> Segment {
+    Segment::new(Vector2::new(1.0, 2.0), Vector2::new(5.0, -2.0))
+}
+
+#[test]
+fn split_segment_at_middle() {
+    let (first, second) = segment().split_at(0.5);
+    assert_eq!(
+        first,
+        Segment::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 0.0))
+    );
+    assert_eq!(
+        second,
+        Segment::new(Vector2::new(3.0, 0.0), Vector2::new(5.0, -2.0))
+    );
+}
+
+#[test]
+fn split_segment_at_endpoints() {
+    let seg = segment();
+
+    let (first, second) = seg.split_at(0.0);
+    assert_eq!(first, Segment::new(seg.a, seg.a));
+    assert_eq!(second, seg);
+
+    let (first, second) = seg.split_at(1.0);
+    assert_eq!(first, seg);
+    assert_eq!(second, Segment::new(seg.b, seg.b));
+}
+
+#[test]
+#[should_panic]
+fn split_segment_out_of_range() {
+    let _ = segment().split_at(1.5);
+}
@@ -0,0 +1,61 @@
+use barry2d::bounding_volume::Aabb;
+use barry2d::math::{real_consts, Isometry2, Real, Vector2};
+use barry2d::shape::{Ball, Capsule, Cuboid, Shape};
+
+fn rot45(translation: Vector2) -> Isometry2 {
+    Isometry2::new(translation, real_consts::FRAC_PI_4)
+}
+
+fn assert_aabb_eq(aabb: Aabb, mins: Vector2, maxs: Vector2) {
+    assert_relative_eq!(aabb.mins, mins, epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs, maxs, epsilon = 1.0e-5);
+}
+
+const S: Real = real_consts::FRAC_1_SQRT_2;
+
+#[test]
+fn ball_aabb() {
+    let ball = Ball::new(1.0);
+    let t = Vector2::new(1.0, 2.0);
+
+    assert_aabb_eq(
+        ball.compute_aabb(Isometry2::IDENTITY),
+        Vector2::splat(-1.0),
+        Vector2::splat(1.0),
+    );
+    assert_aabb_eq(
+        ball.compute_aabb(rot45(t)),
+        t - Vector2::splat(1.0),
+        t + Vector2::splat(1.0),
+    );
+}
+
+#[test]
+fn cuboid_aabb() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    let t = Vector2::new(1.0, 2.0);
+
+    assert_aabb_eq(
+        cuboid.compute_aabb(Isometry2::IDENTITY),
+        -Vector2::new(1.0, 2.0),
+        Vector2::new(1.0, 2.0),
+    );
+
+    let he = Vector2::splat(3.0 * S);
+    assert_aabb_eq(cuboid.compute_aabb(rot45(t)), t - he, t + he);
+}
+
+#[test]
+fn capsule_aabb() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+    let t = Vector2::new(-1.0, 0.5);
+
+    assert_aabb_eq(
+        capsule.compute_aabb(Isometry2::IDENTITY),
+        Vector2::new(-0.5, -1.5),
+        Vector2::new(0.5, 1.5),
+    );
+
+    let he = Vector2::splat(S + 0.5);
+    assert_aabb_eq(capsule.compute_aabb(rot45(t)), t - he, t + he);
+}
@@ -0,0 +1,68 @@
+use barry2d::math::{Real, Vector2};
+use barry2d::query::PointQuery;
+use barry2d::shape::{Ball, Capsule, Cuboid, Shape, Triangle};
+
+fn assert_ccw(outline: &[Vector2]) {
+    let mut area = 0.0;
+
+    for i in 0..outline.len() {
+        let a = outline[i];
+        let b = outline[(i + 1) % outline.len()];
+        area += a.perp_dot(b);
+    }
+
+    assert!(area > 0.0);
+}
+
+#[test]
+fn cuboid_outline_is_its_four_corners() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 2.0));
+    let outline = cuboid.outline(32);
+
+    assert_eq!(outline.len(), 4);
+    assert_ccw(&outline);
+
+    for pt in &outline {
+        assert_eq!(pt.abs(), cuboid.half_extents);
+    }
+}
+
+#[test]
+fn ball_outline_lies_on_the_circle() {
+    let ball = Ball::new(1.5);
+    let outline = ball.outline(32);
+
+    assert_eq!(outline.len(), 32);
+    assert_ccw(&outline);
+
+    for pt in &outline {
+        assert_relative_eq!(pt.length(), 1.5, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn capsule_outline_is_a_stadium() {
+    let capsule = Capsule::new(Vector2::new(-1.0, 0.5), Vector2::new(2.0, 1.0), 0.3);
+    let outline = capsule.outline(16);
+
+    assert_eq!(outline.len(), 32);
+    assert_ccw(&outline);
+
+    for pt in &outline {
+        let dist = capsule.segment.distance_to_local_point(*pt, true);
+        assert_relative_eq!(dist, 0.3 as Real, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn support_map_outline_removes_duplicate_vertices() {
+    let triangle = Triangle::new(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    );
+    let outline = triangle.outline(64);
+
+    assert_eq!(outline.len(), 3);
+    assert_ccw(&outline);
+}
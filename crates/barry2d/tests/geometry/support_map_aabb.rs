@@ -0,0 +1,36 @@
+use barry2d::bounding_volume::details::support_map_aabb;
+use barry2d::math::{Isometry2, Vector2};
+use barry2d::shape::{Ball, Cuboid, Shape, SupportMap};
+
+fn positions() -> [Isometry2; 3] {
+    [
+        Isometry2::IDENTITY,
+        Isometry2::new(Vector2::new(1.0, -2.0), 0.3),
+        Isometry2::new(Vector2::new(-4.0, 0.5), 2.5),
+    ]
+}
+
+#[test]
+fn support_map_aabb_matches_cuboid_aabb() {
+    let cuboid = Cuboid::new(Vector2::new(1.0, 0.25));
+
+    for pos in positions() {
+        let aabb = support_map_aabb(pos, &cuboid);
+        let expected = cuboid.compute_aabb(pos);
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn support_map_aabb_matches_ball_aabb() {
+    let ball = Ball::new(0.75);
+    let shape: &dyn SupportMap = &ball;
+
+    for pos in positions() {
+        let aabb = support_map_aabb(pos, shape);
+        let expected = ball.compute_aabb(pos);
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+    }
+}
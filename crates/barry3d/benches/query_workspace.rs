@@ -0,0 +1,79 @@
+//! Compares computing contacts between penetrating pairs with a fresh `QueryWorkspace` per
+//! pair against a single workspace shared by all the pairs.
+
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::details::contact_support_map_support_map_with_workspace;
+use barry3d::query::QueryWorkspace;
+use barry3d::shape::Cuboid;
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const NUM_PAIRS: usize = 1000;
+const NUM_RUNS: u32 = 100;
+
+/// Generates penetrating cuboid pairs so that every query goes through EPA.
+fn penetrating_pairs() -> Vec<(Isometry3, Cuboid, Cuboid)> {
+    let mut rng: IsaacRng = SeedableRng::seed_from_u64(0);
+
+    (0..NUM_PAIRS)
+        .map(|_| {
+            let pos12 = Isometry3::new(
+                Vector3::new(rng.gen(), rng.gen(), rng.gen()) * 0.5,
+                Vector3::new(rng.gen(), rng.gen(), rng.gen()),
+            );
+            (
+                pos12,
+                Cuboid::new(Vector3::splat(1.0)),
+                Cuboid::new(Vector3::splat(1.0)),
+            )
+        })
+        .collect()
+}
+
+/// The average time taken by `run`, over `NUM_RUNS` runs.
+fn time(mut run: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..NUM_RUNS {
+        run();
+    }
+    start.elapsed() / NUM_RUNS
+}
+
+fn main() {
+    let pairs = penetrating_pairs();
+
+    let fresh = time(|| {
+        for (pos12, c1, c2) in &pairs {
+            // Allocates new EPA buffers for each pair.
+            let mut workspace = QueryWorkspace::default();
+            black_box(contact_support_map_support_map_with_workspace(
+                *pos12,
+                c1,
+                c2,
+                0.0,
+                &mut workspace,
+                None,
+            ));
+        }
+    });
+
+    // The EPA buffers are allocated by the first pair and reused afterwards.
+    let mut workspace = QueryWorkspace::default();
+    let shared = time(|| {
+        for (pos12, c1, c2) in &pairs {
+            black_box(contact_support_map_support_map_with_workspace(
+                *pos12,
+                c1,
+                c2,
+                0.0,
+                &mut workspace,
+                None,
+            ));
+        }
+    });
+
+    println!("contact, {NUM_PAIRS} pairs, fresh workspace:  {fresh:?}");
+    println!("contact, {NUM_PAIRS} pairs, shared workspace: {shared:?}");
+}
@@ -0,0 +1,69 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Cuboid, Shape};
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use test::Bencher;
+
+const NUM_POSES: usize = 1000;
+
+fn random_poses() -> Vec<Isometry3> {
+    let mut rng: IsaacRng = SeedableRng::seed_from_u64(0);
+
+    (0..NUM_POSES)
+        .map(|_| {
+            Isometry3::new(
+                Vector3::new(rng.gen(), rng.gen(), rng.gen()) * 10.0,
+                Vector3::new(rng.gen(), rng.gen(), rng.gen()),
+            )
+        })
+        .collect()
+}
+
+#[bench]
+fn bench_cuboid_aabb_absolute_rotation(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let poses = random_poses();
+
+    bh.iter(|| {
+        for pos in &poses {
+            test::black_box(cuboid.aabb(*pos));
+        }
+    })
+}
+
+#[bench]
+fn bench_cuboid_aabb_transformed_vertices(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let poses = random_poses();
+
+    bh.iter(|| {
+        for pos in &poses {
+            let vertices = cuboid
+                .local_aabb()
+                .vertices()
+                .map(|pt| pos.transform_point(pt));
+            test::black_box(Aabb::from_points(&vertices));
+        }
+    })
+}
+
+#[bench]
+fn bench_cuboid_compute_aabbs_simd(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let poses: Vec<_> = (0..10).flat_map(|_| random_poses()).collect();
+
+    bh.iter(|| test::black_box(cuboid.compute_aabbs(&poses)))
+}
+
+#[bench]
+fn bench_cuboid_compute_aabb_per_pose(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let poses: Vec<_> = (0..10).flat_map(|_| random_poses()).collect();
+
+    bh.iter(|| {
+        for pos in &poses {
+            test::black_box(cuboid.compute_aabb(*pos));
+        }
+    })
+}
@@ -11,6 +11,8 @@ use test::Bencher;
 #[macro_use]
 mod macros;
 
+mod cuboid_aabb;
+
 #[cfg(feature = "dim2")]
 type ConvexHull = Vec<Vector>;
 #[cfg(feature = "dim3")]
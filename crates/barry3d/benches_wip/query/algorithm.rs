@@ -1,5 +1,7 @@
-use barry3d::math::Vector3;
-use barry3d::query::gjk::{CSOPoint, VoronoiSimplex};
+use barry3d::math::{Isometry3, UnitVector3, Vector3};
+use barry3d::query::details::distance_support_map_support_map_with_params;
+use barry3d::query::gjk::{self, CSOPoint, GJKInitDir, VoronoiSimplex};
+use barry3d::shape::{ConvexPolyhedron, Cuboid, Cylinder};
 use test::Bencher;
 
 #[bench]
@@ -41,3 +43,65 @@ fn bench_johnson_simplex_tls(bh: &mut Bencher) {
         test::black_box(spl.project_origin_and_reduce());
     })
 }
+
+#[bench]
+fn bench_gjk_init_dir_translation_delta(bh: &mut Bencher) {
+    bench_gjk_init_dir(bh, GJKInitDir::TranslationDelta)
+}
+
+#[bench]
+fn bench_gjk_init_dir_center_delta(bh: &mut Bencher) {
+    bench_gjk_init_dir(bh, GJKInitDir::CenterDelta)
+}
+
+fn bench_gjk_init_dir(bh: &mut Bencher, init_dir: GJKInitDir) {
+    // A pair of shapes lying far from their local origin.
+    let cuboid1 = ConvexPolyhedron::from_convex_hull(
+        &Cuboid::new(Vector3::splat(0.5))
+            .to_trimesh()
+            .0
+            .iter()
+            .map(|pt| *pt + Vector3::new(0.0, 5.0, 0.0))
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let cuboid2 = Cuboid::new(Vector3::splat(0.5));
+    let pos12 = Isometry3::from_xyz(3.0, 4.0, 0.0);
+    let mut simplex = VoronoiSimplex::new();
+
+    bh.iter(|| {
+        test::black_box(distance_support_map_support_map_with_params(
+            pos12,
+            &cuboid1,
+            &cuboid2,
+            &mut simplex,
+            init_dir,
+        ))
+    })
+}
+
+#[bench]
+fn bench_gjk_closest_points(bh: &mut Bencher) {
+    // Curved shapes need many GJK iterations, each computing a support point of both shapes.
+    let cylinder1 = Cylinder::new(1.0, 0.5);
+    let cylinder2 = Cylinder::new(0.5, 1.0);
+    let pos12 = Isometry3::new(Vector3::new(2.0, 1.5, 0.5), Vector3::new(0.3, 1.2, -0.4));
+    let mut simplex = VoronoiSimplex::new();
+
+    bh.iter(|| {
+        simplex.reset(CSOPoint::from_shapes(
+            pos12,
+            &cylinder1,
+            &cylinder2,
+            UnitVector3::X,
+        ));
+        test::black_box(gjk::closest_points(
+            pos12,
+            &cylinder1,
+            &cylinder2,
+            f32::MAX,
+            true,
+            &mut simplex,
+        ))
+    })
+}
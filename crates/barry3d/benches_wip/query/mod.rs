@@ -1,3 +1,4 @@
 mod algorithm;
 mod contacts;
+mod posed_point_query;
 mod ray;
@@ -0,0 +1,72 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{PointQuery, PosedPointQuery};
+use barry3d::shape::Cuboid;
+use rand::{Rng, SeedableRng};
+use rand_isaac::IsaacRng;
+use test::Bencher;
+
+const NUM_POINTS: usize = 10000;
+
+fn random_points() -> Vec<Vector3> {
+    let mut rng: IsaacRng = SeedableRng::seed_from_u64(0);
+
+    (0..NUM_POINTS)
+        .map(|_| Vector3::new(rng.gen(), rng.gen(), rng.gen()) * 10.0 - 5.0)
+        .collect()
+}
+
+fn posed_cuboid() -> (Isometry3, Cuboid) {
+    let pos = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 1.2, -0.4));
+    (pos, Cuboid::new(Vector3::new(0.5, 2.0, 1.5)))
+}
+
+#[bench]
+fn bench_contains_point_naive(bh: &mut Bencher) {
+    let (pos, cuboid) = posed_cuboid();
+    let points = random_points();
+
+    bh.iter(|| {
+        for pt in &points {
+            // Inverts `pos` for each point.
+            test::black_box(cuboid.contains_point(pos, *pt));
+        }
+    })
+}
+
+#[bench]
+fn bench_contains_point_posed(bh: &mut Bencher) {
+    let (pos, cuboid) = posed_cuboid();
+    let points = random_points();
+    let posed = PosedPointQuery::new(pos, &cuboid);
+
+    bh.iter(|| {
+        for pt in &points {
+            test::black_box(posed.contains(*pt));
+        }
+    })
+}
+
+#[bench]
+fn bench_project_point_naive(bh: &mut Bencher) {
+    let (pos, cuboid) = posed_cuboid();
+    let points = random_points();
+
+    bh.iter(|| {
+        for pt in &points {
+            test::black_box(cuboid.project_point(pos, *pt, false));
+        }
+    })
+}
+
+#[bench]
+fn bench_project_point_posed(bh: &mut Bencher) {
+    let (pos, cuboid) = posed_cuboid();
+    let points = random_points();
+    let posed = PosedPointQuery::new(pos, &cuboid);
+
+    bh.iter(|| {
+        for pt in &points {
+            test::black_box(posed.project(*pt, false));
+        }
+    })
+}
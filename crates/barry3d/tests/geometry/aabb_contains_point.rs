@@ -0,0 +1,29 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::Vector3;
+
+#[test]
+fn aabb_contains_interior_point() {
+    let aabb = Aabb::new(Vector3::new(-1.0, 0.0, 1.0), Vector3::new(1.0, 2.0, 3.0));
+
+    assert!(aabb.contains_point(Vector3::new(0.0, 1.0, 2.0)));
+    assert!(aabb.contains_local_point(Vector3::new(0.5, 0.5, 2.5)));
+}
+
+#[test]
+fn aabb_contains_boundary_point() {
+    let aabb = Aabb::new(Vector3::new(-1.0, 0.0, 1.0), Vector3::new(1.0, 2.0, 3.0));
+
+    // On a face, on an edge, and on a vertex.
+    assert!(aabb.contains_point(Vector3::new(1.0, 1.0, 2.0)));
+    assert!(aabb.contains_point(Vector3::new(-1.0, 2.0, 2.0)));
+    assert!(aabb.contains_point(Vector3::new(1.0, 0.0, 3.0)));
+}
+
+#[test]
+fn aabb_does_not_contain_exterior_point() {
+    let aabb = Aabb::new(Vector3::new(-1.0, 0.0, 1.0), Vector3::new(1.0, 2.0, 3.0));
+
+    assert!(!aabb.contains_point(Vector3::new(0.0, 1.0, 0.0)));
+    assert!(!aabb.contains_point(Vector3::new(1.001, 1.0, 2.0)));
+    assert!(!aabb.contains_point(Vector3::new(0.0, -0.5, 2.0)));
+}
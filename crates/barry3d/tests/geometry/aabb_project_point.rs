@@ -0,0 +1,50 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::Vector3;
+
+fn aabb() -> Aabb {
+    Aabb::new(Vector3::new(-1.0, -2.0, -3.0), Vector3::new(1.0, 2.0, 3.0))
+}
+
+#[test]
+fn aabb_project_point_outside_along_one_axis() {
+    let aabb = aabb();
+    let pt = Vector3::new(5.0, 0.5, -1.0);
+
+    assert_eq!(aabb.project_point(pt), Vector3::new(1.0, 0.5, -1.0));
+
+    let (proj, normal) = aabb.closest_point_and_normal(pt, true);
+    assert_eq!(proj, Vector3::new(1.0, 0.5, -1.0));
+    assert_eq!(*normal.unwrap(), Vector3::X);
+}
+
+#[test]
+fn aabb_project_point_outside_corner() {
+    let aabb = aabb();
+    let pt = Vector3::new(-2.0, 3.0, 4.0);
+
+    assert_eq!(aabb.project_point(pt), Vector3::new(-1.0, 2.0, 3.0));
+
+    let (proj, normal) = aabb.closest_point_and_normal(pt, false);
+    assert_eq!(proj, Vector3::new(-1.0, 2.0, 3.0));
+    assert_relative_eq!(
+        *normal.unwrap(),
+        Vector3::new(-1.0, 1.0, 1.0).normalize(),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn aabb_project_point_inside() {
+    let aabb = aabb();
+    let pt = Vector3::new(0.5, 0.0, 0.0);
+
+    assert_eq!(aabb.project_point(pt), pt);
+
+    let (proj, normal) = aabb.closest_point_and_normal(pt, true);
+    assert_eq!(proj, pt);
+    assert!(normal.is_none());
+
+    let (proj, normal) = aabb.closest_point_and_normal(pt, false);
+    assert_eq!(proj, Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(*normal.unwrap(), Vector3::X);
+}
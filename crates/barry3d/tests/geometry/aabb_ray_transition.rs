@@ -0,0 +1,38 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::Vector3;
+use barry3d::query::Ray;
+
+#[test]
+fn ray_transition_overlapping_aabbs() {
+    let aabb1 = Aabb::new(Vector3::splat(0.0), Vector3::splat(2.0));
+    let aabb2 = Aabb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(4.0, 2.0, 2.0));
+    let ray = Ray::new(Vector3::new(0.5, 1.0, 1.0), Vector3::X);
+
+    assert_relative_eq!(aabb1.ray_transition(&aabb2, &ray).unwrap(), 1.5);
+    // Going backward, the ray never leaves `aabb1` into `aabb2`.
+    assert_eq!(
+        aabb1.ray_transition(&aabb2, &Ray::new(ray.origin, -ray.dir)),
+        None
+    );
+}
+
+#[test]
+fn ray_transition_adjacent_aabbs() {
+    let aabb1 = Aabb::new(Vector3::splat(0.0), Vector3::splat(1.0));
+    let aabb2 = Aabb::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0));
+    let ray = Ray::new(Vector3::new(0.25, 0.5, 0.5), Vector3::new(2.0, 0.0, 0.0));
+
+    assert_relative_eq!(aabb1.ray_transition(&aabb2, &ray).unwrap(), 0.375);
+    // Moving along `y`, the ray leaves `aabb1` without entering `aabb2`.
+    let ray = Ray::new(ray.origin, Vector3::Y);
+    assert_eq!(aabb1.ray_transition(&aabb2, &ray), None);
+}
+
+#[test]
+fn ray_transition_separated_aabbs() {
+    let aabb1 = Aabb::new(Vector3::splat(0.0), Vector3::splat(1.0));
+    let aabb2 = Aabb::new(Vector3::new(2.0, 0.0, 0.0), Vector3::new(3.0, 1.0, 1.0));
+    let ray = Ray::new(Vector3::new(0.5, 0.5, 0.5), Vector3::X);
+
+    assert_eq!(aabb1.ray_transition(&aabb2, &ray), None);
+}
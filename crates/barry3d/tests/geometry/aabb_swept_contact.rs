@@ -0,0 +1,49 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::Vector3;
+
+fn unit_box() -> Aabb {
+    Aabb::new(Vector3::splat(-1.0), Vector3::splat(1.0))
+}
+
+#[test]
+fn swept_contact_from_the_left() {
+    let other = Aabb::new(Vector3::new(-4.0, -0.5, -0.5), Vector3::new(-3.0, 0.5, 0.5));
+    let (t, normal) = unit_box()
+        .swept_contact(&other, Vector3::new(2.0, 0.1, 0.0))
+        .unwrap();
+
+    assert_relative_eq!(t, 1.0);
+    assert_eq!(*normal, -Vector3::X);
+}
+
+#[test]
+fn swept_contact_from_below() {
+    let other = Aabb::new(Vector3::new(0.5, -5.0, 0.0), Vector3::new(1.5, -3.0, 1.0));
+    let (t, normal) = unit_box()
+        .swept_contact(&other, Vector3::new(-0.5, 4.0, 0.0))
+        .unwrap();
+
+    assert_relative_eq!(t, 0.5);
+    assert_eq!(*normal, -Vector3::Y);
+}
+
+#[test]
+fn swept_contact_from_above_diagonally() {
+    // The X projections start overlapping at t = 0.5 but the Z ones only at t = 1.
+    let other = Aabb::new(Vector3::new(1.5, -0.5, 2.0), Vector3::new(2.5, 0.5, 3.0));
+    let (t, normal) = unit_box()
+        .swept_contact(&other, Vector3::new(-1.0, 0.0, -1.0))
+        .unwrap();
+
+    assert_relative_eq!(t, 1.0);
+    assert_eq!(*normal, Vector3::Z);
+}
+
+#[test]
+fn swept_contact_miss() {
+    let other = Aabb::new(Vector3::new(-4.0, 2.0, -0.5), Vector3::new(-3.0, 3.0, 0.5));
+    assert!(unit_box()
+        .swept_contact(&other, Vector3::new(2.0, 0.0, 0.0))
+        .is_none());
+    assert!(unit_box().swept_contact(&other, Vector3::ZERO).is_none());
+}
@@ -0,0 +1,50 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Real, Vector3};
+
+#[test]
+fn swept_overlap_sub_interval() {
+    let a = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    let b = Aabb::new(Vector3::new(3.0, -0.5, -0.5), Vector3::new(4.0, 0.5, 0.5));
+
+    let (t_enter, t_exit) = a
+        .swept_overlap_interval(&b, Vector3::new(-2.0, 0.0, 0.0))
+        .unwrap();
+
+    assert_relative_eq!(t_enter, 1.0);
+    assert_relative_eq!(t_exit, 2.5);
+}
+
+#[test]
+fn swept_overlap_immediately() {
+    let a = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    let b = Aabb::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(1.5, 1.5, 1.5));
+
+    let (t_enter, t_exit) = a
+        .swept_overlap_interval(&b, Vector3::new(1.0, 0.0, 0.0))
+        .unwrap();
+    assert_eq!(t_enter, 0.0);
+    assert_relative_eq!(t_exit, 0.5);
+
+    let (t_enter, t_exit) = a.swept_overlap_interval(&b, Vector3::ZERO).unwrap();
+    assert_eq!(t_enter, 0.0);
+    assert_eq!(t_exit, Real::MAX);
+}
+
+#[test]
+fn swept_overlap_never() {
+    let a = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    let b = Aabb::new(Vector3::new(3.0, 3.0, -0.5), Vector3::new(4.0, 4.0, 0.5));
+
+    // Moving away.
+    assert!(a
+        .swept_overlap_interval(&b, Vector3::new(1.0, 0.0, 0.0))
+        .is_none());
+    // The projections on the `x` and `y` axes never overlap at the same time.
+    assert!(a
+        .swept_overlap_interval(&b, Vector3::new(-1.0, -10.0, 0.0))
+        .is_none());
+    // No motion along an already separated axis.
+    assert!(a
+        .swept_overlap_interval(&b, Vector3::new(-1.0, 0.0, 0.0))
+        .is_none());
+}
@@ -0,0 +1,40 @@
+use barry3d::bounding_volume::{Aabb, BoundingVolume};
+use barry3d::math::{Isometry3, Real, Vector3};
+
+#[test]
+fn swept_aabb_contains_rotating_off_center_box() {
+    // A box centered at (2, 0, 0) in the local-space of a body rotating around its origin.
+    let local_box = Aabb::from_half_extents(Vector3::new(2.0, 0.0, 0.0), Vector3::splat(0.5));
+    let bounding_radius = Vector3::new(2.5, 0.5, 0.5).length();
+    let start = Vector3::new(1.0, -1.0, 0.5);
+    let linvel = Vector3::new(3.0, 0.0, -1.0);
+    let angvel = Vector3::new(0.0, 0.0, 2.0);
+    let dt = 0.5;
+
+    let aabb0 = local_box.transform_by(Isometry3::from_translation(start));
+    let swept = aabb0.swept_with_rotation(linvel, angvel, dt, bounding_radius);
+
+    for i in 0..=20 {
+        let t = dt * i as Real / 20.0;
+        let pos = Isometry3::new(start + linvel * t, angvel * t);
+
+        for vtx in local_box.vertices() {
+            let pt = pos.transform_point(vtx);
+            assert!(
+                swept.loosened(1.0e-5).contains_point(pt),
+                "{:?} not in {:?}",
+                pt,
+                swept
+            );
+        }
+    }
+}
+
+#[test]
+fn swept_aabb_without_rotation_is_translational_sweep() {
+    let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    let swept = aabb.swept_with_rotation(Vector3::new(2.0, -4.0, 0.0), Vector3::ZERO, 0.5, 3.0);
+
+    assert_eq!(swept.mins, Vector3::new(-1.0, -3.0, -1.0));
+    assert_eq!(swept.maxs, Vector3::new(2.0, 1.0, 1.0));
+}
@@ -0,0 +1,26 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Isometry3, Vector3};
+
+use super::rand_vector;
+
+#[test]
+fn aabb_transform_by_matches_transformed_vertices() {
+    let mut rng = oorandom::Rand32::new(42);
+
+    for _ in 0..100 {
+        let mins = rand_vector(&mut rng, 5.0);
+        let aabb = Aabb::new(
+            mins,
+            mins + (rand_vector(&mut rng, 1.0) + Vector3::ONE) * 3.0,
+        );
+        let pos = Isometry3::new(rand_vector(&mut rng, 10.0), rand_vector(&mut rng, 3.0));
+
+        // The reference: transform the eight corners explicitly.
+        let vertices = aabb.vertices().map(|pt| pos.transform_point(pt));
+        let expected = Aabb::from_points(&vertices);
+        let result = aabb.transform_by(pos);
+
+        assert_relative_eq!(result.mins, expected.mins, epsilon = 1.0e-4);
+        assert_relative_eq!(result.maxs, expected.maxs, epsilon = 1.0e-4);
+    }
+}
@@ -0,0 +1,45 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::Ball;
+
+#[test]
+fn ball_ball_contact_depth() {
+    let ball1 = Ball::new(1.0);
+    let ball2 = Ball::new(0.5);
+
+    let contact = query::contact(
+        Isometry3::IDENTITY,
+        &ball1,
+        Isometry3::from_xyz(1.2, 0.0, 0.0),
+        &ball2,
+        0.0,
+    )
+    .unwrap()
+    .expect("the balls are penetrating");
+    assert_relative_eq!(contact.dist, -0.3, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        contact.point1,
+        Vector3::new(1.0, 0.0, 0.0),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        contact.point2,
+        Vector3::new(0.7, 0.0, 0.0),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn ball_ball_contact_beyond_prediction() {
+    let ball = Ball::new(1.0);
+    let pos2 = Isometry3::from_xyz(3.0, 0.0, 0.0);
+
+    assert!(query::contact(Isometry3::IDENTITY, &ball, pos2, &ball, 0.5)
+        .unwrap()
+        .is_none());
+
+    let contact = query::contact(Isometry3::IDENTITY, &ball, pos2, &ball, 1.5)
+        .unwrap()
+        .expect("the balls are within the prediction distance");
+    assert_relative_eq!(contact.dist, 1.0, epsilon = 1.0e-6);
+}
@@ -0,0 +1,79 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{self, details};
+use barry3d::shape::{Ball, Capsule};
+
+#[test]
+fn ball_capsule_cylindrical_side_contact() {
+    let ball = Ball::new(0.5);
+    let capsule = Capsule::new_y(1.0, 0.3);
+    let pos_ball = Isometry3::from_xyz(0.7, 0.2, 0.0);
+    let pos_capsule = Isometry3::IDENTITY;
+
+    let contact = query::contact(pos_ball, &ball, pos_capsule, &capsule, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal1, -Vector3::X, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal2, Vector3::X, epsilon = 1.0e-6);
+
+    // The contact points, in the local-space of each shape.
+    let pos12 = pos_ball.inv_mul(pos_capsule);
+    let local_contact = details::contact_ball_capsule(pos12, &ball, &capsule, 0.0).unwrap();
+    assert_relative_eq!(local_contact.point1, -Vector3::X * 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        local_contact.point2,
+        Vector3::new(0.3, 0.2, 0.0),
+        epsilon = 1.0e-6
+    );
+
+    // Same contact with the shapes swapped.
+    let swapped = query::contact(pos_capsule, &capsule, pos_ball, &ball, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(swapped.dist, contact.dist, epsilon = 1.0e-6);
+    assert_relative_eq!(*swapped.normal1, *contact.normal2, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ball_capsule_cap_contact() {
+    let ball = Ball::new(0.5);
+    let capsule = Capsule::new_y(1.0, 0.3);
+
+    // Along the axis of the capsule.
+    let pos12 = Isometry3::from_xyz(0.0, -1.6, 0.0);
+    let contact = details::contact_ball_capsule(pos12, &ball, &capsule, 0.0).unwrap();
+    assert_relative_eq!(contact.dist, -0.2, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal1, -Vector3::Y, epsilon = 1.0e-6);
+
+    // Diagonally on the cap.
+    let pos12 = Isometry3::from_xyz(-0.3, -1.4, 0.0);
+    let contact = details::contact_ball_capsule(pos12, &ball, &capsule, 0.0).unwrap();
+    assert_relative_eq!(contact.dist, -0.3, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        *contact.normal2,
+        Vector3::new(0.6, 0.8, 0.0),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        contact.point2,
+        Vector3::new(0.18, 1.24, 0.0),
+        epsilon = 1.0e-6
+    );
+
+    // Out of the prediction distance.
+    let pos12 = Isometry3::from_xyz(0.0, -2.0, 0.0);
+    assert!(details::contact_ball_capsule(pos12, &ball, &capsule, 0.1).is_none());
+    assert!(details::contact_ball_capsule(pos12, &ball, &capsule, 0.3).is_some());
+}
+
+#[test]
+fn ball_center_on_capsule_segment() {
+    let ball = Ball::new(0.5);
+    let capsule = Capsule::new_y(1.0, 0.3);
+    let pos12 = Isometry3::from_xyz(0.0, -0.5, 0.0);
+
+    let contact = details::contact_ball_capsule(pos12, &ball, &capsule, 0.0).unwrap();
+    assert_relative_eq!(contact.dist, -0.8 as Real, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.normal2.dot(Vector3::Y), 0.0, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal1, -*contact.normal2, epsilon = 1.0e-6);
+}
@@ -0,0 +1,96 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::details;
+use barry3d::shape::{Ball, Triangle};
+
+fn triangle() -> Triangle {
+    Triangle::new(
+        Vector3::ZERO,
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+    )
+}
+
+#[test]
+fn ball_triangle_face_contact() {
+    let ball = Ball::new(0.5);
+    let pos12 = Isometry3::from_xyz(0.5, 0.5, 0.4);
+    let contact = details::contact_triangle_ball(pos12, &triangle(), &ball, 0.0).unwrap();
+
+    assert_relative_eq!(*contact.normal1, Vector3::Z, epsilon = 1.0e-6);
+    assert_relative_eq!(*contact.normal2, -Vector3::Z, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        contact.point1,
+        Vector3::new(0.5, 0.5, 0.0),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        contact.point2,
+        Vector3::new(0.0, 0.0, -0.5),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-6);
+
+    // Below the triangle, the normal points downward.
+    let pos12 = Isometry3::from_xyz(0.5, 0.5, -0.4);
+    let contact = details::contact_triangle_ball(pos12, &triangle(), &ball, 0.0).unwrap();
+    assert_relative_eq!(*contact.normal1, -Vector3::Z, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ball_triangle_edge_and_vertex_contacts() {
+    let ball = Ball::new(0.5);
+
+    // Closest to the hypotenuse, from outside of the triangle.
+    let pos12 = Isometry3::from_xyz(1.2, 1.2, 0.0);
+    let contact = details::contact_triangle_ball(pos12, &triangle(), &ball, 0.0).unwrap();
+    let expected = Vector3::new(1.0, 1.0, 0.0).normalize();
+    assert_relative_eq!(*contact.normal1, expected, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        contact.point1,
+        Vector3::new(1.0, 1.0, 0.0),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(contact.dist, Real::sqrt(2.0) * 0.2 - 0.5, epsilon = 1.0e-6);
+
+    // Closest to the vertex at the origin.
+    let pos12 = Isometry3::from_xyz(-0.2, -0.2, 0.2);
+    let contact = details::contact_triangle_ball(pos12, &triangle(), &ball, 0.0).unwrap();
+    let expected = Vector3::new(-0.2, -0.2, 0.2).normalize();
+    assert_relative_eq!(*contact.normal1, expected, epsilon = 1.0e-6);
+    assert_relative_eq!(contact.point1, Vector3::ZERO, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ball_triangle_shared_edge_normal() {
+    // Two coplanar triangles sharing the edge from (2, 0, 0) to (0, 2, 0).
+    let tri1 = triangle();
+    let tri2 = Triangle::new(
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(2.0, 2.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+    );
+    let ball = Ball::new(0.5);
+    let pos12 = Isometry3::from_xyz(1.0, 1.0, 0.4);
+
+    for tri in [tri1, tri2] {
+        let contact = details::contact_triangle_ball(pos12, &tri, &ball, 0.0).unwrap();
+        assert_relative_eq!(*contact.normal1, Vector3::Z, epsilon = 1.0e-6);
+        assert_relative_eq!(contact.dist, -0.1, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn ball_triangle_contact_flipped_and_prediction() {
+    let ball = Ball::new(0.5);
+    let pos_ball = Isometry3::from_xyz(0.5, 0.5, 0.7);
+
+    assert!(details::contact_triangle_ball(pos_ball, &triangle(), &ball, 0.1).is_none());
+
+    let contact = details::contact_triangle_ball(pos_ball, &triangle(), &ball, 0.3).unwrap();
+    let flipped =
+        details::contact_ball_triangle(pos_ball.inverse(), &ball, &triangle(), 0.3).unwrap();
+    assert_relative_eq!(contact.dist, 0.2, epsilon = 1.0e-6);
+    assert_relative_eq!(flipped.dist, contact.dist, epsilon = 1.0e-6);
+    assert_relative_eq!(*flipped.normal2, *contact.normal1, epsilon = 1.0e-6);
+    assert_relative_eq!(flipped.point2, contact.point1, epsilon = 1.0e-6);
+}
@@ -0,0 +1,62 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{self, details};
+use barry3d::shape::{Ball, Triangle};
+
+fn triangle() -> Triangle {
+    Triangle::new(
+        Vector3::ZERO,
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+    )
+}
+
+// The distance between a ball centered at `center` and the triangle at the origin.
+fn distance(center: Vector3) -> Real {
+    let ball = Ball::new(0.5);
+    let pos_ball = Isometry3::from_xyz(center.x, center.y, center.z);
+    let pos12 = pos_ball.inverse();
+
+    let dist = details::distance_ball_triangle(pos12, &ball, &triangle());
+    assert_relative_eq!(
+        details::distance_triangle_ball(pos_ball, &triangle(), &ball),
+        dist,
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        query::distance(pos_ball, &ball, Isometry3::IDENTITY, &triangle()).unwrap(),
+        dist,
+        epsilon = 1.0e-5
+    );
+    dist
+}
+
+#[test]
+fn ball_over_triangle_face() {
+    assert_relative_eq!(distance(Vector3::new(0.5, 0.5, 1.0)), 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ball_over_triangle_edge() {
+    // Closest to the hypotenuse, at `(1, 1, 0)`.
+    let dist = distance(Vector3::new(2.0, 2.0, 0.0));
+    assert_relative_eq!(dist, Real::sqrt(2.0) - 0.5, epsilon = 1.0e-6);
+
+    // Closest to the edge along `X`, at `(1, 0, 0)`.
+    let dist = distance(Vector3::new(1.0, -1.0, 1.0));
+    assert_relative_eq!(dist, Real::sqrt(2.0) - 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ball_over_triangle_vertex() {
+    let dist = distance(Vector3::new(-1.0, -1.0, 1.0));
+    assert_relative_eq!(dist, Real::sqrt(3.0) - 0.5, epsilon = 1.0e-6);
+
+    let dist = distance(Vector3::new(3.0, -1.0, 0.0));
+    assert_relative_eq!(dist, Real::sqrt(2.0) - 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn ball_intersecting_triangle() {
+    assert_eq!(distance(Vector3::new(0.5, 0.5, 0.2)), 0.0);
+    assert_eq!(distance(Vector3::new(2.2, 0.0, 0.0)), 0.0);
+}
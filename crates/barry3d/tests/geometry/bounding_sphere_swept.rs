@@ -0,0 +1,32 @@
+use barry3d::bounding_volume::BoundingSphere;
+use barry3d::math::{Real, Vector3};
+use barry3d::query::PointQuery;
+
+use super::rand_vector;
+
+#[test]
+fn swept_sphere_contains_start_and_end_spheres() {
+    let sphere = BoundingSphere::new(Vector3::new(1.0, -2.0, 0.5), 0.75);
+    let vel = Vector3::new(3.0, 1.0, -2.0);
+    let dt: Real = 0.5;
+    let capsule = sphere.swept(vel, dt);
+
+    assert_eq!(capsule.segment.a, sphere.center);
+    assert_relative_eq!(capsule.segment.b, sphere.center + vel * dt);
+    assert_relative_eq!(capsule.radius, sphere.radius);
+
+    let mut rng = oorandom::Rand32::new(42);
+    for _ in 0..100 {
+        let dir = rand_vector(&mut rng, 1.0).normalize();
+
+        for t in [0.0, 0.5 * dt, dt] {
+            let center = sphere.center + vel * t;
+            let pt = center + dir * sphere.radius * 0.999;
+            assert!(capsule.contains_local_point(pt));
+        }
+    }
+
+    // A point just behind the start sphere, away from the motion, is outside.
+    let behind = sphere.center - vel.normalize() * sphere.radius * 1.01;
+    assert!(!capsule.contains_local_point(behind));
+}
@@ -0,0 +1,43 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn bounding_spheres_reject_far_pairs() {
+    let ball = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 2.0));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(10.0, 0.0, 0.0);
+
+    assert!(!query::bounding_spheres_intersect(
+        pos1, &ball, pos2, &cuboid, 0.0
+    ));
+    assert!(!query::bounding_spheres_intersect(
+        pos1, &ball, pos2, &cuboid, 5.0
+    ));
+    assert!(query::bounding_spheres_intersect(
+        pos1, &ball, pos2, &cuboid, 6.0
+    ));
+}
+
+#[test]
+fn bounding_spheres_pass_close_pairs_resolved_by_precise_query() {
+    let ball = Ball::new(1.0);
+    // The bounding sphere of this cuboid has a radius of 3.
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 2.0));
+    let pos1 = Isometry3::IDENTITY;
+
+    // Close enough for the bounding spheres, but not for the shapes themselves.
+    let pos2 = Isometry3::from_xyz(2.2, 2.2, 0.0);
+    assert!(query::bounding_spheres_intersect(
+        pos1, &ball, pos2, &cuboid, 0.0
+    ));
+    assert!(!query::intersection_test(pos1, &ball, pos2, &cuboid).unwrap());
+
+    // Actually intersecting.
+    let pos2 = Isometry3::from_xyz(1.5, 0.0, 0.0);
+    assert!(query::bounding_spheres_intersect(
+        pos1, &ball, pos2, &cuboid, 0.0
+    ));
+    assert!(query::intersection_test(pos1, &ball, pos2, &cuboid).unwrap());
+}
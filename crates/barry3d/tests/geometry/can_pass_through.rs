@@ -0,0 +1,44 @@
+use barry3d::math::{Isometry3, Real, UnitVector3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid};
+
+// Two unit boxes with a gap of the given width along `x` between them.
+fn obstacles(gap: Real) -> (Isometry3, Isometry3, Cuboid) {
+    let offset = 1.0 + gap / 2.0;
+    (
+        Isometry3::from_xyz(-offset, 0.0, 3.0),
+        Isometry3::from_xyz(offset, 0.0, 3.0),
+        Cuboid::new(Vector3::splat(1.0)),
+    )
+}
+
+#[test]
+fn ball_passes_through_gap_wider_than_ball() {
+    let ball = Ball::new(0.5);
+    let (pos1, pos2, cuboid) = obstacles(1.2);
+    assert!(query::can_pass_through(&ball, UnitVector3::Z, pos1, &cuboid, pos2, &cuboid).unwrap());
+}
+
+#[test]
+fn ball_does_not_pass_through_gap_narrower_than_ball() {
+    let ball = Ball::new(0.5);
+    let (pos1, pos2, cuboid) = obstacles(0.8);
+    assert!(!query::can_pass_through(&ball, UnitVector3::Z, pos1, &cuboid, pos2, &cuboid).unwrap());
+
+    // A thin box fits through the same gap.
+    let thin = Cuboid::new(Vector3::new(0.3, 1.0, 1.0));
+    assert!(query::can_pass_through(&thin, UnitVector3::Z, pos1, &cuboid, pos2, &cuboid).unwrap());
+}
+
+#[test]
+fn no_gap_across_the_path() {
+    let ball = Ball::new(0.5);
+
+    // The boxes are lined up along the path.
+    let (pos1, pos2, cuboid) = obstacles(2.0);
+    assert!(!query::can_pass_through(&ball, UnitVector3::X, pos1, &cuboid, pos2, &cuboid).unwrap());
+
+    // The boxes overlap.
+    let (pos1, pos2, cuboid) = obstacles(-0.5);
+    assert!(!query::can_pass_through(&ball, UnitVector3::Z, pos1, &cuboid, pos2, &cuboid).unwrap());
+}
@@ -0,0 +1,72 @@
+use barry3d::math::{Isometry3, UnitVector3, Vector3};
+use barry3d::query;
+use barry3d::shape::Capsule;
+
+#[test]
+fn parallel_capsules_contact() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+    let pos12 = Isometry3::from_xyz(0.8, 0.5, 0.0);
+
+    let contact = query::details::contact_capsule_capsule(pos12, &capsule, &capsule, 0.0)
+        .expect("Penetration not found.");
+    assert_relative_eq!(contact.dist, -0.2, epsilon = 1.0e-5);
+    assert_relative_eq!(*contact.normal1, Vector3::X, epsilon = 1.0e-5);
+    assert_relative_eq!(*contact.normal2, -Vector3::X, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point1.x, 0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(contact.point2.x, -0.5, epsilon = 1.0e-5);
+}
+
+#[test]
+fn separated_capsules_contact() {
+    let capsule1 = Capsule::new_y(1.0, 0.5);
+    let capsule2 = Capsule::new_x(1.0, 0.25);
+    let pos12 = Isometry3::from_xyz(0.0, 2.0, 0.0);
+
+    let contact = query::details::contact_capsule_capsule(pos12, &capsule1, &capsule2, 1.0)
+        .expect("Contact not found.");
+    assert_relative_eq!(contact.dist, 0.25, epsilon = 1.0e-5);
+    assert_eq!(contact.normal1, UnitVector3::Y);
+    assert_relative_eq!(
+        contact.point1,
+        Vector3::new(0.0, 1.5, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        contact.point2,
+        Vector3::new(0.0, -0.25, 0.0),
+        epsilon = 1.0e-5
+    );
+
+    assert!(query::details::contact_capsule_capsule(pos12, &capsule1, &capsule2, 0.1).is_none());
+}
+
+#[test]
+fn crossing_capsules_contact_matches_epa() {
+    let capsule1 = Capsule::new_y(1.0, 0.5);
+    let capsule2 = Capsule::new_x(1.0, 0.25);
+    let pos12 = Isometry3::IDENTITY;
+
+    let contact = query::details::contact_capsule_capsule(pos12, &capsule1, &capsule2, 0.0)
+        .expect("Penetration not found.");
+    let expected =
+        query::details::contact_support_map_support_map(pos12, &capsule1, &capsule2, 0.0).unwrap();
+    assert!(contact.dist < 0.0);
+    assert_relative_eq!(contact.dist, expected.dist, epsilon = 1.0e-5);
+}
+
+#[test]
+fn capsule_capsule_contact_through_dispatcher() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+    let pos1 = Isometry3::from_xyz(1.0, 0.0, 0.0);
+    let pos2 = Isometry3::from_xyz(1.8, 0.0, 0.0);
+
+    let contact = query::contact(pos1, &capsule, pos2, &capsule, 0.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, -0.2, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        contact.point1,
+        Vector3::new(1.5, 0.0, 0.0),
+        epsilon = 1.0e-5
+    );
+}
@@ -0,0 +1,35 @@
+use barry3d::math::{real_consts, Isometry3, Vector3};
+use barry3d::query::{details, ContactManifold};
+use barry3d::shape::Capsule;
+
+#[test]
+fn parallel_capsules_side_by_side_generate_two_contacts() {
+    let capsule = Capsule::new_x(1.0, 0.5);
+    let pos12 = Isometry3::from_xyz(0.5, 0.9, 0.0);
+    let mut manifold: ContactManifold<(), ()> = ContactManifold::new();
+
+    details::contact_manifold_capsule_capsule(pos12, &capsule, &capsule, 0.0, &mut manifold);
+
+    assert_eq!(manifold.points.len(), 2);
+    assert_relative_eq!(manifold.local_n1, Vector3::Y, epsilon = 1.0e-5);
+
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, -0.1, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn crossed_capsules_generate_one_contact() {
+    let capsule = Capsule::new_x(1.0, 0.5);
+    let pos12 = Isometry3::new(
+        Vector3::new(0.0, 0.9, 0.0),
+        Vector3::new(0.0, real_consts::FRAC_PI_2, 0.0),
+    );
+    let mut manifold: ContactManifold<(), ()> = ContactManifold::new();
+
+    details::contact_manifold_capsule_capsule(pos12, &capsule, &capsule, 0.0, &mut manifold);
+
+    assert_eq!(manifold.points.len(), 1);
+    assert_relative_eq!(manifold.points[0].dist, -0.1, epsilon = 1.0e-5);
+    assert_relative_eq!(manifold.local_n1, Vector3::Y, epsilon = 1.0e-5);
+}
@@ -0,0 +1,42 @@
+use barry3d::math::{Real, Vector3};
+use barry3d::shape::Capsule;
+
+use super::rand_vector;
+
+#[test]
+fn capsule_from_endpoints_places_endpoints() {
+    let mut rng = oorandom::Rand32::new(42);
+    let mut rand_point = || rand_vector(&mut rng, 5.0);
+
+    let mut cases = vec![
+        (Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 5.0, 3.0)),
+        (Vector3::new(1.0, 5.0, 3.0), Vector3::new(1.0, 2.0, 3.0)),
+        (Vector3::new(-1.0, 0.0, 0.0), Vector3::new(4.0, 0.0, 0.0)),
+    ];
+    cases.extend((0..50).map(|_| (rand_point(), rand_point())));
+
+    for (a, b) in cases {
+        let radius: Real = 0.3;
+        let (pos, capsule) = Capsule::from_endpoints(a, b, radius);
+
+        // The local capsule is centered and aligned with `Y`.
+        assert_relative_eq!(capsule.center(), Vector3::ZERO, epsilon = 1.0e-5);
+        assert_relative_eq!(capsule.segment.a.x, 0.0, epsilon = 1.0e-5);
+        assert_relative_eq!(capsule.segment.a.z, 0.0, epsilon = 1.0e-5);
+        assert_relative_eq!(capsule.height(), a.distance(b), epsilon = 1.0e-5);
+        assert_eq!(capsule.radius, radius);
+
+        let placed = capsule.transform_by(pos);
+        assert_relative_eq!(placed.segment.a, a, epsilon = 1.0e-4);
+        assert_relative_eq!(placed.segment.b, b, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn capsule_from_coincident_endpoints() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let (pos, capsule) = Capsule::from_endpoints(a, a, 0.5);
+    let placed = capsule.transform_by(pos);
+    assert_eq!(placed.segment.a, a);
+    assert_eq!(placed.segment.b, a);
+}
@@ -0,0 +1,49 @@
+use barry3d::math::{real_consts, Real, Vector3};
+use barry3d::query::PointQuery;
+use barry3d::shape::Capsule;
+
+#[test]
+fn capsule_from_points_encloses_elongated_cloud() {
+    let mut rng = oorandom::Rand32::new(42);
+    let center = Vector3::new(1.0, -2.0, 3.0);
+    let dir = Vector3::new(1.0, 2.0, -0.5).normalize();
+    let (u, v) = dir.any_orthonormal_pair();
+
+    let points: Vec<_> = (0..200)
+        .map(|_| {
+            let t = rng.rand_float() * 10.0 - 5.0;
+            let angle = rng.rand_float() * real_consts::TAU;
+            let r = rng.rand_float() * 0.5;
+            center + dir * t + (u * angle.cos() + v * angle.sin()) * r
+        })
+        .collect();
+
+    let (pos, capsule) = Capsule::from_points(&points);
+    let placed = capsule.transform_by(pos);
+
+    assert!(capsule.radius <= 0.5 + 1.0e-4);
+    assert!(capsule.height() <= 10.0);
+    assert_relative_eq!(
+        (placed.segment.b - placed.segment.a)
+            .normalize()
+            .dot(dir)
+            .abs(),
+        1.0,
+        epsilon = 1.0e-2
+    );
+
+    for pt in &points {
+        let proj = placed.segment.project_local_point(*pt, true).point;
+        assert!(proj.distance(*pt) <= capsule.radius + 1.0e-4);
+    }
+}
+
+#[test]
+fn capsule_from_single_point() {
+    let pt = Vector3::new(1.0, 2.0, 3.0);
+    let (pos, capsule) = Capsule::from_points(&[pt]);
+
+    assert_eq!(capsule.radius, 0.0 as Real);
+    assert_eq!(capsule.height(), 0.0);
+    assert_relative_eq!(pos.translation, pt, epsilon = 1.0e-5);
+}
@@ -0,0 +1,74 @@
+use barry3d::math::{real_consts, Isometry3, UnitVector3, Vector3};
+use barry3d::query::details::{contact_capsule_halfspace, contact_halfspace_capsule};
+use barry3d::shape::{Capsule, HalfSpace};
+
+fn floor() -> HalfSpace {
+    HalfSpace::new(UnitVector3::Y)
+}
+
+#[test]
+fn capsule_standing_on_floor() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+    let pos1 = Isometry3::from_xyz(2.0, 1.4, -1.0);
+    let contacts = contact_capsule_halfspace(pos1.inverse(), &capsule, &floor(), 0.0);
+
+    assert_eq!(contacts.len(), 1);
+    assert_relative_eq!(contacts[0].dist, -0.1, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        pos1.transform_point(contacts[0].point1),
+        Vector3::new(2.0, -0.1, -1.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        contacts[0].point2,
+        Vector3::new(2.0, 0.0, -1.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(*contacts[0].normal1, -Vector3::Y, epsilon = 1.0e-5);
+    assert_eq!(contacts[0].normal2, UnitVector3::Y);
+}
+
+#[test]
+fn capsule_lying_on_floor() {
+    let capsule = Capsule::new_x(1.0, 0.5);
+    let pos1 = Isometry3::from_xyz(0.0, 0.45, 0.0);
+    let contacts = contact_capsule_halfspace(pos1.inverse(), &capsule, &floor(), 0.0);
+
+    assert_eq!(contacts.len(), 2);
+    for contact in &contacts {
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-5);
+        assert_relative_eq!(contact.point2.y, 0.0, epsilon = 1.0e-5);
+    }
+    assert_relative_eq!(
+        (contacts[0].point2.x - contacts[1].point2.x).abs(),
+        2.0,
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn capsule_angled_against_floor() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+    // Tilted by 45 degrees, so its lowest endpoint is at `1.0 - sqrt(2) / 2` above the floor.
+    let pos1 = Isometry3::new(
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::Z * real_consts::FRAC_PI_4,
+    );
+    let expected_dist = 1.0 - real_consts::FRAC_1_SQRT_2 - 0.5;
+
+    let contacts = contact_capsule_halfspace(pos1.inverse(), &capsule, &floor(), 0.0);
+    assert_eq!(contacts.len(), 1);
+    assert_relative_eq!(contacts[0].dist, expected_dist, epsilon = 1.0e-5);
+
+    // With a large prediction, the upper endpoint generates a second contact.
+    let contacts = contact_capsule_halfspace(pos1.inverse(), &capsule, &floor(), 2.0);
+    assert_eq!(contacts.len(), 2);
+    assert_relative_eq!(contacts[0].dist, expected_dist, epsilon = 1.0e-5);
+    assert!(contacts[1].dist > contacts[0].dist);
+
+    // Same result with the shapes swapped.
+    let flipped = contact_halfspace_capsule(pos1, &floor(), &capsule, 0.0);
+    assert_eq!(flipped.len(), 1);
+    assert_relative_eq!(flipped[0].dist, expected_dist, epsilon = 1.0e-5);
+    assert_relative_eq!(flipped[0].point1.y, 0.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,62 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Capsule, Shape, SupportMap};
+
+use super::rand_vector;
+
+fn expected_support_point(capsule: &Capsule, dir: Vector3) -> Vector3 {
+    capsule.segment.local_support_point(dir) + dir.normalize() * capsule.radius
+}
+
+#[test]
+fn capsule_support_point_matches_segment_plus_radius() {
+    let capsule = Capsule::new(
+        Vector3::new(-1.0, 0.5, 0.0),
+        Vector3::new(2.0, -1.0, 1.0),
+        0.3,
+    );
+    let mut rng = oorandom::Rand32::new(42);
+
+    for _ in 0..100 {
+        let dir = rand_vector(&mut rng, 1.0);
+        assert_relative_eq!(
+            capsule.local_support_point(dir),
+            expected_support_point(&capsule, dir),
+            epsilon = 1.0e-6
+        );
+    }
+}
+
+#[test]
+fn capsule_support_point_perpendicular_to_segment() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+
+    // Both endpoints are equally good, the result must still lie on the capsule's surface
+    // at the tip of the radius along `dir`.
+    for dir in [Vector3::X, -Vector3::Z, Vector3::new(1.0, 0.0, 1.0)] {
+        let pt = capsule.local_support_point(dir);
+        assert_relative_eq!(pt.y.abs(), 1.0);
+        assert_relative_eq!(pt.dot(dir.normalize()), 0.5, epsilon = 1.0e-6);
+    }
+
+    // Directions slightly off the perpendicular pick the correct endpoint.
+    let pt = capsule.local_support_point(Vector3::new(1.0, -1.0e-4, 0.0));
+    assert!(pt.y < 0.0);
+    let pt = capsule.local_support_point(Vector3::new(1.0, 1.0e-4, 0.0));
+    assert!(pt.y > 0.0);
+}
+
+#[test]
+fn capsule_is_used_as_a_support_map() {
+    let capsule = Capsule::new_x(1.0, 0.5);
+    let support_map = capsule
+        .as_support_map()
+        .expect("Capsule must be a support map.");
+    let dir = Vector3::new(1.0, 2.0, -1.0);
+    let pos = Isometry3::from_xyz(1.0, 2.0, 3.0);
+
+    assert_relative_eq!(
+        support_map.support_point(pos, dir),
+        pos.transform_point(expected_support_point(&capsule, dir)),
+        epsilon = 1.0e-6
+    );
+}
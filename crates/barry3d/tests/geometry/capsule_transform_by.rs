@@ -0,0 +1,25 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::Capsule;
+
+#[test]
+fn capsule_transform_by_translates_endpoints() {
+    let capsule = Capsule::new(
+        Vector3::new(0.0, -1.0, 0.5),
+        Vector3::new(0.5, 1.0, 0.0),
+        0.3,
+    );
+    let pos = Isometry3::new(Vector3::new(-2.0, 4.0, 1.0), Vector3::new(0.1, -0.5, 0.2));
+
+    let transformed = capsule.transform_by(pos);
+    assert_relative_eq!(
+        transformed.segment.a,
+        pos.transform_point(capsule.segment.a),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        transformed.segment.b,
+        pos.transform_point(capsule.segment.b),
+        epsilon = 1.0e-6
+    );
+    assert_eq!(transformed.radius, capsule.radius);
+}
@@ -0,0 +1,42 @@
+use barry3d::math::Vector3;
+use barry3d::query::PointQuery;
+use barry3d::shape::{Capsule, Segment, SupportMap};
+
+use super::rand_vector;
+
+#[test]
+fn zero_radius_capsule_projects_like_segment() {
+    let a = Vector3::new(-1.0, 0.5, 2.0);
+    let b = Vector3::new(2.0, -1.0, 0.0);
+    let capsule = Capsule::new(a, b, 0.0);
+    let segment = Segment::new(a, b);
+
+    assert!(Capsule::new(a, b, 0.1).as_segment().is_none());
+    let as_segment = capsule.as_segment().unwrap();
+    assert_eq!((as_segment.a, as_segment.b), (a, b));
+
+    let mut rng = oorandom::Rand32::new(42);
+
+    // Include points lying exactly on the segment.
+    let mut points: Vec<_> = (0..100).map(|_| rand_vector(&mut rng, 4.0)).collect();
+    points.extend([a, b, (a + b) / 2.0]);
+
+    for pt in points {
+        for solid in [true, false] {
+            let proj = capsule.project_local_point(pt, solid);
+            let expected = segment.project_local_point(pt, solid);
+
+            assert!(proj.point.is_finite());
+            assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-5);
+            assert_eq!(proj.is_inside, expected.is_inside);
+            assert_relative_eq!(
+                capsule.distance_to_local_point(pt, solid),
+                segment.distance_to_local_point(pt, solid),
+                epsilon = 1.0e-5
+            );
+        }
+
+        assert!(capsule.local_support_point(pt).is_finite());
+        assert!(capsule.distance_gradient(pt).is_finite());
+    }
+}
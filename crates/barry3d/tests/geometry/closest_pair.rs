@@ -0,0 +1,34 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Capsule, Cuboid, Shape};
+
+#[test]
+fn closest_pair_among_four_shapes() {
+    let ball = Ball::new(0.5);
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 0.5));
+    let capsule = Capsule::new_y(1.0, 0.25);
+    let big_ball = Ball::new(2.0);
+
+    let shapes: [(Isometry3, &dyn Shape); 4] = [
+        (Isometry3::from_xyz(0.0, 0.0, 0.0), &ball),
+        (Isometry3::from_xyz(10.0, 0.0, 0.0), &cuboid),
+        // The capsule is 0.3 away from the cuboid's `+x` face.
+        (Isometry3::from_xyz(11.55, 0.0, 0.0), &capsule),
+        // The big ball has a large bounding sphere but is 1.0 away from the small ball.
+        (Isometry3::from_xyz(0.0, 0.0, -3.5), &big_ball),
+    ];
+
+    let (i, j, dist) = query::closest_pair(&shapes).unwrap().unwrap();
+    assert_eq!((i, j), (1, 2));
+    assert_relative_eq!(dist, 0.3, epsilon = 1.0e-5);
+}
+
+#[test]
+fn closest_pair_needs_two_shapes() {
+    let ball = Ball::new(0.5);
+    assert_eq!(query::closest_pair(&[]), Ok(None));
+    assert_eq!(
+        query::closest_pair(&[(Isometry3::IDENTITY, &ball as &dyn Shape)]),
+        Ok(None)
+    );
+}
@@ -0,0 +1,83 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{self, ClosestPoints, TouchStatus};
+use barry3d::shape::Cuboid;
+
+const TOLERANCE: Real = 1.0e-4;
+
+#[test]
+fn cuboids_at_exact_tangency() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos1 = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::Y * 0.3);
+    let pos2 = pos1 * Isometry3::from_xyz(0.3, 2.0, -0.2);
+
+    let (points, status) =
+        query::closest_points_robust(pos1, &cuboid, pos2, &cuboid, TOLERANCE).unwrap();
+    assert_eq!(status, TouchStatus::Touching);
+
+    let witness = match points {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert_eq!(p1, p2);
+            p1
+        }
+        _ => panic!("expected a single witness point, got {:?}", points),
+    };
+
+    // The witness lies on the plane of the face shared by both cuboids.
+    let local_witness = pos1.inverse_transform_point(witness);
+    assert_relative_eq!(local_witness.y, 1.0, epsilon = 1.0e-5);
+
+    // Repeated runs yield the same witness.
+    for _ in 0..10 {
+        let result = query::closest_points_robust(pos1, &cuboid, pos2, &cuboid, TOLERANCE).unwrap();
+        assert_eq!(result, (points, status));
+    }
+}
+
+#[test]
+fn cuboids_near_tangency() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos1 = Isometry3::IDENTITY;
+
+    // Tiny gaps and penetrations below the tolerance are reported as touching.
+    for gap in [-1.0e-5, -1.0e-6, 0.0, 1.0e-6, 1.0e-5] {
+        let pos2 = Isometry3::from_xyz(0.3, 2.0 + gap, -0.2);
+        let (points, status) =
+            query::closest_points_robust(pos1, &cuboid, pos2, &cuboid, TOLERANCE).unwrap();
+        assert_eq!(status, TouchStatus::Touching);
+
+        match points {
+            ClosestPoints::WithinMargin(p1, p2) => {
+                assert_eq!(p1, p2);
+                assert_relative_eq!(p1.y, 1.0, epsilon = 1.0e-4);
+            }
+            _ => panic!("expected a single witness point, got {:?}", points),
+        }
+    }
+}
+
+#[test]
+fn cuboids_separated_or_penetrating() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos1 = Isometry3::IDENTITY;
+
+    let pos2 = Isometry3::from_xyz(0.3, 2.5, -0.2);
+    let (points, status) =
+        query::closest_points_robust(pos1, &cuboid, pos2, &cuboid, TOLERANCE).unwrap();
+    match (points, status) {
+        (ClosestPoints::WithinMargin(p1, p2), TouchStatus::Separated(dist)) => {
+            assert_relative_eq!(dist, 0.5, epsilon = 1.0e-5);
+            assert_relative_eq!(p1.y, 1.0, epsilon = 1.0e-5);
+            assert_relative_eq!(p2.y, 1.5, epsilon = 1.0e-5);
+        }
+        res => panic!("expected separated cuboids, got {:?}", res),
+    }
+
+    let pos2 = Isometry3::from_xyz(0.3, 1.5, -0.2);
+    let (points, status) =
+        query::closest_points_robust(pos1, &cuboid, pos2, &cuboid, TOLERANCE).unwrap();
+    assert_eq!(points, ClosestPoints::Intersecting);
+    match status {
+        TouchStatus::Penetrating(depth) => assert_relative_eq!(depth, 0.5, epsilon = 1.0e-5),
+        _ => panic!("expected penetrating cuboids, got {:?}", status),
+    }
+}
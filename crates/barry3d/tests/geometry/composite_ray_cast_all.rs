@@ -0,0 +1,40 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::Ray;
+use barry3d::shape::TriMesh;
+
+#[test]
+fn trimesh_ray_cast_all_returns_hits_in_depth_order() {
+    // Three overlapping triangles stacked along `Y`, given out of depth order.
+    let heights = [0.0, 2.0, 1.0];
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for (i, y) in heights.iter().enumerate() {
+        let base = 3 * i as u32;
+        vertices.push(Vector3::new(0.0, *y, 0.0));
+        vertices.push(Vector3::new(1.0, *y, 0.0));
+        vertices.push(Vector3::new(0.0, *y, 1.0));
+        indices.push([base, base + 2, base + 1]);
+    }
+
+    let mesh = TriMesh::new(vertices, indices);
+    let m = Isometry3::from_xyz(0.0, 1.0, 0.0);
+    let ray = Ray::new(Vector3::new(0.2, 6.0, 0.2), -Vector3::Y);
+
+    let hits = mesh.cast_ray_all(m, &ray, 10.0, true);
+    let parts: Vec<_> = hits.iter().map(|(part_id, _)| *part_id).collect();
+    assert_eq!(parts, vec![1, 2, 0]);
+    assert_relative_eq!(hits[0].1.toi, 3.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hits[1].1.toi, 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hits[2].1.toi, 5.0, epsilon = 1.0e-5);
+
+    // Hits beyond `max_toi` are ignored.
+    let hits = mesh.cast_ray_all(m, &ray, 4.5, true);
+    let parts: Vec<_> = hits.iter().map(|(part_id, _)| *part_id).collect();
+    assert_eq!(parts, vec![1, 2]);
+
+    // The closest hit matches the single-hit ray cast.
+    let (part_id, hit) = mesh.cast_ray_and_get_part(m, &ray, 10.0, true).unwrap();
+    assert_eq!(part_id, 1);
+    assert_relative_eq!(hit.toi, 3.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,48 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::Ray;
+use barry3d::shape::{Compound, SharedShape, TriMesh};
+
+#[test]
+fn trimesh_ray_cast_returns_hit_triangle() {
+    // Two triangles side by side on the `y = 0` plane.
+    let vertices = vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(3.0, 0.0, 0.0),
+        Vector3::new(4.0, 0.0, 0.0),
+        Vector3::new(3.0, 0.0, 1.0),
+    ];
+    let mesh = TriMesh::new(vertices, vec![[0, 2, 1], [3, 5, 4]]);
+    let m = Isometry3::from_xyz(0.0, 1.0, 0.0);
+
+    let ray = Ray::new(Vector3::new(3.2, 5.0, 0.2), -Vector3::Y);
+    let (part_id, hit) = mesh.cast_ray_and_get_part(m, &ray, 10.0, true).unwrap();
+    assert_eq!(part_id, 1);
+    assert_relative_eq!(hit.toi, 4.0, epsilon = 1.0e-5);
+
+    let ray = Ray::new(Vector3::new(0.2, 5.0, 0.2), -Vector3::Y);
+    let (part_id, _) = mesh.cast_ray_and_get_part(m, &ray, 10.0, true).unwrap();
+    assert_eq!(part_id, 0);
+
+    let ray = Ray::new(Vector3::new(2.0, 5.0, 0.2), -Vector3::Y);
+    assert!(mesh.cast_ray_and_get_part(m, &ray, 10.0, true).is_none());
+}
+
+#[test]
+fn compound_ray_cast_returns_hit_shape() {
+    let compound = Compound::new(vec![
+        (Isometry3::from_xyz(-3.0, 0.0, 0.0), SharedShape::ball(1.0)),
+        (
+            Isometry3::from_xyz(3.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ]);
+    let ray = Ray::new(Vector3::new(3.0, 5.0, 0.0), -Vector3::Y);
+
+    let (part_id, hit) = compound
+        .cast_ray_and_get_part(Isometry3::IDENTITY, &ray, 10.0, true)
+        .unwrap();
+    assert_eq!(part_id, 1);
+    assert_relative_eq!(hit.toi, 4.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,59 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{PointQuery, Ray, RayCast};
+use barry3d::shape::{Compound, Shape, SharedShape};
+
+fn two_balls() -> Compound {
+    Compound::new(vec![
+        (Isometry3::from_xyz(-3.0, 0.0, 0.0), SharedShape::ball(1.0)),
+        (Isometry3::from_xyz(3.0, 0.0, 0.0), SharedShape::ball(1.0)),
+    ])
+}
+
+#[test]
+fn compound_aabb() {
+    let compound = two_balls();
+    let aabb = compound.compute_aabb(Isometry3::from_xyz(0.0, 1.0, 0.0));
+
+    assert_relative_eq!(aabb.mins, Vector3::new(-4.0, 0.0, -1.0));
+    assert_relative_eq!(aabb.maxs, Vector3::new(4.0, 2.0, 1.0));
+}
+
+#[test]
+fn compound_ray_hits_only_one_part() {
+    let compound = two_balls();
+    let m = Isometry3::IDENTITY;
+
+    // Goes through the second ball only.
+    let ray = Ray::new(Vector3::new(3.0, 5.0, 0.0), -Vector3::Y);
+    let hit = compound
+        .cast_ray_and_get_normal(m, &ray, 10.0, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 4.0, epsilon = 1.0e-5);
+    assert_relative_eq!(hit.normal, Vector3::Y, epsilon = 1.0e-5);
+
+    // Goes between both balls.
+    let ray = Ray::new(Vector3::new(0.0, 5.0, 0.0), -Vector3::Y);
+    assert!(compound.cast_ray(m, &ray, 10.0, true).is_none());
+
+    // Goes through both balls, and hits the first one first.
+    let ray = Ray::new(Vector3::new(-10.0, 0.0, 0.0), Vector3::X);
+    assert_relative_eq!(
+        compound.cast_ray(m, &ray, 20.0, true).unwrap(),
+        6.0,
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn compound_point_query() {
+    let compound = two_balls();
+    let m = Isometry3::IDENTITY;
+
+    assert_relative_eq!(
+        compound.distance_to_point(m, Vector3::ZERO, true),
+        2.0,
+        epsilon = 1.0e-5
+    );
+    assert!(compound.contains_point(m, Vector3::new(3.5, 0.0, 0.0)));
+    assert!(!compound.contains_point(m, Vector3::ZERO));
+}
@@ -0,0 +1,47 @@
+use barry3d::bounding_volume::BoundingVolume;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Compound, Shape, SharedShape, SimdCompositeShape};
+
+fn compound() -> Compound {
+    Compound::new(vec![
+        (Isometry3::from_xyz(-3.0, 0.0, 0.0), SharedShape::ball(1.0)),
+        (
+            Isometry3::from_xyz(3.0, 1.0, 0.0),
+            SharedShape::cuboid(1.0, 0.5, 2.0),
+        ),
+        (
+            Isometry3::from_xyz(0.0, -2.0, 1.0),
+            SharedShape::capsule_y(1.0, 0.5),
+        ),
+    ])
+}
+
+#[test]
+fn compound_parts_with_aabb() {
+    let compound = compound();
+    let pos = Isometry3::from_xyz(1.0, 2.0, 3.0);
+
+    let mut parts: Vec<_> = compound.parts_with_aabb(pos).collect();
+    parts.sort_by_key(|(part_id, _)| *part_id);
+    assert_eq!(parts.len(), compound.shapes().len());
+
+    for (i, ((part_id, aabb), (part_pos, part))) in parts.iter().zip(compound.shapes()).enumerate()
+    {
+        let expected = part.compute_aabb(pos * *part_pos);
+        assert_eq!(*part_id as usize, i);
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn compound_parts_with_aabb_rotated() {
+    let compound = compound();
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.4, 0.2, -0.7));
+
+    for (part_id, aabb) in compound.parts_with_aabb(pos) {
+        let (part_pos, part) = &compound.shapes()[part_id as usize];
+        let tight = part.compute_aabb(pos * *part_pos);
+        assert!(aabb.loosened(1.0e-5).contains(&tight));
+    }
+}
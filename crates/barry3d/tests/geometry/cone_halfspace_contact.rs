@@ -0,0 +1,92 @@
+use barry3d::math::{real_consts, Isometry3, Real, UnitVector3, Vector3};
+use barry3d::query::details::{contact_cone_halfspace, contact_halfspace_cone};
+use barry3d::shape::{Cone, HalfSpace};
+
+fn floor() -> HalfSpace {
+    HalfSpace::new(UnitVector3::Y)
+}
+
+#[test]
+fn cone_standing_on_apex() {
+    let cone = Cone::new(1.0, 0.5);
+    // Upside down, with the apex 0.1 below the floor.
+    let pos1 = Isometry3::new(Vector3::new(0.0, 0.9, 0.0), Vector3::X * real_consts::PI);
+    let contacts = contact_cone_halfspace(pos1.inverse(), &cone, &floor(), 0.0);
+
+    assert_eq!(contacts.len(), 1);
+    assert_relative_eq!(contacts[0].dist, -0.1, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        contacts[0].point1,
+        Vector3::new(0.0, 1.0, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(contacts[0].point2, Vector3::ZERO, epsilon = 1.0e-5);
+}
+
+#[test]
+fn cone_standing_on_base() {
+    let cone = Cone::new(1.0, 0.5);
+    let pos1 = Isometry3::from_xyz(1.0, 0.95, 2.0);
+    let contacts = contact_cone_halfspace(pos1.inverse(), &cone, &floor(), 0.0);
+
+    assert_eq!(contacts.len(), 8);
+    for contact in &contacts {
+        assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-5);
+        assert_relative_eq!(contact.point1.y, -1.0, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            Vector3::new(contact.point1.x, 0.0, contact.point1.z).length(),
+            0.5,
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(contact.point2.y, 0.0, epsilon = 1.0e-5);
+        assert_relative_eq!(*contact.normal1, -Vector3::Y, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn tilted_cone_touches_rim() {
+    let cone = Cone::new(1.0, 0.5);
+    let angle = real_consts::FRAC_PI_6;
+    // The lowest rim point is at `-0.5 * sin(angle) - cos(angle)` from the cone center.
+    let lowest = -0.5 * angle.sin() - angle.cos();
+    let pos1 = Isometry3::new(Vector3::new(0.0, -lowest - 0.01, 0.0), Vector3::Z * angle);
+
+    let contacts = contact_cone_halfspace(pos1.inverse(), &cone, &floor(), 0.0);
+    assert_eq!(contacts.len(), 1);
+    assert_relative_eq!(contacts[0].dist, -0.01, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        contacts[0].point1,
+        Vector3::new(-0.5, -1.0, 0.0),
+        epsilon = 1.0e-5
+    );
+
+    // With a larger prediction, an arc of the rim around the lowest point is in contact.
+    let contacts = contact_cone_halfspace(pos1.inverse(), &cone, &floor(), 0.1);
+    assert_eq!(contacts.len(), 3);
+    assert_relative_eq!(contacts[0].dist, -0.01, epsilon = 1.0e-5);
+}
+
+#[test]
+fn cone_lying_on_its_side() {
+    let cone = Cone::new(1.0, 0.5);
+    // Tilted until the segment between the apex and a rim point is horizontal.
+    let angle = real_consts::PI - (2.0 as Real).atan2(0.5);
+    let apex_height = angle.cos();
+    let pos1 = Isometry3::new(
+        Vector3::new(0.0, -apex_height - 0.01, 0.0),
+        Vector3::Z * angle,
+    );
+
+    let contacts = contact_cone_halfspace(pos1.inverse(), &cone, &floor(), 0.0);
+    assert_eq!(contacts.len(), 2);
+    for contact in &contacts {
+        assert_relative_eq!(contact.dist, -0.01, epsilon = 1.0e-5);
+    }
+
+    let flipped = contact_halfspace_cone(pos1, &floor(), &cone, 0.0);
+    assert_eq!(flipped.len(), 2);
+    for (contact, flipped) in contacts.iter().zip(flipped.iter()) {
+        assert_relative_eq!(contact.point1, flipped.point2, epsilon = 1.0e-5);
+        assert_relative_eq!(contact.point2, flipped.point1, epsilon = 1.0e-5);
+    }
+}
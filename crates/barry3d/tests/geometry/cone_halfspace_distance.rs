@@ -0,0 +1,55 @@
+use barry3d::math::{real_consts, Isometry3, UnitVector3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Cone, HalfSpace};
+
+fn ground() -> (Isometry3, HalfSpace) {
+    (Isometry3::IDENTITY, HalfSpace::new(UnitVector3::Y))
+}
+
+#[test]
+fn cone_apex_down_above_plane() {
+    let cone = Cone::new(1.0, 0.5);
+    let (plane_pos, plane) = ground();
+    let cone_pos = Isometry3::new(
+        Vector3::new(3.0, 1.5, -2.0),
+        Vector3::new(real_consts::PI, 0.0, 0.0),
+    );
+
+    let pos12 = cone_pos.inv_mul(plane_pos);
+    let dist = query::details::distance_cone_halfspace(pos12, &cone, &plane);
+    assert_relative_eq!(dist, 0.5, epsilon = 1.0e-5);
+
+    let dist = query::details::distance_halfspace_cone(pos12.inverse(), &plane, &cone);
+    assert_relative_eq!(dist, 0.5, epsilon = 1.0e-5);
+}
+
+#[test]
+fn cone_base_down_on_plane() {
+    let cone = Cone::new(1.0, 0.5);
+    let (plane_pos, plane) = ground();
+
+    let resting = Isometry3::from_xyz(1.0, 1.0, 0.0);
+    let dist = query::details::distance_cone_halfspace(resting.inv_mul(plane_pos), &cone, &plane);
+    assert_relative_eq!(dist, 0.0, epsilon = 1.0e-5);
+
+    let above = Isometry3::from_xyz(1.0, 1.2, 0.0);
+    let dist = query::details::distance_cone_halfspace(above.inv_mul(plane_pos), &cone, &plane);
+    assert_relative_eq!(dist, 0.2, epsilon = 1.0e-5);
+}
+
+#[test]
+fn tilted_cone_matches_support_map_distance() {
+    let cone = Cone::new(1.0, 0.5);
+    let (plane_pos, plane) = ground();
+
+    for angle in [0.1, 0.7, 1.5, 2.5, 3.0] {
+        let cone_pos = Isometry3::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.3, angle));
+        let pos12 = cone_pos.inv_mul(plane_pos);
+
+        assert_relative_eq!(
+            query::details::distance_cone_halfspace(pos12, &cone, &plane),
+            query::details::distance_support_map_halfspace(pos12, &cone, &plane),
+            epsilon = 1.0e-5
+        );
+    }
+}
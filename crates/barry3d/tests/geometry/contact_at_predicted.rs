@@ -0,0 +1,74 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn predicted_separation_decreases_for_approaching_shapes() {
+    let ball = Ball::new(0.5);
+    let pos1 = Isometry3::from_xyz(0.0, 0.0, 0.0);
+    let pos2 = Isometry3::from_xyz(3.0, 0.0, 0.0);
+    let vel1 = Vector3::new(1.0, 0.0, 0.0);
+    let vel2 = Vector3::new(-1.0, 0.0, 0.0);
+
+    let current = query::contact(pos1, &ball, pos2, &ball, 10.0)
+        .unwrap()
+        .unwrap();
+    let predicted = query::contact_at_predicted(pos1, vel1, &ball, pos2, vel2, &ball, 0.5, 10.0)
+        .unwrap()
+        .unwrap();
+
+    assert_relative_eq!(current.dist, 2.0, epsilon = 1.0e-5);
+    assert_relative_eq!(predicted.dist, 1.0, epsilon = 1.0e-5);
+    assert!(predicted.dist < current.dist);
+
+    // The contact is attached to the shapes at their current poses.
+    assert_relative_eq!(
+        predicted.point1,
+        Vector3::new(0.5, 0.0, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        predicted.point2,
+        Vector3::new(2.5, 0.0, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(*predicted.normal1, Vector3::X, epsilon = 1.0e-5);
+    assert_relative_eq!(*predicted.normal2, -Vector3::X, epsilon = 1.0e-5);
+
+    // Moving apart increases the predicted separation.
+    let receding = query::contact_at_predicted(pos1, -vel1, &ball, pos2, -vel2, &ball, 0.5, 10.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(receding.dist, 3.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn predicted_contact_beyond_prediction_distance() {
+    let ball = Ball::new(0.5);
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let pos1 = Isometry3::from_xyz(0.0, 2.0, 0.0);
+    let pos2 = Isometry3::IDENTITY;
+    let vel1 = Vector3::new(0.0, -2.0, 0.0);
+
+    // Currently separated by 1.0, touching after 0.5 and penetrating by 0.2 after 0.6.
+    assert!(
+        query::contact_at_predicted(pos1, vel1, &ball, pos2, Vector3::ZERO, &cuboid, 0.4, 0.1)
+            .unwrap()
+            .is_none()
+    );
+    let contact =
+        query::contact_at_predicted(pos1, vel1, &ball, pos2, Vector3::ZERO, &cuboid, 0.6, 0.1)
+            .unwrap()
+            .unwrap();
+    assert_relative_eq!(contact.dist, -0.2, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        contact.point1,
+        Vector3::new(0.0, 1.5, 0.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        contact.point2,
+        Vector3::new(0.0, 0.5, 0.0),
+        epsilon = 1.0e-5
+    );
+}
@@ -0,0 +1,39 @@
+use barry3d::math::{Isometry3, Real, UnitVector3, Vector3};
+use barry3d::query::Contact;
+
+// The contact between a ball of radius `r1` at the origin and a ball of radius `r2` centered
+// at `center2`, expressed in the frame where the first ball is at the origin.
+fn ball_ball_contact(r1: Real, r2: Real, center2: Vector3) -> Contact {
+    let n = UnitVector3::new(center2).unwrap();
+    Contact::new(
+        *n * r1,
+        center2 - *n * r2,
+        n,
+        -n,
+        center2.length() - r1 - r2,
+    )
+}
+
+fn assert_contacts_eq(a: &Contact, b: &Contact) {
+    assert_relative_eq!(a.point1, b.point1, epsilon = 1.0e-5);
+    assert_relative_eq!(a.point2, b.point2, epsilon = 1.0e-5);
+    assert_relative_eq!(*a.normal1, *b.normal1, epsilon = 1.0e-5);
+    assert_relative_eq!(*a.normal2, *b.normal2, epsilon = 1.0e-5);
+    assert_relative_eq!(a.dist, b.dist, epsilon = 1.0e-5);
+}
+
+#[test]
+fn contact_frame_conversions() {
+    let pos12 = Isometry3::new(Vector3::new(1.0, 0.5, -0.5), Vector3::new(-0.4, 0.1, 0.2));
+
+    // The same contact, seen from each ball.
+    let local1 = ball_ball_contact(1.0, 0.5, pos12.translation);
+    let local2 = ball_ball_contact(0.5, 1.0, pos12.inverse().translation).flipped();
+
+    assert_contacts_eq(&local1.to_frame2(pos12), &local2);
+    assert_contacts_eq(&local2.to_frame1(pos12), &local1);
+
+    // Round-trips.
+    assert_contacts_eq(&local1.to_frame2(pos12).to_frame1(pos12), &local1);
+    assert_contacts_eq(&local2.to_frame1(pos12).to_frame2(pos12), &local2);
+}
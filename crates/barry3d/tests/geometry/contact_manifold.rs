@@ -0,0 +1,85 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Compound, Cuboid, Shape, SharedShape};
+
+fn assert_deepest_matches_contact(
+    pos1: Isometry3,
+    g1: &dyn Shape,
+    pos2: Isometry3,
+    g2: &dyn Shape,
+    prediction: Real,
+) -> usize {
+    let contact = query::contact(pos1, g1, pos2, g2, prediction)
+        .unwrap()
+        .unwrap();
+    let manifold = query::contact_manifold(pos1, g1, pos2, g2, prediction)
+        .unwrap()
+        .unwrap();
+    let deepest = manifold.find_deepest_contact().unwrap();
+
+    assert_relative_eq!(deepest.dist, contact.dist, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        pos1.rotation * manifold.local_n1,
+        *contact.normal1,
+        epsilon = 1.0e-5
+    );
+    manifold.points.len()
+}
+
+#[test]
+fn contact_manifold_cuboid_cuboid_resting() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(0.2, 1.9, -0.3);
+
+    let num_points = assert_deepest_matches_contact(pos1, &cuboid, pos2, &cuboid, 0.0);
+    assert!(num_points > 1);
+}
+
+#[test]
+fn contact_manifold_ball_ball() {
+    let ball = Ball::new(1.0);
+    let pos1 = Isometry3::from_xyz(1.0, 2.0, 3.0);
+    let pos2 = Isometry3::from_xyz(1.0, 3.5, 3.0);
+
+    let num_points = assert_deepest_matches_contact(pos1, &ball, pos2, &ball, 0.0);
+    assert_eq!(num_points, 1);
+}
+
+#[test]
+fn contact_manifold_fallback_to_single_point() {
+    let parts = vec![
+        (
+            Isometry3::from_xyz(-2.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+        (
+            Isometry3::from_xyz(2.0, 0.0, 0.0),
+            SharedShape::cuboid(1.0, 1.0, 1.0),
+        ),
+    ];
+    let compound = Compound::new(parts);
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(2.0, 1.4, 0.0);
+
+    let num_points = assert_deepest_matches_contact(pos1, &compound, pos2, &cuboid, 0.0);
+    assert_eq!(num_points, 1);
+}
+
+#[test]
+fn contact_manifold_separated() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos2 = Isometry3::from_xyz(0.0, 3.0, 0.0);
+
+    assert!(
+        query::contact_manifold(Isometry3::IDENTITY, &cuboid, pos2, &cuboid, 0.5)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        query::contact_manifold(Isometry3::IDENTITY, &cuboid, pos2, &cuboid, 1.5)
+            .unwrap()
+            .is_some()
+    );
+}
@@ -0,0 +1,62 @@
+use barry3d::math::{Isometry3, Real, UnitVector3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn tilted_boxes_use_the_imposed_normal() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let angle: Real = 0.05;
+    let pos12 = Isometry3::new(Vector3::new(0.3, 1.45, -0.2), Vector3::Z * angle);
+
+    let manifold =
+        query::contact_manifold_along_normal(pos12, &ground, &cuboid, UnitVector3::Y, 0.1)
+            .unwrap()
+            .unwrap();
+
+    assert_eq!(manifold.local_n1, Vector3::Y);
+    assert_relative_eq!(
+        pos12.rotation * manifold.local_n2,
+        -Vector3::Y,
+        epsilon = 1.0e-6
+    );
+
+    // The four vertices of the bottom face of the tilted cuboid lie above the ground.
+    assert_eq!(manifold.points.len(), 4);
+    for contact in &manifold.points {
+        let pt2 = pos12.transform_point(contact.local_p2);
+        assert_relative_eq!(contact.local_p1.y, 0.5, epsilon = 1.0e-6);
+        assert_relative_eq!(contact.local_p1.x, pt2.x, epsilon = 1.0e-6);
+        assert_relative_eq!(contact.local_p1.z, pt2.z, epsilon = 1.0e-6);
+        assert_relative_eq!(contact.dist, pt2.y - 0.5, epsilon = 1.0e-6);
+    }
+
+    let deepest = manifold.find_deepest_contact().unwrap();
+    assert_relative_eq!(
+        deepest.dist,
+        0.95 - angle.cos() - angle.sin(),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn separated_along_the_imposed_normal() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos12 = Isometry3::new(Vector3::new(0.3, 1.6, -0.2), Vector3::Z * 0.05);
+
+    let manifold =
+        query::contact_manifold_along_normal(pos12, &ground, &cuboid, UnitVector3::Y, 0.0).unwrap();
+    assert!(manifold.is_none());
+}
+
+#[test]
+fn unsupported_shapes() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(0.0, 1.4, 0.0);
+
+    assert!(
+        query::contact_manifold_along_normal(pos12, &ground, &ball, UnitVector3::Y, 0.0).is_err()
+    );
+}
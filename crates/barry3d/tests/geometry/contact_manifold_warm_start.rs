@@ -0,0 +1,55 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::details::contact_manifold_cuboid_cuboid;
+use barry3d::query::ContactManifold;
+use barry3d::shape::Cuboid;
+
+fn cuboid_manifold(
+    cuboid1: &Cuboid,
+    cuboid2: &Cuboid,
+    pos12: Isometry3,
+) -> ContactManifold<(), Real> {
+    let mut manifold = ContactManifold::new();
+    contact_manifold_cuboid_cuboid(pos12, cuboid1, cuboid2, 0.0, &mut manifold);
+    manifold
+}
+
+#[test]
+fn match_and_carry_warm_starts_persistent_contacts() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+
+    // First frame: the small box rests on the top face of the large box.
+    let mut previous = cuboid_manifold(&cuboid1, &cuboid2, Isometry3::from_xyz(0.0, 1.45, 0.0));
+    assert_eq!(previous.points.len(), 4);
+    for (i, pt) in previous.points.iter_mut().enumerate() {
+        pt.data = 1.0 + i as Real;
+    }
+
+    // Second frame: the small box slid so that it overhangs the `+X` side of the large box.
+    // Its two vertices on the `-X` side still touch the large box, but the other two vertices
+    // are replaced by edge-edge contacts along the edge of the large box.
+    let mut current = cuboid_manifold(&cuboid1, &cuboid2, Isometry3::from_xyz(0.8, 1.45, 0.0));
+    assert!(!current.points.is_empty());
+    current.match_and_carry(&previous, 0.1);
+
+    let mut num_carried = 0;
+    let mut num_dropped = 0;
+
+    for pt in &current.points {
+        let old = previous
+            .points
+            .iter()
+            .find(|old| old.fid1 == pt.fid1 && old.fid2 == pt.fid2);
+
+        if let Some(old) = old {
+            assert_eq!(pt.data, old.data);
+            num_carried += 1;
+        } else {
+            assert_eq!(pt.data, 0.0);
+            num_dropped += 1;
+        }
+    }
+
+    assert_eq!(num_carried, 2);
+    assert_eq!(num_dropped, current.points.len() - 2);
+}
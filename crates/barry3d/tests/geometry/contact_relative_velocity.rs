@@ -0,0 +1,56 @@
+use barry3d::math::{UnitVector3, Vector3};
+use barry3d::query::Contact;
+
+// A contact between two unit cubes stacked along `y`, with lever arms relative to their centers.
+fn stacked_cubes_contact() -> Contact {
+    Contact::new(
+        Vector3::new(0.5, 0.5, 0.0),
+        Vector3::new(0.5, -0.5, 0.0),
+        UnitVector3::Y,
+        UnitVector3::NEG_Y,
+        0.0,
+    )
+}
+
+#[test]
+fn relative_velocity_pure_translation() {
+    let contact = stacked_cubes_contact();
+    let vel = contact.relative_velocity(
+        Vector3::new(1.0, 2.0, 0.0),
+        Vector3::ZERO,
+        Vector3::new(1.0, 5.0, -1.0),
+        Vector3::ZERO,
+    );
+    assert_relative_eq!(vel, Vector3::new(0.0, 3.0, -1.0));
+}
+
+#[test]
+fn relative_velocity_pure_rotation_about_contact() {
+    // Both bodies rotate about an axis passing through the contact point, so the contact
+    // point itself does not move.
+    let mut contact = stacked_cubes_contact();
+    contact.point1 = Vector3::ZERO;
+    contact.point2 = Vector3::ZERO;
+
+    let vel = contact.relative_velocity(
+        Vector3::ZERO,
+        Vector3::new(0.0, 0.0, 2.0),
+        Vector3::ZERO,
+        Vector3::new(1.0, -3.0, 0.5),
+    );
+    assert_relative_eq!(vel, Vector3::ZERO);
+}
+
+#[test]
+fn relative_velocity_combined_motion() {
+    let contact = stacked_cubes_contact();
+    let linvel1 = Vector3::new(0.0, 1.0, 0.0);
+    let angvel1 = Vector3::new(0.0, 0.0, 2.0);
+    let linvel2 = Vector3::new(1.0, 0.0, 0.0);
+    let angvel2 = Vector3::new(0.0, 1.0, 0.0);
+
+    // Body 1: (0, 1, 0) + (0, 0, 2) x (0.5, 0.5, 0) = (-1, 2, 0).
+    // Body 2: (1, 0, 0) + (0, 1, 0) x (0.5, -0.5, 0) = (1, 0, -0.5).
+    let vel = contact.relative_velocity(linvel1, angvel1, linvel2, angvel2);
+    assert_relative_eq!(vel, Vector3::new(2.0, -2.0, -0.5));
+}
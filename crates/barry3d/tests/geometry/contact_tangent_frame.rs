@@ -0,0 +1,38 @@
+use barry3d::math::{UnitVector3, Vector3};
+use barry3d::query::Contact;
+
+fn contact_with_normal(normal: Vector3) -> Contact {
+    let n = UnitVector3::new(normal).unwrap();
+    Contact::new(Vector3::ZERO, Vector3::ZERO, n, -n, 0.0)
+}
+
+#[test]
+fn first_tangent_follows_tangential_velocity() {
+    let contact = contact_with_normal(Vector3::new(0.0, 1.0, 1.0));
+    let normal = *contact.normal1;
+    let rel_vel = Vector3::new(2.0, -1.0, 3.0);
+    let [t1, t2] = contact.tangent_frame_along(rel_vel);
+
+    let tangent_vel = rel_vel - normal * rel_vel.dot(normal);
+    assert_relative_eq!(*t1, tangent_vel.normalize(), epsilon = 1.0e-6);
+
+    // The tangents and the normal form a right-handed orthonormal basis.
+    assert_relative_eq!(t1.dot(normal), 0.0, epsilon = 1.0e-6);
+    assert_relative_eq!(t2.dot(normal), 0.0, epsilon = 1.0e-6);
+    assert_relative_eq!(t1.dot(*t2), 0.0, epsilon = 1.0e-6);
+    assert_relative_eq!(t2.length(), 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(t1.cross(*t2), normal, epsilon = 1.0e-6);
+}
+
+#[test]
+fn normal_aligned_velocity_gives_arbitrary_tangents() {
+    let contact = contact_with_normal(Vector3::new(1.0, 2.0, -0.5));
+    let normal = *contact.normal1;
+
+    for rel_vel in [normal * 3.0, Vector3::ZERO] {
+        let [t1, t2] = contact.tangent_frame_along(rel_vel);
+        assert_relative_eq!(t1.length(), 1.0, epsilon = 1.0e-6);
+        assert_relative_eq!(t1.dot(normal), 0.0, epsilon = 1.0e-6);
+        assert_relative_eq!(t1.cross(*t2), normal, epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,44 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn ball_inside_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 1.0, 3.0));
+    let ball = Ball::new(0.5);
+    let pos1 = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.2, 0.7));
+
+    let inside = pos1 * Isometry3::from_xyz(1.4, 0.4, -2.4);
+    assert!(query::contains_shape(pos1, &cuboid, inside, &ball));
+    // The ball still intersects the cuboid, but is no longer contained.
+    let poking_out = pos1 * Isometry3::from_xyz(1.4, 0.7, -2.4);
+    assert!(query::intersection_test(pos1, &cuboid, poking_out, &ball).unwrap());
+    assert!(!query::contains_shape(pos1, &cuboid, poking_out, &ball));
+}
+
+#[test]
+fn cuboid_inside_ball() {
+    let ball = Ball::new(2.0);
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let pos1 = Isometry3::from_xyz(3.0, 0.0, 0.0);
+
+    assert!(query::contains_shape(
+        pos1,
+        &ball,
+        Isometry3::from_xyz(3.5, 0.5, 0.0),
+        &cuboid
+    ));
+    assert!(!query::contains_shape(
+        pos1,
+        &ball,
+        Isometry3::from_xyz(4.5, 0.0, 0.0),
+        &cuboid
+    ));
+    // The big shape can never be contained in the small one.
+    assert!(!query::contains_shape(
+        Isometry3::IDENTITY,
+        &cuboid,
+        Isometry3::IDENTITY,
+        &ball
+    ));
+}
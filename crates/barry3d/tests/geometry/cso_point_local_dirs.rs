@@ -0,0 +1,37 @@
+use barry3d::math::{Isometry3, UnitVector3, Vector3};
+use barry3d::query::gjk::CSOPoint;
+use barry3d::shape::{Cone, Cuboid, Cylinder, SupportMap};
+
+use super::rand_vector;
+
+#[test]
+fn cso_point_from_local_dirs_matches_support_points() {
+    let mut rng = oorandom::Rand32::new(42);
+
+    let cuboid = Cuboid::new(Vector3::new(0.5, 1.0, 1.5));
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let cone = Cone::new(0.5, 1.0);
+    let shapes: [&dyn SupportMap; 3] = [&cuboid, &cylinder, &cone];
+
+    for _ in 0..100 {
+        let pos12 = Isometry3::new(rand_vector(&mut rng, 5.0), rand_vector(&mut rng, 3.0));
+        let rot21 = pos12.rotation.inverse();
+        let dir = match UnitVector3::new(rand_vector(&mut rng, 1.0)) {
+            Ok(dir) => dir,
+            Err(_) => continue,
+        };
+
+        for g1 in shapes {
+            for g2 in shapes {
+                let expected1 = g1.local_support_point(*dir);
+                let expected2 = g2.support_point(pos12, -*dir);
+
+                let pt = CSOPoint::from_local_dirs(pos12, g1, g2, *dir, rot21 * -*dir);
+                assert_eq!(pt.orig1, expected1);
+                assert_eq!(pt.orig2, expected2);
+                assert_eq!(pt.point, expected1 - expected2);
+                assert_eq!(pt, CSOPoint::from_shapes(pos12, g1, g2, dir));
+            }
+        }
+    }
+}
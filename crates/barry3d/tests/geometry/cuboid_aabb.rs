@@ -0,0 +1,31 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Cuboid, Shape};
+
+use super::rand_vector;
+
+fn aabb_from_transformed_vertices(cuboid: &Cuboid, pos: Isometry3) -> Aabb {
+    let vertices = cuboid
+        .local_aabb()
+        .vertices()
+        .map(|pt| pos.transform_point(pt));
+    Aabb::from_points(&vertices)
+}
+
+#[test]
+fn cuboid_aabb_matches_transformed_vertices() {
+    let mut rng = oorandom::Rand32::new(42);
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+
+    for _ in 0..1000 {
+        let translation = rand_vector(&mut rng, 5.0);
+        let axisangle = rand_vector(&mut rng, 3.0);
+        let pos = Isometry3::new(translation, axisangle);
+
+        let aabb = cuboid.compute_aabb(pos);
+        let expected = aabb_from_transformed_vertices(&cuboid, pos);
+
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-4);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-4);
+    }
+}
@@ -0,0 +1,62 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::details::contact_manifold_cuboid_ball;
+use barry3d::query::ContactManifold;
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn cuboid_ball_single_point_by_default() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(0.3, 1.49, -0.2);
+    let mut manifold = ContactManifold::<(), ()>::new();
+
+    contact_manifold_cuboid_ball(pos12, &cuboid, &ball, 0.0, 0.0, &mut manifold, false);
+
+    assert_eq!(manifold.points.len(), 1);
+    assert_relative_eq!(manifold.points[0].dist, -0.01, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        manifold.points[0].local_p1,
+        Vector3::new(0.3, 0.5, -0.2),
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn cuboid_ball_contact_patch() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(0.3, 1.49, -0.2);
+    let mut manifold = ContactManifold::<(), ()>::new();
+
+    contact_manifold_cuboid_ball(pos12, &cuboid, &ball, 0.0, 0.1, &mut manifold, false);
+
+    assert_eq!(manifold.points.len(), 4);
+    assert_relative_eq!(manifold.local_n1, Vector3::Y, epsilon = 1.0e-5);
+
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, -0.01, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.local_p1.y, 0.5, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            pt.local_p1.distance(Vector3::new(0.3, 0.5, -0.2)),
+            0.1,
+            epsilon = 1.0e-5
+        );
+        // The ball-side points are offset by the same amount, in the ball’s local-space.
+        assert_relative_eq!(
+            pos12.transform_point(pt.local_p2) - pt.local_p1,
+            Vector3::new(0.0, -0.01, 0.0),
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn cuboid_ball_contact_patch_not_fitting_on_face() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(1.95, 1.49, 0.0);
+    let mut manifold = ContactManifold::<(), ()>::new();
+
+    contact_manifold_cuboid_ball(pos12, &cuboid, &ball, 0.0, 0.1, &mut manifold, false);
+    assert_eq!(manifold.points.len(), 1);
+}
@@ -0,0 +1,151 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{self, TOIStatus};
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn cuboid_face_swept_into_ball() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let ball = Ball::new(1.0);
+    let pos_cuboid = Isometry3::from_xyz(-5.0, 0.0, 0.0);
+    let pos_ball = Isometry3::IDENTITY;
+    let vel_cuboid = Vector3::X;
+
+    let toi = query::time_of_impact(
+        pos_cuboid,
+        vel_cuboid,
+        &cuboid,
+        pos_ball,
+        Vector3::ZERO,
+        &ball,
+        10.0,
+        true,
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_ne!(toi.status, TOIStatus::Penetrating);
+    assert_relative_eq!(toi.toi, 3.5, epsilon = 1.0e-3);
+    assert_relative_eq!(toi.witness2, -Vector3::X, epsilon = 1.0e-3);
+    assert_relative_eq!(toi.witness1.x, 0.5, epsilon = 1.0e-3);
+    assert_relative_eq!(*toi.normal1, Vector3::X, epsilon = 1.0e-3);
+    assert_relative_eq!(*toi.normal2, -Vector3::X, epsilon = 1.0e-3);
+}
+
+#[test]
+fn cuboid_edge_swept_into_ball() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let ball = Ball::new(1.0);
+    // The bottom edge of the cuboid, at `y = 0.7`, hits the ball off its center.
+    let pos_cuboid = Isometry3::from_xyz(-5.0, 1.2, 0.0);
+    let pos_ball = Isometry3::IDENTITY;
+
+    let toi = query::time_of_impact(
+        pos_cuboid,
+        Vector3::X,
+        &cuboid,
+        pos_ball,
+        Vector3::ZERO,
+        &ball,
+        10.0,
+        true,
+    )
+    .unwrap()
+    .unwrap();
+
+    let hit_x: Real = -Real::sqrt(1.0 - 0.7 * 0.7);
+    assert_relative_eq!(toi.toi, 4.5 + hit_x, epsilon = 1.0e-3);
+
+    // The witness point on the ball lies on its surface, where the edge touches it.
+    assert_relative_eq!(toi.witness2.length(), ball.radius, epsilon = 1.0e-3);
+    assert_relative_eq!(
+        toi.witness2,
+        Vector3::new(hit_x, 0.7, 0.0),
+        epsilon = 1.0e-3
+    );
+    assert_relative_eq!(*toi.normal2, toi.witness2.normalize(), epsilon = 1.0e-3);
+
+    // The witness point on the cuboid is on its bottom-right edge, in its local-space.
+    assert_relative_eq!(toi.witness1.x, 0.5, epsilon = 1.0e-3);
+    assert_relative_eq!(toi.witness1.y, -0.5, epsilon = 1.0e-3);
+}
+
+#[test]
+fn cuboid_swept_past_ball() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let ball = Ball::new(1.0);
+    let pos_cuboid = Isometry3::from_xyz(-5.0, 2.0, 0.0);
+
+    let toi = query::time_of_impact(
+        pos_cuboid,
+        Vector3::X,
+        &cuboid,
+        Isometry3::IDENTITY,
+        Vector3::ZERO,
+        &ball,
+        10.0,
+        true,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+}
+
+#[test]
+fn cast_cuboid_edge_into_ball() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let ball = Ball::new(1.0);
+    let pos_ball = Isometry3::from_xyz(1.0, -2.0, 3.0);
+    let pos_cuboid = pos_ball * Isometry3::from_xyz(-5.0, 1.2, 0.0);
+
+    let hit = query::cast_shape_single(pos_cuboid, Vector3::X, &cuboid, pos_ball, &ball, 10.0)
+        .unwrap()
+        .unwrap();
+
+    let hit_x: Real = -Real::sqrt(1.0 - 0.7 * 0.7);
+    assert_relative_eq!(hit.toi, 4.5 + hit_x, epsilon = 1.0e-3);
+
+    // The witness points are in world-space, and the one on the ball lies on its surface.
+    let witness2 = pos_ball.inverse_transform_point(hit.witness2);
+    assert_relative_eq!(witness2.length(), ball.radius, epsilon = 1.0e-3);
+    assert_relative_eq!(witness2, Vector3::new(hit_x, 0.7, 0.0), epsilon = 1.0e-3);
+    assert_relative_eq!(hit.witness1, hit.witness2, epsilon = 1.0e-3);
+    assert_relative_eq!(*hit.normal, -witness2.normalize(), epsilon = 1.0e-3);
+}
+
+#[test]
+fn cast_cuboid_past_ball() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let ball = Ball::new(1.0);
+
+    let hit = query::cast_shape_single(
+        Isometry3::from_xyz(-5.0, 2.0, 0.0),
+        Vector3::X,
+        &cuboid,
+        Isometry3::IDENTITY,
+        &ball,
+        10.0,
+    )
+    .unwrap();
+    assert!(hit.is_none());
+}
+
+#[test]
+fn cast_cuboid_penetrating_ball() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let ball = Ball::new(1.0);
+
+    let hit = query::cast_shape_single(
+        Isometry3::from_xyz(-1.2, 0.0, 0.0),
+        Vector3::X,
+        &cuboid,
+        Isometry3::IDENTITY,
+        &ball,
+        10.0,
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(hit.toi, 0.0);
+    assert_relative_eq!(*hit.normal, Vector3::X, epsilon = 1.0e-3);
+    assert_relative_eq!(hit.witness1, Vector3::new(-0.7, 0.0, 0.0), epsilon = 1.0e-3);
+    assert_relative_eq!(hit.witness2, -Vector3::X, epsilon = 1.0e-3);
+}
@@ -0,0 +1,46 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{self, sat};
+use barry3d::shape::{Capsule, Cuboid};
+
+use super::rand_vector;
+
+#[test]
+fn cuboid_capsule_separation_above_face() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let capsule = Capsule::new(
+        Vector3::new(-1.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        0.5,
+    );
+    let pos12 = Isometry3::from_xyz(0.5, 3.0, 0.0);
+
+    let (separation, dir) = sat::cuboid_capsule_separation(pos12, &cuboid, &capsule);
+    assert_relative_eq!(separation, 0.5, epsilon = 1.0e-5);
+    assert_relative_eq!(dir, Vector3::Y, epsilon = 1.0e-5);
+}
+
+#[test]
+fn cuboid_capsule_separation_matches_gjk() {
+    let mut rng = oorandom::Rand32::new(42);
+    let mut num_tested = 0;
+
+    for _ in 0..200 {
+        let cuboid = Cuboid::new((rand_vector(&mut rng, 1.0) + Vector3::splat(1.2)) * 0.5);
+        let capsule = Capsule::new(rand_vector(&mut rng, 1.0), rand_vector(&mut rng, 1.0), 0.2);
+        let pos12 = Isometry3::new(rand_vector(&mut rng, 4.0), rand_vector(&mut rng, 3.0));
+
+        let gjk_dist = query::distance(Isometry3::IDENTITY, &cuboid, pos12, &capsule).unwrap();
+
+        if gjk_dist < 1.0e-2 {
+            // Only separated configurations are compared.
+            continue;
+        }
+
+        let (separation, dir) = sat::cuboid_capsule_separation(pos12, &cuboid, &capsule);
+        assert_relative_eq!(separation, gjk_dist, epsilon = 1.0e-3);
+        assert_relative_eq!(dir.length(), 1.0, epsilon = 1.0e-5);
+        num_tested += 1;
+    }
+
+    assert!(num_tested > 50);
+}
@@ -0,0 +1,58 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::details::contact_manifold_cuboid_cuboid_with_normal_snapping;
+use barry3d::query::ContactManifold;
+use barry3d::shape::Cuboid;
+
+// A wide box resting on a small box, tilted by `angle` around `Z`, with its bottom face
+// penetrating the top face of the small box by 0.01 at the center.
+fn tilted_manifold(angle: Real, max_snap_angle: Real) -> ContactManifold<(), ()> {
+    let small = Cuboid::new(Vector3::splat(0.5));
+    let wide = Cuboid::new(Vector3::new(3.0, 0.5, 3.0));
+    let rotation = Vector3::Z * angle;
+    let pos12 = Isometry3::new(
+        Vector3::new(-0.5 * angle.sin(), 0.49 + 0.5 * angle.cos(), 0.0),
+        rotation,
+    );
+
+    let mut manifold = ContactManifold::new();
+    contact_manifold_cuboid_cuboid_with_normal_snapping(
+        pos12,
+        &small,
+        &wide,
+        0.0,
+        max_snap_angle,
+        &mut manifold,
+    );
+    manifold
+}
+
+#[test]
+fn snapped_normal_stays_constant_within_tolerance() {
+    let max_snap_angle = 0.05;
+
+    for angle in [0.0, 0.01, 0.02, 0.04, -0.03] {
+        let manifold = tilted_manifold(angle, max_snap_angle);
+        assert!(!manifold.points.is_empty());
+        assert_eq!(manifold.local_n1, Vector3::Y);
+    }
+
+    // Beyond the tolerance, the normal follows the tilted face of the wide box.
+    let manifold = tilted_manifold(0.1, max_snap_angle);
+    assert!(!manifold.points.is_empty());
+    assert_relative_eq!(
+        manifold.local_n1.angle_between(Vector3::Y),
+        0.1,
+        epsilon = 1.0e-3
+    );
+}
+
+#[test]
+fn unsnapped_normal_follows_small_rotations() {
+    let manifold = tilted_manifold(0.01, 0.0);
+    assert!(!manifold.points.is_empty());
+    assert_relative_eq!(
+        manifold.local_n1.angle_between(Vector3::Y),
+        0.01,
+        epsilon = 1.0e-3
+    );
+}
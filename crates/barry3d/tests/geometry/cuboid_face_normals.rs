@@ -0,0 +1,18 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{Cuboid, FeatureId, SupportMap};
+
+#[test]
+fn cuboid_face_normals_match_faces() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let normals = Cuboid::face_normals();
+
+    for (i, normal) in normals.iter().enumerate() {
+        // The support point in the direction of the normal lies on the corresponding face.
+        let support_pt = cuboid.local_support_point(**normal);
+        assert_eq!(support_pt.dot(**normal), cuboid.half_extents[i % 3]);
+        assert_eq!(
+            cuboid.feature_normal(FeatureId::Face(i as u32)),
+            Some(*normal)
+        );
+    }
+}
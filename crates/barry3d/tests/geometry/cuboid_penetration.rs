@@ -0,0 +1,59 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{real_consts, Isometry3, Real, Vector3};
+use barry3d::query;
+use barry3d::shape::Cuboid;
+
+fn voxel() -> Aabb {
+    Aabb::new(Vector3::splat(-1.0), Vector3::splat(1.0))
+}
+
+fn check_penetration(pos: Isometry3, cuboid: &Cuboid, dir: Vector3, depth: Real) {
+    let (mtv_dir, mtv_depth) =
+        query::cuboid_penetration(pos, cuboid, &voxel()).expect("Penetration not found.");
+    assert_relative_eq!(mtv_dir, dir, epsilon = 1.0e-5);
+    assert_relative_eq!(mtv_depth, depth, epsilon = 1.0e-5);
+
+    // Applying the translation separates the shapes.
+    let separated = Isometry3 {
+        translation: pos.translation + mtv_dir * (mtv_depth + 1.0e-3),
+        ..pos
+    };
+    assert!(query::cuboid_penetration(separated, cuboid, &voxel()).is_none());
+}
+
+#[test]
+fn face_penetration() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let pos = Isometry3::from_xyz(0.2, -0.2, 1.3);
+    check_penetration(pos, &cuboid, Vector3::Z, 0.2);
+}
+
+#[test]
+fn edge_penetration() {
+    // The cuboid rests on one of its edges, which is parallel to `x`.
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let lowest = 0.5 * (2.0 as Real).sqrt();
+    let pos = Isometry3::new(
+        Vector3::new(0.0, 1.0 + lowest - 0.1, 0.0),
+        Vector3::X * real_consts::FRAC_PI_4,
+    );
+    check_penetration(pos, &cuboid, Vector3::Y, 0.1);
+}
+
+#[test]
+fn corner_penetration() {
+    // The cuboid is rotated so that its `(1, 1, 1)` corner points downward.
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let lowest = 0.5 * (3.0 as Real).sqrt();
+    let axis = Vector3::new(1.0, 0.0, -1.0).normalize();
+    let angle = (-1.0 / (3.0 as Real).sqrt()).acos();
+    let pos = Isometry3::new(Vector3::new(0.0, 1.0 + lowest - 0.1, 0.0), axis * angle);
+    check_penetration(pos, &cuboid, Vector3::Y, 0.1);
+}
+
+#[test]
+fn separated_cuboid() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let pos = Isometry3::from_xyz(1.6, 0.0, 0.0);
+    assert!(query::cuboid_penetration(pos, &cuboid, &voxel()).is_none());
+}
@@ -1,7 +1,7 @@
 // https://github.com/dimforge/barry/issues/242
 
 use barry3d::math::{Isometry3, Rotation3, Vector3};
-use barry3d::query::Ray;
+use barry3d::query::{Ray, RayCast};
 use barry3d::shape::{Ball, Cuboid, Shape};
 use bevy_math::Quat;
 
@@ -87,3 +87,29 @@ fn shape_ray_cast_points_to_surface() {
         Cuboid::new(Vector3::new(0.5, 1.0, 0.5)),
     );
 }
+
+#[test]
+fn cuboid_ray_cast_with_min_toi_skips_surface_hit() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let ray = Ray::new(Vector3::new(1.0001, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+
+    let toi = cuboid.cast_local_ray(&ray, std::f32::MAX, false).unwrap();
+    assert_relative_eq!(toi, 0.0001, epsilon = 1.0e-5);
+
+    // The entry hit is skipped so we get the exit point instead.
+    let toi = cuboid
+        .cast_local_ray_with_range(&ray, 0.01, std::f32::MAX, false)
+        .unwrap();
+    assert_relative_eq!(toi, 2.0001, epsilon = 1.0e-5);
+
+    // With a solid cast, the shifted origin is inside of the cuboid.
+    let toi = cuboid
+        .cast_local_ray_with_range(&ray, 0.01, std::f32::MAX, true)
+        .unwrap();
+    assert_relative_eq!(toi, 0.01, epsilon = 1.0e-5);
+
+    // The range doesn't contain any hit.
+    assert!(cuboid
+        .cast_local_ray_with_range(&ray, 0.01, 1.0, false)
+        .is_none());
+}
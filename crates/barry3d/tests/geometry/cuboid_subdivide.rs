@@ -0,0 +1,52 @@
+use approx::assert_relative_eq;
+use barry3d::math::{Real, Vector3};
+use barry3d::shape::Cuboid;
+
+#[test]
+fn cuboid_subdivision_tiles_the_original() {
+    let cuboid = Cuboid::new(Vector3::new(1.5, 0.5, 2.0));
+    let counts = [3, 1, 4];
+    let cells = cuboid.subdivide(counts);
+    assert_eq!(cells.len(), 12);
+
+    let bounds: Vec<_> = cells
+        .iter()
+        .map(|(pos, cell)| {
+            (
+                pos.translation - cell.half_extents,
+                pos.translation + cell.half_extents,
+            )
+        })
+        .collect();
+
+    // Every cell lies inside of the original cuboid.
+    for (mins, maxs) in &bounds {
+        assert!(mins.cmpge(-cuboid.half_extents - 1.0e-5).all());
+        assert!(maxs.cmple(cuboid.half_extents + 1.0e-5).all());
+    }
+
+    // The cells don't overlap.
+    for (i, (mins1, maxs1)) in bounds.iter().enumerate() {
+        for (mins2, maxs2) in &bounds[i + 1..] {
+            let overlap = (maxs1.min(*maxs2) - mins1.max(*mins2)).max(Vector3::ZERO);
+            assert_relative_eq!(overlap.x * overlap.y * overlap.z, 0.0, epsilon = 1.0e-5);
+        }
+    }
+
+    // Together, they fill the original cuboid.
+    let volume = |hext: Vector3| 8.0 * hext.x * hext.y * hext.z;
+    let total: Real = cells
+        .iter()
+        .map(|(_, cell)| volume(cell.half_extents))
+        .sum();
+    assert_relative_eq!(total, volume(cuboid.half_extents), epsilon = 1.0e-4);
+
+    let mins = bounds
+        .iter()
+        .fold(Vector3::MAX, |acc, (mins, _)| acc.min(*mins));
+    let maxs = bounds
+        .iter()
+        .fold(Vector3::MIN, |acc, (_, maxs)| acc.max(*maxs));
+    assert_relative_eq!(mins, -cuboid.half_extents, epsilon = 1.0e-5);
+    assert_relative_eq!(maxs, cuboid.half_extents, epsilon = 1.0e-5);
+}
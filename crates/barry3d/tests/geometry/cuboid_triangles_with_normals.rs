@@ -0,0 +1,23 @@
+use barry3d::math::Vector3;
+use barry3d::shape::Cuboid;
+
+#[test]
+fn cuboid_triangles_with_normals() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let triangles: Vec<_> = cuboid.triangles_with_normals().collect();
+    assert_eq!(triangles.len(), 12);
+
+    for (triangle, normal) in triangles {
+        // The normal matches the triangle's winding.
+        assert_relative_eq!(*triangle.normal().unwrap(), *normal, epsilon = 1.0e-6);
+
+        // All the vertices lie on the face with that normal.
+        for pt in [triangle.a, triangle.b, triangle.c] {
+            assert_relative_eq!(
+                pt.dot(*normal),
+                cuboid.half_extents.dot(normal.abs()),
+                epsilon = 1.0e-6
+            );
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{self, details, ContactManifold};
+use barry3d::shape::{CoordinateAxis, Cuboid, Cylinder, CylinderFeature, PackedFeatureId};
+
+fn manifold(
+    pos1: Isometry3,
+    cylinder: &Cylinder,
+    pos2: Isometry3,
+    cuboid: &Cuboid,
+    prediction: Real,
+) -> ContactManifold<(), ()> {
+    let mut manifold = ContactManifold::new();
+    details::contact_manifold_cylinder_cuboid(
+        pos1.inv_mul(pos2),
+        cylinder,
+        cuboid,
+        prediction,
+        &mut manifold,
+        false,
+    );
+    manifold
+}
+
+#[test]
+fn cylinder_cap_on_cuboid_face() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let pos1 = Isometry3::from_xyz(0.3, 1.49, -0.2);
+
+    let manifold = manifold(pos1, &cylinder, Isometry3::IDENTITY, &cuboid, 0.1);
+    assert_relative_eq!(manifold.local_n1, -Vector3::Y, epsilon = 1.0e-4);
+    assert_eq!(
+        manifold.points.len(),
+        CylinderFeature::NUM_CAP_VERTICES,
+        "{:?}",
+        manifold.points
+    );
+
+    for pt in &manifold.points {
+        assert_relative_eq!(pt.dist, -0.01, epsilon = 1.0e-4);
+        assert_relative_eq!(pt.local_p1.y, -1.0, epsilon = 1.0e-4);
+        assert_relative_eq!(pt.local_p2.y, 0.5, epsilon = 1.0e-4);
+        // The bottom cap of the cylinder touches the top face of the cuboid.
+        assert_eq!(pt.fid1, PackedFeatureId::face(9));
+        assert!(pt.fid2.is_face());
+    }
+}
+
+#[test]
+fn cylinder_side_on_cuboid_face() {
+    let cylinder = Cylinder::with_axis(1.0, 0.5, CoordinateAxis::X);
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+
+    for dx in [0.0, 1.0e-3, 2.0e-3] {
+        let pos1 = Isometry3::from_xyz(dx, 0.99, 0.0);
+        let manifold = manifold(pos1, &cylinder, Isometry3::IDENTITY, &cuboid, 0.1);
+
+        assert_relative_eq!(manifold.local_n1, -Vector3::Y, epsilon = 1.0e-4);
+        assert_eq!(manifold.points.len(), 2);
+
+        let mut xs: Vec<_> = manifold.points.iter().map(|pt| pt.local_p1.x).collect();
+        xs.sort_by(Real::total_cmp);
+        assert_relative_eq!(xs[0], -1.0, epsilon = 1.0e-4);
+        assert_relative_eq!(xs[1], 1.0, epsilon = 1.0e-4);
+
+        for pt in &manifold.points {
+            assert_relative_eq!(pt.dist, -0.01, epsilon = 1.0e-4);
+        }
+    }
+}
+
+// Issue #157.
+#[test]
+fn cylinder_cuboid_issue_157_manifold() {
+    let cylinder = Cylinder::new(0.925, 0.5);
+    let cylinder_at = Isometry3::from_xyz(10.97, 0.925, 61.02);
+    let cuboid = Cuboid::new(Vector3::new(0.05, 0.75, 0.5));
+    let cuboid_at = Isometry3::from_xyz(11.50, 0.75, 60.5);
+    let prediction = 0.1;
+
+    let manifold1 = query::contact_manifold(cylinder_at, &cylinder, cuboid_at, &cuboid, prediction)
+        .unwrap()
+        .unwrap();
+    assert!(!manifold1.points.is_empty());
+
+    for pt in &manifold1.points {
+        assert!(pt.dist <= prediction && pt.dist > -0.05);
+    }
+
+    // Both argument orders and a slightly moved configuration give consistent manifolds.
+    let manifold2 = query::contact_manifold(cuboid_at, &cuboid, cylinder_at, &cylinder, prediction)
+        .unwrap()
+        .unwrap();
+    assert_eq!(manifold1.points.len(), manifold2.points.len());
+    assert_relative_eq!(manifold1.local_n1, manifold2.local_n2, epsilon = 1.0e-4);
+
+    let moved_at = Isometry3::from_xyz(10.97, 0.9255, 61.02);
+    let manifold3 = query::contact_manifold(moved_at, &cylinder, cuboid_at, &cuboid, prediction)
+        .unwrap()
+        .unwrap();
+    assert_eq!(manifold1.points.len(), manifold3.points.len());
+    assert_relative_eq!(manifold1.local_n1, manifold3.local_n1, epsilon = 1.0e-3);
+}
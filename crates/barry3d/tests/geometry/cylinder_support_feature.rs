@@ -0,0 +1,73 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{CoordinateAxis, Cylinder, CylinderFeature};
+
+#[test]
+fn axial_direction_gives_cap() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+
+    for sign in [1.0, -1.0] {
+        let CylinderFeature::Cap(vertices) = cylinder.support_feature(Vector3::Y * sign) else {
+            panic!("Expected a cap.");
+        };
+
+        for (k, v) in vertices.iter().enumerate() {
+            let next = vertices[(k + 1) % vertices.len()];
+            let next2 = vertices[(k + 2) % vertices.len()];
+
+            assert_relative_eq!(v.y, sign, epsilon = 1.0e-6);
+            assert_relative_eq!(Vector3::new(v.x, 0.0, v.z).length(), 0.5, epsilon = 1.0e-6);
+            // Counterclockwise when seen from outside.
+            assert!((next - *v).cross(next2 - next).y * sign > 0.0);
+        }
+    }
+}
+
+#[test]
+fn radial_direction_gives_lateral_segment() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let CylinderFeature::Lateral(segment) = cylinder.support_feature(Vector3::new(0.0, 0.0, -2.0))
+    else {
+        panic!("Expected a lateral segment.");
+    };
+
+    assert_relative_eq!(segment.a, Vector3::new(0.0, -1.0, -0.5), epsilon = 1.0e-6);
+    assert_relative_eq!(segment.b, Vector3::new(0.0, 1.0, -0.5), epsilon = 1.0e-6);
+}
+
+#[test]
+fn oblique_direction_gives_rim_segment() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let CylinderFeature::Rim(segment) = cylinder.support_feature(Vector3::new(1.0, -1.0, 0.0))
+    else {
+        panic!("Expected a rim segment.");
+    };
+
+    assert_relative_eq!(segment.a.y, -1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(segment.b.y, -1.0, epsilon = 1.0e-6);
+    // The segment is tangent to the rim at the support point.
+    let mid = (segment.a + segment.b) / 2.0;
+    assert_relative_eq!(mid.z, 0.0, epsilon = 1.0e-6);
+    assert!(mid.x > 0.0);
+    assert_relative_eq!(segment.a.z, -segment.b.z, epsilon = 1.0e-6);
+}
+
+#[test]
+fn support_feature_respects_axis() {
+    let cylinder = Cylinder::with_axis(1.0, 0.5, CoordinateAxis::X);
+
+    assert!(matches!(
+        cylinder.support_feature(Vector3::X),
+        CylinderFeature::Cap(_)
+    ));
+
+    let CylinderFeature::Lateral(segment) = cylinder.support_feature(Vector3::Y) else {
+        panic!("Expected a lateral segment.");
+    };
+    assert_relative_eq!(segment.a, Vector3::new(-1.0, 0.5, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(segment.b, Vector3::new(1.0, 0.5, 0.0), epsilon = 1.0e-6);
+
+    assert!(matches!(
+        cylinder.support_feature(Vector3::new(1.0, 0.0, 1.0)),
+        CylinderFeature::Rim(_)
+    ));
+}
@@ -0,0 +1,36 @@
+use barry3d::math::{Isometry3, Real, Rotation3, Vector3};
+use barry3d::query;
+use barry3d::shape::Cuboid;
+use bevy_math::Quat;
+
+// A cube standing on its corner `(-0.5, -0.5, -0.5)`, with that corner at the height `y`.
+fn cube_on_corner(y: Real) -> Isometry3 {
+    let corner_dir = Vector3::new(-1.0, -1.0, -1.0).normalize();
+    let rotation = Quat::from_rotation_arc(corner_dir, -Vector3::Y);
+    let half_diagonal = Real::sqrt(3.0) * 0.5;
+    let mut pos = Isometry3::from_rotation(Rotation3(rotation));
+    pos.translation = Vector3::new(0.1, y + half_diagonal, -0.2);
+    pos
+}
+
+#[test]
+fn cube_corner_stabbing_into_box() {
+    let cube = Cuboid::new(Vector3::splat(0.5));
+    let floor = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let pos_floor = Isometry3::from_xyz(0.0, -0.5, 0.0);
+
+    let pos1 = cube_on_corner(-0.1);
+    let (point, depth) = query::deepest_penetration_point(pos1.inv_mul(pos_floor), &cube, &floor)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(point, Vector3::splat(-0.5), epsilon = 1.0e-5);
+    assert_relative_eq!(depth, 0.1, epsilon = 1.0e-5);
+
+    // Not penetrating.
+    let pos1 = cube_on_corner(0.1);
+    assert!(
+        query::deepest_penetration_point(pos1.inv_mul(pos_floor), &cube, &floor)
+            .unwrap()
+            .is_none()
+    );
+}
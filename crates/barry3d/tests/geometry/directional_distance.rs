@@ -0,0 +1,36 @@
+use barry3d::math::{Isometry3, UnitVector3, Vector3};
+use barry3d::query;
+use barry3d::shape::Cuboid;
+
+#[test]
+fn directional_distance_box_onto_box() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos12 = Isometry3::from_xyz(0.3, 4.0, -0.7);
+
+    // The gap between the bottom face of the box and the top face of the ground.
+    let gap = 4.0 - 1.0 - 0.5;
+    let dist = query::directional_distance(pos12, &ground, &cuboid, UnitVector3::NEG_Y).unwrap();
+
+    assert_relative_eq!(dist.unwrap(), gap, epsilon = 1.0e-5);
+}
+
+#[test]
+fn directional_distance_moving_away() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos12 = Isometry3::from_xyz(0.0, 4.0, 0.0);
+
+    let dist = query::directional_distance(pos12, &ground, &cuboid, UnitVector3::Y).unwrap();
+    assert!(dist.is_none());
+}
+
+#[test]
+fn directional_distance_penetrating() {
+    let ground = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos12 = Isometry3::from_xyz(0.0, 1.2, 0.0);
+
+    let dist = query::directional_distance(pos12, &ground, &cuboid, UnitVector3::NEG_Y).unwrap();
+    assert_eq!(dist, Some(0.0));
+}
@@ -0,0 +1,73 @@
+use barry3d::math::Vector3;
+use barry3d::query::{PointProjection, PointQuery};
+use barry3d::shape::{Ball, Capsule, Cuboid, FeatureId};
+
+use super::rand_vector;
+
+/// Wraps a shape so that `distance_gradient` falls back to finite differences.
+struct FiniteDifferences<S>(S);
+
+impl<S: PointQuery> PointQuery for FiniteDifferences<S> {
+    fn project_local_point(&self, pt: Vector3, solid: bool) -> PointProjection {
+        self.0.project_local_point(pt, solid)
+    }
+
+    fn project_local_point_and_get_feature(&self, pt: Vector3) -> (PointProjection, FeatureId) {
+        self.0.project_local_point_and_get_feature(pt)
+    }
+}
+
+fn random_points(n: usize) -> Vec<Vector3> {
+    let mut rng = oorandom::Rand32::new(42);
+    (0..n).map(|_| rand_vector(&mut rng, 3.0)).collect()
+}
+
+fn assert_gradients_match(shape: impl PointQuery + Copy, skip: impl Fn(Vector3) -> bool) {
+    let fd = FiniteDifferences(shape);
+
+    for pt in random_points(500) {
+        if skip(pt) {
+            continue;
+        }
+
+        let analytic = shape.distance_gradient(pt);
+        let approx = fd.distance_gradient(pt);
+
+        assert_relative_eq!(analytic.length(), 1.0, epsilon = 1.0e-5);
+        assert_relative_eq!(analytic, approx, epsilon = 1.0e-2);
+    }
+}
+
+#[test]
+fn ball_distance_gradient() {
+    assert_gradients_match(Ball::new(1.5), |_| false);
+}
+
+#[test]
+fn capsule_distance_gradient() {
+    assert_gradients_match(Capsule::new_y(1.0, 0.5), |_| false);
+}
+
+#[test]
+fn cuboid_distance_gradient() {
+    let half_extents = Vector3::new(1.0, 1.5, 2.0);
+
+    // Skip interior points close to the planes where two faces are equally near,
+    // since the gradient is discontinuous there.
+    let near_medial_plane = |pt: Vector3| {
+        let mut depths = (half_extents - pt.abs()).to_array();
+        depths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        depths[0] > 0.0 && depths[1] - depths[0] < 1.0e-2
+    };
+
+    assert_gradients_match(Cuboid::new(half_extents), near_medial_plane);
+}
+
+#[test]
+fn cuboid_gradient_is_face_normal_inside() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.5, 2.0));
+    assert_eq!(
+        cuboid.distance_gradient(Vector3::new(0.1, -1.4, 0.0)),
+        -Vector3::Y
+    );
+}
@@ -0,0 +1,58 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Capsule, Cuboid, Shape, TriMesh};
+
+#[test]
+fn distance_to_aabb_matches_cuboid_distance() {
+    let aabb = Aabb::new(Vector3::new(1.0, -1.0, 0.0), Vector3::new(3.0, 2.0, 1.0));
+    let cuboid = Cuboid::new(aabb.half_extents());
+    let cuboid_pos = Isometry3::from_translation(aabb.center());
+
+    let shapes: [(Isometry3, &dyn Shape); 3] = [
+        (Isometry3::from_xyz(-2.0, 0.5, 0.5), &Ball::new(0.5)),
+        (
+            Isometry3::new(Vector3::new(2.0, 5.0, 0.0), Vector3::new(0.3, 0.0, 0.2)),
+            &Capsule::new_y(0.5, 0.25),
+        ),
+        (
+            Isometry3::new(Vector3::new(0.0, 0.0, -2.0), Vector3::new(0.1, 0.5, 0.0)),
+            &Cuboid::new(Vector3::new(0.5, 0.2, 0.1)),
+        ),
+    ];
+
+    for (pos, shape) in shapes {
+        let dist = shape.distance_to_aabb(pos, &aabb).unwrap();
+        let expected = query::distance(pos, shape, cuboid_pos, &cuboid).unwrap();
+        assert!(dist > 0.0);
+        assert_relative_eq!(dist, expected, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn distance_to_aabb_intersecting() {
+    let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    let ball = Ball::new(0.5);
+
+    let dist = ball
+        .distance_to_aabb(Isometry3::from_xyz(1.2, 0.0, 0.0), &aabb)
+        .unwrap();
+    assert_eq!(dist, 0.0);
+}
+
+#[test]
+fn distance_to_aabb_unsupported() {
+    let trimesh = TriMesh::new(
+        vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        ],
+        vec![[0, 1, 2]],
+    );
+    let aabb = Aabb::new(Vector3::ZERO, Vector3::ONE);
+
+    assert!(trimesh
+        .distance_to_aabb(Isometry3::IDENTITY, &aabb)
+        .is_err());
+}
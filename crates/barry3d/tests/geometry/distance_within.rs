@@ -0,0 +1,49 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{self, details};
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn distance_within_rejects_far_shapes() {
+    let ball = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(12.0, 0.0, 0.0);
+
+    assert_eq!(
+        query::distance_within(pos1, &ball, pos2, &cuboid, 1.0).unwrap(),
+        None
+    );
+    assert_eq!(
+        details::distance_support_map_support_map_within(pos2, &cuboid, &cuboid, 1.0),
+        None
+    );
+}
+
+#[test]
+fn distance_within_range() {
+    let ball = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(2.5, 0.0, 0.0);
+
+    let dist = query::distance_within(pos1, &ball, pos2, &cuboid, 1.0).unwrap();
+    assert_relative_eq!(dist.unwrap(), 0.5, epsilon = 1.0e-5);
+
+    let dist = details::distance_support_map_support_map_within(pos2, &ball, &cuboid, 1.0);
+    assert_relative_eq!(dist.unwrap(), 0.5, epsilon = 1.0e-5);
+}
+
+#[test]
+fn distance_within_penetrating() {
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let pos2 = Isometry3::from_xyz(1.5, 0.0, 0.0);
+
+    assert_eq!(
+        query::distance_within(Isometry3::IDENTITY, &cuboid, pos2, &cuboid, 1.0).unwrap(),
+        Some(0.0)
+    );
+    assert_eq!(
+        details::distance_support_map_support_map_within(pos2, &cuboid, &cuboid, 1.0),
+        Some(0.0)
+    );
+}
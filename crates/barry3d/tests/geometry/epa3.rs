@@ -1,5 +1,6 @@
 use barry3d::math::{Isometry3, UnitVector3, Vector3};
-use barry3d::query;
+use barry3d::query::gjk::{GJKResult, VoronoiSimplex};
+use barry3d::query::{self, QueryWorkspace};
 use barry3d::shape::Cuboid;
 
 #[test]
@@ -20,3 +21,53 @@ fn cuboid_cuboid_EPA() {
     assert_eq!(res.dist, -1.8);
     assert_eq!(res.normal1, -UnitVector3::Y);
 }
+
+#[test]
+fn cuboid_cuboid_deep_penetration() {
+    let c1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let c2 = Cuboid::new(Vector3::new(0.5, 2.0, 2.0));
+
+    let pos12 = Isometry3::from_xyz(0.3, 0.0, 0.0);
+    let res = query::details::contact_support_map_support_map(pos12, &c1, &c2, 0.0)
+        .expect("Penetration not found.");
+    assert_relative_eq!(res.dist, -1.2, epsilon = 1.0e-5);
+    assert_relative_eq!(*res.normal1, Vector3::X, epsilon = 1.0e-5);
+    assert_relative_eq!(*res.normal2, -Vector3::X, epsilon = 1.0e-5);
+
+    let pos12 = Isometry3::from_xyz(-0.1, 0.05, 0.0);
+    let res = query::details::contact_support_map_support_map(pos12, &c1, &c2, 0.0)
+        .expect("Penetration not found.");
+    assert_relative_eq!(res.dist, -1.4, epsilon = 1.0e-5);
+    assert_relative_eq!(*res.normal1, -Vector3::X, epsilon = 1.0e-5);
+}
+
+#[test]
+fn cuboid_cuboid_epa_with_shared_workspace() {
+    let c1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let c2 = Cuboid::new(Vector3::new(0.5, 2.0, 2.0));
+    let mut workspace = QueryWorkspace::default();
+
+    for pos12 in [
+        Isometry3::from_xyz(0.3, 0.0, 0.0),
+        Isometry3::from_xyz(-0.1, 0.05, 0.0),
+    ] {
+        let with_params = query::details::contact_support_map_support_map_with_params(
+            pos12,
+            &c1,
+            &c2,
+            0.0,
+            &mut VoronoiSimplex::new(),
+            None,
+        );
+        let with_workspace = query::details::contact_support_map_support_map_with_workspace(
+            pos12,
+            &c1,
+            &c2,
+            0.0,
+            &mut workspace,
+            None,
+        );
+        assert!(matches!(with_workspace, GJKResult::ClosestPoints(..)));
+        assert_eq!(with_params, with_workspace);
+    }
+}
@@ -0,0 +1,46 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{Ball, Cuboid, Cylinder, Shape};
+
+#[test]
+fn ball_farthest_point() {
+    let ball = Ball::new(2.0);
+    let from = Vector3::new(1.0, 1.0, 0.0);
+    let expected = -from.normalize() * 2.0;
+
+    assert_relative_eq!(ball.farthest_local_point(from), expected);
+}
+
+#[test]
+fn cuboid_farthest_point() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let from = Vector3::new(0.5, -1.0, 0.2);
+
+    assert_eq!(
+        cuboid.farthest_local_point(from),
+        Vector3::new(-1.0, 2.0, -3.0)
+    );
+}
+
+#[test]
+fn cuboid_farthest_point_from_off_center_point() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    // A point outside of the cuboid, near its `(+x, +y, -z)` corner.
+    let from = Vector3::new(5.0, 0.5, -4.0);
+
+    assert_eq!(
+        cuboid.farthest_local_point(from),
+        Vector3::new(-1.0, -2.0, 3.0)
+    );
+}
+
+#[test]
+fn cylinder_farthest_point() {
+    let cylinder = Cylinder::new(1.0, 1.0);
+    let from = Vector3::new(0.1, 0.5, 0.0);
+
+    assert_relative_eq!(
+        cylinder.farthest_local_point(from),
+        Vector3::new(-1.0, -1.0, 0.0),
+        epsilon = 1.0e-5
+    );
+}
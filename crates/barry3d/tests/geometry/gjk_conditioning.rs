@@ -0,0 +1,44 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::details::distance_support_map_support_map_with_params;
+use barry3d::query::gjk::{CSOPoint, GJKInitDir, VoronoiSimplex};
+use barry3d::shape::Ball;
+
+fn segment_simplex(a: Vector3, b: Vector3) -> VoronoiSimplex {
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::single_point(a));
+    assert!(simplex.add_point(CSOPoint::single_point(b)));
+    let _ = simplex.project_origin_and_reduce();
+    simplex
+}
+
+#[test]
+fn well_conditioned_projection() {
+    let simplex = segment_simplex(Vector3::new(-1.0, 1.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(simplex.dimension(), 1);
+    assert_relative_eq!(simplex.conditioning(), 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn nearly_degenerate_projection() {
+    // The origin projects almost exactly on the first vertex.
+    let simplex = segment_simplex(Vector3::new(-0.001, 1.0, 0.0), Vector3::new(10.0, 1.0, 0.0));
+    assert_eq!(simplex.dimension(), 1);
+    assert!(simplex.conditioning() < 1.0e-3);
+}
+
+#[test]
+fn gjk_distance_conditioning() {
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(3.0, 1.0, -2.0);
+    let mut simplex = VoronoiSimplex::new();
+
+    let dist = distance_support_map_support_map_with_params(
+        pos12,
+        &ball,
+        &ball,
+        &mut simplex,
+        GJKInitDir::TranslationDelta,
+    );
+    assert_relative_eq!(dist, pos12.translation.length() - 2.0, epsilon = 1.0e-5);
+    assert_relative_eq!(workspace.simplex.conditioning(), 1.0, epsilon = 1.0e-6);
+}
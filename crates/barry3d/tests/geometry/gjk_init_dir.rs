@@ -0,0 +1,111 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::details::{
+    distance_support_map_support_map_with_params,
+    distance_support_map_support_map_within_with_params,
+};
+use barry3d::query::gjk::{GJKInitDir, VoronoiSimplex};
+use barry3d::shape::SupportMap;
+use std::cell::Cell;
+
+/// A ball lying far from its local origin, counting the evaluations of its support function.
+struct OffCenterBall {
+    center: Vector3,
+    radius: Real,
+    num_support_calls: Cell<usize>,
+}
+
+impl OffCenterBall {
+    fn new(center: Vector3, radius: Real) -> Self {
+        Self {
+            center,
+            radius,
+            num_support_calls: Cell::new(0),
+        }
+    }
+
+    fn take_num_support_calls(&self) -> usize {
+        self.num_support_calls.replace(0)
+    }
+}
+
+impl SupportMap for OffCenterBall {
+    fn local_support_point(&self, dir: Vector3) -> Vector3 {
+        self.num_support_calls.set(self.num_support_calls.get() + 1);
+        self.center + dir.normalize_or_zero() * self.radius
+    }
+}
+
+#[test]
+fn gjk_center_delta_init_dir_converges_faster_for_off_center_shapes() {
+    let ball1 = OffCenterBall::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+    let ball2 = OffCenterBall::new(Vector3::new(0.0, -1.0, 0.0), 1.0);
+    // The centers are 2.05 apart while the origins are offset along `X` only.
+    let center_delta = Vector3::new(-2.5, 2.0, 0.0).normalize() * 2.05;
+    let pos12 = Isometry3::from_xyz(-center_delta.x, 2.0 - center_delta.y, 0.0);
+    let mut simplex = VoronoiSimplex::new();
+
+    let mut run = |init_dir: GJKInitDir| {
+        let dist = distance_support_map_support_map_with_params(
+            pos12,
+            &ball1,
+            &ball2,
+            &mut simplex,
+            init_dir,
+        );
+        let calls = ball1.take_num_support_calls() + ball2.take_num_support_calls();
+        (dist, calls)
+    };
+
+    let (dist_translation, calls_translation) = run(GJKInitDir::TranslationDelta);
+    let (dist_center, calls_center) = run(GJKInitDir::CenterDelta);
+    let (dist_custom, calls_custom) = run(GJKInitDir::Custom(center_delta));
+
+    assert_relative_eq!(dist_translation, 0.05, epsilon = 1.0e-3);
+    assert_relative_eq!(dist_center, 0.05, epsilon = 1.0e-3);
+    assert_relative_eq!(dist_custom, 0.05, epsilon = 1.0e-3);
+
+    // Only count the support points evaluated by GJK itself.
+    GJKInitDir::CenterDelta.direction(pos12, &ball1, &ball2);
+    let calls_init = ball1.take_num_support_calls() + ball2.take_num_support_calls();
+    assert_eq!(calls_init, 12);
+    assert!(calls_center - calls_init < calls_translation);
+    assert!(calls_custom < calls_translation);
+}
+
+#[test]
+fn gjk_init_dir_from_optional_vector() {
+    let ball = OffCenterBall::new(Vector3::ZERO, 1.0);
+    let pos12 = Isometry3::from_xyz(3.0, 0.0, 0.0);
+    let mut simplex = VoronoiSimplex::new();
+
+    let dist_none =
+        distance_support_map_support_map_with_params(pos12, &ball, &ball, &mut simplex, None);
+    let dist_some = distance_support_map_support_map_with_params(
+        pos12,
+        &ball,
+        &ball,
+        &mut simplex,
+        Some(-Vector3::X),
+    );
+    assert_relative_eq!(dist_none, 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(dist_some, 1.0, epsilon = 1.0e-5);
+
+    let dist_within = distance_support_map_support_map_within_with_params(
+        pos12,
+        &ball,
+        &ball,
+        2.0,
+        &mut simplex,
+        GJKInitDir::CenterDelta,
+    );
+    assert_relative_eq!(dist_within.unwrap(), 1.0, epsilon = 1.0e-5);
+    assert!(distance_support_map_support_map_within_with_params(
+        pos12,
+        &ball,
+        &ball,
+        0.5,
+        &mut simplex,
+        GJKInitDir::CenterDelta,
+    )
+    .is_none());
+}
@@ -0,0 +1,61 @@
+use barry3d::math::{Isometry3, Real, UnitVector3, Vector3};
+use barry3d::query::{PointQuery, Ray, RayCast};
+use barry3d::shape::{HalfSpace, Shape};
+
+fn floor() -> (Isometry3, HalfSpace) {
+    (
+        Isometry3::from_xyz(0.0, 1.0, 0.0),
+        HalfSpace::new(UnitVector3::Y),
+    )
+}
+
+#[test]
+fn halfspace_ray_cast_onto_floor() {
+    let (pos, floor) = floor();
+
+    let ray = Ray::new(Vector3::new(2.0, 5.0, -3.0), Vector3::new(0.0, -2.0, 0.0));
+    let hit = floor
+        .cast_ray_and_get_normal(pos, &ray, Real::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, Vector3::Y);
+    assert_relative_eq!(ray.point_at(hit.toi), Vector3::new(2.0, 1.0, -3.0));
+
+    // Too short or pointing away from the floor.
+    assert!(floor.cast_ray(pos, &ray, 1.0, true).is_none());
+    let up = Ray::new(ray.origin, Vector3::Y);
+    assert!(floor.cast_ray(pos, &up, Real::MAX, true).is_none());
+
+    // Starting inside of the solid half-space.
+    let inside = Ray::new(Vector3::new(0.0, -1.0, 0.0), Vector3::Y);
+    assert_eq!(floor.cast_ray(pos, &inside, Real::MAX, true), Some(0.0));
+    assert_relative_eq!(floor.cast_ray(pos, &inside, Real::MAX, false).unwrap(), 2.0);
+}
+
+#[test]
+fn halfspace_point_projection() {
+    let (pos, floor) = floor();
+
+    let above = Vector3::new(1.0, 4.0, 2.0);
+    let proj = floor.project_point(pos, above, true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Vector3::new(1.0, 1.0, 2.0));
+    assert_relative_eq!(floor.distance_to_point(pos, above, true), 3.0);
+
+    let below = Vector3::new(1.0, -2.0, 2.0);
+    let proj = floor.project_point(pos, below, false);
+    assert!(proj.is_inside);
+    assert_relative_eq!(proj.point, Vector3::new(1.0, 1.0, 2.0));
+    assert!(floor.contains_point(pos, below));
+    assert_relative_eq!(floor.distance_to_point(pos, below, false), -3.0);
+    assert_eq!(floor.distance_to_point(pos, below, true), 0.0);
+}
+
+#[test]
+fn halfspace_aabb_is_huge() {
+    let (pos, floor) = floor();
+    let aabb = floor.compute_aabb(pos);
+
+    assert!(aabb.mins.max_element() < -1.0e30);
+    assert!(aabb.maxs.min_element() > 1.0e30);
+}
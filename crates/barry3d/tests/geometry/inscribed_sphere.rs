@@ -0,0 +1,50 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{Ball, Capsule, Cuboid, Cylinder, Shape, TriMesh};
+
+#[test]
+fn primitive_inscribed_spheres() {
+    let ball = Ball::new(1.5).local_inscribed_sphere().unwrap();
+    assert_eq!(ball.center(), Vector3::ZERO);
+    assert_eq!(ball.radius(), 1.5);
+
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.25, 2.0))
+        .local_inscribed_sphere()
+        .unwrap();
+    assert_eq!(cuboid.center(), Vector3::ZERO);
+    assert_eq!(cuboid.radius(), 0.25);
+
+    let capsule = Capsule::new(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(3.0, 0.0, 0.0),
+        0.5,
+    )
+    .local_inscribed_sphere()
+    .unwrap();
+    assert_relative_eq!(capsule.center(), Vector3::new(2.0, 0.0, 0.0));
+    assert_eq!(capsule.radius(), 0.5);
+
+    let cylinder = Cylinder::new(0.3, 1.0).local_inscribed_sphere().unwrap();
+    assert_eq!(cylinder.center(), Vector3::ZERO);
+    assert_eq!(cylinder.radius(), 0.3);
+}
+
+#[test]
+fn inscribed_sphere_is_inside_of_shape() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.25, 2.0));
+    let sphere = cuboid.local_inscribed_sphere().unwrap();
+    let bounding = cuboid.local_bounding_sphere();
+    assert!(sphere.radius() <= bounding.radius());
+}
+
+#[test]
+fn inscribed_sphere_unsupported() {
+    let trimesh = TriMesh::new(
+        vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        ],
+        vec![[0, 1, 2]],
+    );
+    assert!(trimesh.local_inscribed_sphere().is_none());
+}
@@ -0,0 +1,145 @@
+use barry3d::math::{Isometry3, Real, UnitVector, Vector3};
+use barry3d::query::{details, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+use barry3d::shape::{Ball, FeatureId, TriMesh, TriMeshFlags, Triangle};
+
+// Two coplanar triangles forming the square [0, 2] x [0, 2], sharing its diagonal.
+fn square_triangles() -> [(Triangle, [Option<UnitVector>; 3]); 2] {
+    let up = Some(UnitVector::Z);
+    [
+        (
+            Triangle::new(
+                Vector3::ZERO,
+                Vector3::new(2.0, 0.0, 0.0),
+                Vector3::new(2.0, 2.0, 0.0),
+            ),
+            [None, None, up],
+        ),
+        (
+            Triangle::new(
+                Vector3::ZERO,
+                Vector3::new(2.0, 2.0, 0.0),
+                Vector3::new(0.0, 2.0, 0.0),
+            ),
+            [up, None, None],
+        ),
+    ]
+}
+
+// The positions of a ball rolling across the diagonal shared by both triangles.
+fn rolling_positions() -> impl Iterator<Item = Isometry3> {
+    (0..=40).map(|i| {
+        let x = 0.6 + i as Real * 0.02;
+        Isometry3::from_xyz(x, 2.0 - x, 0.45)
+    })
+}
+
+#[test]
+fn ball_rolling_across_coplanar_triangles_has_up_normal() {
+    let ball = Ball::new(0.5);
+    let mut num_raw_edge_normals = 0;
+
+    for pos12 in rolling_positions() {
+        for (triangle, adjacent_normals) in square_triangles() {
+            if let Some(raw) = details::contact_triangle_ball(pos12, &triangle, &ball, 0.0) {
+                if raw.normal1.z < 0.999 {
+                    num_raw_edge_normals += 1;
+                }
+            }
+
+            if let Some(contact) = details::contact_triangle_ball_with_adjacent_normals(
+                pos12,
+                &triangle,
+                &adjacent_normals,
+                &ball,
+                0.0,
+            ) {
+                assert_relative_eq!(*contact.normal1, Vector3::Z, epsilon = 1.0e-6);
+                assert_relative_eq!(contact.dist, -0.05, epsilon = 1.0e-6);
+            }
+
+            let flipped = details::contact_ball_triangle_with_adjacent_normals(
+                pos12.inverse(),
+                &ball,
+                &triangle,
+                &adjacent_normals,
+                0.0,
+            );
+            if let Some(flipped) = flipped {
+                assert_relative_eq!(*flipped.normal2, Vector3::Z, epsilon = 1.0e-6);
+            }
+        }
+    }
+
+    // Without the adjacency information, the shared edge produces tilted normals.
+    assert!(num_raw_edge_normals > 0);
+}
+
+#[test]
+fn trimesh_ball_manifolds_fix_internal_edges() {
+    let vertices = vec![
+        Vector3::ZERO,
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(2.0, 2.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+    ];
+    let indices = vec![[0, 1, 2], [0, 2, 3]];
+    let mesh = TriMesh::with_flags(vertices, indices, TriMeshFlags::FIX_INTERNAL_EDGES);
+    let ball = Ball::new(0.5);
+
+    assert_eq!(
+        mesh.triangle_adjacent_normals(0),
+        Some([None, None, Some(UnitVector::Z)])
+    );
+    assert_eq!(
+        mesh.triangle_adjacent_normals(1),
+        Some([Some(UnitVector::Z), None, None])
+    );
+
+    for pos12 in rolling_positions() {
+        let mut manifolds: Vec<ContactManifold<(), ()>> = vec![];
+        let mut workspace = None;
+        DefaultQueryDispatcher
+            .contact_manifolds(pos12, &mesh, &ball, 0.0, &mut manifolds, &mut workspace)
+            .unwrap();
+
+        let mut num_points = 0;
+        for manifold in manifolds.iter().filter(|m| !m.points.is_empty()) {
+            num_points += manifold.points.len();
+            assert_relative_eq!(manifold.local_n1, Vector3::Z, epsilon = 1.0e-6);
+            assert_relative_eq!(manifold.local_n2, -Vector3::Z, epsilon = 1.0e-6);
+        }
+        assert!(num_points > 0);
+    }
+}
+
+#[test]
+fn convex_edge_normal_is_clamped_to_adjacent_face() {
+    let triangle = Triangle::new(
+        Vector3::ZERO,
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+    );
+    // The triangle across the edge AB bends downward by 45 degrees.
+    let adjacent = UnitVector::new(Vector3::new(0.0, -1.0, 1.0)).unwrap();
+    let adjacent_normals = [Some(adjacent), None, None];
+
+    // A normal pointing farther than the adjacent normal is clamped to it.
+    let outward = UnitVector::new(Vector3::new(0.0, -1.0, 0.2)).unwrap();
+    let corrected =
+        triangle.correct_internal_edge_normal(FeatureId::Edge(0), outward, &adjacent_normals);
+    assert_relative_eq!(*corrected, *adjacent, epsilon = 1.0e-6);
+
+    // A normal between the face normal and the adjacent normal is kept.
+    let between = UnitVector::new(Vector3::new(0.0, -0.5, 1.0)).unwrap();
+    let corrected =
+        triangle.correct_internal_edge_normal(FeatureId::Edge(0), between, &adjacent_normals);
+    assert_relative_eq!(*corrected, *between, epsilon = 1.0e-6);
+
+    // Face contacts and edges without adjacent triangles are left untouched.
+    let corrected =
+        triangle.correct_internal_edge_normal(FeatureId::Face(0), outward, &adjacent_normals);
+    assert_relative_eq!(*corrected, *outward, epsilon = 1.0e-6);
+    let corrected =
+        triangle.correct_internal_edge_normal(FeatureId::Edge(1), outward, &adjacent_normals);
+    assert_relative_eq!(*corrected, *outward, epsilon = 1.0e-6);
+}
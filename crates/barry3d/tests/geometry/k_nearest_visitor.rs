@@ -0,0 +1,55 @@
+use barry3d::math::Vector3;
+use barry3d::query::visitors::KNearestVisitor;
+use barry3d::query::PointQuery;
+use barry3d::shape::{Ball, TriMesh};
+
+use super::rand_vector;
+
+#[test]
+fn k_nearest_visitor_matches_brute_force() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 20);
+    let mesh = TriMesh::new(vertices, indices);
+    let mut rng = oorandom::Rand32::new(42);
+
+    for k in [1, 5, 12] {
+        for _ in 0..50 {
+            let point = rand_vector(&mut rng, 2.0);
+            let mut visitor = KNearestVisitor::new(&mesh, point, k, true);
+            let _ = mesh.qbvh().traverse_best_first(&mut visitor);
+            let nearest = visitor.into_nearest();
+
+            let mut expected: Vec<_> = (0..mesh.num_triangles() as u32)
+                .map(|i| (i, mesh.triangle(i).distance_to_local_point(point, true)))
+                .collect();
+            expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+            assert_eq!(nearest.len(), k);
+            for ((part_id, dist), (_, expected_dist)) in nearest.iter().zip(&expected) {
+                assert_relative_eq!(*dist, *expected_dist, epsilon = 1.0e-5);
+                assert_relative_eq!(
+                    mesh.triangle(*part_id).distance_to_local_point(point, true),
+                    *dist,
+                    epsilon = 1.0e-5
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn k_nearest_visitor_with_more_parts_than_the_mesh() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(4, 4);
+    let mesh = TriMesh::new(vertices, indices);
+    let k = mesh.num_triangles() + 10;
+
+    let mut visitor = KNearestVisitor::new(&mesh, Vector3::new(0.5, 2.0, 0.0), k, true);
+    let _ = mesh.qbvh().traverse_best_first(&mut visitor);
+    let nearest = visitor.into_nearest();
+
+    assert_eq!(nearest.len(), mesh.num_triangles());
+    assert!(nearest.windows(2).all(|w| w[0].1 <= w[1].1));
+
+    let mut visitor = KNearestVisitor::new(&mesh, Vector3::ZERO, 0, true);
+    let _ = mesh.qbvh().traverse_best_first(&mut visitor);
+    assert!(visitor.into_nearest().is_empty());
+}
@@ -0,0 +1,37 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid, Shape};
+
+#[test]
+fn fast_ball_velocity_clamped_to_reach_wall() {
+    let ball = Ball::new(0.5);
+    let wall = Cuboid::new(Vector3::new(1.0, 5.0, 5.0));
+    let far_wall = Cuboid::new(Vector3::new(1.0, 5.0, 5.0));
+    let obstacles: [(Isometry3, &dyn Shape); 2] = [
+        (Isometry3::from_xyz(20.0, 0.0, 0.0), &far_wall),
+        (Isometry3::from_xyz(10.0, 0.0, 0.0), &wall),
+    ];
+    let pos = Isometry3::IDENTITY;
+    let vel = Vector3::new(100.0, 0.0, 0.0);
+    let dt = 0.1;
+
+    let safe_vel = query::max_safe_velocity(pos, vel, &ball, &obstacles, dt).unwrap();
+    assert_relative_eq!(safe_vel, Vector3::new(85.0, 0.0, 0.0), epsilon = 1.0e-3);
+
+    // Moving with the clamped velocity brings the ball in contact with the wall.
+    let end_pos = Isometry3::from_translation(safe_vel * dt);
+    let dist = query::distance(end_pos, &ball, obstacles[1].0, &wall).unwrap();
+    assert_relative_eq!(dist, 0.0, epsilon = 1.0e-3);
+}
+
+#[test]
+fn slow_ball_velocity_unchanged() {
+    let ball = Ball::new(0.5);
+    let wall = Cuboid::new(Vector3::new(1.0, 5.0, 5.0));
+    let obstacles: [(Isometry3, &dyn Shape); 1] = [(Isometry3::from_xyz(10.0, 0.0, 0.0), &wall)];
+    let vel = Vector3::new(10.0, 0.0, 0.0);
+
+    let safe_vel =
+        query::max_safe_velocity(Isometry3::IDENTITY, vel, &ball, &obstacles, 0.1).unwrap();
+    assert_eq!(safe_vel, vel);
+}
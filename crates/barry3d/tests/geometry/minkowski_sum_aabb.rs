@@ -0,0 +1,26 @@
+use barry3d::bounding_volume::details::minkowski_sum_aabb;
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::Cuboid;
+
+#[test]
+fn aabb_minkowski_sum() {
+    let a = Aabb::new(Vector3::new(-1.0, 0.0, 2.0), Vector3::new(1.0, 1.0, 3.0));
+    let b = Aabb::new(Vector3::new(0.0, -2.0, -1.0), Vector3::new(0.5, 2.0, 1.0));
+    let sum = a.minkowski_sum(&b);
+
+    assert_eq!(sum.mins, Vector3::new(-1.0, -2.0, 1.0));
+    assert_eq!(sum.maxs, Vector3::new(1.5, 3.0, 4.0));
+    assert_eq!(sum.extents(), a.extents() + b.extents());
+}
+
+#[test]
+fn unit_boxes_minkowski_sum() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let pos1 = Isometry3::from_xyz(1.0, 2.0, 3.0);
+    let pos2 = Isometry3::from_xyz(-4.0, 0.5, 1.0);
+
+    let sum = minkowski_sum_aabb(&cuboid, pos1, &cuboid, pos2);
+    assert_relative_eq!(sum.half_extents(), Vector3::splat(1.0));
+    assert_relative_eq!(sum.center(), Vector3::new(-3.0, 2.5, 4.0));
+}
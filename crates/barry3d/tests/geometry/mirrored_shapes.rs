@@ -0,0 +1,65 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{Capsule, Cone, CoordinateAxis, Cuboid, Cylinder, Mirrorable, SupportMap};
+
+fn mirror(v: Vector3, axis: usize) -> Vector3 {
+    let mut result = v;
+    result[axis] = -result[axis];
+    result
+}
+
+fn directions() -> Vec<Vector3> {
+    vec![
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, -1.0, 0.0),
+        Vector3::new(0.3, 0.5, -0.8),
+        Vector3::new(-1.0, 2.0, 1.5),
+        Vector3::new(0.1, -0.2, 0.9),
+    ]
+}
+
+fn check_mirrored_support<S: SupportMap + Mirrorable>(shape: &S, axes: &[usize]) {
+    for &axis in axes {
+        let mirrored = shape.mirrored(axis);
+
+        for dir in directions() {
+            let expected = mirror(shape.local_support_point(mirror(dir, axis)), axis);
+            let actual = mirrored.local_support_point(dir);
+            assert_relative_eq!(expected.dot(dir), actual.dot(dir), epsilon = 1.0e-5);
+        }
+    }
+}
+
+#[test]
+fn mirrored_cuboid_support() {
+    check_mirrored_support(&Cuboid::new(Vector3::new(1.0, 2.0, 0.5)), &[0, 1, 2]);
+}
+
+#[test]
+fn mirrored_capsule_support() {
+    let capsule = Capsule::new(
+        Vector3::new(0.2, 1.0, -0.3),
+        Vector3::new(1.5, -0.4, 0.8),
+        0.3,
+    );
+    check_mirrored_support(&capsule, &[0, 1, 2]);
+
+    let mirrored = capsule.mirrored(0);
+    assert_eq!(mirrored.segment.a, Vector3::new(-0.2, 1.0, -0.3));
+    assert_eq!(mirrored.segment.b, Vector3::new(-1.5, -0.4, 0.8));
+}
+
+#[test]
+fn mirrored_cylinder_support() {
+    check_mirrored_support(&Cylinder::new(1.0, 0.5), &[0, 1, 2]);
+}
+
+#[test]
+fn mirrored_cone_support() {
+    check_mirrored_support(&Cone::new(1.0, 0.5), &[0, 1, 2]);
+    check_mirrored_support(&Cone::with_axis(1.0, 0.5, CoordinateAxis::X), &[0, 1, 2]);
+
+    let mirrored = Cone::new(1.0, 0.5).mirrored(1);
+    assert_eq!(mirrored.axis, CoordinateAxis::Y);
+    assert_eq!(mirrored.apex_direction(), -Vector3::Y);
+    assert_eq!(mirrored.mirrored(1), Cone::new(1.0, 0.5));
+}
@@ -1,11 +1,142 @@
+use barry3d::math::{Real, Vector3};
+
+/// A random vector with coordinates uniformly distributed in `[-scale, scale]`.
+pub fn rand_vector(rng: &mut oorandom::Rand32, scale: Real) -> Vector3 {
+    Vector3::new(
+        rng.rand_float() * 2.0 - 1.0,
+        rng.rand_float() * 2.0 - 1.0,
+        rng.rand_float() * 2.0 - 1.0,
+    ) * scale
+}
+
+mod aabb_contains_point;
+mod aabb_project_point;
+mod aabb_ray_transition;
+mod aabb_swept_contact;
+mod aabb_swept_overlap;
+mod aabb_swept_rotation;
+mod aabb_transform_by;
+mod ball_ball_contact;
 mod ball_ball_toi;
+mod ball_capsule_contact;
+mod ball_triangle_contact;
+mod ball_triangle_distance;
 mod ball_triangle_toi;
+mod bounding_sphere_swept;
+mod bounding_spheres_intersect;
+mod can_pass_through;
+mod capsule_capsule_contact;
+mod capsule_capsule_manifold;
+mod capsule_from_endpoints;
+mod capsule_from_points;
+mod capsule_halfspace_contact;
+mod capsule_support_point;
+mod capsule_transform_by;
+mod capsule_zero_radius;
+mod closest_pair;
+mod closest_points_robust;
+mod composite_ray_cast_all;
+mod composite_ray_cast_part;
+mod compound;
+mod compound_parts_aabb;
+mod cone_halfspace_contact;
+mod cone_halfspace_distance;
+mod contact_at_predicted;
+mod contact_frames;
+mod contact_manifold;
+mod contact_manifold_along_normal;
+mod contact_manifold_warm_start;
+mod contact_relative_velocity;
+mod contact_tangent_frame;
+mod contains_shape;
 mod convex_hull;
+mod cso_point_local_dirs;
+mod cuboid_aabb;
+mod cuboid_ball_contact_patch;
+mod cuboid_ball_toi_witnesses;
+mod cuboid_capsule_separation;
+mod cuboid_cuboid_normal_snapping;
+mod cuboid_face_normals;
+mod cuboid_penetration;
 mod cuboid_ray_cast;
+mod cuboid_subdivide;
+mod cuboid_triangles_with_normals;
 mod cylinder_cuboid_contact;
+mod cylinder_cuboid_manifold;
+mod cylinder_support_feature;
+mod deepest_penetration_point;
+mod directional_distance;
+mod distance_gradient;
+mod distance_to_aabb;
+mod distance_within;
 mod epa3;
+mod farthest_point;
+mod gjk_conditioning;
+mod gjk_init_dir;
+mod halfspace;
+mod inscribed_sphere;
+mod internal_edge_normals;
+mod k_nearest_visitor;
+mod max_safe_velocity;
+mod minkowski_sum_aabb;
+mod mirrored_shapes;
+mod mtv;
+mod non_finite_inputs;
+mod nonlinear_rigid_motion;
+mod penetrating_toi;
+mod point_support_map_toi;
+mod polyhedron_centroid;
+mod polyhedron_polyhedron_sat;
+mod posed_point_query;
+mod project_onto_axis;
+mod project_point_in_halfspace;
+mod qbvh_quality;
+mod qbvh_streaming_build;
+mod query_errors;
+mod ray_origin_inside;
+mod ray_stays_inside;
+mod reduce_contacts;
+mod sat_cuboid_cuboid;
+mod sat_max_separation;
+mod segment_transformed;
+mod shape_aabb;
+mod shape_axis;
+mod shape_bounding_sphere;
+mod shape_compute_aabbs;
+mod shape_content_hash;
+mod shape_curvature;
+mod shape_intersects_frustum;
+mod shape_is_ccd_safe;
+mod shape_local_aabb_loosened;
+mod shape_min_signed_distance_to_planes;
+mod shape_section_with_plane;
+mod simd_aabb_transform_by;
+mod sphere_tree;
 mod still_objects_toi;
+mod support_cache;
+mod support_feature;
+mod support_map_aabb;
+mod support_map_segment_distance;
+// `debug_validate_support_map` only exists in debug builds.
+#[cfg(debug_assertions)]
+mod support_map_validation;
+mod surface_point_along;
+mod swept_ball_closest_point;
 mod time_of_impact3;
+mod time_of_impact_ca;
+mod time_of_impact_path;
+mod time_of_impact_pivot;
+mod time_of_separation;
+mod time_to_leave_aabb;
+mod toi_ball_ball;
+mod touch_status;
+mod translated_support_map;
+mod triangle_normal;
+mod triangle_point_feature;
+mod trimesh_compact;
 mod trimesh_connected_components;
+mod trimesh_conservative_advancement_toi;
 mod trimesh_intersection;
+mod trimesh_triangles_in_ball;
 mod trimesh_trimesh_toi;
+mod world_space_ray_cast;
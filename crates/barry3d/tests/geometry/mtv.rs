@@ -0,0 +1,29 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::Cuboid;
+
+#[test]
+fn mtv_separates_overlapping_cuboids() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5, 2.0, 0.5));
+    let pos1 = Isometry3::from_xyz(0.0, 0.0, 0.0);
+    let pos2 = Isometry3::from_xyz(1.3, 0.2, 0.1);
+
+    let mtv = query::mtv(pos1, &cuboid1, pos2, &cuboid2).unwrap().unwrap();
+    assert_relative_eq!(mtv, Vector3::new(-0.2, 0.0, 0.0), epsilon = 1.0e-5);
+
+    let separated_pos1 = Isometry3::from_xyz(mtv.x, mtv.y, mtv.z) * pos1;
+    let contact = query::contact(separated_pos1, &cuboid1, pos2, &cuboid2, 0.1)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(contact.dist, 0.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn mtv_disjoint_cuboids() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(3.0, 0.0, 0.0);
+
+    assert!(query::mtv(pos1, &cuboid, pos2, &cuboid).unwrap().is_none());
+}
@@ -0,0 +1,55 @@
+// Non-finite inputs trigger a debug assertion, and return a well-defined result in release
+// mode instead of running the iterative algorithms up to their iteration limit.
+
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cone, Cuboid};
+
+fn nan_position() -> Isometry3 {
+    Isometry3::from_xyz(Real::NAN, 0.0, 1.0)
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn distance_with_nan_position() {
+    let ball = Ball::new(1.0);
+    let dist = query::distance(Isometry3::IDENTITY, &ball, nan_position(), &ball).unwrap();
+    assert!(dist.is_nan());
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn gjk_distance_with_nan_position() {
+    let pos12 = nan_position();
+    let dist = query::details::distance_support_map_support_map(
+        pos12,
+        &Cuboid::new(Vector3::splat(1.0)),
+        &Cone::new(1.0, 0.5),
+    );
+    assert!(dist.is_nan());
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn contact_with_nan_position() {
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let contact = query::contact(Isometry3::IDENTITY, &cuboid, nan_position(), &cuboid, 0.0);
+    assert_eq!(contact, Ok(None));
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic)]
+fn time_of_impact_with_infinite_velocity() {
+    let ball = Ball::new(1.0);
+    let toi = query::time_of_impact(
+        Isometry3::IDENTITY,
+        Vector3::new(Real::INFINITY, 0.0, 0.0),
+        &ball,
+        Isometry3::from_xyz(5.0, 0.0, 0.0),
+        Vector3::ZERO,
+        &ball,
+        Real::MAX,
+        true,
+    );
+    assert!(toi.unwrap().is_none());
+}
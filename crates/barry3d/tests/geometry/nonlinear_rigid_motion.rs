@@ -0,0 +1,65 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::NonlinearRigidMotion;
+
+fn motion() -> NonlinearRigidMotion {
+    NonlinearRigidMotion::new(
+        Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.1)),
+        Vector3::new(0.5, 0.0, -0.25),
+        Vector3::new(1.0, -0.5, 0.0),
+        Vector3::new(0.0, 1.0, 0.5),
+    )
+}
+
+#[test]
+fn position_at_time_moves_the_center_linearly() {
+    let motion = motion();
+    let center = motion.start.transform_point(motion.local_center);
+
+    let pos0 = motion.position_at_time(0.0);
+    assert_relative_eq!(pos0.translation, motion.start.translation, epsilon = 1.0e-5);
+
+    for t in [0.5, 1.0, 2.0] {
+        let pos = motion.position_at_time(t);
+        let rotation = Isometry3::new(Vector3::ZERO, motion.angvel * t).rotation;
+        assert_relative_eq!(
+            pos.transform_point(motion.local_center),
+            center + motion.linvel * t,
+            epsilon = 1.0e-5
+        );
+        assert_relative_eq!(
+            pos.rotation * Vector3::X,
+            rotation * (motion.start.rotation * Vector3::X),
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn append_translation_keeps_the_rotation_center() {
+    let mut motion = motion();
+    motion.linvel = Vector3::ZERO;
+    let center = motion.start.transform_point(motion.local_center);
+    let tra = Vector3::new(-1.0, 0.5, 2.0);
+
+    let appended = motion.append_translation(tra);
+    assert_relative_eq!(
+        appended.start.translation,
+        motion.start.translation + tra,
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        appended.start.transform_point(appended.local_center),
+        center,
+        epsilon = 1.0e-5
+    );
+
+    // The angular velocity is still applied around the original center.
+    for t in [0.5, 1.0] {
+        let pos = appended.position_at_time(t);
+        assert_relative_eq!(
+            pos.transform_point(appended.local_center),
+            center,
+            epsilon = 1.0e-5
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use approx::assert_relative_eq;
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{time_of_impact, TOIStatus};
+use barry3d::shape::Cuboid;
+
+#[test]
+fn initially_penetrating_toi_reports_separation_normal() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    // The cuboids overlap by 0.2 along `X`, and by 2.0 along `Y` and `Z`.
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(1.8, 0.0, 0.0);
+    // The motion is unrelated to the shallowest separation direction.
+    let vel1 = Vector3::new(0.0, 1.0, 0.0);
+    let vel2 = Vector3::ZERO;
+
+    let toi = time_of_impact(pos1, vel1, &cuboid, pos2, vel2, &cuboid, Real::MAX, true)
+        .unwrap()
+        .expect("the cuboids overlap initially");
+
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_relative_eq!(*toi.normal1, Vector3::X, epsilon = 1.0e-5);
+    assert_relative_eq!(*toi.normal2, -Vector3::X, epsilon = 1.0e-5);
+
+    let pos12 = pos1.inv_mul(pos2);
+    let depth = (toi.witness1 - pos12.transform_point(toi.witness2)).dot(*toi.normal1);
+    assert_relative_eq!(depth, 0.2, epsilon = 1.0e-5);
+}
@@ -0,0 +1,79 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::details::time_of_impact_point_support_map;
+use barry3d::query::{self, Ray, RayCast, TOIStatus};
+use barry3d::shape::{Ball, Cuboid, RoundCuboid};
+
+#[test]
+fn point_support_map_toi_matches_ray_cast() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let pos12 = Isometry3::new(Vector3::new(5.0, 0.2, -0.3), Vector3::new(0.1, 0.4, -0.2));
+    let point_vel = Vector3::new(2.0, 0.1, 0.0);
+
+    let toi = time_of_impact_point_support_map(pos12, point_vel, &cuboid, Real::MAX).unwrap();
+    let local_ray = Ray::new(
+        pos12.inverse_transform_point(Vector3::ZERO),
+        pos12.rotation.inverse() * point_vel,
+    );
+    let expected = cuboid.cast_local_ray(&local_ray, Real::MAX, true).unwrap();
+
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, expected, epsilon = 1.0e-5);
+    assert_relative_eq!(toi.witness2, local_ray.point_at(expected), epsilon = 1.0e-5);
+
+    // Same as the general time of impact with a ball collapsed to a point.
+    let point = Ball::new(0.0);
+    let general = query::time_of_impact(
+        Isometry3::IDENTITY,
+        point_vel,
+        &point,
+        pos12,
+        Vector3::ZERO,
+        &cuboid,
+        Real::MAX,
+        true,
+    )
+    .unwrap()
+    .unwrap();
+    assert_relative_eq!(toi.toi, general.toi, epsilon = 1.0e-4);
+    assert_relative_eq!(*toi.normal1, *general.normal1, epsilon = 1.0e-4);
+}
+
+#[test]
+fn point_support_map_toi_rounded_shape() {
+    let round_cuboid = RoundCuboid {
+        inner_shape: Cuboid::new(Vector3::new(1.0, 1.0, 1.0)),
+        border_radius: 0.5,
+    };
+    let pos12 = Isometry3::from_xyz(5.0, 0.0, 0.0);
+
+    let toi =
+        time_of_impact_point_support_map(pos12, Vector3::new(1.0, 0.0, 0.0), &round_cuboid, 10.0)
+            .unwrap();
+    assert_relative_eq!(toi.toi, 3.5, epsilon = 1.0e-4);
+
+    // Missing or out of reach.
+    assert!(time_of_impact_point_support_map(
+        pos12,
+        Vector3::new(0.0, 1.0, 0.0),
+        &round_cuboid,
+        10.0
+    )
+    .is_none());
+    assert!(time_of_impact_point_support_map(
+        pos12,
+        Vector3::new(1.0, 0.0, 0.0),
+        &round_cuboid,
+        3.0
+    )
+    .is_none());
+}
+
+#[test]
+fn point_support_map_toi_penetrating() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos12 = Isometry3::from_xyz(0.5, 0.0, 0.0);
+
+    let toi = time_of_impact_point_support_map(pos12, Vector3::X, &cuboid, 10.0).unwrap();
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+}
@@ -0,0 +1,38 @@
+use barry3d::math::Vector3;
+use barry3d::shape::Cuboid;
+use barry3d::utils::polyhedron_centroid;
+
+#[test]
+fn tetrahedron_centroid() {
+    let vertices = [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(4.0, 0.0, 0.0),
+        Vector3::new(0.0, 4.0, 0.0),
+        Vector3::new(0.0, 0.0, 4.0),
+    ];
+    let faces = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]];
+
+    assert_relative_eq!(
+        polyhedron_centroid(&vertices, &faces).unwrap(),
+        Vector3::new(1.0, 1.0, 1.0),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn cuboid_centroid() {
+    let offset = Vector3::new(1.0, -2.0, 3.0);
+    let (vertices, faces) = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh();
+    let vertices: Vec<_> = vertices.into_iter().map(|v| v + offset).collect();
+
+    assert_relative_eq!(
+        polyhedron_centroid(&vertices, &faces).unwrap(),
+        offset,
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn empty_polyhedron_centroid() {
+    assert_eq!(polyhedron_centroid(&[], &[]), None);
+}
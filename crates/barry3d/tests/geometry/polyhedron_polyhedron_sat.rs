@@ -0,0 +1,107 @@
+use barry3d::math::{real_consts, Isometry3, Real, Vector3};
+use barry3d::query::{sat, DefaultQueryDispatcher, PointQuery, QueryDispatcher};
+use barry3d::shape::ConvexPolyhedron;
+
+use super::rand_vector;
+
+fn cube(half_extent: Real) -> ConvexPolyhedron {
+    let mut points = vec![];
+    for i in 0..8 {
+        let sign = |bit: usize| if i & (1 << bit) == 0 { -1.0 } else { 1.0 };
+        points.push(Vector3::new(sign(0), sign(1), sign(2)) * half_extent);
+    }
+    ConvexPolyhedron::from_convex_hull(&points).unwrap()
+}
+
+#[test]
+fn stacked_cubes() {
+    let cube = cube(1.0);
+    let pos12 = Isometry3::from_xyz(0.1, 1.8, -0.2);
+
+    let (axis, depth, points) = sat::polyhedron_polyhedron_sat(&cube, &cube, pos12).unwrap();
+    assert_relative_eq!(axis, Vector3::Y, epsilon = 1.0e-5);
+    assert_relative_eq!(depth, 0.2, epsilon = 1.0e-5);
+
+    // The contact points are the corners of the overlap of the two square faces.
+    assert_eq!(points.len(), 4);
+    for pt in points {
+        assert_relative_eq!(pt.y, 0.8, epsilon = 1.0e-5);
+        assert!(pt.x >= -0.9 - 1.0e-5 && pt.x <= 1.0 + 1.0e-5);
+        assert!(pt.z >= -1.0 - 1.0e-5 && pt.z <= 0.8 + 1.0e-5);
+    }
+
+    let disjoint = Isometry3::from_xyz(0.1, 2.1, -0.2);
+    assert!(sat::polyhedron_polyhedron_sat(&cube, &cube, disjoint).is_none());
+}
+
+#[test]
+fn crossed_cube_edges() {
+    let cube = cube(1.0);
+    // Rotate each cube by 45 degrees so that the top edge of the first one, parallel to `X`,
+    // crosses the bottom edge of the second one, parallel to `Z`.
+    let pos1 = Isometry3::new(Vector3::ZERO, Vector3::X * real_consts::FRAC_PI_4);
+    let height = 2.0 * Real::sqrt(2.0) - 0.1;
+    let pos2 = Isometry3::new(Vector3::Y * height, Vector3::Z * real_consts::FRAC_PI_4);
+    let pos12 = pos1.inv_mul(pos2);
+
+    let (axis, depth, points) = sat::polyhedron_polyhedron_sat(&cube, &cube, pos12).unwrap();
+    assert_relative_eq!(pos1.rotation * axis, Vector3::Y, epsilon = 1.0e-5);
+    assert_relative_eq!(depth, 0.1, epsilon = 1.0e-5);
+
+    // The contact point is on the bottom edge of the second cube, where the edges cross.
+    assert_eq!(points.len(), 1);
+    assert_relative_eq!(
+        pos1.transform_point(points[0]),
+        Vector3::new(0.0, Real::sqrt(2.0) - 0.1, 0.0),
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn random_hulls_match_gjk_epa() {
+    let mut rng = oorandom::Rand32::new(42);
+
+    let mut num_penetrations = 0;
+
+    for _ in 0..200 {
+        let points1: Vec<_> = (0..12).map(|_| rand_vector(&mut rng, 1.0)).collect();
+        let points2: Vec<_> = (0..12).map(|_| rand_vector(&mut rng, 1.0)).collect();
+        let poly1 = ConvexPolyhedron::from_convex_hull(&points1).unwrap();
+        let poly2 = ConvexPolyhedron::from_convex_hull(&points2).unwrap();
+        let pos12 = Isometry3::new(rand_vector(&mut rng, 1.5), rand_vector(&mut rng, 3.0));
+
+        let sat = sat::polyhedron_polyhedron_sat(&poly1, &poly2, pos12);
+        let contact = DefaultQueryDispatcher
+            .contact(pos12, &poly1, &poly2, 0.0)
+            .unwrap();
+
+        // Skip the cases too close to touching to be classified reliably by both algorithms.
+        if contact.map_or(false, |c| c.dist.abs() < 1.0e-3) {
+            continue;
+        }
+
+        match (sat, contact) {
+            (None, None) => {}
+            (Some((axis, depth, points)), Some(contact)) => {
+                num_penetrations += 1;
+                assert_relative_eq!(depth, -contact.dist, epsilon = 1.0e-3);
+
+                if depth > 0.05 {
+                    assert!(axis.dot(*contact.normal1) > 0.99);
+                }
+
+                // The contact points lie in the overlap region, up to the penetration depth.
+                assert!(!points.is_empty());
+                for pt in points {
+                    let dist1 = poly1.distance_to_point(Isometry3::IDENTITY, pt, true);
+                    let dist2 = poly2.distance_to_point(pos12, pt, true);
+                    assert!(dist1 <= depth + 1.0e-3);
+                    assert!(dist2 <= depth + 1.0e-3);
+                }
+            }
+            (sat, contact) => panic!("SAT and GJK disagree: {:?} vs. {:?}", sat, contact),
+        }
+    }
+
+    assert!(num_penetrations > 10);
+}
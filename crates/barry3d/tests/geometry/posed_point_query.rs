@@ -0,0 +1,41 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{PointQuery, PosedPointQuery};
+use barry3d::shape::Cuboid;
+
+#[test]
+fn posed_point_query_matches_point_query() {
+    let mut rng = oorandom::Rand32::new(42);
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let pos = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 1.2, -0.4));
+    let posed = PosedPointQuery::new(pos, &cuboid);
+
+    for _ in 0..1000 {
+        let pt = Vector3::new(rng.rand_float(), rng.rand_float(), rng.rand_float()) * 8.0
+            + Vector3::new(-3.0, -6.0, -1.0);
+
+        assert_eq!(posed.contains(pt), cuboid.contains_point(pos, pt));
+
+        let proj = posed.project(pt, false);
+        let expected = cuboid.project_point(pos, pt, false);
+        assert_eq!(proj.is_inside, expected.is_inside);
+        assert_relative_eq!(proj.point, expected.point, epsilon = 1.0e-4);
+        assert_relative_eq!(
+            posed.distance(pt, true),
+            cuboid.distance_to_point(pos, pt, true),
+            epsilon = 1.0e-4
+        );
+    }
+}
+
+#[test]
+fn posed_point_query_projects_in_world_space() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let pos = Isometry3::from_xyz(10.0, 0.0, 0.0);
+    let posed = PosedPointQuery::new(pos, &cuboid);
+
+    let proj = posed.project(Vector3::new(13.0, 0.5, 0.0), false);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(proj.point, Vector3::new(11.0, 0.5, 0.0), epsilon = 1.0e-5);
+    assert!(posed.contains(Vector3::new(10.5, 0.0, 0.0)));
+    assert!(!posed.contains(Vector3::new(0.5, 0.0, 0.0)));
+}
@@ -0,0 +1,46 @@
+use barry3d::math::{Isometry3, Real, UnitVector3, Vector3};
+use barry3d::shape::{Ball, Cuboid, Shape};
+
+use super::rand_vector;
+
+fn random_axes() -> Vec<UnitVector3> {
+    let mut rng = oorandom::Rand32::new(42);
+
+    (0..50)
+        .filter_map(|_| UnitVector3::new(rand_vector(&mut rng, 1.0)).ok())
+        .collect()
+}
+
+#[test]
+fn cuboid_projection_matches_vertices() {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let pos = Isometry3::new(Vector3::new(1.0, -3.0, 2.0), Vector3::new(0.4, 1.1, -0.3));
+
+    for axis in random_axes() {
+        let (min, max) = cuboid.project_onto_axis(pos, axis);
+        let projections = cuboid
+            .local_aabb()
+            .vertices()
+            .map(|pt| pos.transform_point(pt).dot(*axis));
+        let expected_min = projections.iter().copied().fold(Real::MAX, Real::min);
+        let expected_max = projections.iter().copied().fold(-Real::MAX, Real::max);
+
+        assert_relative_eq!(min, expected_min, epsilon = 1.0e-4);
+        assert_relative_eq!(max, expected_max, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn ball_projection() {
+    let ball = Ball::new(0.75);
+    let center = Vector3::new(3.0, 1.0, -2.0);
+    let pos = Isometry3::new(center, Vector3::new(0.2, -0.5, 0.9));
+
+    for axis in random_axes() {
+        let (min, max) = ball.project_onto_axis(pos, axis);
+        let c = center.dot(*axis);
+
+        assert_relative_eq!(min, c - 0.75, epsilon = 1.0e-4);
+        assert_relative_eq!(max, c + 0.75, epsilon = 1.0e-4);
+    }
+}
@@ -0,0 +1,87 @@
+use barry3d::math::{Isometry3, Real, UnitVector, Vector3};
+use barry3d::query::PointQuery;
+use barry3d::shape::{Ball, Cuboid, Shape};
+
+#[test]
+fn ball_project_point_in_halfspace() {
+    let ball = Ball::new(1.0);
+    let plane_point = Vector3::new(0.0, 0.5, 0.0);
+    let pt = Vector3::new(2.0, 0.0, 0.0);
+
+    // The unconstrained projection lies below the plane.
+    let unconstrained = ball.project_local_point(pt, false).point;
+    assert_relative_eq!(unconstrained, Vector3::X, epsilon = 1.0e-6);
+
+    let proj = ball
+        .project_local_point_in_halfspace(pt, plane_point, UnitVector::Y)
+        .unwrap();
+    assert_relative_eq!(
+        proj,
+        Vector3::new(Real::sqrt(0.75), 0.5, 0.0),
+        epsilon = 1.0e-6
+    );
+
+    // A point whose unconstrained projection is above the plane is projected normally.
+    let above = Vector3::new(0.0, 3.0, 0.0);
+    let proj = ball
+        .project_local_point_in_halfspace(above, plane_point, UnitVector::Y)
+        .unwrap();
+    assert_relative_eq!(proj, Vector3::Y, epsilon = 1.0e-6);
+
+    // The ball is entirely below the plane.
+    assert!(ball
+        .project_local_point_in_halfspace(pt, Vector3::new(0.0, 1.5, 0.0), UnitVector::Y)
+        .is_none());
+}
+
+#[test]
+fn cuboid_project_point_in_halfspace() {
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let plane_point = Vector3::new(0.0, 0.5, 0.0);
+    let pt = Vector3::new(3.0, -3.0, 0.2);
+
+    let unconstrained = cuboid.project_local_point(pt, false).point;
+    assert_relative_eq!(
+        unconstrained,
+        Vector3::new(1.0, -1.0, 0.2),
+        epsilon = 1.0e-6
+    );
+
+    let proj = cuboid
+        .project_local_point_in_halfspace(pt, plane_point, UnitVector::Y)
+        .unwrap();
+    assert_relative_eq!(proj, Vector3::new(1.0, 0.5, 0.2), epsilon = 1.0e-6);
+
+    // A point inside of the cuboid, closest to the bottom face.
+    let inside = Vector3::new(0.1, -0.9, -0.3);
+    let proj = cuboid
+        .project_local_point_in_halfspace(inside, plane_point, UnitVector::Y)
+        .unwrap();
+    assert_relative_eq!(proj.y, 0.5, epsilon = 1.0e-6);
+    assert!(proj.x.abs() == 1.0 || proj.z.abs() == 1.0);
+
+    assert!(cuboid
+        .project_local_point_in_halfspace(pt, Vector3::new(0.0, 1.5, 0.0), UnitVector::Y)
+        .is_none());
+}
+
+#[test]
+fn shape_project_point_in_halfspace_world_space() {
+    let pos = Isometry3::from_xyz(1.0, 2.0, 3.0);
+    let plane_point = pos.translation + Vector3::new(0.0, 0.5, 0.0);
+    let pt = pos.translation + Vector3::new(2.0, 0.0, 0.0);
+
+    let shapes: [&dyn Shape; 2] = [&Ball::new(1.0), &Cuboid::new(Vector3::splat(1.0))];
+    let expected = [
+        Vector3::new(Real::sqrt(0.75), 0.5, 0.0),
+        Vector3::new(1.0, 0.5, 0.0),
+    ];
+
+    for (shape, expected) in shapes.iter().zip(expected.iter()) {
+        let proj = shape
+            .project_point_in_halfspace(pos, pt, plane_point, UnitVector::Y)
+            .unwrap();
+        assert!((proj - plane_point).dot(Vector3::Y) >= -1.0e-6);
+        assert_relative_eq!(proj, pos.translation + *expected, epsilon = 1.0e-5);
+    }
+}
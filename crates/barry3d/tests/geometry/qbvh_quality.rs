@@ -0,0 +1,49 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Real, Vector3};
+use barry3d::partitioning::{Qbvh, QbvhUpdateWorkspace};
+
+const GRID_SIZE: u32 = 16;
+
+fn cell_aabb(cell: u32) -> Aabb {
+    let center = Vector3::new((cell % GRID_SIZE) as Real, (cell / GRID_SIZE) as Real, 0.0) * 2.0;
+    Aabb::from_half_extents(center, Vector3::splat(0.5))
+}
+
+#[test]
+fn qbvh_quality_ranks_trees() {
+    let num_cells = GRID_SIZE * GRID_SIZE;
+
+    let mut good = Qbvh::new();
+    good.clear_and_rebuild((0..num_cells).map(|i| (i, cell_aabb(i))), 0.0);
+
+    // Build the same tree, then shuffle its leaves across the grid. Refitting doesn’t change the
+    // topology, so sibling leaves end up far apart.
+    let mut rng = oorandom::Rand32::new(42);
+    let mut permutation: Vec<u32> = (0..num_cells).collect();
+    for i in (1..permutation.len()).rev() {
+        permutation.swap(i, rng.rand_range(0..i as u32 + 1) as usize);
+    }
+
+    let mut bad = good.clone();
+    let mut workspace = QbvhUpdateWorkspace::default();
+    for i in 0..num_cells {
+        bad.pre_update_or_insert(i);
+    }
+    let _ = bad.refit(0.0, &mut workspace, |i| cell_aabb(permutation[*i as usize]));
+
+    // Rebuilding the shuffled tree restores its quality.
+    let mut rebuilt = Qbvh::new();
+    rebuilt.clear_and_rebuild(
+        (0..num_cells).map(|i| (i, cell_aabb(permutation[i as usize]))),
+        0.0,
+    );
+
+    let good_quality = good.quality();
+    let bad_quality = bad.quality();
+    let rebuilt_quality = rebuilt.quality();
+
+    assert!(good_quality > 0.0);
+    assert!(bad_quality > good_quality * 1.5);
+    assert!(rebuilt_quality < bad_quality);
+    assert_eq!(Qbvh::<u32>::new().quality(), 0.0);
+}
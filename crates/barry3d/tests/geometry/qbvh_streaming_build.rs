@@ -0,0 +1,48 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::Vector3;
+use barry3d::partitioning::Qbvh;
+use barry3d::shape::{Ball, Triangle};
+
+use super::rand_vector;
+
+#[test]
+fn streaming_build_matches_batch_build() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 20);
+    let triangle_aabb = |i: u32| {
+        let idx = indices[i as usize];
+        Triangle::new(
+            vertices[idx[0] as usize],
+            vertices[idx[1] as usize],
+            vertices[idx[2] as usize],
+        )
+        .local_aabb()
+    };
+
+    let aabbs: Vec<_> = (0..indices.len() as u32)
+        .map(|i| (i, triangle_aabb(i)))
+        .collect();
+    let mut batch = Qbvh::new();
+    batch.clear_and_rebuild(aabbs.into_iter(), 0.0);
+
+    let mut streaming = Qbvh::new();
+    streaming.clear_and_rebuild_with_fn(indices.len() as u32, triangle_aabb, 0.0);
+
+    assert_eq!(batch.root_aabb(), streaming.root_aabb());
+    assert_eq!(batch.raw_nodes().len(), streaming.raw_nodes().len());
+    for (a, b) in batch.raw_nodes().iter().zip(streaming.raw_nodes()) {
+        assert_eq!(a.children, b.children);
+    }
+
+    let mut rng = oorandom::Rand32::new(42);
+    for _ in 0..100 {
+        let center = rand_vector(&mut rng, 1.0);
+        let region = Aabb::from_half_extents(center, Vector3::splat(rng.rand_float() * 0.5));
+
+        let mut batch_hits = vec![];
+        let mut streaming_hits = vec![];
+        batch.intersect_aabb(&region, &mut batch_hits);
+        streaming.intersect_aabb(&region, &mut streaming_hits);
+
+        assert_eq!(batch_hits, streaming_hits);
+    }
+}
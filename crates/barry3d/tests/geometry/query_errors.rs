@@ -0,0 +1,56 @@
+use approx::assert_relative_eq;
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::details::try_distance_support_map_support_map;
+use barry3d::query::epa::EPA;
+use barry3d::query::gjk::VoronoiSimplex;
+use barry3d::query::QueryError;
+use barry3d::shape::{Ball, SupportMap};
+use std::cell::Cell;
+
+// A (non-convex) "shape" whose support points move slowly toward the origin along the
+// `X` axis at each evaluation, so that GJK keeps making just enough progress to never
+// converge before reaching its iteration limit.
+struct ShrinkingRay {
+    radius: Cell<Real>,
+}
+
+impl SupportMap for ShrinkingRay {
+    fn local_support_point(&self, _: Vector3) -> Vector3 {
+        let radius = self.radius.get();
+        self.radius.set(radius * 0.996);
+        Vector3::X * radius
+    }
+}
+
+#[test]
+fn try_distance_reports_non_convergence() {
+    let shape = ShrinkingRay {
+        radius: Cell::new(1.0e18),
+    };
+    let result = try_distance_support_map_support_map(Isometry3::IDENTITY, &shape, &Ball::new(0.0));
+    assert_eq!(result, Err(QueryError::NotConverged));
+}
+
+#[test]
+fn try_distance_reports_non_finite_inputs() {
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(Real::NAN, 0.0, 0.0);
+    let result = try_distance_support_map_support_map(pos12, &ball, &ball);
+    assert_eq!(result, Err(QueryError::NonFiniteInput));
+}
+
+#[test]
+fn try_distance_between_separated_balls() {
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(0.0, 5.0, 0.0);
+    let dist = try_distance_support_map_support_map(pos12, &ball, &ball).unwrap();
+    assert_relative_eq!(dist, 3.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn try_epa_reports_non_finite_inputs() {
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(0.0, Real::INFINITY, 0.0);
+    let result = EPA::new().try_closest_points(pos12, &ball, &ball, &VoronoiSimplex::new());
+    assert_eq!(result.err(), Some(QueryError::NonFiniteInput));
+}
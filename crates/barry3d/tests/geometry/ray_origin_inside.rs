@@ -0,0 +1,45 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Real, Vector3};
+use barry3d::query::{Ray, RayCast};
+use barry3d::shape::{Ball, Cuboid, Cylinder};
+
+#[test]
+fn cuboid_ray_origin_inside_and_outside() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let dir = Vector3::new(1.0, 0.0, 0.0);
+
+    let inside = Ray::new(Vector3::new(0.5, -1.5, 2.5), dir);
+    let outside = Ray::new(Vector3::new(-5.0, 0.0, 0.0), dir);
+    let behind = Ray::new(Vector3::new(5.0, 0.0, 0.0), dir);
+
+    assert!(cuboid.origin_inside(&inside));
+    assert!(!cuboid.origin_inside(&outside));
+    assert!(!cuboid.origin_inside(&behind));
+
+    // The solid cast reports an immediate hit exactly when the origin is inside.
+    assert_eq!(cuboid.cast_local_ray(&inside, Real::MAX, true), Some(0.0));
+    assert_eq!(cuboid.cast_local_ray(&outside, Real::MAX, true), Some(4.0));
+    assert_eq!(cuboid.cast_local_ray(&behind, Real::MAX, true), None);
+
+    let aabb = Aabb::new(-cuboid.half_extents, cuboid.half_extents);
+    assert!(aabb.origin_inside(&inside));
+    assert!(!aabb.origin_inside(&outside));
+}
+
+#[test]
+fn ray_origin_inside_matches_solid_cast() {
+    let ball = Ball::new(1.0);
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let dir = Vector3::new(0.0, 0.0, 1.0);
+
+    for (origin, in_ball, in_cylinder) in [
+        (Vector3::ZERO, true, true),
+        (Vector3::new(0.0, 0.9, 0.0), true, true),
+        (Vector3::new(0.0, 0.0, 0.9), true, false),
+        (Vector3::new(0.0, 1.5, 0.0), false, false),
+    ] {
+        let ray = Ray::new(origin, dir);
+        assert_eq!(ball.origin_inside(&ray), in_ball);
+        assert_eq!(cylinder.origin_inside(&ray), in_cylinder);
+    }
+}
@@ -0,0 +1,31 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::Ray;
+use barry3d::shape::{Ball, Cuboid, Shape};
+
+#[test]
+fn ray_fully_inside_ball() {
+    let ball = Ball::new(10.0);
+    let pos = Isometry3::from_xyz(1.0, 2.0, 3.0);
+    let ray = Ray::new(Vector3::new(0.0, 2.0, 3.0), Vector3::X);
+
+    assert!(ball.ray_stays_inside(pos, &ray, 5.0));
+}
+
+#[test]
+fn ray_exiting_ball() {
+    let ball = Ball::new(10.0);
+    let pos = Isometry3::from_xyz(1.0, 2.0, 3.0);
+    let ray = Ray::new(Vector3::new(0.0, 2.0, 3.0), Vector3::X);
+
+    // The ray leaves the ball at `x = 11`.
+    assert!(!ball.ray_stays_inside(pos, &ray, 12.0));
+}
+
+#[test]
+fn ray_starting_outside_cuboid() {
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let ray = Ray::new(Vector3::new(-2.0, 0.0, 0.0), Vector3::X);
+
+    // The ray enters the cuboid but doesn't start inside of it.
+    assert!(!cuboid.ray_stays_inside(Isometry3::IDENTITY, &ray, 1.5));
+}
@@ -0,0 +1,63 @@
+use barry3d::math::{Real, Vector3};
+use barry3d::query::details::reduce_contacts;
+use barry3d::query::{ContactManifold, TrackedContact};
+use barry3d::shape::PackedFeatureId;
+
+fn manifold_with_points(points: &[(Vector3, Real)]) -> ContactManifold<(), ()> {
+    let mut manifold = ContactManifold::new();
+    manifold.local_n1 = Vector3::Y;
+    manifold.local_n2 = -Vector3::Y;
+
+    for (pt, dist) in points {
+        manifold.points.push(TrackedContact::new(
+            *pt,
+            *pt - Vector3::Y * *dist,
+            PackedFeatureId::UNKNOWN,
+            PackedFeatureId::UNKNOWN,
+            *dist,
+        ));
+    }
+
+    manifold
+}
+
+#[test]
+fn reduce_coplanar_points_to_convex_extremes() {
+    let points = [
+        (Vector3::new(0.5, 0.0, 0.0), -0.05),
+        (Vector3::new(-1.0, 0.0, -1.0), -0.05),
+        (Vector3::new(0.0, 0.0, 0.5), -0.05),
+        (Vector3::new(1.0, 0.0, -1.0), -0.05),
+        (Vector3::new(-0.5, 0.0, 0.0), -0.05),
+        (Vector3::new(-1.0, 0.0, 1.0), -0.05),
+        (Vector3::new(0.0, 0.0, -0.5), -0.05),
+        (Vector3::new(1.0, 0.0, 1.0), -0.1),
+    ];
+    let mut manifold = manifold_with_points(&points);
+
+    reduce_contacts(&mut manifold, 4);
+
+    assert_eq!(manifold.points.len(), 4);
+    assert_eq!(manifold.find_deepest_contact().unwrap().dist, -0.1);
+
+    for pt in &manifold.points {
+        assert_eq!(pt.local_p1.x.abs(), 1.0);
+        assert_eq!(pt.local_p1.z.abs(), 1.0);
+    }
+}
+
+#[test]
+fn reduce_keeps_manifolds_with_few_points() {
+    let points = [
+        (Vector3::new(1.0, 0.0, 0.0), -0.1),
+        (Vector3::new(-1.0, 0.0, 0.0), -0.2),
+    ];
+    let mut manifold = manifold_with_points(&points);
+
+    reduce_contacts(&mut manifold, 4);
+    assert_eq!(manifold.points.len(), 2);
+
+    reduce_contacts(&mut manifold, 1);
+    assert_eq!(manifold.points.len(), 1);
+    assert_eq!(manifold.points[0].dist, -0.2);
+}
@@ -0,0 +1,20 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::sat;
+use barry3d::shape::Cuboid;
+
+#[test]
+fn translated_cuboids_separation() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let pos12 = Isometry3::from_xyz(3.0, 0.2, -0.1);
+
+    let (separation, dir) =
+        sat::cuboid_cuboid_find_local_separating_normal_oneway(&cuboid1, &cuboid2, pos12);
+    assert_relative_eq!(separation, 1.5, epsilon = 1.0e-6);
+    assert_eq!(dir, Vector3::X);
+
+    let (separation, dir) =
+        sat::cuboid_cuboid_compute_separation_wrt_local_line(&cuboid1, &cuboid2, pos12, Vector3::X);
+    assert_relative_eq!(separation, 1.5, epsilon = 1.0e-6);
+    assert_eq!(dir, Vector3::X);
+}
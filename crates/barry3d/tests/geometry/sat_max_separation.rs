@@ -0,0 +1,55 @@
+use barry3d::math::{Isometry3, UnitVector, Vector3};
+use barry3d::query::sat;
+use barry3d::shape::Cuboid;
+
+#[test]
+fn support_map_max_separation_matches_cuboid_cuboid() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.3, 1.5, 0.7));
+    let poses = [
+        Isometry3::from_xyz(3.0, 0.2, -0.1),
+        Isometry3::new(Vector3::new(-1.0, 2.5, 0.5), Vector3::new(0.3, -0.2, 1.1)),
+        Isometry3::new(Vector3::new(0.1, 0.1, 0.1), Vector3::new(1.0, 0.5, 0.2)),
+    ];
+    let dirs = [
+        Vector3::X,
+        Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(-0.2, 0.7, 0.4),
+        Vector3::new(0.0, -1.0, 3.0),
+    ];
+
+    for pos12 in poses {
+        for dir in dirs {
+            let dir = UnitVector::new(dir).unwrap();
+            let (expected, _) = sat::cuboid_cuboid_compute_separation_wrt_local_line(
+                &cuboid1, &cuboid2, pos12, *dir,
+            );
+            let separation = sat::support_map_max_separation(pos12, &cuboid1, &cuboid2, dir);
+            assert_relative_eq!(separation, expected, epsilon = 1.0e-5);
+        }
+    }
+}
+
+#[test]
+fn cuboid_cuboid_separating_normal_witnesses() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let pos12 = Isometry3::from_xyz(3.0, 0.2, 0.0);
+
+    let (separation, axis, pt1, pt2) =
+        sat::cuboid_cuboid_find_local_separating_normal_oneway_with_witnesses(
+            &cuboid1, &cuboid2, pos12,
+        );
+
+    assert_relative_eq!(separation, 1.5);
+    assert_eq!(axis, Vector3::X);
+    // `pt1` lies on the +X face of `cuboid1`, and `pt2` on the -X face of `cuboid2`.
+    assert_relative_eq!(pt1.x, 1.0);
+    assert!(pt1.y.abs() <= 1.0 && pt1.z.abs() <= 1.0);
+    assert_relative_eq!(pt2.x, -0.5);
+    assert_relative_eq!(
+        (pos12.transform_point(pt2) - pt1).dot(axis),
+        separation,
+        epsilon = 1.0e-5
+    );
+}
@@ -0,0 +1,24 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::Segment;
+
+#[test]
+fn segment_transformed_translates_endpoints() {
+    let segment = Segment::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, -1.0));
+    let pos = Isometry3::new(Vector3::new(3.0, -1.0, 2.0), Vector3::new(0.2, 0.3, -0.4));
+
+    let transformed = segment.transformed(pos);
+    assert_relative_eq!(
+        transformed.a,
+        pos.transform_point(segment.a),
+        epsilon = 1.0e-6
+    );
+    assert_relative_eq!(
+        transformed.b,
+        pos.transform_point(segment.b),
+        epsilon = 1.0e-6
+    );
+
+    let translated = segment.transformed(Isometry3::from_xyz(3.0, -1.0, 2.0));
+    assert_eq!(translated.a, Vector3::new(4.0, -1.0, 2.0));
+    assert_eq!(translated.b, Vector3::new(3.0, 1.0, 1.0));
+}
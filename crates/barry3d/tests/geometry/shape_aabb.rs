@@ -0,0 +1,95 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{real_consts, Isometry3, Real, Vector3};
+use barry3d::shape::{Ball, Capsule, Cone, Cuboid, Cylinder, Shape};
+
+fn rot45(translation: Vector3) -> Isometry3 {
+    Isometry3::new(translation, Vector3::new(0.0, 0.0, real_consts::FRAC_PI_4))
+}
+
+fn assert_aabb_eq(aabb: Aabb, mins: Vector3, maxs: Vector3) {
+    assert_relative_eq!(aabb.mins, mins, epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.maxs, maxs, epsilon = 1.0e-5);
+}
+
+const S: Real = real_consts::FRAC_1_SQRT_2;
+
+#[test]
+fn ball_aabb() {
+    let ball = Ball::new(1.0);
+    let t = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_aabb_eq(
+        ball.compute_aabb(Isometry3::IDENTITY),
+        Vector3::splat(-1.0),
+        Vector3::splat(1.0),
+    );
+    assert_aabb_eq(
+        ball.compute_aabb(rot45(t)),
+        t - Vector3::splat(1.0),
+        t + Vector3::splat(1.0),
+    );
+}
+
+#[test]
+fn cuboid_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let t = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_aabb_eq(
+        cuboid.compute_aabb(Isometry3::IDENTITY),
+        -Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+
+    let he = Vector3::new(3.0 * S, 3.0 * S, 3.0);
+    assert_aabb_eq(cuboid.compute_aabb(rot45(t)), t - he, t + he);
+}
+
+#[test]
+fn capsule_aabb() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+    let t = Vector3::new(-1.0, 0.5, 2.0);
+
+    assert_aabb_eq(
+        capsule.compute_aabb(Isometry3::IDENTITY),
+        Vector3::new(-0.5, -1.5, -0.5),
+        Vector3::new(0.5, 1.5, 0.5),
+    );
+
+    let he = Vector3::new(S + 0.5, S + 0.5, 0.5);
+    assert_aabb_eq(capsule.compute_aabb(rot45(t)), t - he, t + he);
+}
+
+#[test]
+fn cone_aabb() {
+    let cone = Cone::new(1.0, 0.5);
+    let t = Vector3::new(2.0, -1.0, 0.5);
+
+    assert_aabb_eq(
+        cone.compute_aabb(Isometry3::IDENTITY),
+        Vector3::new(-0.5, -1.0, -0.5),
+        Vector3::new(0.5, 1.0, 0.5),
+    );
+
+    // The apex ends up at `(-S, S, 0)` and the base circle is centered at `(S, -S, 0)`.
+    assert_aabb_eq(
+        cone.compute_aabb(rot45(t)),
+        t + Vector3::new(-S, -S - 0.5 * S, -0.5),
+        t + Vector3::new(S + 0.5 * S, S, 0.5),
+    );
+}
+
+#[test]
+fn cylinder_aabb() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+    let t = Vector3::new(0.0, 3.0, -2.0);
+
+    assert_aabb_eq(
+        cylinder.compute_aabb(Isometry3::IDENTITY),
+        Vector3::new(-0.5, -1.0, -0.5),
+        Vector3::new(0.5, 1.0, 0.5),
+    );
+
+    let he = Vector3::new(1.5 * S, 1.5 * S, 0.5);
+    assert_aabb_eq(cylinder.compute_aabb(rot45(t)), t - he, t + he);
+}
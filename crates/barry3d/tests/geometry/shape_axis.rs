@@ -0,0 +1,107 @@
+use barry3d::math::{real_consts, Isometry3, Vector3};
+use barry3d::query::PointQuery;
+use barry3d::shape::{Cone, CoordinateAxis, Cylinder, Shape, SupportMap};
+
+fn dirs() -> [Vector3; 5] {
+    [
+        Vector3::new(0.3, 0.8, 0.1),
+        Vector3::new(-1.0, 0.2, 0.5),
+        Vector3::new(0.1, -0.4, -2.0),
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::new(0.7, -0.7, 0.2),
+    ]
+}
+
+#[test]
+fn z_axis_cylinder_matches_rotated_y_axis_cylinder() {
+    let cyl_y = Cylinder::new(1.5, 0.5);
+    let cyl_z = Cylinder::with_axis(1.5, 0.5, CoordinateAxis::Z);
+    // Rotates the `Y` axis onto the `Z` axis.
+    let pos = Isometry3::new(Vector3::ZERO, Vector3::X * real_consts::FRAC_PI_2);
+
+    for dir in dirs() {
+        assert_relative_eq!(
+            cyl_z.local_support_point(dir),
+            cyl_y.support_point(pos, dir),
+            epsilon = 1.0e-5
+        );
+    }
+
+    let aabb = cyl_z.local_aabb();
+    assert_relative_eq!(aabb.maxs, Vector3::new(0.5, 0.5, 1.5), epsilon = 1.0e-5);
+    assert_relative_eq!(aabb.mins, -Vector3::new(0.5, 0.5, 1.5), epsilon = 1.0e-5);
+    assert_eq!(cyl_z.local_bounding_sphere(), cyl_y.local_bounding_sphere());
+
+    let proj = cyl_z.project_local_point(Vector3::new(0.1, 0.2, 3.0), false);
+    assert_relative_eq!(proj.point, Vector3::new(0.1, 0.2, 1.5), epsilon = 1.0e-5);
+}
+
+#[test]
+fn x_axis_cone_matches_rotated_y_axis_cone() {
+    let cone_y = Cone::new(1.0, 0.75);
+    let cone_x = Cone::with_axis(1.0, 0.75, CoordinateAxis::X);
+    // Rotates the `Y` axis onto the `X` axis.
+    let pos = Isometry3::new(Vector3::ZERO, -Vector3::Z * real_consts::FRAC_PI_2);
+
+    for dir in dirs() {
+        assert_relative_eq!(
+            cone_x.local_support_point(dir),
+            cone_y.support_point(pos, dir),
+            epsilon = 1.0e-5
+        );
+    }
+
+    // The apex points toward +X.
+    assert_relative_eq!(
+        cone_x.local_support_point(Vector3::X),
+        Vector3::new(1.0, 0.0, 0.0),
+        epsilon = 1.0e-5
+    );
+
+    let mprops_x = cone_x.mass_properties(1.0);
+    let mprops_y = cone_y.mass_properties(1.0);
+    assert_relative_eq!(mprops_x.mass(), mprops_y.mass(), epsilon = 1.0e-5);
+    assert_relative_eq!(
+        mprops_x.local_com,
+        Vector3::new(mprops_y.local_com.y, 0.0, 0.0),
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn default_axis_is_y() {
+    assert_eq!(Cylinder::new(1.0, 0.5).axis, CoordinateAxis::Y);
+    assert_eq!(Cone::new(1.0, 0.5).axis, CoordinateAxis::default());
+}
+
+#[test]
+fn y_up_permutations_are_inverse_rotations() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    for axis in [CoordinateAxis::X, CoordinateAxis::Y, CoordinateAxis::Z] {
+        assert_eq!(axis.to_y_up(axis.unit_vector()), Vector3::Y);
+        assert_eq!(axis.from_y_up(axis.to_y_up(v)), v);
+        assert_relative_eq!(
+            axis.rotation_from_y_up() * axis.to_y_up(v),
+            v,
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn cone_struct_literal_with_zero_apex_sign() {
+    // A zero apex sign, e.g., from a zeroed or deserialized cone, means the apex points up.
+    let cone = Cone {
+        half_height: 1.0,
+        radius: 0.5,
+        axis: CoordinateAxis::Z,
+        apex_sign: 0.0,
+    };
+    assert_eq!(cone.apex_direction(), Vector3::Z);
+    assert_relative_eq!(
+        cone.local_support_point(Vector3::Z),
+        Vector3::Z,
+        epsilon = 1.0e-5
+    );
+}
@@ -0,0 +1,77 @@
+use barry3d::math::{real_consts, Isometry3, Vector3};
+use barry3d::shape::{Ball, Cone, Cuboid, Segment, Shape};
+
+fn pos() -> Isometry3 {
+    Isometry3::new(
+        Vector3::new(0.0, 0.0, 5.0),
+        Vector3::new(0.0, 0.0, real_consts::FRAC_PI_2),
+    )
+}
+
+#[test]
+fn ball_bounding_sphere() {
+    let ball = Ball::new(1.5);
+
+    let local = ball.local_bounding_sphere();
+    assert_eq!(local.center(), Vector3::ZERO);
+    assert_eq!(local.radius(), 1.5);
+
+    let sphere = ball.bounding_sphere(pos());
+    assert_relative_eq!(sphere.center(), Vector3::new(0.0, 0.0, 5.0));
+    assert_eq!(sphere.radius(), 1.5);
+    assert_eq!((&ball as &dyn Shape).compute_bounding_sphere(pos()), sphere);
+}
+
+#[test]
+fn cuboid_bounding_sphere() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 2.0));
+
+    let sphere = cuboid.bounding_sphere(pos());
+    assert_relative_eq!(sphere.center(), Vector3::new(0.0, 0.0, 5.0));
+    assert_relative_eq!(sphere.radius(), 3.0);
+    assert_eq!(
+        (&cuboid as &dyn Shape).compute_bounding_sphere(pos()),
+        sphere
+    );
+}
+
+#[test]
+fn off_center_bounding_sphere_is_rotated() {
+    let segment = Segment::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(3.0, 0.0, 0.0));
+
+    let sphere = segment.bounding_sphere(pos());
+    assert_relative_eq!(
+        sphere.center(),
+        Vector3::new(0.0, 2.0, 5.0),
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(sphere.radius(), 1.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn rotated_cone_bounding_sphere_contains_apex_and_base() {
+    let cone = Cone::new(1.0, 0.5);
+    let pos = Isometry3::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(real_consts::FRAC_PI_2, 0.0, 0.0),
+    );
+
+    let sphere = cone.bounding_sphere(pos);
+    let apex = pos.transform_point(Vector3::new(0.0, 1.0, 0.0));
+    let base_points = [
+        Vector3::new(0.5, -1.0, 0.0),
+        Vector3::new(-0.5, -1.0, 0.0),
+        Vector3::new(0.0, -1.0, 0.5),
+        Vector3::new(0.0, -1.0, -0.5),
+    ];
+
+    // The apex is now along +Z.
+    assert_relative_eq!(apex, Vector3::new(1.0, 2.0, 4.0), epsilon = 1.0e-5);
+
+    let eps = 1.0e-5;
+    assert!(sphere.center().distance(apex) <= sphere.radius() + eps);
+    for pt in base_points {
+        let pt = pos.transform_point(pt);
+        assert!(sphere.center().distance(pt) <= sphere.radius() + eps);
+    }
+}
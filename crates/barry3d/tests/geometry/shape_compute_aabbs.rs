@@ -0,0 +1,42 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Ball, Cuboid, Shape, Triangle};
+
+use super::rand_vector;
+
+fn random_poses(n: usize) -> Vec<Isometry3> {
+    let mut rng = oorandom::Rand32::new(42);
+
+    (0..n)
+        .map(|_| Isometry3::new(rand_vector(&mut rng, 10.0), rand_vector(&mut rng, 3.0)))
+        .collect()
+}
+
+fn assert_matches_compute_aabb(shape: &dyn Shape, poses: &[Isometry3]) {
+    let aabbs = shape.compute_aabbs(poses);
+    assert_eq!(aabbs.len(), poses.len());
+
+    for (aabb, pos) in aabbs.iter().zip(poses.iter()) {
+        let expected = shape.compute_aabb(*pos);
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-4);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn compute_aabbs_matches_compute_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let ball = Ball::new(0.7);
+    let triangle = Triangle::new(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(2.0, 0.5, 0.0),
+        Vector3::new(-1.0, 1.0, 3.0),
+    );
+
+    // Include counts that are not multiples of the SIMD width.
+    for n in [0, 1, 3, 4, 7, 100, 1001] {
+        let poses = random_poses(n);
+        assert_matches_compute_aabb(&cuboid, &poses);
+        assert_matches_compute_aabb(&ball, &poses);
+        assert_matches_compute_aabb(&triangle, &poses);
+    }
+}
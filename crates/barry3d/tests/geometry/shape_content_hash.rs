@@ -0,0 +1,55 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Ball, Capsule, Compound, Cuboid, Cylinder, RoundCuboid, Shape, SharedShape};
+
+#[test]
+fn identical_shapes_have_equal_hashes() {
+    assert_eq!(
+        Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).content_hash(),
+        Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).content_hash()
+    );
+    assert_eq!(Ball::new(0.5).content_hash(), Ball::new(0.5).content_hash());
+
+    // The hash is computed from the parameters of the shape, not from its address.
+    let shared = SharedShape::capsule_y(1.0, 0.5);
+    let capsule = Capsule::new_y(1.0, 0.5);
+    assert_eq!(shared.content_hash(), capsule.content_hash());
+
+    let compound = || {
+        Compound::new(vec![
+            (Isometry3::from_xyz(1.0, 0.0, 0.0), SharedShape::ball(0.5)),
+            (Isometry3::IDENTITY, SharedShape::cuboid(1.0, 1.0, 1.0)),
+        ])
+    };
+    assert_eq!(compound().content_hash(), compound().content_hash());
+}
+
+#[test]
+fn different_shapes_have_different_hashes() {
+    let hashes = [
+        Ball::new(0.5).content_hash(),
+        Ball::new(0.6).content_hash(),
+        Cuboid::new(Vector3::splat(0.5)).content_hash(),
+        Cuboid::new(Vector3::new(0.5, 0.5, 0.6)).content_hash(),
+        Cuboid::new(Vector3::new(0.6, 0.5, 0.5)).content_hash(),
+        RoundCuboid {
+            inner_shape: Cuboid::new(Vector3::splat(0.5)),
+            border_radius: 0.1,
+        }
+        .content_hash(),
+        Capsule::new_y(0.5, 0.5).content_hash(),
+        Capsule::new_x(0.5, 0.5).content_hash(),
+        Cylinder::new(0.5, 0.5).content_hash(),
+        Compound::new(vec![(Isometry3::IDENTITY, SharedShape::ball(0.5))]).content_hash(),
+        Compound::new(vec![(
+            Isometry3::from_xyz(0.0, 1.0, 0.0),
+            SharedShape::ball(0.5),
+        )])
+        .content_hash(),
+    ];
+
+    for (i, h1) in hashes.iter().enumerate() {
+        for h2 in &hashes[i + 1..] {
+            assert_ne!(h1, h2);
+        }
+    }
+}
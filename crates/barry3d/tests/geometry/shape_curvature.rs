@@ -0,0 +1,75 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{Ball, Capsule, Cuboid, Cylinder, Shape};
+
+#[test]
+fn ball_curvature() {
+    let ball = Ball::new(2.0);
+    let pts = [
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, -2.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0).normalize() * 2.0,
+    ];
+
+    for pt in pts {
+        assert_eq!(ball.local_curvature_at(pt), [0.5, 0.5]);
+    }
+}
+
+#[test]
+fn cuboid_curvature() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let pts = [
+        Vector3::new(1.0, 0.5, 0.5),
+        Vector3::new(0.2, -2.0, 1.0),
+        Vector3::new(-0.3, 0.1, 3.0),
+    ];
+
+    for pt in pts {
+        assert_eq!(cuboid.local_curvature_at(pt), [0.0, 0.0]);
+    }
+}
+
+#[test]
+fn cylinder_curvature() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+
+    // Points on the side.
+    assert_eq!(
+        cylinder.local_curvature_at(Vector3::new(0.5, 0.2, 0.0)),
+        [2.0, 0.0]
+    );
+    assert_eq!(
+        cylinder.local_curvature_at(Vector3::new(0.0, -0.7, -0.5)),
+        [2.0, 0.0]
+    );
+
+    // Points on the caps.
+    assert_eq!(
+        cylinder.local_curvature_at(Vector3::new(0.1, 1.0, 0.1)),
+        [0.0, 0.0]
+    );
+    assert_eq!(
+        cylinder.local_curvature_at(Vector3::new(-0.2, -1.0, 0.0)),
+        [0.0, 0.0]
+    );
+}
+
+#[test]
+fn capsule_curvature() {
+    let capsule = Capsule::new_y(1.0, 0.5);
+
+    // Point on the cylindrical part.
+    assert_eq!(
+        capsule.local_curvature_at(Vector3::new(0.5, 0.3, 0.0)),
+        [2.0, 0.0]
+    );
+    // Points on the caps.
+    assert_eq!(
+        capsule.local_curvature_at(Vector3::new(0.0, 1.5, 0.0)),
+        [2.0, 2.0]
+    );
+    assert_eq!(
+        capsule.local_curvature_at(Vector3::new(0.0, -1.5, 0.0)),
+        [2.0, 2.0]
+    );
+}
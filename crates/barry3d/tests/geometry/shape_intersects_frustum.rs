@@ -0,0 +1,54 @@
+use barry3d::math::{real_consts, Isometry3, Real, UnitVector3, Vector3};
+use barry3d::shape::{Cuboid, Shape};
+
+// A frustum with its apex at the origin, looking toward `-Z` with a 90 degrees field of view,
+// between the near plane `z = -1` and the far plane `z = -10`.
+fn frustum() -> Vec<(UnitVector3, Real)> {
+    let plane = |x: Real, y: Real, z: Real, offset: Real| {
+        (UnitVector3::new(Vector3::new(x, y, z)).unwrap(), offset)
+    };
+
+    vec![
+        plane(1.0, 0.0, -1.0, 0.0),
+        plane(-1.0, 0.0, -1.0, 0.0),
+        plane(0.0, 1.0, -1.0, 0.0),
+        plane(0.0, -1.0, -1.0, 0.0),
+        plane(0.0, 0.0, -1.0, -1.0),
+        plane(0.0, 0.0, 1.0, 10.0),
+    ]
+}
+
+#[test]
+fn cuboid_fully_inside_frustum() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let pos = Isometry3::from_xyz(0.5, -1.0, -5.0);
+    assert!(cuboid.intersects_frustum(pos, &frustum()));
+}
+
+#[test]
+fn cuboid_straddling_frustum() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let planes = frustum();
+
+    // Across the right plane.
+    assert!(cuboid.intersects_frustum(Isometry3::from_xyz(5.0, 0.0, -5.0), &planes));
+    // Across the near plane.
+    assert!(cuboid.intersects_frustum(Isometry3::from_xyz(0.0, 0.0, -0.8), &planes));
+    // Across the far plane, rotated so only one of its corners is inside.
+    let pos = Isometry3::new(
+        Vector3::new(0.0, 0.0, -10.8),
+        Vector3::new(1.0, 1.0, 0.0).normalize() * real_consts::FRAC_PI_4,
+    );
+    assert!(cuboid.intersects_frustum(pos, &planes));
+}
+
+#[test]
+fn cuboid_fully_outside_frustum() {
+    let cuboid = Cuboid::new(Vector3::splat(0.5));
+    let planes = frustum();
+
+    assert!(!cuboid.intersects_frustum(Isometry3::from_xyz(10.0, 0.0, -5.0), &planes));
+    assert!(!cuboid.intersects_frustum(Isometry3::from_xyz(0.0, -8.0, -5.0), &planes));
+    assert!(!cuboid.intersects_frustum(Isometry3::from_xyz(0.0, 0.0, 2.0), &planes));
+    assert!(!cuboid.intersects_frustum(Isometry3::from_xyz(0.0, 0.0, -12.0), &planes));
+}
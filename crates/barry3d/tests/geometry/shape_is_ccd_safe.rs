@@ -0,0 +1,23 @@
+use barry3d::math::{Real, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid, Shape};
+
+#[test]
+fn required_margin_is_the_step_displacement() {
+    assert_relative_eq!(query::required_margin(10.0, 0.5), 5.0);
+    assert_relative_eq!(query::required_margin(3.0, 0.0), 0.0);
+}
+
+#[test]
+fn thin_wall_is_unsafe_at_high_speed() {
+    let dt: Real = 1.0 / 60.0;
+    let wall = Cuboid::new(Vector3::new(5.0, 5.0, 0.05));
+
+    // Moving 1/6 per step is more than the thickness of the wall.
+    assert!(!wall.is_ccd_safe(10.0, dt));
+    // Moving 1/60 per step is not.
+    assert!(wall.is_ccd_safe(1.0, dt));
+
+    // A thick ball is safe at the speed where the wall isn’t.
+    assert!(Ball::new(0.5).is_ccd_safe(10.0, dt));
+}
@@ -0,0 +1,32 @@
+use barry3d::bounding_volume::BoundingVolume;
+use barry3d::math::Vector3;
+use barry3d::shape::{Ball, Capsule, Cuboid, Cylinder, RoundCuboid, Shape, TriMesh};
+
+#[test]
+fn local_aabb_loosened_matches_loosened_local_aabb() {
+    let (vertices, indices) = Ball::new(1.5).to_trimesh(10, 10);
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Ball::new(0.7)),
+        Box::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0))),
+        Box::new(Capsule::new(
+            Vector3::new(-1.0, 0.5, 2.0),
+            Vector3::new(3.0, -1.0, 0.0),
+            0.4,
+        )),
+        Box::new(RoundCuboid {
+            inner_shape: Cuboid::new(Vector3::new(1.0, 0.5, 0.2)),
+            border_radius: 0.1,
+        }),
+        Box::new(Cylinder::new(1.0, 0.5)),
+        Box::new(TriMesh::new(vertices, indices)),
+    ];
+
+    for shape in &shapes {
+        for margin in [0.0, 0.05, 1.3] {
+            let expected = shape.compute_local_aabb().loosened(margin);
+            let aabb = shape.local_aabb_loosened(margin);
+            assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+            assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use barry3d::math::{real_consts, Isometry3, Real, UnitVector3, Vector3};
+use barry3d::shape::{Ball, Cuboid, Shape};
+
+// The room [-5, 5] x [0, 3] x [-4, 4], bounded by its walls, floor, and ceiling.
+fn room() -> Vec<(UnitVector3, Real)> {
+    vec![
+        (UnitVector3::X, 5.0),
+        (UnitVector3::NEG_X, 5.0),
+        (UnitVector3::Y, 0.0),
+        (UnitVector3::NEG_Y, 3.0),
+        (UnitVector3::Z, 4.0),
+        (UnitVector3::NEG_Z, 4.0),
+    ]
+}
+
+#[test]
+fn box_inside_room() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+    let pos = Isometry3::from_xyz(1.0, 1.0, 0.0);
+
+    // The nearest plane is the floor, 0.5 below the box.
+    assert_relative_eq!(
+        cuboid.min_signed_distance_to_planes(pos, &room()),
+        0.5,
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn box_poking_through_a_wall() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+    let pos = Isometry3::from_xyz(4.7, 1.5, 0.0);
+    assert_relative_eq!(
+        cuboid.min_signed_distance_to_planes(pos, &room()),
+        -0.7,
+        epsilon = 1.0e-6
+    );
+
+    // Rotated by 45 degrees about `Y`, a corner pokes through the wall by sqrt(2) - 0.3.
+    let pos = Isometry3::new(
+        Vector3::new(4.7, 1.5, 0.0),
+        Vector3::Y * real_consts::FRAC_PI_4,
+    );
+    assert_relative_eq!(
+        cuboid.min_signed_distance_to_planes(pos, &room()),
+        0.3 - Real::sqrt(2.0),
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn no_planes() {
+    let ball = Ball::new(1.0);
+    assert_eq!(
+        ball.min_signed_distance_to_planes(Isometry3::IDENTITY, &[]),
+        Real::MAX
+    );
+}
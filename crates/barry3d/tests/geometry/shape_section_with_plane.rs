@@ -0,0 +1,114 @@
+use barry3d::math::{Isometry3, Real, UnitVector, Vector3};
+use barry3d::shape::{Ball, Cuboid, Cylinder, Shape};
+
+fn assert_ccw_convex(section: &[Vector3], normal: Vector3) {
+    for i in 0..section.len() {
+        let a = section[i];
+        let b = section[(i + 1) % section.len()];
+        let c = section[(i + 2) % section.len()];
+        assert!((b - a).cross(c - b).dot(normal) >= -1.0e-5);
+    }
+}
+
+#[test]
+fn ball_section_through_center_is_unit_circle() {
+    let ball = Ball::new(1.0);
+    let pos = Isometry3::from_xyz(1.0, -2.0, 3.0);
+    let normal = UnitVector::new(Vector3::new(1.0, 2.0, -0.5)).unwrap();
+    let section = ball
+        .section_with_plane(pos, pos.translation, normal)
+        .unwrap();
+
+    assert!(section.len() >= 3);
+    assert_ccw_convex(&section, *normal);
+
+    for pt in &section {
+        assert_relative_eq!(pt.distance(pos.translation), 1.0, epsilon = 1.0e-5);
+        assert_relative_eq!((*pt - pos.translation).dot(*normal), 0.0, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn ball_section_off_center_and_outside() {
+    let ball = Ball::new(1.0);
+    let section = ball
+        .local_section_with_plane(Vector3::new(0.0, 0.6, 0.0), UnitVector::Y)
+        .unwrap();
+
+    for pt in &section {
+        assert_relative_eq!(pt.y, 0.6, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.length(), 1.0, epsilon = 1.0e-5);
+    }
+
+    assert!(ball
+        .local_section_with_plane(Vector3::new(0.0, 1.5, 0.0), UnitVector::Y)
+        .is_none());
+}
+
+#[test]
+fn cuboid_sections() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+
+    // A rectangle orthogonal to an axis.
+    let rectangle = cuboid
+        .local_section_with_plane(Vector3::new(0.5, 0.0, 0.0), UnitVector::X)
+        .unwrap();
+    assert_eq!(rectangle.len(), 4);
+    assert_ccw_convex(&rectangle, Vector3::X);
+
+    for pt in &rectangle {
+        assert_relative_eq!(pt.x, 0.5, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.y.abs(), 2.0, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.z.abs(), 3.0, epsilon = 1.0e-5);
+    }
+
+    // A regular hexagon for a cube cut through its center orthogonally to a diagonal.
+    let cube = Cuboid::new(Vector3::splat(1.0));
+    let normal = UnitVector::new(Vector3::splat(1.0)).unwrap();
+    let hexagon = cube
+        .local_section_with_plane(Vector3::ZERO, normal)
+        .unwrap();
+    assert_eq!(hexagon.len(), 6);
+    assert_ccw_convex(&hexagon, *normal);
+
+    for pt in &hexagon {
+        assert_relative_eq!(pt.length(), Real::sqrt(2.0), epsilon = 1.0e-5);
+    }
+
+    assert!(cube
+        .local_section_with_plane(Vector3::new(0.0, 0.0, 1.5), UnitVector::Z)
+        .is_none());
+}
+
+#[test]
+fn cylinder_sections() {
+    let cylinder = Cylinder::new(1.0, 0.5);
+
+    // A disk orthogonal to the axis.
+    let disk = cylinder
+        .local_section_with_plane(Vector3::new(0.0, 0.3, 0.0), UnitVector::Y)
+        .unwrap();
+    assert_eq!(disk.len(), 32);
+    assert_ccw_convex(&disk, Vector3::Y);
+
+    for pt in &disk {
+        assert_relative_eq!(pt.y, 0.3, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            Vector3::new(pt.x, 0.0, pt.z).length(),
+            0.5,
+            epsilon = 1.0e-5
+        );
+    }
+
+    // A rectangle parallel to the axis.
+    let rectangle = cylinder
+        .local_section_with_plane(Vector3::new(0.1, 0.0, 0.0), UnitVector::X)
+        .unwrap();
+    assert_eq!(rectangle.len(), 4);
+
+    for pt in &rectangle {
+        assert_relative_eq!(pt.x, 0.1, epsilon = 1.0e-5);
+        assert_relative_eq!(pt.y.abs(), 1.0, epsilon = 1.0e-5);
+        assert!(pt.z.abs() <= 0.5);
+    }
+}
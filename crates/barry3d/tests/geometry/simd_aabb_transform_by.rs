@@ -0,0 +1,17 @@
+use barry3d::bounding_volume::{Aabb, SimdAabb};
+use barry3d::math::{Isometry3, SimdIsometry, Vector3, SIMD_WIDTH};
+
+#[test]
+fn simd_aabb_transform_by_matches_aabb() {
+    let aabb = Aabb::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(2.0, 1.0, 3.0));
+    let pos = Isometry3::new(Vector3::new(-3.0, 4.0, 2.0), Vector3::new(0.4, 0.1, -0.3));
+
+    let transformed = SimdAabb::splat(aabb).transform_by(SimdIsometry::splat(pos));
+    let expected = aabb.transform_by(pos);
+
+    for lane in 0..SIMD_WIDTH {
+        let result = transformed.extract(lane);
+        assert_relative_eq!(result.mins, expected.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(result.maxs, expected.maxs, epsilon = 1.0e-5);
+    }
+}
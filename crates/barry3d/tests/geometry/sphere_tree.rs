@@ -0,0 +1,75 @@
+use barry3d::bounding_volume::{BoundingSphere, BoundingVolume};
+use barry3d::math::Real;
+use barry3d::partitioning::SphereTree;
+use barry3d::query::{PointQuery, Ray, RayCast};
+use barry3d::shape::{Ball, Shape, TriMesh};
+
+use super::rand_vector;
+
+#[test]
+fn sphere_tree_queries_match_qbvh_queries() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 20);
+    let mesh = TriMesh::new(vertices, indices);
+    let tree = SphereTree::from_composite_shape(&mesh);
+    let mut rng = oorandom::Rand32::new(42);
+
+    assert_eq!(tree.nodes().len(), mesh.num_triangles() * 2 - 1);
+    let root = tree.root_bounding_sphere().unwrap();
+    for i in 0..mesh.num_triangles() as u32 {
+        assert!(root
+            .loosened(1.0e-5)
+            .contains(&mesh.triangle(i).local_bounding_sphere()));
+    }
+
+    for _ in 0..100 {
+        let origin = rand_vector(&mut rng, 3.0);
+        let ray = Ray::new(origin, rand_vector(&mut rng, 0.5) - origin);
+
+        let expected = mesh.cast_local_ray(&ray, Real::MAX, true);
+        let result = tree.cast_ray(&mesh, &ray, Real::MAX, true);
+
+        match (expected, result) {
+            (Some(expected), Some((part_id, toi))) => {
+                assert_relative_eq!(toi, expected, epsilon = 1.0e-5);
+                let part_toi = mesh
+                    .triangle(part_id)
+                    .cast_local_ray(&ray, Real::MAX, true)
+                    .unwrap();
+                assert_relative_eq!(part_toi, toi, epsilon = 1.0e-5);
+            }
+            (None, None) => {}
+            _ => panic!("Ray cast mismatch: {:?} vs. {:?}", expected, result),
+        }
+
+        let point = rand_vector(&mut rng, 2.0);
+        let expected = mesh.project_local_point(point, false);
+        let (_, proj) = tree.closest_point(&mesh, point, false).unwrap();
+        assert_relative_eq!(
+            point.distance(proj.point),
+            point.distance(expected.point),
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn sphere_tree_query_sphere_matches_brute_force() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 20);
+    let mesh = TriMesh::new(vertices, indices);
+    let tree = SphereTree::from_composite_shape(&mesh);
+    let mut rng = oorandom::Rand32::new(42);
+
+    for _ in 0..50 {
+        let query = BoundingSphere::new(rand_vector(&mut rng, 1.5), rng.rand_float());
+
+        let mut result = vec![];
+        tree.query_sphere(&query, &mut result);
+        result.sort_unstable();
+
+        let expected: Vec<_> = (0..mesh.num_triangles() as u32)
+            .filter(|i| query.intersects(&mesh.triangle(*i).compute_local_bounding_sphere()))
+            .collect();
+
+        assert_eq!(result, expected);
+    }
+}
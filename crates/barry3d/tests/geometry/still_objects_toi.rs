@@ -1,6 +1,6 @@
 use barry3d::math::{Isometry3, Vector3};
 use barry3d::query::time_of_impact;
-use barry3d::shape::Cuboid;
+use barry3d::shape::{Ball, Cuboid, HeightField};
 
 /**
  * Issue #141
@@ -53,3 +53,47 @@ fn moving_up_misses() {
 fn moving_down_hits() {
     assert!(collide(-1.0).is_some());
 }
+
+fn collide_still_overlapping(stop_at_penetration: bool) -> Option<f32> {
+    let pos1 = Isometry3::from_xyz(0.0, 0.9, 0.0);
+    let pos2 = Isometry3::IDENTITY;
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+
+    time_of_impact(
+        pos1,
+        Vector3::ZERO,
+        &cuboid,
+        pos2,
+        Vector3::ZERO,
+        &cuboid,
+        std::f32::MAX,
+        stop_at_penetration,
+    )
+    .unwrap()
+    .map(|toi| toi.toi)
+}
+
+#[test]
+fn no_movement_overlapping() {
+    assert_eq!(collide_still_overlapping(true), Some(0.0));
+    assert_eq!(collide_still_overlapping(false), None);
+}
+
+#[test]
+fn no_movement_without_contact_support() {
+    // There is no contact query between a heightfield and a ball.
+    let heightfield = HeightField::new(vec![vec![0.0; 3]; 3], Vector3::new(10.0, 1.0, 10.0));
+    let ball = Ball::new(0.5);
+
+    let toi = time_of_impact(
+        Isometry3::IDENTITY,
+        Vector3::ZERO,
+        &heightfield,
+        Isometry3::from_xyz(0.0, 2.0, 0.0),
+        Vector3::ZERO,
+        &ball,
+        std::f32::MAX,
+        true,
+    );
+    assert!(matches!(toi, Ok(None)));
+}
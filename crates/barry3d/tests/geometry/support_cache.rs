@@ -0,0 +1,66 @@
+use barry3d::math::{Isometry3, Real, UnitVector3, Vector3};
+use barry3d::query::epa::EPA;
+use barry3d::query::gjk::{self, CSOPoint, GJKResult, SupportCache, VoronoiSimplex};
+use barry3d::shape::{Cuboid, SupportMap};
+
+#[test]
+fn support_cache_hits_nearby_directions() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let mut cache = SupportCache::new();
+    let tolerance = 0.05;
+
+    let dir1 = UnitVector3::new(Vector3::new(1.0, 1.0, 1.0)).unwrap();
+    let pt1 = cache.support_or_cached(&cuboid, dir1, tolerance);
+    assert_eq!(pt1, cuboid.local_support_point_toward(dir1));
+    assert_eq!((cache.hits(), cache.misses()), (0, 1));
+
+    // A direction within the cone reuses the cached point, which is the same vertex.
+    let dir2 = UnitVector3::new(Vector3::new(1.0, 1.02, 0.99)).unwrap();
+    assert!(dir1.angle_between(*dir2) < tolerance);
+    let pt2 = cache.support_or_cached(&cuboid, dir2, tolerance);
+    assert_eq!(pt2, cuboid.local_support_point_toward(dir2));
+    assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+    // A distant direction requires a new support point.
+    let dir3 = UnitVector3::new(Vector3::new(-1.0, 1.0, -1.0)).unwrap();
+    let pt3 = cache.support_or_cached(&cuboid, dir3, tolerance);
+    assert_eq!(pt3, cuboid.local_support_point_toward(dir3));
+    assert_eq!(pt3, Vector3::new(-1.0, 2.0, -3.0));
+    assert_eq!((cache.hits(), cache.misses()), (1, 2));
+
+    cache.clear();
+    assert_eq!((cache.hits(), cache.misses()), (0, 0));
+    cache.support_or_cached(&cuboid, dir2, tolerance);
+    assert_eq!(cache.misses(), 1);
+}
+
+#[test]
+fn epa_with_support_caches_matches_exact_epa() {
+    let c1 = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let c2 = Cuboid::new(Vector3::new(0.5, 2.0, 1.5));
+    let mut caches = [SupportCache::new(), SupportCache::new()];
+    let mut epa = EPA::new();
+
+    for pos12 in [
+        Isometry3::new(Vector3::new(0.4, 0.1, 0.2), Vector3::new(0.1, 0.2, 0.3)),
+        Isometry3::new(Vector3::new(0.41, 0.1, 0.2), Vector3::new(0.1, 0.2, 0.3)),
+    ] {
+        let mut simplex = VoronoiSimplex::new();
+        simplex.reset(CSOPoint::from_shapes(pos12, &c1, &c2, UnitVector3::X));
+        let res = gjk::closest_points(pos12, &c1, &c2, Real::MAX, true, &mut simplex);
+        assert_eq!(res, GJKResult::Intersection);
+
+        let exact = epa.try_closest_points(pos12, &c1, &c2, &simplex).unwrap();
+        let cached = epa
+            .try_closest_points_with_support_caches(pos12, &c1, &c2, &simplex, &mut caches, 0.01)
+            .unwrap();
+        let exact_depth = (exact.1 - exact.0).dot(*exact.2);
+        let cached_depth = (cached.1 - cached.0).dot(*cached.2);
+        assert_relative_eq!(cached_depth, exact_depth, epsilon = 1.0e-4);
+        assert_relative_eq!(*cached.2, *exact.2, epsilon = 1.0e-4);
+    }
+
+    // The second query only slightly moved the shapes, so it reused the cached support points.
+    assert!(caches[0].hits() > 0);
+    assert!(caches[1].hits() > 0);
+}
@@ -0,0 +1,35 @@
+use barry3d::math::Vector3;
+use barry3d::query::PointQuery;
+use barry3d::shape::{Ball, Cuboid, FeatureId, SupportMap};
+
+#[test]
+fn cuboid_support_feature_vertex_ids() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+
+    for vid in 0..8u32 {
+        let sign = |i: u32| if vid & (1 << i) != 0 { -1.0 } else { 1.0 };
+        let dir = Vector3::new(sign(0) * 0.3, sign(1) * 0.5, sign(2) * 0.7);
+        let (pt, feature) = cuboid.local_support_feature(dir);
+
+        assert_eq!(pt, cuboid.local_support_point(dir));
+        assert_eq!(
+            pt,
+            cuboid.half_extents * Vector3::new(sign(0), sign(1), sign(2))
+        );
+        assert_eq!(feature, FeatureId::Vertex(vid));
+
+        // The same vertex id is reported when projecting a point near that corner.
+        let (_, proj_feature) = cuboid.project_local_point_and_get_feature(pt + dir);
+        assert_eq!(proj_feature, feature);
+    }
+}
+
+#[test]
+fn default_support_feature_is_unknown() {
+    let ball = Ball::new(1.0);
+    let dir = Vector3::new(1.0, 2.0, 3.0);
+    let (pt, feature) = ball.local_support_feature(dir);
+
+    assert_eq!(pt, ball.local_support_point(dir));
+    assert_eq!(feature, FeatureId::Unknown);
+}
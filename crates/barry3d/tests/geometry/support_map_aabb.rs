@@ -0,0 +1,36 @@
+use barry3d::bounding_volume::details::support_map_aabb;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Ball, Cuboid, Shape, SupportMap};
+
+fn positions() -> [Isometry3; 3] {
+    [
+        Isometry3::IDENTITY,
+        Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.2, 0.1)),
+        Isometry3::new(Vector3::new(-4.0, 0.5, 3.0), Vector3::new(1.0, 2.0, -0.5)),
+    ]
+}
+
+#[test]
+fn support_map_aabb_matches_cuboid_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.25, 0.5));
+
+    for pos in positions() {
+        let aabb = support_map_aabb(pos, &cuboid);
+        let expected = cuboid.compute_aabb(pos);
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn support_map_aabb_matches_ball_aabb() {
+    let ball = Ball::new(0.75);
+    let shape: &dyn SupportMap = &ball;
+
+    for pos in positions() {
+        let aabb = support_map_aabb(pos, shape);
+        let expected = ball.compute_aabb(pos);
+        assert_relative_eq!(aabb.mins, expected.mins, epsilon = 1.0e-5);
+        assert_relative_eq!(aabb.maxs, expected.maxs, epsilon = 1.0e-5);
+    }
+}
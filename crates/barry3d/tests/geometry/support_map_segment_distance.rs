@@ -0,0 +1,51 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::details::distance_support_map_segment;
+use barry3d::shape::Cuboid;
+
+#[test]
+fn cuboid_segment_distance() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+
+    // Segment inside of the cuboid.
+    let (dist, _, _) = distance_support_map_segment(
+        Isometry3::IDENTITY,
+        &cuboid,
+        Vector3::new(-0.5, 0.0, 0.0),
+        Vector3::new(0.5, 0.2, 0.0),
+    );
+    assert_eq!(dist, 0.0);
+
+    // Segment grazing the top face.
+    let (dist, _, _) = distance_support_map_segment(
+        Isometry3::IDENTITY,
+        &cuboid,
+        Vector3::new(-2.0, 1.0, 0.0),
+        Vector3::new(2.0, 1.0, 0.0),
+    );
+    assert_relative_eq!(dist, 0.0, epsilon = 1.0e-4);
+
+    // Segment far from the cuboid, parallel to one of its faces.
+    let (dist, pt1, pt2) = distance_support_map_segment(
+        Isometry3::IDENTITY,
+        &cuboid,
+        Vector3::new(3.0, -1.0, 0.5),
+        Vector3::new(3.0, 1.0, 0.5),
+    );
+    assert_relative_eq!(dist, 2.0, epsilon = 1.0e-4);
+    assert_relative_eq!(pt1.x, 1.0, epsilon = 1.0e-4);
+    assert_relative_eq!(pt1.z, 0.5, epsilon = 1.0e-4);
+    assert_relative_eq!(pt2.x, 3.0, epsilon = 1.0e-4);
+    assert_relative_eq!(pt1.distance(pt2), dist, epsilon = 1.0e-4);
+
+    // Segment far from the cuboid, with its endpoints given in its own local-space.
+    let pos12 = Isometry3::from_xyz(0.0, 5.0, 0.0);
+    let (dist, pt1, pt2) = distance_support_map_segment(
+        pos12,
+        &cuboid,
+        Vector3::new(2.0, 0.0, 2.0),
+        Vector3::new(2.0, 3.0, 2.0),
+    );
+    assert_relative_eq!(dist, 4.2426405, epsilon = 1.0e-4);
+    assert_relative_eq!(pt1, Vector3::new(1.0, 1.0, 1.0), epsilon = 1.0e-4);
+    assert_relative_eq!(pt2, Vector3::new(2.0, 0.0, 2.0), epsilon = 1.0e-4);
+}
@@ -0,0 +1,41 @@
+use barry3d::math::Vector3;
+use barry3d::shape::{
+    debug_validate_support_map, Ball, Cone, Cuboid, SupportMap, SupportMapInconsistency,
+};
+
+/// A cuboid support map which picks the wrong vertex along the `x` axis.
+struct BuggyCuboid {
+    half_extents: Vector3,
+}
+
+impl SupportMap for BuggyCuboid {
+    fn local_support_point(&self, dir: Vector3) -> Vector3 {
+        Vector3::new(
+            -self.half_extents.x.copysign(dir.x),
+            self.half_extents.y.copysign(dir.y),
+            self.half_extents.z.copysign(dir.z),
+        )
+    }
+}
+
+#[test]
+fn valid_support_maps_pass_validation() {
+    assert_eq!(debug_validate_support_map(&Ball::new(1.5), 64), Ok(()));
+    assert_eq!(
+        debug_validate_support_map(&Cuboid::new(Vector3::new(1.0, 2.0, 3.0)), 64),
+        Ok(())
+    );
+    assert_eq!(debug_validate_support_map(&Cone::new(1.0, 0.5), 64), Ok(()));
+}
+
+#[test]
+fn buggy_support_map_is_flagged() {
+    let buggy = BuggyCuboid {
+        half_extents: Vector3::new(1.0, 2.0, 3.0),
+    };
+
+    assert!(matches!(
+        debug_validate_support_map(&buggy, 64),
+        Err(SupportMapInconsistency::NotMaximal { .. })
+    ));
+}
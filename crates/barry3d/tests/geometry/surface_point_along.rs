@@ -0,0 +1,28 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::shape::{Cuboid, Shape};
+
+#[test]
+fn snap_point_down_onto_box_top() {
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let pos = Isometry3::from_xyz(1.0, 3.0, -1.0);
+
+    let snapped = cuboid.surface_point_along(pos, Vector3::new(0.5, 10.0, -1.5), -Vector3::Y);
+    assert_relative_eq!(
+        snapped.unwrap(),
+        Vector3::new(0.5, 3.5, -1.5),
+        epsilon = 1.0e-5
+    );
+
+    // Points that are not above the box are not snapped, even if they are close to it.
+    let from = Vector3::new(3.2, 3.6, -1.0);
+    let snapped = cuboid.surface_point_along(pos, from, -Vector3::Y);
+    assert!(snapped.is_none());
+
+    // Points inside of the box snap onto the boundary hit from the inside.
+    let snapped = cuboid.surface_point_along(pos, Vector3::new(1.0, 3.0, -1.0), -Vector3::Y);
+    assert_relative_eq!(
+        snapped.unwrap(),
+        Vector3::new(1.0, 2.5, -1.0),
+        epsilon = 1.0e-5
+    );
+}
@@ -0,0 +1,43 @@
+use barry3d::math::Vector3;
+use barry3d::query::{swept_ball_closest_point, PointQuery};
+use barry3d::shape::Capsule;
+
+use super::rand_vector;
+
+#[test]
+fn swept_ball_closest_point_matches_capsule_projection() {
+    let mut rng = oorandom::Rand32::new(42);
+
+    for i in 0..1000 {
+        let a = rand_vector(&mut rng, 5.0);
+        // Also test balls that don’t move at all.
+        let b = if i % 10 == 0 {
+            a
+        } else {
+            rand_vector(&mut rng, 5.0)
+        };
+        let radius = rand_vector(&mut rng, 5.0).x.abs() * 0.5 + 0.1;
+        let target = rand_vector(&mut rng, 5.0);
+
+        let expected = Capsule::new(a, b, radius)
+            .project_local_point(target, true)
+            .point;
+        let result = swept_ball_closest_point(a, b, radius, target);
+        assert_relative_eq!(result, expected, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn swept_ball_closest_point_inside_sweep() {
+    let a = Vector3::new(-1.0, 0.0, 0.0);
+    let b = Vector3::new(1.0, 0.0, 0.0);
+
+    let target = Vector3::new(0.5, 0.2, 0.0);
+    assert_eq!(swept_ball_closest_point(a, b, 0.5, target), target);
+
+    let target = Vector3::new(2.0, 0.0, 0.0);
+    assert_relative_eq!(
+        swept_ball_closest_point(a, b, 0.5, target),
+        Vector3::new(1.5, 0.0, 0.0)
+    );
+}
@@ -0,0 +1,108 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{self, TOIStatus};
+use barry3d::shape::{Cuboid, TriMesh};
+
+#[test]
+fn spinning_boxes_touch_due_to_rotation() {
+    let bar = Cuboid::new(Vector3::new(2.0, 0.1, 0.1));
+    let pos1 = Isometry3::IDENTITY;
+    let pos2 = Isometry3::from_xyz(0.0, 3.0, 0.0);
+    // The bars rotate in opposite directions, so their ends at `x > 0` get closer.
+    let omega1 = Vector3::Z;
+    let omega2 = -Vector3::Z;
+
+    // Without rotation, the shapes never touch.
+    let linear_toi = query::time_of_impact(
+        pos1,
+        Vector3::ZERO,
+        &bar,
+        pos2,
+        Vector3::ZERO,
+        &bar,
+        2.0,
+        true,
+    )
+    .unwrap();
+    assert!(linear_toi.is_none());
+
+    let toi = query::time_of_impact_ca(
+        pos1,
+        Vector3::ZERO,
+        omega1,
+        &bar,
+        pos2,
+        Vector3::ZERO,
+        omega2,
+        &bar,
+        2.0,
+    )
+    .unwrap()
+    .expect("the bars should touch");
+
+    // The corners of both bars meet when `4 * sin(t) + 0.2 * cos(t) = 3`.
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 0.7967, epsilon = 1.0e-2);
+}
+
+#[test]
+fn spinning_boxes_too_far_apart() {
+    let bar = Cuboid::new(Vector3::new(2.0, 0.1, 0.1));
+    let toi = query::time_of_impact_ca(
+        Isometry3::IDENTITY,
+        Vector3::ZERO,
+        Vector3::Z,
+        &bar,
+        Isometry3::from_xyz(0.0, 5.0, 0.0),
+        Vector3::ZERO,
+        -Vector3::Z,
+        &bar,
+        2.0,
+    )
+    .unwrap();
+
+    assert!(toi.is_none());
+}
+
+#[test]
+fn penetrating_boxes_report_their_contact() {
+    let bar = Cuboid::new(Vector3::new(2.0, 0.1, 0.1));
+    let toi = query::time_of_impact_ca(
+        Isometry3::IDENTITY,
+        Vector3::ZERO,
+        Vector3::Z,
+        &bar,
+        Isometry3::from_xyz(0.0, 0.1, 0.0),
+        Vector3::ZERO,
+        -Vector3::Z,
+        &bar,
+        2.0,
+    )
+    .unwrap()
+    .expect("the bars are penetrating");
+
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+    assert_eq!(toi.toi, 0.0);
+    assert_relative_eq!(toi.normal1.y, 1.0, epsilon = 1.0e-5);
+    assert_relative_eq!(toi.witness1.y, 0.1, epsilon = 1.0e-5);
+    assert_relative_eq!(toi.witness2.y, -0.1, epsilon = 1.0e-5);
+}
+
+#[test]
+fn non_convex_shapes_are_unsupported() {
+    let bar = Cuboid::new(Vector3::new(2.0, 0.1, 0.1));
+    let (vertices, indices) = bar.to_trimesh();
+    let mesh = TriMesh::new(vertices, indices);
+    let toi = query::time_of_impact_ca(
+        Isometry3::IDENTITY,
+        Vector3::ZERO,
+        Vector3::Z,
+        &mesh,
+        Isometry3::from_xyz(0.0, 3.0, 0.0),
+        Vector3::ZERO,
+        -Vector3::Z,
+        &bar,
+        2.0,
+    );
+
+    assert!(toi.is_err());
+}
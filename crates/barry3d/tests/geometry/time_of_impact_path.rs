@@ -0,0 +1,69 @@
+use barry3d::math::{real_consts::PI, Isometry3, Real, Vector3};
+use barry3d::query::{self, TOIStatus};
+use barry3d::shape::Ball;
+
+// A pose moving counterclockwise along a circle of radius 5 in the `XZ` plane, always facing
+// the center of the circle.
+fn circular_path(t: Real) -> Isometry3 {
+    Isometry3::new(
+        Vector3::new(5.0 * t.cos(), 0.0, 5.0 * t.sin()),
+        -Vector3::Y * t,
+    )
+}
+
+#[test]
+fn ball_following_circular_path_hits_partway() {
+    let ball = Ball::new(0.5);
+    // The obstacle lies on the circle, a quarter turn after the start of the path.
+    let obstacle = Isometry3::from_xyz(0.0, 0.0, 5.0);
+
+    let toi = query::time_of_impact_path(&circular_path, &ball, obstacle, &ball, PI, 32)
+        .unwrap()
+        .expect("the ball must hit the obstacle");
+
+    // The centers are 1.0 apart when the chord between them spans `2 * asin(0.1)` radians.
+    let expected = PI / 2.0 - 2.0 * (0.1 as Real).asin();
+    assert_relative_eq!(toi.toi, expected, epsilon = 1.0e-2);
+    assert_ne!(toi.status, TOIStatus::Penetrating);
+
+    let pos1 = circular_path(toi.toi);
+    assert_relative_eq!(
+        pos1.translation.distance(obstacle.translation),
+        1.0,
+        epsilon = 1.0e-2
+    );
+}
+
+#[test]
+fn ball_following_circular_path_misses_obstacle_beyond_end() {
+    let ball = Ball::new(0.5);
+    // The obstacle is on the circle, but on the half not covered before `max_t`.
+    let obstacle = Isometry3::from_xyz(0.0, 0.0, -5.0);
+
+    let toi =
+        query::time_of_impact_path(&circular_path, &ball, obstacle, &ball, PI - 0.5, 32).unwrap();
+    assert!(toi.is_none());
+}
+
+#[test]
+fn zero_length_path_only_checks_the_start() {
+    let ball = Ball::new(0.5);
+
+    let far = Isometry3::from_xyz(0.0, 0.0, 5.0);
+    let toi = query::time_of_impact_path(&circular_path, &ball, far, &ball, 0.0, 32).unwrap();
+    assert!(toi.is_none());
+
+    let overlapping = Isometry3::from_xyz(5.5, 0.0, 0.0);
+    let toi = query::time_of_impact_path(&circular_path, &ball, overlapping, &ball, 0.0, 32)
+        .unwrap()
+        .expect("the balls overlap at the start of the path");
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+
+    let touching = Isometry3::from_xyz(6.0, 0.0, 0.0);
+    let toi = query::time_of_impact_path(&circular_path, &ball, touching, &ball, 0.0, 32)
+        .unwrap()
+        .expect("the balls touch at the start of the path");
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Converged);
+}
@@ -0,0 +1,73 @@
+use barry3d::math::{real_consts, Isometry3, Real, Vector3};
+use barry3d::query::{self, TOIStatus};
+use barry3d::shape::{Ball, Cuboid};
+
+// A door panel of width 2, hinged around the `Y` axis at the origin, and a ball at a distance
+// 1.5 from the hinge, at the angle `FRAC_PI_4` from the closed door.
+fn door_and_obstacle() -> (Isometry3, Cuboid, Isometry3, Ball) {
+    let door = Cuboid::new(Vector3::new(1.0, 1.0, 0.05));
+    let obstacle = Ball::new(0.1);
+    let angle = real_consts::FRAC_PI_4;
+    let pos_door = Isometry3::from_xyz(1.0, 0.0, 0.0);
+    let pos_obstacle = Isometry3::from_xyz(1.5 * angle.cos(), 0.0, 1.5 * angle.sin());
+    (pos_door, door, pos_obstacle, obstacle)
+}
+
+#[test]
+fn door_hits_obstacle() {
+    let (pos_door, door, pos_obstacle, obstacle) = door_and_obstacle();
+
+    // The magnitude of the angular velocity is irrelevant.
+    for omega in [-Vector3::Y, -Vector3::Y * 3.0] {
+        let toi = query::time_of_impact_pivot(
+            pos_door,
+            Vector3::ZERO,
+            omega,
+            &door,
+            pos_obstacle,
+            &obstacle,
+            real_consts::FRAC_PI_2,
+        )
+        .unwrap()
+        .expect("the door should hit the obstacle");
+
+        // The door touches the ball when the distance from its center to the midplane of the
+        // door, `1.5 * sin(FRAC_PI_4 - angle)`, equals the half-thickness plus the radius.
+        let expected = real_consts::FRAC_PI_4 - Real::asin(0.15 / 1.5);
+        assert_eq!(toi.status, TOIStatus::Converged);
+        assert_relative_eq!(toi.toi, expected, epsilon = 1.0e-3);
+        assert_relative_eq!(toi.witness1.z, 0.05, epsilon = 1.0e-3);
+    }
+}
+
+#[test]
+fn door_swings_away_from_obstacle() {
+    let (pos_door, door, pos_obstacle, obstacle) = door_and_obstacle();
+    let toi = query::time_of_impact_pivot(
+        pos_door,
+        Vector3::ZERO,
+        Vector3::Y,
+        &door,
+        pos_obstacle,
+        &obstacle,
+        real_consts::FRAC_PI_2,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+}
+
+#[test]
+fn door_stops_before_obstacle() {
+    let (pos_door, door, pos_obstacle, obstacle) = door_and_obstacle();
+    let toi = query::time_of_impact_pivot(
+        pos_door,
+        Vector3::ZERO,
+        -Vector3::Y,
+        &door,
+        pos_obstacle,
+        &obstacle,
+        0.5,
+    )
+    .unwrap();
+    assert!(toi.is_none());
+}
@@ -0,0 +1,59 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, Cuboid};
+
+#[test]
+fn overlapping_balls_moving_apart() {
+    let ball1 = Ball::new(1.0);
+    let ball2 = Ball::new(0.5);
+    let pos12 = Isometry3::from_xyz(1.0, 0.0, 0.0);
+    let vel12 = Vector3::new(2.0, 0.0, 0.0);
+
+    // The overlap of 0.5 is resolved at the relative speed of 2.
+    let toi = query::time_of_separation(pos12, vel12, &ball1, &ball2, 10.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(toi, 0.25, epsilon = 1.0e-5);
+
+    // Moving toward each other first, then apart.
+    let toi = query::time_of_separation(pos12, -vel12, &ball1, &ball2, 10.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(toi, 1.25, epsilon = 1.0e-5);
+
+    // Still overlapping at `max_toi`.
+    assert_eq!(
+        query::time_of_separation(pos12, vel12, &ball1, &ball2, 0.2).unwrap(),
+        None
+    );
+    // Never separating.
+    assert_eq!(
+        query::time_of_separation(pos12, Vector3::ZERO, &ball1, &ball2, 10.0).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn disjoint_shapes_are_already_separated() {
+    let ball = Ball::new(1.0);
+    let pos12 = Isometry3::from_xyz(3.0, 0.0, 0.0);
+    let vel12 = Vector3::new(-1.0, 0.0, 0.0);
+
+    assert_eq!(
+        query::time_of_separation(pos12, vel12, &ball, &ball, 10.0).unwrap(),
+        Some(0.0)
+    );
+}
+
+#[test]
+fn overlapping_cuboids_sliding_apart() {
+    let cuboid = Cuboid::new(Vector3::splat(1.0));
+    let pos12 = Isometry3::from_xyz(1.5, 0.3, 0.0);
+    let vel12 = Vector3::new(0.0, 3.0, 0.0);
+
+    // The cuboids separate once their offset along `Y` reaches 2.
+    let toi = query::time_of_separation(pos12, vel12, &cuboid, &cuboid, 10.0)
+        .unwrap()
+        .unwrap();
+    assert_relative_eq!(toi, 1.7 / 3.0, epsilon = 1.0e-4);
+}
@@ -0,0 +1,45 @@
+use barry3d::bounding_volume::Aabb;
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query;
+use barry3d::shape::Ball;
+
+fn region() -> Aabb {
+    Aabb::new(
+        Vector3::new(-10.0, -5.0, -5.0),
+        Vector3::new(10.0, 5.0, 5.0),
+    )
+}
+
+#[test]
+fn shape_leaves_region_through_one_face() {
+    let ball = Ball::new(1.0);
+    let pos = Isometry3::from_xyz(2.0, 0.0, 0.0);
+    let vel = Vector3::new(2.0, 0.5, 0.0);
+
+    // The ball touches the +X face when its center reaches x = 9, and the +Y face at y = 4.
+    let toi = query::time_to_leave_aabb(pos, vel, &ball, &region(), 10.0).unwrap();
+    assert_relative_eq!(toi, 3.5);
+}
+
+#[test]
+fn shape_never_leaves_region_within_max_toi() {
+    let ball = Ball::new(1.0);
+    let pos = Isometry3::from_xyz(0.0, 0.0, 0.0);
+
+    assert!(
+        query::time_to_leave_aabb(pos, Vector3::new(-1.0, 0.0, 0.0), &ball, &region(), 5.0)
+            .is_none()
+    );
+    assert!(query::time_to_leave_aabb(pos, Vector3::ZERO, &ball, &region(), 5.0).is_none());
+}
+
+#[test]
+fn shape_already_outside_region() {
+    let ball = Ball::new(1.0);
+    let pos = Isometry3::from_xyz(0.0, 4.5, 0.0);
+
+    assert_eq!(
+        query::time_to_leave_aabb(pos, Vector3::ZERO, &ball, &region(), 5.0),
+        Some(0.0)
+    );
+}
@@ -0,0 +1,44 @@
+use barry3d::math::{Real, Vector3};
+use barry3d::query;
+
+#[test]
+fn head_on_approach() {
+    // The centers are 10 apart and close in at 2 per second: they touch when 3 apart.
+    let center12 = Vector3::new(10.0, 0.0, 0.0);
+    let vel12 = Vector3::new(-2.0, 0.0, 0.0);
+    let toi = query::toi_ball_ball(center12, vel12, 1.0, 2.0, Real::MAX).unwrap();
+    assert_relative_eq!(toi, 3.5, epsilon = 1.0e-6);
+
+    // Too far in the future.
+    assert!(query::toi_ball_ball(center12, vel12, 1.0, 2.0, 3.0).is_none());
+    // Moving away from each other.
+    assert!(query::toi_ball_ball(center12, -vel12, 1.0, 2.0, Real::MAX).is_none());
+}
+
+#[test]
+fn grazing_miss_and_hit() {
+    // The second ball passes at a distance of 3.1 from the first one's center.
+    let vel12 = Vector3::new(-1.0, 0.0, 0.0);
+    let center12 = Vector3::new(10.0, 3.1, 0.0);
+    assert!(query::toi_ball_ball(center12, vel12, 1.0, 2.0, Real::MAX).is_none());
+
+    // At a distance of 2.9, the balls touch before the centers are aligned.
+    let center12 = Vector3::new(10.0, 2.9, 0.0);
+    let toi = query::toi_ball_ball(center12, vel12, 1.0, 2.0, Real::MAX).unwrap();
+    let expected = 10.0 - Real::sqrt(3.0 * 3.0 - 2.9 * 2.9);
+    assert_relative_eq!(toi, expected, epsilon = 1.0e-5);
+    assert_relative_eq!((center12 + vel12 * toi).length(), 3.0, epsilon = 1.0e-5);
+}
+
+#[test]
+fn already_overlapping() {
+    let center12 = Vector3::new(1.0, 1.0, 0.0);
+    assert_eq!(
+        query::toi_ball_ball(center12, Vector3::X, 1.0, 1.0, Real::MAX),
+        Some(0.0)
+    );
+    assert_eq!(
+        query::toi_ball_ball(center12, Vector3::ZERO, 1.0, 1.0, 0.0),
+        Some(0.0)
+    );
+}
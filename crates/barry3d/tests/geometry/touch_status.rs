@@ -0,0 +1,56 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{self, TouchStatus};
+use barry3d::shape::{Ball, Cuboid};
+
+const TOLERANCE: Real = 1.0e-4;
+
+fn ball_ball_status(center2: Vector3) -> TouchStatus {
+    query::touch_status(
+        Isometry3::IDENTITY,
+        &Ball::new(1.0),
+        Isometry3::from_translation(center2),
+        &Ball::new(0.5),
+        TOLERANCE,
+    )
+    .unwrap()
+}
+
+#[test]
+fn tangent_balls_are_touching() {
+    assert_eq!(
+        ball_ball_status(Vector3::new(1.5, 0.0, 0.0)),
+        TouchStatus::Touching
+    );
+
+    let oblique = Vector3::new(1.0, -2.0, 0.5).normalize() * 1.5;
+    assert_eq!(ball_ball_status(oblique), TouchStatus::Touching);
+}
+
+#[test]
+fn separated_and_penetrating_balls() {
+    match ball_ball_status(Vector3::new(0.0, 1.6, 0.0)) {
+        TouchStatus::Separated(dist) => assert_relative_eq!(dist, 0.1, epsilon = 1.0e-5),
+        status => panic!("Unexpected status: {:?}", status),
+    }
+
+    match ball_ball_status(Vector3::new(0.0, 0.0, 1.4)) {
+        TouchStatus::Penetrating(depth) => assert_relative_eq!(depth, 0.1, epsilon = 1.0e-5),
+        status => panic!("Unexpected status: {:?}", status),
+    }
+}
+
+#[test]
+fn resting_cuboid_is_touching() {
+    let ground = Cuboid::new(Vector3::new(10.0, 1.0, 10.0));
+    let ball = Ball::new(0.5);
+
+    let status = query::touch_status(
+        Isometry3::IDENTITY,
+        &ground,
+        Isometry3::from_xyz(2.0, 1.5, -3.0),
+        &ball,
+        TOLERANCE,
+    )
+    .unwrap();
+    assert_eq!(status, TouchStatus::Touching);
+}
@@ -0,0 +1,41 @@
+use barry3d::math::{Isometry3, UnitVector3, Vector3};
+use barry3d::query;
+use barry3d::shape::{Ball, SupportMap, Translated};
+
+#[test]
+fn translated_ball_support_points() {
+    let ball = Ball::new(0.5);
+    let offset = Vector3::new(1.0, -2.0, 3.0);
+    let translated = Translated::new(ball, offset);
+
+    let dirs = [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(-0.3, 0.7, 0.2),
+        Vector3::new(0.0, -4.0, 1.0),
+    ];
+
+    for dir in dirs {
+        assert_relative_eq!(
+            translated.local_support_point(dir),
+            offset + ball.local_support_point(dir),
+            epsilon = 1.0e-6
+        );
+
+        let dir = UnitVector3::new(dir).unwrap();
+        assert_relative_eq!(
+            translated.local_support_point_toward(dir),
+            offset + ball.local_support_point_toward(dir),
+            epsilon = 1.0e-6
+        );
+    }
+}
+
+#[test]
+fn translated_ball_distance() {
+    let ball = Ball::new(0.5);
+    let translated = Translated::new(ball, Vector3::new(2.0, 0.0, 0.0));
+    let pos12 = Isometry3::from_xyz(4.0, 0.0, 0.0);
+
+    let dist = query::details::distance_support_map_support_map(pos12, &translated, &ball);
+    assert_relative_eq!(dist, 1.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,36 @@
+use barry3d::math::Vector3;
+use barry3d::utils::{triangle_normal, TriangleDegeneracy};
+
+#[test]
+fn valid_triangle_normal() {
+    let normal = triangle_normal(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+
+    assert_relative_eq!(*normal, Vector3::Z);
+}
+
+#[test]
+fn collinear_triangle_normal() {
+    let normal = triangle_normal(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 1.0, 1.0),
+        Vector3::new(2.0, 2.0, 2.0),
+    );
+
+    assert_eq!(normal, Err(TriangleDegeneracy::CollinearPoints));
+}
+
+#[test]
+fn coincident_triangle_normal() {
+    let normal = triangle_normal(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(1.0, 2.0, 3.0),
+    );
+
+    assert_eq!(normal, Err(TriangleDegeneracy::CoincidentPoints));
+}
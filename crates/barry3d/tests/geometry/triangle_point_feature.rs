@@ -0,0 +1,65 @@
+use barry3d::math::Vector3;
+use barry3d::query::PointQuery;
+use barry3d::shape::{FeatureId, Triangle};
+
+fn triangle() -> Triangle {
+    Triangle::new(
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 2.0, 0.0),
+    )
+}
+
+fn check(pt: Vector3, expected_proj: Vector3, expected_feature: FeatureId) {
+    let (proj, feature) = triangle().project_local_point_and_get_feature(pt);
+    assert_relative_eq!(proj.point, expected_proj, epsilon = 1.0e-6);
+    assert_eq!(feature, expected_feature);
+}
+
+#[test]
+fn triangle_project_point_on_face_interior() {
+    for z in [-1.0, 0.5] {
+        let (proj, feature) =
+            triangle().project_local_point_and_get_feature(Vector3::new(0.5, 0.5, z));
+        assert_relative_eq!(proj.point, Vector3::new(0.5, 0.5, 0.0), epsilon = 1.0e-6);
+        assert!(matches!(feature, FeatureId::Face(_)));
+    }
+}
+
+#[test]
+fn triangle_project_point_on_edges() {
+    check(
+        Vector3::new(1.0, -1.0, 0.3),
+        Vector3::new(1.0, 0.0, 0.0),
+        FeatureId::Edge(0),
+    );
+    check(
+        Vector3::new(1.5, 1.5, -0.2),
+        Vector3::new(1.0, 1.0, 0.0),
+        FeatureId::Edge(1),
+    );
+    check(
+        Vector3::new(-1.0, 1.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        FeatureId::Edge(2),
+    );
+}
+
+#[test]
+fn triangle_project_point_on_vertices() {
+    check(
+        Vector3::new(-1.0, -1.0, 0.5),
+        Vector3::new(0.0, 0.0, 0.0),
+        FeatureId::Vertex(0),
+    );
+    check(
+        Vector3::new(3.0, -0.5, 0.0),
+        Vector3::new(2.0, 0.0, 0.0),
+        FeatureId::Vertex(1),
+    );
+    check(
+        Vector3::new(-0.5, 3.0, -0.5),
+        Vector3::new(0.0, 2.0, 0.0),
+        FeatureId::Vertex(2),
+    );
+}
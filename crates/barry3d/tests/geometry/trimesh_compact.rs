@@ -0,0 +1,162 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{PointQuery, PointQueryWithLocation, Ray};
+use barry3d::shape::{
+    Ball, CompactableCompositeShape, Compound, Cuboid, SharedShape, TriMesh, TriMeshFlags,
+};
+
+use super::rand_vector;
+
+#[test]
+fn trimesh_compact_after_removing_half_the_triangles() {
+    let (vertices, indices) = Ball::new(1.0).to_trimesh(20, 20);
+    let num_triangles = indices.len() as u32;
+    let mut mesh = TriMesh::new(vertices, indices);
+    let original = mesh.clone();
+
+    for i in (1..num_triangles).step_by(2) {
+        assert!(mesh.remove_triangle(i));
+    }
+    assert!(!mesh.remove_triangle(1));
+    assert!(!mesh.remove_triangle(num_triangles));
+
+    let mut rng = oorandom::Rand32::new(42);
+    let rays: Vec<_> = (0..100)
+        .map(|_| {
+            let origin = rand_vector(&mut rng, 3.0);
+            Ray::new(origin, rand_vector(&mut rng, 0.5) - origin)
+        })
+        .collect();
+    let points: Vec<_> = (0..100).map(|_| rand_vector(&mut rng, 2.0)).collect();
+
+    // Queries before compaction ignore the removed triangles.
+    let hits_before: Vec<_> = rays
+        .iter()
+        .map(|ray| mesh.cast_ray_and_get_part(Isometry3::IDENTITY, ray, Real::MAX, true))
+        .collect();
+    let projs_before: Vec<_> = points
+        .iter()
+        .map(|pt| mesh.project_local_point_and_get_location(*pt, false))
+        .collect();
+
+    for (part_id, _) in hits_before.iter().flatten() {
+        assert_eq!(part_id % 2, 0);
+    }
+    for (_, (part_id, _)) in &projs_before {
+        assert_eq!(part_id % 2, 0);
+    }
+
+    let remap = mesh.compact();
+    assert_eq!(mesh.num_triangles() as u32, (num_triangles + 1) / 2);
+    assert_eq!(remap.len() as u32, (num_triangles + 1) / 2);
+
+    for (old_id, new_id) in &remap {
+        assert_eq!(old_id % 2, 0);
+        assert_eq!(*new_id, old_id / 2);
+        let old_tri = original.triangle(*old_id);
+        let new_tri = mesh.triangle(*new_id);
+        assert_eq!(
+            [old_tri.a, old_tri.b, old_tri.c],
+            [new_tri.a, new_tri.b, new_tri.c]
+        );
+    }
+
+    // Queries after compaction find the same triangles, under their new ids.
+    for (ray, hit_before) in rays.iter().zip(&hits_before) {
+        let hit_after = mesh.cast_ray_and_get_part(Isometry3::IDENTITY, ray, Real::MAX, true);
+
+        match (hit_before, hit_after) {
+            (Some((before_id, before)), Some((after_id, after))) => {
+                assert_eq!(remap[before_id], after_id);
+                assert_relative_eq!(before.toi, after.toi, epsilon = 1.0e-5);
+            }
+            (None, None) => {}
+            _ => panic!("Ray cast mismatch after compaction."),
+        }
+    }
+
+    for (pt, (proj_before, (before_id, _))) in points.iter().zip(&projs_before) {
+        let (proj_after, (after_id, _)) = mesh.project_local_point_and_get_location(*pt, false);
+        assert_eq!(remap[before_id], after_id);
+        assert_relative_eq!(proj_before.point, proj_after.point, epsilon = 1.0e-5);
+        assert_relative_eq!(
+            pt.distance(proj_before.point),
+            mesh.distance_to_local_point(*pt, false).abs(),
+            epsilon = 1.0e-5
+        );
+    }
+}
+
+#[test]
+fn trimesh_removed_triangles_are_not_adjacent() {
+    let (vertices, indices) = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh();
+    let num_triangles = indices.len() as u32;
+    let mut mesh = TriMesh::with_flags(
+        vertices,
+        indices,
+        TriMeshFlags::FIX_INTERNAL_EDGES | TriMeshFlags::CONNECTED_COMPONENTS,
+    );
+
+    let adjacent = mesh.triangle_adjacent_normals(0).unwrap();
+    assert!(adjacent.iter().all(|normal| normal.is_some()));
+    assert!(mesh.connected_components().is_some());
+
+    for i in 1..num_triangles {
+        assert!(mesh.remove_part(i));
+    }
+
+    // The neighbors of the remaining triangle were removed, so all its edges are boundaries.
+    let adjacent = mesh.triangle_adjacent_normals(0).unwrap();
+    assert_eq!(adjacent, [None; 3]);
+    assert!(mesh.connected_components().is_none());
+
+    let remap = CompactableCompositeShape::compact(&mut mesh);
+    assert_eq!(remap.len(), 1);
+    assert_eq!(mesh.num_triangles(), 1);
+    assert_eq!(mesh.triangle_adjacent_normals(0).unwrap(), [None; 3]);
+    assert_eq!(
+        mesh.connected_components()
+            .unwrap()
+            .num_connected_components(),
+        1
+    );
+}
+
+#[test]
+fn compound_compact_after_removing_parts() {
+    let shapes: Vec<_> = (0..6)
+        .map(|i| {
+            let pos = Isometry3::from_xyz(i as Real * 3.0, 0.0, 0.0);
+            (pos, SharedShape::ball(0.5 + i as Real * 0.1))
+        })
+        .collect();
+    let mut compound = Compound::new(shapes.clone());
+
+    assert!(compound.remove_part(1));
+    assert!(compound.remove_part(4));
+    assert!(!compound.remove_part(4));
+    assert!(!compound.remove_part(6));
+
+    // Removed parts are ignored by the queries.
+    let removed_center = Vector3::new(3.0, 0.0, 0.0);
+    assert!(!compound.contains_local_point(removed_center));
+
+    let remap = compound.compact();
+    assert_eq!(compound.shapes().len(), 4);
+    assert_eq!(remap.len(), 4);
+
+    for (old_id, new_id) in &remap {
+        let (old_pos, old_shape) = &shapes[*old_id as usize];
+        let (new_pos, new_shape) = &compound.shapes()[*new_id as usize];
+        assert_eq!(old_pos, new_pos);
+        assert_eq!(
+            old_shape.as_ball().unwrap().radius,
+            new_shape.as_ball().unwrap().radius
+        );
+    }
+
+    assert_eq!(remap[&0], 0);
+    assert_eq!(remap[&5], 3);
+    assert!(!compound.contains_local_point(removed_center));
+    assert!(compound.contains_local_point(Vector3::new(15.0, 0.0, 0.0)));
+    assert_relative_eq!(compound.local_aabb().maxs.x, 16.0, epsilon = 1.0e-5);
+}
@@ -0,0 +1,124 @@
+use barry3d::math::{Isometry3, Vector3};
+use barry3d::query::{details, DefaultQueryDispatcher, TOIStatus};
+use barry3d::shape::{Ball, TriMesh};
+
+fn build_thin_wall() -> TriMesh {
+    let points = vec![
+        Vector3::new(0.0, -10.0, -10.0),
+        Vector3::new(0.0, 10.0, -10.0),
+        Vector3::new(0.0, 10.0, 10.0),
+        Vector3::new(0.0, -10.0, 10.0),
+    ];
+    let indices = vec![[0u32, 1, 2], [0, 2, 3]];
+    TriMesh::new(points, indices)
+}
+
+#[test]
+fn fast_ball_does_not_tunnel_through_thin_wall() {
+    let wall = build_thin_wall();
+    let ball = Ball::new(0.05);
+    let pos12 = Isometry3::from_xyz(-5.0, 0.3, 0.2);
+    let vel12 = Vector3::new(1000.0, 0.0, 0.0);
+
+    let toi = details::time_of_impact_composite_shape_shape_with_conservative_advancement(
+        &DefaultQueryDispatcher,
+        pos12,
+        vel12,
+        &wall,
+        &ball,
+        1.0,
+        1.0e-4,
+        true,
+    )
+    .expect("the ball tunneled through the wall");
+
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 4.95 / 1000.0, epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness1.x, 0.0, epsilon = 1.0e-4);
+    assert_relative_eq!(toi.normal1.x.abs(), 1.0, epsilon = 1.0e-4);
+}
+
+#[test]
+fn ball_moving_away_from_thin_wall_does_not_hit() {
+    let wall = build_thin_wall();
+    let ball = Ball::new(0.05);
+    let pos12 = Isometry3::from_xyz(-5.0, 0.3, 0.2);
+    let vel12 = Vector3::new(-1000.0, 0.0, 0.0);
+
+    let toi = details::time_of_impact_composite_shape_shape_with_conservative_advancement(
+        &DefaultQueryDispatcher,
+        pos12,
+        vel12,
+        &wall,
+        &ball,
+        1.0,
+        1.0e-4,
+        true,
+    );
+
+    assert!(toi.is_none());
+}
+
+#[test]
+fn ball_sliding_along_thin_wall_does_not_hit() {
+    let wall = build_thin_wall();
+    let ball = Ball::new(0.05);
+    let pos12 = Isometry3::from_xyz(-0.1, 0.3, 0.2);
+    let vel12 = Vector3::new(0.0, 1000.0, 0.0);
+
+    let toi = details::time_of_impact_composite_shape_shape_with_conservative_advancement(
+        &DefaultQueryDispatcher,
+        pos12,
+        vel12,
+        &wall,
+        &ball,
+        1.0,
+        1.0e-4,
+        true,
+    );
+
+    assert!(toi.is_none());
+}
+
+// A floor on the plane `y = 0` and a wall on the plane `x = 2`.
+fn build_floor_and_wall() -> TriMesh {
+    let points = vec![
+        Vector3::new(-10.0, 0.0, -10.0),
+        Vector3::new(10.0, 0.0, -10.0),
+        Vector3::new(10.0, 0.0, 10.0),
+        Vector3::new(-10.0, 0.0, 10.0),
+        Vector3::new(2.0, 0.0, -10.0),
+        Vector3::new(2.0, 10.0, -10.0),
+        Vector3::new(2.0, 10.0, 10.0),
+        Vector3::new(2.0, 0.0, 10.0),
+    ];
+    let indices = vec![[0u32, 2, 1], [0, 3, 2], [4, 5, 6], [4, 6, 7]];
+    TriMesh::new(points, indices)
+}
+
+#[test]
+fn ball_sliding_along_floor_hits_wall() {
+    let mesh = build_floor_and_wall();
+    let ball = Ball::new(0.5);
+    // The ball hovers above the floor, so its closest points with the mesh don't approach
+    // each other until it gets close to the wall.
+    let pos12 = Isometry3::from_xyz(-3.0, 0.6, 0.0);
+    let vel12 = Vector3::new(10.0, 0.0, 0.0);
+
+    let toi = details::time_of_impact_composite_shape_shape_with_conservative_advancement(
+        &DefaultQueryDispatcher,
+        pos12,
+        vel12,
+        &mesh,
+        &ball,
+        1.0,
+        1.0e-4,
+        true,
+    )
+    .expect("the ball tunneled through the wall");
+
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert_relative_eq!(toi.toi, 4.5 / 10.0, epsilon = 1.0e-4);
+    assert_relative_eq!(toi.witness1.x, 2.0, epsilon = 1.0e-4);
+    assert_relative_eq!(toi.normal1.x.abs(), 1.0, epsilon = 1.0e-4);
+}
@@ -0,0 +1,74 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::PointQuery;
+use barry3d::shape::TriMesh;
+
+// A flat grid of `n x n` unit squares, each split into two triangles.
+fn grid(n: u32) -> TriMesh {
+    let mut vertices = vec![];
+    let mut indices = vec![];
+
+    for i in 0..=n {
+        for j in 0..=n {
+            vertices.push(Vector3::new(i as Real, j as Real, 0.0));
+        }
+    }
+
+    for i in 0..n {
+        for j in 0..n {
+            let a = i * (n + 1) + j;
+            let b = a + n + 1;
+            indices.push([a, b, b + 1]);
+            indices.push([a, b + 1, a + 1]);
+        }
+    }
+
+    TriMesh::new(vertices, indices)
+}
+
+#[test]
+fn triangles_in_ball_selects_nearby_cell() {
+    let mesh = grid(10);
+    let mut out = vec![];
+    mesh.triangles_in_ball(
+        Isometry3::IDENTITY,
+        Vector3::new(5.5, 5.5, 0.1),
+        0.4,
+        &mut out,
+    );
+    out.sort_unstable();
+
+    // Only the two triangles of the cell [5, 6] x [5, 6] are close enough.
+    assert_eq!(out, vec![110, 111]);
+}
+
+#[test]
+fn triangles_in_ball_matches_brute_force() {
+    let mesh = grid(10);
+    let pos = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 1.2, -0.4));
+    let mut rng = oorandom::Rand32::new(42);
+
+    for _ in 0..100 {
+        let local_center = Vector3::new(
+            rng.rand_float() * 12.0 - 1.0,
+            rng.rand_float() * 12.0 - 1.0,
+            rng.rand_float() * 2.0 - 1.0,
+        );
+        let center = pos.transform_point(local_center);
+        let radius = rng.rand_float() * 3.0;
+
+        let mut out = vec![];
+        mesh.triangles_in_ball(pos, center, radius, &mut out);
+        out.sort_unstable();
+
+        let expected: Vec<u32> = (0..mesh.num_triangles() as u32)
+            .filter(|i| {
+                mesh.triangle(*i)
+                    .local_aabb()
+                    .distance_to_local_point(local_center, true)
+                    <= radius
+            })
+            .collect();
+
+        assert_eq!(out, expected);
+    }
+}
@@ -0,0 +1,52 @@
+use barry3d::math::{Isometry3, Real, Vector3};
+use barry3d::query::{Ray, RayCast};
+use barry3d::shape::Cuboid;
+
+#[test]
+fn world_space_ray_cast_on_rotated_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 0.5));
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.5, 0.8));
+    let ray = Ray::new(Vector3::new(-5.0, 1.0, 2.0), Vector3::new(1.0, 0.2, 0.1));
+
+    // Manually express the ray in the local-space of the cuboid.
+    let local_ray = Ray::new(
+        pos.inverse_transform_point(ray.origin),
+        pos.rotation.inverse() * ray.dir,
+    );
+
+    let hit = cuboid
+        .cast_ray_and_get_normal(pos, &ray, Real::MAX, true)
+        .unwrap();
+    let local_hit = cuboid
+        .cast_local_ray_and_get_normal(&local_ray, Real::MAX, true)
+        .unwrap();
+
+    assert_relative_eq!(hit.toi, local_hit.toi, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        hit.normal,
+        pos.rotation * local_hit.normal,
+        epsilon = 1.0e-5
+    );
+    assert_relative_eq!(
+        cuboid.cast_ray(pos, &ray, Real::MAX, true).unwrap(),
+        local_hit.toi,
+        epsilon = 1.0e-5
+    );
+}
+
+#[test]
+fn ray_transform_round_trip() {
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.5, 0.8));
+    let ray = Ray::new(Vector3::new(-5.0, 1.0, 2.0), Vector3::new(1.0, 0.2, 0.1));
+
+    let local_ray = ray.inverse_transform_by(pos);
+    assert_relative_eq!(
+        local_ray.origin,
+        pos.inverse_transform_point(ray.origin),
+        epsilon = 1.0e-5
+    );
+
+    let world_ray = local_ray.transform_by(pos);
+    assert_relative_eq!(world_ray.origin, ray.origin, epsilon = 1.0e-5);
+    assert_relative_eq!(world_ray.dir, ray.dir, epsilon = 1.0e-5);
+}
@@ -1,9 +1,14 @@
 //! Axis Aligned Bounding Box.
 
 use crate::bounding_volume::{BoundingSphere, BoundingVolume};
-use crate::math::{Isometry, Real, UnitVector, Vector, DIM, TWO_DIM};
+use crate::math::{AngVector, Isometry, Real, UnitVector, Vector, DIM, TWO_DIM};
 use crate::shape::{Cuboid, SupportMap};
 use crate::utils::IsometryOps;
+#[cfg(feature = "std")]
+use crate::{
+    bounding_volume::SimdAabb,
+    math::{SimdIsometry, SIMD_WIDTH},
+};
 use arrayvec::ArrayVec;
 
 #[cfg(feature = "rkyv")]
@@ -151,12 +156,37 @@ impl Aabb {
     #[inline]
     pub fn transform_by(&self, m: Isometry) -> Self {
         let ls_center = self.center();
-        let center = m * ls_center;
+        let center = m.transform_point(ls_center);
         let ws_half_extents = m.absolute_transform_vector(self.half_extents());
 
         Aabb::new(center + (-ws_half_extents), center + ws_half_extents)
     }
 
+    /// Computes the `Aabb` bounding `self` transformed by each isometry of `poses`.
+    ///
+    /// The poses are processed in packets of [`SIMD_WIDTH`] using [`SimdAabb::transform_by`].
+    /// The result is identical to calling [`Aabb::transform_by`] on each pose.
+    #[cfg(feature = "std")]
+    pub fn transform_by_all(&self, poses: &[Isometry]) -> Vec<Self> {
+        let simd_self = SimdAabb::splat(*self);
+        let mut result = Vec::with_capacity(poses.len());
+        let mut packets = poses.chunks_exact(SIMD_WIDTH);
+
+        for packet in &mut packets {
+            let isos = array![|ii| packet[ii]; SIMD_WIDTH];
+            let aabbs = simd_self.transform_by(SimdIsometry::from_isometries(isos));
+            result.extend((0..SIMD_WIDTH).map(|ii| aabbs.extract(ii)));
+        }
+
+        result.extend(
+            packets
+                .remainder()
+                .iter()
+                .map(|pos| self.transform_by(*pos)),
+        );
+        result
+    }
+
     #[inline]
     pub fn scaled(self, scale: Vector) -> Self {
         let a = self.mins * scale;
@@ -175,6 +205,9 @@ impl Aabb {
         BoundingSphere::new(center, radius)
     }
 
+    /// Tests whether the given point is inside of this `Aabb`, boundary included.
+    ///
+    /// The point is expressed in the same coordinate system as this `Aabb`.
     #[inline]
     pub fn contains_local_point(&self, point: Vector) -> bool {
         for i in 0..DIM {
@@ -186,6 +219,15 @@ impl Aabb {
         true
     }
 
+    /// Tests whether the given point is inside of this `Aabb`, boundary included.
+    ///
+    /// Since an `Aabb` is already expressed in world-space, this is the same as
+    /// [`Aabb::contains_local_point`].
+    #[inline]
+    pub fn contains_point(&self, point: Vector) -> bool {
+        self.contains_local_point(point)
+    }
+
     /// Computes the intersection of this `Aabb` and another one.
     pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
         let result = Aabb {
@@ -202,6 +244,144 @@ impl Aabb {
         Some(result)
     }
 
+    /// Computes the Minkowski sum of this `Aabb` and another one.
+    ///
+    /// This is the `Aabb` containing all the sums of a point of `self` and a point of `other`.
+    #[inline]
+    pub fn minkowski_sum(&self, other: &Aabb) -> Aabb {
+        Aabb::new(self.mins + other.mins, self.maxs + other.maxs)
+    }
+
+    /// Computes the minimum translation to apply to `self` so it no longer overlaps `other`.
+    ///
+    /// The returned vector is aligned with the axis of least penetration, and its length is
+    /// the penetration depth along that axis. Returns `None` if both `Aabb`s are disjoint.
+    ///
+    /// Touching `Aabb`s intersect, as with [`BoundingVolume::intersects`], so they yield
+    /// `Some(Vector::ZERO)` rather than `None`.
+    pub fn penetration(&self, other: &Aabb) -> Option<Vector> {
+        let mut best_depth = Real::MAX;
+        let mut result = Vector::ZERO;
+
+        for i in 0..DIM {
+            // Depth of penetration when pushing `self` toward the negative/positive axis.
+            let depth_neg = self.maxs[i] - other.mins[i];
+            let depth_pos = other.maxs[i] - self.mins[i];
+
+            if depth_neg < 0.0 || depth_pos < 0.0 {
+                return None;
+            }
+
+            let (depth, sign) = if depth_neg < depth_pos {
+                (depth_neg, -1.0)
+            } else {
+                (depth_pos, 1.0)
+            };
+
+            if depth < best_depth {
+                best_depth = depth;
+                result = Vector::ZERO;
+                result[i] = sign * depth;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Computes the time interval during which `other` overlaps `self` while moving with the
+    /// constant velocity `rel_vel` relative to `self`.
+    ///
+    /// Returns `Some((t_enter, t_exit))` with `0 <= t_enter <= t_exit`, where `t_exit` is
+    /// `Real::MAX` if the boxes never separate again. If both boxes already overlap,
+    /// `t_enter` is zero. Returns `None` if the boxes never overlap for any `t >= 0`.
+    pub fn swept_overlap_interval(&self, other: &Aabb, rel_vel: Vector) -> Option<(Real, Real)> {
+        let mut t_enter: Real = 0.0;
+        let mut t_exit = Real::MAX;
+
+        for i in 0..DIM {
+            if rel_vel[i] == 0.0 {
+                // No motion along this axis: the projections must already overlap.
+                if other.maxs[i] < self.mins[i] || other.mins[i] > self.maxs[i] {
+                    return None;
+                }
+            } else {
+                let inv_vel = 1.0 / rel_vel[i];
+                let t0 = (self.mins[i] - other.maxs[i]) * inv_vel;
+                let t1 = (self.maxs[i] - other.mins[i]) * inv_vel;
+
+                t_enter = t_enter.max(t0.min(t1));
+                t_exit = t_exit.min(t0.max(t1));
+
+                if t_enter > t_exit {
+                    return None;
+                }
+            }
+        }
+
+        Some((t_enter, t_exit))
+    }
+
+    /// Computes the first time at which `other`, moving with the constant velocity `rel_vel`
+    /// relative to `self`, touches `self`, and the outward normal of the face of `self` it hits.
+    ///
+    /// The face hit is the one orthogonal to the axis along which the boxes start overlapping
+    /// last. If the boxes already overlap, the time is zero and the face is the one `other`
+    /// crossed most recently along its motion. Returns `None` if the boxes never overlap for
+    /// any `t >= 0`, or if `rel_vel` is zero.
+    pub fn swept_contact(&self, other: &Aabb, rel_vel: Vector) -> Option<(Real, UnitVector)> {
+        self.swept_overlap_interval(other, rel_vel)?;
+
+        let mut best_t = -Real::MAX;
+        let mut best_axis = None;
+
+        for i in 0..DIM {
+            if rel_vel[i] != 0.0 {
+                let inv_vel = 1.0 / rel_vel[i];
+                let t0 = (self.mins[i] - other.maxs[i]) * inv_vel;
+                let t1 = (self.maxs[i] - other.mins[i]) * inv_vel;
+                let t_enter = t0.min(t1);
+
+                if t_enter > best_t {
+                    best_t = t_enter;
+                    best_axis = Some(i);
+                }
+            }
+        }
+
+        let i = best_axis?;
+        let mut normal = Vector::ZERO;
+        normal[i] = -rel_vel[i].signum();
+
+        Some((best_t.max(0.0), UnitVector::new_unchecked(normal)))
+    }
+
+    /// Computes a conservative `Aabb` bounding `self` while it moves with the linear velocity
+    /// `linear_vel` and rotates with the angular velocity `angular_vel` for a duration `dt`.
+    ///
+    /// The translational swept `Aabb` is enlarged in all directions by the maximum distance a
+    /// point at `bounding_radius` from the rotation center can travel, i.e.,
+    /// `|angular_vel| * dt * bounding_radius`.
+    pub fn swept_with_rotation(
+        &self,
+        linear_vel: Vector,
+        angular_vel: AngVector,
+        dt: Real,
+        bounding_radius: Real,
+    ) -> Aabb {
+        #[cfg(feature = "dim2")]
+        let angular_speed = angular_vel.abs();
+        #[cfg(feature = "dim3")]
+        let angular_speed = angular_vel.length();
+
+        let displacement = linear_vel * dt;
+        let swept = Aabb::new(
+            self.mins.min(self.mins + displacement),
+            self.maxs.max(self.maxs + displacement),
+        );
+
+        swept.loosened(angular_speed * dt.abs() * bounding_radius.abs())
+    }
+
     /// Returns the difference between this `Aabb` and `rhs`.
     ///
     /// Removing another `Aabb` from `self` will result in zero, one, or up to 4 (in 2D) or 8 (in 3D)
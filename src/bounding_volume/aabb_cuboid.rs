@@ -5,6 +5,10 @@ use crate::utils::IsometryOps;
 
 impl Cuboid {
     /// Computes the world-space [`Aabb`] of this cuboid, transformed by `pos`.
+    ///
+    /// Instead of transforming each vertex of the cuboid, the half-extents of the [`Aabb`] are
+    /// computed directly as `|R| * half_extents` where `|R|` is the component-wise absolute value
+    /// of the rotation matrix of `pos`.
     #[inline]
     pub fn aabb(&self, pos: Isometry) -> Aabb {
         let center = pos.translation;
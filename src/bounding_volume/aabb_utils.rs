@@ -2,11 +2,13 @@ use std::iter::IntoIterator;
 
 use crate::bounding_volume::Aabb;
 use crate::math::{Isometry, Vector, DIM};
-use crate::shape::SupportMap;
+use crate::shape::{Shape, SupportMap};
 
-/// Computes the [`Aabb`] of an [support mapped shape](SupportMap).
-#[cfg(feature = "dim3")]
-pub fn support_map_aabb<G>(m: Isometry, i: &G) -> Aabb
+/// Computes the [`Aabb`] of an [support mapped shape](SupportMap) transformed by `m`.
+///
+/// The support points along the `2 * DIM` world-space axis directions bound the shape
+/// exactly, so this gives an [`Aabb`] to any support-mapped shape without an analytic one.
+pub fn support_map_aabb<G: ?Sized>(m: Isometry, i: &G) -> Aabb
 where
     G: SupportMap,
 {
@@ -30,7 +32,7 @@ where
 }
 
 /// Computes the [`Aabb`] of an [support mapped shape](SupportMap).
-pub fn local_support_map_aabb<G>(i: &G) -> Aabb
+pub fn local_support_map_aabb<G: ?Sized>(i: &G) -> Aabb
 where
     G: SupportMap,
 {
@@ -96,3 +98,12 @@ where
 
     Aabb::new(min, max)
 }
+
+/// Computes the Minkowski sum of the [`Aabb`]s of two shapes transformed by `pos1` and `pos2`.
+///
+/// This bounds the set of all the sums of a point of `g1` and a point of `g2`. In particular,
+/// the configuration-space obstacle of `g2` for `g1`, i.e., the set of positions of `g1`
+/// for which they overlap, is bounded by the Minkowski sum of `g2` and the reflection of `g1`.
+pub fn minkowski_sum_aabb(g1: &dyn Shape, pos1: Isometry, g2: &dyn Shape, pos2: Isometry) -> Aabb {
+    g1.compute_aabb(pos1).minkowski_sum(&g2.compute_aabb(pos2))
+}
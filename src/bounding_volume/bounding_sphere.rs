@@ -2,6 +2,7 @@
 
 use crate::bounding_volume::BoundingVolume;
 use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::shape::Capsule;
 
 #[cfg(feature = "rkyv")]
 use rkyv::{bytecheck, CheckBytes};
@@ -42,7 +43,18 @@ impl BoundingSphere {
     /// Transforms this bounding sphere by `m`.
     #[inline]
     pub fn transform_by(&self, m: Isometry) -> BoundingSphere {
-        BoundingSphere::new(m.translation + self.center, self.radius)
+        BoundingSphere::new(m.transform_point(self.center), self.radius)
+    }
+
+    /// Computes the capsule swept by this bounding sphere while it moves with the linear
+    /// velocity `vel` for a duration `dt`.
+    ///
+    /// The segment of the capsule goes from the initial to the final center of the sphere, and
+    /// its radius is the radius of the sphere. Two moving spheres may only collide during `dt`
+    /// if their swept capsules intersect.
+    #[inline]
+    pub fn swept(&self, vel: Vector, dt: Real) -> Capsule {
+        Capsule::new(self.center, self.center + vel * dt, self.radius)
     }
 }
 
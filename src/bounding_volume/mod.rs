@@ -57,8 +57,9 @@ mod simd_aabb;
 
 /// Free functions for some special cases of bounding-volume computation.
 pub mod details {
-    #[cfg(feature = "dim3")]
-    pub use super::aabb_utils::support_map_aabb;
-    pub use super::aabb_utils::{local_point_cloud_aabb, local_support_map_aabb, point_cloud_aabb};
+    pub use super::aabb_utils::{
+        local_point_cloud_aabb, local_support_map_aabb, minkowski_sum_aabb, point_cloud_aabb,
+        support_map_aabb,
+    };
     pub use super::bounding_sphere_utils::point_cloud_bounding_sphere;
 }
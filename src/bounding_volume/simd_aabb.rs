@@ -154,7 +154,7 @@ impl SimdAabb {
     /// Return the Aabb of the `self` transformed by the given isometry.
     pub fn transform_by(&self, transform: SimdIsometry) -> Self {
         let ls_center = self.center();
-        let center = transform * ls_center;
+        let center = transform.transform_point(ls_center);
         let ws_half_extents = transform.absolute_transform_vector(self.half_extents());
         Self {
             mins: center + (-ws_half_extents),
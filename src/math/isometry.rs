@@ -69,6 +69,11 @@ impl Iso2 {
     pub fn inverse_transform_point(self, point: Vector2) -> Vector2 {
         self.rotation.inverse() * (point - self.translation)
     }
+
+    /// Returns `true` if both the translation and rotation of this isometry are finite.
+    pub fn is_finite(self) -> bool {
+        self.translation.is_finite() && self.rotation.is_finite()
+    }
 }
 
 impl Add for Iso2 {
@@ -180,6 +185,11 @@ impl Iso3 {
     pub fn inverse_transform_point(self, point: Vector3) -> Vector3 {
         self.rotation.inverse() * (point - self.translation)
     }
+
+    /// Returns `true` if both the translation and rotation of this isometry are finite.
+    pub fn is_finite(self) -> bool {
+        self.translation.is_finite() && self.rotation.is_finite()
+    }
 }
 
 impl Add for Iso3 {
@@ -4,11 +4,13 @@ use bevy_math::{DVec2, DVec3, Vec2, Vec3};
 
 mod eigen;
 mod isometry;
+mod perp;
 mod rotation;
 mod simd;
 
 pub use eigen::*;
 pub use isometry::*;
+pub use perp::*;
 pub use real::*;
 pub use rotation::*;
 pub use simd::*;
@@ -0,0 +1,31 @@
+//! Two-dimensional cross products.
+//!
+//! These are the reference conventions for 2D cross products throughout this crate: prefer
+//! them over building the perpendicular vectors component-wise. They are thin wrappers around
+//! the equivalent `glam` methods.
+
+use super::{Real, Vector2};
+
+/// Rotates `v` by 90 degrees counterclockwise, i.e., returns `(-v.y, v.x)`.
+#[inline]
+pub fn perp(v: Vector2) -> Vector2 {
+    v.perp()
+}
+
+/// The dot product of [`perp(a)`](perp) with `b`.
+///
+/// This is positive if `b` points to the left of `a`, i.e., if the rotation from `a` to `b`
+/// is counterclockwise.
+#[inline]
+pub fn perp_dot(a: Vector2, b: Vector2) -> Real {
+    a.perp_dot(b)
+}
+
+/// The 2D cross product of `a` and `b`, i.e., the `Z` component of the 3D cross product of
+/// `a` and `b` extended with a zero `Z` component.
+///
+/// This is the same as [`perp_dot`].
+#[inline]
+pub fn cross2(a: Vector2, b: Vector2) -> Real {
+    a.perp_dot(b)
+}
@@ -80,6 +80,11 @@ impl Rotation2 {
             sin: -self.sin,
         }
     }
+
+    /// Returns `true` if the sine and cosine of this rotation are finite.
+    pub fn is_finite(&self) -> bool {
+        self.cos.is_finite() && self.sin.is_finite()
+    }
 }
 
 impl From<Rotation2> for Matrix2 {
@@ -972,6 +972,15 @@ impl SimdQuat {
         )
     }
 
+    pub fn from_quats(quats: [Quat; 4]) -> Self {
+        Self {
+            x: SimdReal::new(quats[0].x, quats[1].x, quats[2].x, quats[3].x),
+            y: SimdReal::new(quats[0].y, quats[1].y, quats[2].y, quats[3].y),
+            z: SimdReal::new(quats[0].z, quats[1].z, quats[2].z, quats[3].z),
+            w: SimdReal::new(quats[0].w, quats[1].w, quats[2].w, quats[3].w),
+        }
+    }
+
     pub fn from_scaled_axis(axis: Vector3) -> Self {
         Self::splat(Quat::from_scaled_axis(axis))
     }
@@ -1126,6 +1135,23 @@ impl SimdRotation2 {
         }
     }
 
+    pub fn from_rotations(rotations: [Rotation2; 4]) -> Self {
+        Self {
+            cos: SimdReal::new(
+                rotations[0].cos,
+                rotations[1].cos,
+                rotations[2].cos,
+                rotations[3].cos,
+            ),
+            sin: SimdReal::new(
+                rotations[0].sin,
+                rotations[1].sin,
+                rotations[2].sin,
+                rotations[3].sin,
+            ),
+        }
+    }
+
     /// Creates a [`SRotation2`] from radians.
     pub fn from_radians(radians: Real) -> Self {
         Self {
@@ -1242,6 +1268,10 @@ impl SimdRotation3 {
         Rotation3(self.0.extract(lane))
     }
 
+    pub fn from_rotations(rotations: [Rotation3; 4]) -> Self {
+        Self(SimdQuat::from_quats(rotations.map(|r| r.0)))
+    }
+
     pub fn from_scaled_axis(axis: Vector3) -> Self {
         Self(SimdQuat::from_scaled_axis(axis))
     }
@@ -1306,6 +1336,14 @@ impl SimdIso2 {
         }
     }
 
+    /// Packs one isometry per lane.
+    pub fn from_isometries(isos: [Isometry2; 4]) -> Self {
+        Self {
+            translation: SimdVec2::from_vecs(isos.map(|iso| iso.translation)),
+            rotation: SimdRotation2::from_rotations(isos.map(|iso| iso.rotation)),
+        }
+    }
+
     pub fn from_translation(translation: Vector2) -> Self {
         Self {
             translation: SimdVec2::splat(translation),
@@ -1417,6 +1455,14 @@ impl SimdIso3 {
         }
     }
 
+    /// Packs one isometry per lane.
+    pub fn from_isometries(isos: [Isometry3; 4]) -> Self {
+        Self {
+            translation: SimdVec3::from_vecs(isos.map(|iso| iso.translation)),
+            rotation: SimdRotation3::from_rotations(isos.map(|iso| iso.rotation)),
+        }
+    }
+
     pub fn from_translation(translation: Vector3) -> Self {
         Self {
             translation: SimdVec3::splat(translation),
@@ -7,6 +7,8 @@ pub use self::qbvh::{
 pub use self::qbvh::{
     GenericQbvh, IndexedData, NodeIndex, Qbvh, QbvhNode, QbvhProxy, QbvhStorage, SimdNodeIndex,
 };
+#[cfg(feature = "std")]
+pub use self::sphere_tree::{SphereTree, SphereTreeChildren, SphereTreeNode};
 #[cfg(feature = "parallel")]
 pub use self::visitor::{ParallelSimdSimultaneousVisitor, ParallelSimdVisitor};
 pub use self::visitor::{
@@ -19,4 +21,6 @@ pub use self::visitor::{
 pub type SimdQbvh<T> = Qbvh<T>;
 
 mod qbvh;
+#[cfg(feature = "std")]
+mod sphere_tree;
 mod visitor;
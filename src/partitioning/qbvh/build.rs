@@ -79,13 +79,24 @@ impl CenterDataSplitter {
         center: Vector,
         indices: &'idx mut [usize],
         aabbs: &[Aabb],
+    ) -> [&'idx mut [usize]; 4] {
+        self.split_dataset_with_fn(subdiv_dims, center, indices, |i| aabbs[i])
+    }
+
+    /// Same as [`Self::split_dataset_wo_workspace`], but reads the Aabb of each part with `aabb`.
+    fn split_dataset_with_fn<'idx>(
+        &self,
+        subdiv_dims: [usize; 2],
+        center: Vector,
+        indices: &'idx mut [usize],
+        aabb: impl Fn(usize) -> Aabb + Copy,
     ) -> [&'idx mut [usize]; 4] {
         // TODO: should we split wrt. the median instead of the average?
         // TODO: we should ensure each subslice contains at least 4 elements each (or less if
         // indices has less than 16 elements in the first place).
         let (left, right) = split_indices_wrt_dim(
             indices,
-            aabbs,
+            aabb,
             center,
             subdiv_dims[0],
             self.enable_fallback_split,
@@ -93,14 +104,14 @@ impl CenterDataSplitter {
 
         let (left_bottom, left_top) = split_indices_wrt_dim(
             left,
-            aabbs,
+            aabb,
             center,
             subdiv_dims[1],
             self.enable_fallback_split,
         );
         let (right_bottom, right_top) = split_indices_wrt_dim(
             right,
-            aabbs,
+            aabb,
             center,
             subdiv_dims[1],
             self.enable_fallback_split,
@@ -274,6 +285,82 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
     }
 }
 
+impl Qbvh<u32> {
+    /// Clears this quaternary BVH and rebuilds it from the parts with indices `0..num_parts`,
+    /// computing the Aabb of each part on-the-fly with `part_aabb`.
+    ///
+    /// Unlike [`Self::clear_and_rebuild`], this doesn't store the Aabbs of all the parts during
+    /// the build, which reduces the peak memory usage for very large meshes. Instead,
+    /// `part_aabb` is called several times per part, so it should be cheap and must always
+    /// return the same Aabb for a given part. The resulting tree is identical to the one built
+    /// by [`Self::clear_and_rebuild`] from the same Aabbs.
+    pub fn clear_and_rebuild_with_fn(
+        &mut self,
+        num_parts: u32,
+        part_aabb: impl Fn(u32) -> Aabb,
+        dilation_factor: Real,
+    ) {
+        self.free_list.clear();
+        self.nodes.clear();
+        self.proxies = (0..num_parts).map(QbvhProxy::detached).collect();
+
+        let mut indices: Vec<_> = (0..num_parts as usize).collect();
+        let aabb = |i: usize| part_aabb(i as u32);
+
+        self.nodes.push(QbvhNode {
+            simd_aabb: SimdAabb::new_invalid(),
+            children: [1, u32::MAX, u32::MAX, u32::MAX],
+            parent: NodeIndex::invalid(),
+            flags: QbvhNodeFlags::default(),
+        });
+        let root_id = NodeIndex::new(0, 0);
+        let (_, root_aabb) =
+            self.do_recurse_build_with_fn(&mut indices, &aabb, root_id, dilation_factor);
+
+        self.root_aabb = root_aabb;
+        self.nodes[0].simd_aabb = SimdAabb::from([
+            root_aabb,
+            Aabb::new_invalid(),
+            Aabb::new_invalid(),
+            Aabb::new_invalid(),
+        ]);
+    }
+
+    fn do_recurse_build_with_fn(
+        &mut self,
+        indices: &mut [usize],
+        aabb: &impl Fn(usize) -> Aabb,
+        parent: NodeIndex,
+        dilation: Real,
+    ) -> (u32, Aabb) {
+        if indices.len() <= 4 {
+            return self.push_leaf(indices, aabb, parent, dilation);
+        }
+
+        let (center, subdiv_dims) = center_and_subdiv_dims(indices, aabb);
+        let id = self.push_internal_node(parent);
+
+        // Recurse!
+        let splits =
+            CenterDataSplitter::default().split_dataset_with_fn(subdiv_dims, center, indices, aabb);
+        let n = [
+            NodeIndex::new(id, 0),
+            NodeIndex::new(id, 1),
+            NodeIndex::new(id, 2),
+            NodeIndex::new(id, 3),
+        ];
+
+        let children = [
+            self.do_recurse_build_with_fn(splits[0], aabb, n[0], dilation),
+            self.do_recurse_build_with_fn(splits[1], aabb, n[1], dilation),
+            self.do_recurse_build_with_fn(splits[2], aabb, n[2], dilation),
+            self.do_recurse_build_with_fn(splits[3], aabb, n[3], dilation),
+        ];
+
+        self.set_children(id, children, dilation)
+    }
+}
+
 impl<LeafData: IndexedData> Qbvh<LeafData> {
     /// Clears this quaternary BVH and rebuilds it from a new set of data and Aabbs.
     pub fn clear_and_rebuild_with_splitter(
@@ -340,74 +427,11 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
     ) -> (u32, Aabb) {
         if indices.len() <= 4 {
             // Leaf case.
-            let my_id = self.nodes.len();
-            let mut leaf_aabbs = [Aabb::new_invalid(); 4];
-            let mut proxy_ids = [u32::MAX; 4];
-
-            for (k, id) in indices.iter().enumerate() {
-                leaf_aabbs[k] = aabbs[*id];
-                proxy_ids[k] = *id as u32;
-                self.proxies[*id].node = NodeIndex::new(my_id as u32, k as u8);
-            }
-
-            let mut node = QbvhNode {
-                simd_aabb: SimdAabb::from(leaf_aabbs),
-                children: proxy_ids,
-                parent,
-                flags: QbvhNodeFlags::LEAF,
-            };
-
-            node.simd_aabb.dilate_by_factor(SimdReal::splat(dilation));
-            let my_aabb = node.simd_aabb.to_merged_aabb();
-            self.nodes.push(node);
-
-            return (my_id as u32, my_aabb);
-        }
-
-        // Compute the center and variance along each dimension.
-        // In 3D we compute the variance to not-subdivide the dimension with lowest variance.
-        // Therefore variance computation is not needed in 2D because we only have 2 dimension
-        // to split in the first place.
-        let mut center = Vector::ZERO;
-        #[cfg(feature = "dim3")]
-        let mut variance = Vector::ZERO;
-
-        let center_denom = 1.0 / (indices.len() as Real);
-
-        for i in &*indices {
-            let coords = aabbs[*i].center();
-            center += coords * center_denom;
+            return self.push_leaf(indices, &|i| aabbs[i], parent, dilation);
         }
 
-        #[cfg(feature = "dim3")]
-        {
-            let variance_denom = 1.0 / ((indices.len() - 1) as Real);
-            for i in &*indices {
-                let dir_to_center = aabbs[*i].center() - center;
-                variance += dir_to_center * dir_to_center * variance_denom;
-            }
-        }
-
-        // Find the axis with minimum variance. This is the axis along
-        // which we are **not** subdividing our set.
-        #[allow(unused_mut)] // Does not need to be mutable in 2D.
-        let mut subdiv_dims = [0, 1];
-        #[cfg(feature = "dim3")]
-        {
-            let min = variance.min_index();
-            subdiv_dims[0] = (min + 1) % 3;
-            subdiv_dims[1] = (min + 2) % 3;
-        }
-
-        let node = QbvhNode {
-            simd_aabb: SimdAabb::new_invalid(),
-            children: [0; 4], // Will be set after the recursive call
-            parent,
-            flags: QbvhNodeFlags::default(),
-        };
-
-        let id = self.nodes.len() as u32;
-        self.nodes.push(node);
+        let (center, subdiv_dims) = center_and_subdiv_dims(indices, &|i| aabbs[i]);
+        let id = self.push_internal_node(parent);
 
         // Split the set along the two subdiv_dims dimensions.
         let proxies = BuilderProxies {
@@ -432,6 +456,57 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
             self.do_recurse_build_generic(splitter, splits[3], aabbs, n[3], dilation),
         ];
 
+        self.set_children(id, children, dilation)
+    }
+
+    /// Pushes a leaf containing the parts with the given indices.
+    fn push_leaf(
+        &mut self,
+        indices: &[usize],
+        aabb: &impl Fn(usize) -> Aabb,
+        parent: NodeIndex,
+        dilation: Real,
+    ) -> (u32, Aabb) {
+        let my_id = self.nodes.len();
+        let mut leaf_aabbs = [Aabb::new_invalid(); 4];
+        let mut proxy_ids = [u32::MAX; 4];
+
+        for (k, id) in indices.iter().enumerate() {
+            leaf_aabbs[k] = aabb(*id);
+            proxy_ids[k] = *id as u32;
+            self.proxies[*id].node = NodeIndex::new(my_id as u32, k as u8);
+        }
+
+        let mut node = QbvhNode {
+            simd_aabb: SimdAabb::from(leaf_aabbs),
+            children: proxy_ids,
+            parent,
+            flags: QbvhNodeFlags::LEAF,
+        };
+
+        node.simd_aabb.dilate_by_factor(SimdReal::splat(dilation));
+        let my_aabb = node.simd_aabb.to_merged_aabb();
+        self.nodes.push(node);
+
+        (my_id as u32, my_aabb)
+    }
+
+    /// Pushes an internal node whose children are set later by [`Self::set_children`].
+    fn push_internal_node(&mut self, parent: NodeIndex) -> u32 {
+        let node = QbvhNode {
+            simd_aabb: SimdAabb::new_invalid(),
+            children: [0; 4], // Will be set after the recursive call
+            parent,
+            flags: QbvhNodeFlags::default(),
+        };
+
+        let id = self.nodes.len() as u32;
+        self.nodes.push(node);
+        id
+    }
+
+    /// Sets the children of the internal node `id`, given their indices and Aabbs.
+    fn set_children(&mut self, id: u32, children: [(u32, Aabb); 4], dilation: Real) -> (u32, Aabb) {
         // Now we know the indices of the child nodes.
         self.nodes[id as usize].children =
             [children[0].0, children[1].0, children[2].0, children[3].0];
@@ -445,3 +520,47 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
         (id, my_aabb)
     }
 }
+
+/// Computes the center of the parts with the given indices, and the two dimensions along which
+/// they are split.
+fn center_and_subdiv_dims(
+    indices: &[usize],
+    aabb: &impl Fn(usize) -> Aabb,
+) -> (Vector, [usize; 2]) {
+    // Compute the center and variance along each dimension.
+    // In 3D we compute the variance to not-subdivide the dimension with lowest variance.
+    // Therefore variance computation is not needed in 2D because we only have 2 dimension
+    // to split in the first place.
+    let mut center = Vector::ZERO;
+    #[cfg(feature = "dim3")]
+    let mut variance = Vector::ZERO;
+
+    let center_denom = 1.0 / (indices.len() as Real);
+
+    for i in indices {
+        let coords = aabb(*i).center();
+        center += coords * center_denom;
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        let variance_denom = 1.0 / ((indices.len() - 1) as Real);
+        for i in indices {
+            let dir_to_center = aabb(*i).center() - center;
+            variance += dir_to_center * dir_to_center * variance_denom;
+        }
+    }
+
+    // Find the axis with minimum variance. This is the axis along
+    // which we are **not** subdividing our set.
+    #[allow(unused_mut)] // Does not need to be mutable in 2D.
+    let mut subdiv_dims = [0, 1];
+    #[cfg(feature = "dim3")]
+    {
+        let min = variance.min_index();
+        subdiv_dims[0] = (min + 1) % 3;
+        subdiv_dims[1] = (min + 2) % 3;
+    }
+
+    (center, subdiv_dims)
+}
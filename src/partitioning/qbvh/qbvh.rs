@@ -4,6 +4,8 @@ use crate::partitioning::qbvh::storage::QbvhStorage;
 use crate::utils::DefaultStorage;
 use bitflags::bitflags;
 
+#[cfg(feature = "std")]
+use crate::math::{Real, SIMD_WIDTH};
 #[cfg(feature = "rkyv")]
 use rkyv::{bytecheck, CheckBytes};
 #[cfg(all(feature = "std", feature = "cuda"))]
@@ -219,7 +221,7 @@ impl<LeafData> QbvhProxy<LeafData> {
         }
     }
 
-    pub(super) fn is_detached(&self) -> bool {
+    pub(crate) fn is_detached(&self) -> bool {
         self.node.is_invalid()
     }
 }
@@ -394,6 +396,61 @@ impl<LeafData: IndexedData> Qbvh<LeafData> {
         }
         self
     }
+
+    /// Computes the surface-area-heuristic (SAH) cost of this tree.
+    ///
+    /// This is the sum of the surface areas (perimeters in 2D) of the Aabbs of all the nodes
+    /// reachable from the root, including the leaves, divided by the surface area of the root
+    /// Aabb. It estimates the number of Aabbs tested by a ray hitting the root Aabb: the lower,
+    /// the better. Moving leaves with [`Qbvh::refit`] degrades the tree since it doesn’t change
+    /// its topology, so comparing this cost with the one right after the last rebuild tells when
+    /// to rebuild it again.
+    ///
+    /// Returns `0.0` if this tree is empty, or if its root Aabb has no area.
+    pub fn quality(&self) -> Real {
+        let root_area = match self.nodes.first() {
+            Some(root) => surface_area(&root.simd_aabb.to_merged_aabb()),
+            None => return 0.0,
+        };
+
+        if root_area <= 0.0 {
+            return 0.0;
+        }
+
+        let mut cost = 0.0;
+        let mut stack = vec![0];
+
+        while let Some(id) = stack.pop() {
+            let node = &self.nodes[id as usize];
+
+            for lane in 0..SIMD_WIDTH {
+                let aabb = node.simd_aabb.extract(lane);
+
+                // Skip the empty lanes.
+                if aabb.mins.cmpgt(aabb.maxs).any() {
+                    continue;
+                }
+
+                cost += surface_area(&aabb);
+
+                if !node.is_leaf() {
+                    stack.push(node.children[lane]);
+                }
+            }
+        }
+
+        cost / root_area
+    }
+}
+
+/// The surface area of an Aabb in 3D, or its perimeter in 2D.
+#[cfg(feature = "std")]
+fn surface_area(aabb: &Aabb) -> Real {
+    let extents = aabb.extents();
+    #[cfg(feature = "dim2")]
+    return 2.0 * (extents.x + extents.y);
+    #[cfg(feature = "dim3")]
+    return 2.0 * (extents.x * extents.y + extents.y * extents.z + extents.z * extents.x);
 }
 
 impl<LeafData: IndexedData, Storage: QbvhStorage<LeafData>> GenericQbvh<LeafData, Storage> {
@@ -3,7 +3,7 @@ use crate::math::Vector;
 
 pub fn split_indices_wrt_dim<'a>(
     indices: &'a mut [usize],
-    aabbs: &[Aabb],
+    aabb: impl Fn(usize) -> Aabb,
     split_point: Vector,
     dim: usize,
     enable_fallback_split: bool,
@@ -16,7 +16,7 @@ pub fn split_indices_wrt_dim<'a>(
     // we will iterate exactly once per index.
     for _ in 0..indices.len() {
         let i = indices[icurr];
-        let center = aabbs[i].center();
+        let center = aabb(i).center();
 
         if center[dim] > split_point[dim] {
             ilast -= 1;
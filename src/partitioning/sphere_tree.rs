@@ -0,0 +1,287 @@
+use crate::bounding_volume::{BoundingSphere, BoundingVolume};
+use crate::math::{Real, Vector, DIM};
+use crate::query::{PointProjection, PointQuery, Ray, RayCast};
+use crate::shape::SimdCompositeShape;
+
+/// The content of a [`SphereTreeNode`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SphereTreeChildren {
+    /// A leaf containing the id of one part.
+    Leaf(u32),
+    /// An internal node containing the indices of its two children.
+    Internal(u32, u32),
+}
+
+/// A node of a [`SphereTree`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SphereTreeNode {
+    /// The bounding sphere enclosing all the parts below this node.
+    pub bounding_sphere: BoundingSphere,
+    /// The part id of this leaf, or the children of this internal node.
+    pub children: SphereTreeChildren,
+}
+
+/// A binary bounding-volume-hierarchy of bounding spheres.
+///
+/// Each leaf contains the id of one part together with its bounding sphere, and each internal
+/// node contains the merged bounding sphere of its two children. The tree stores no geometry:
+/// queries needing the exact shape of the parts take the composite shape the tree was built
+/// from, and operate in its local-space.
+#[derive(Clone, Debug, Default)]
+pub struct SphereTree {
+    nodes: Vec<SphereTreeNode>,
+}
+
+impl SphereTree {
+    /// Builds a sphere tree from a set of part ids and their bounding spheres.
+    ///
+    /// The parts are split recursively in two halves of equal size along the axis where the
+    /// centers of their bounding spheres are the most spread out.
+    pub fn new(parts: impl IntoIterator<Item = (u32, BoundingSphere)>) -> Self {
+        let mut parts: Vec<_> = parts.into_iter().collect();
+        let mut result = Self {
+            nodes: Vec::with_capacity(parts.len() * 2),
+        };
+
+        if !parts.is_empty() {
+            let _ = result.build_recursive(&mut parts);
+        }
+
+        result
+    }
+
+    /// Builds a sphere tree from the parts of a composite shape.
+    ///
+    /// The bounding sphere of each part is computed in the local-space of the composite shape.
+    pub fn from_composite_shape(shape: &dyn SimdCompositeShape) -> Self {
+        let qbvh = shape.qbvh();
+        let parts = qbvh
+            .iter_data()
+            .filter(|(node_id, _)| qbvh.node_aabb(*node_id).is_some())
+            .map(|(_, part_id)| {
+                let mut sphere = BoundingSphere::new(Vector::ZERO, 0.0);
+                shape.map_part_at(*part_id, &mut |part_pos, part| {
+                    sphere = match part_pos {
+                        Some(pos) => part.compute_bounding_sphere(pos),
+                        None => part.compute_local_bounding_sphere(),
+                    };
+                });
+                (*part_id, sphere)
+            });
+
+        Self::new(parts)
+    }
+
+    fn build_recursive(&mut self, parts: &mut [(u32, BoundingSphere)]) -> u32 {
+        let id = self.nodes.len() as u32;
+
+        if let [(part_id, sphere)] = parts {
+            self.nodes.push(SphereTreeNode {
+                bounding_sphere: *sphere,
+                children: SphereTreeChildren::Leaf(*part_id),
+            });
+            return id;
+        }
+
+        // Split along the axis where the centers are the most spread out.
+        let mut mins = Vector::splat(Real::MAX);
+        let mut maxs = Vector::splat(-Real::MAX);
+        for (_, sphere) in parts.iter() {
+            mins = mins.min(sphere.center);
+            maxs = maxs.max(sphere.center);
+        }
+
+        let extents = maxs - mins;
+        let axis = (0..DIM)
+            .max_by(|i, j| extents[*i].total_cmp(&extents[*j]))
+            .unwrap_or(0);
+        let mid = parts.len() / 2;
+        let _ =
+            parts.select_nth_unstable_by(mid, |a, b| a.1.center[axis].total_cmp(&b.1.center[axis]));
+
+        // Push a placeholder that is overwritten once the children are built.
+        self.nodes.push(SphereTreeNode {
+            bounding_sphere: parts[0].1,
+            children: SphereTreeChildren::Internal(u32::MAX, u32::MAX),
+        });
+
+        let (left_parts, right_parts) = parts.split_at_mut(mid);
+        let left = self.build_recursive(left_parts);
+        let right = self.build_recursive(right_parts);
+        let bounding_sphere = self.nodes[left as usize]
+            .bounding_sphere
+            .merged(&self.nodes[right as usize].bounding_sphere);
+
+        self.nodes[id as usize] = SphereTreeNode {
+            bounding_sphere,
+            children: SphereTreeChildren::Internal(left, right),
+        };
+        id
+    }
+
+    /// The nodes of this tree. The root, if any, is the first node.
+    pub fn nodes(&self) -> &[SphereTreeNode] {
+        &self.nodes
+    }
+
+    /// The bounding sphere enclosing all the parts of this tree, if it isn’t empty.
+    pub fn root_bounding_sphere(&self) -> Option<BoundingSphere> {
+        self.nodes.first().map(|node| node.bounding_sphere)
+    }
+
+    /// Collects into `out` the ids of all the parts with a bounding sphere intersecting `sphere`.
+    ///
+    /// The parts below a node with a bounding sphere contained in `sphere` are collected without
+    /// testing them individually.
+    pub fn query_sphere(&self, sphere: &BoundingSphere, out: &mut Vec<u32>) {
+        // The node indices, and whether they are known to be contained in `sphere`.
+        let mut stack = vec![];
+
+        if !self.nodes.is_empty() {
+            stack.push((0, false));
+        }
+
+        while let Some((id, mut contained)) = stack.pop() {
+            let node = &self.nodes[id as usize];
+
+            if !contained {
+                if !sphere.intersects(&node.bounding_sphere) {
+                    continue;
+                }
+
+                contained = sphere.contains(&node.bounding_sphere);
+            }
+
+            match node.children {
+                SphereTreeChildren::Leaf(part_id) => out.push(part_id),
+                SphereTreeChildren::Internal(left, right) => {
+                    stack.push((right, contained));
+                    stack.push((left, contained));
+                }
+            }
+        }
+    }
+
+    /// Casts a ray on the parts of `shape`, and returns the id of the first part hit together
+    /// with the time of impact.
+    ///
+    /// The ray is expressed in the local-space of `shape`, which must be the composite shape
+    /// this tree was built from.
+    pub fn cast_ray(
+        &self,
+        shape: &dyn SimdCompositeShape,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, Real)> {
+        let mut best = None;
+        let mut best_toi = max_toi;
+        let mut stack = vec![];
+
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+
+        while let Some(id) = stack.pop() {
+            let node = &self.nodes[id as usize];
+
+            // Cast against a solid sphere so rays starting inside of it aren’t discarded.
+            if node
+                .bounding_sphere
+                .cast_local_ray(ray, best_toi, true)
+                .is_none()
+            {
+                continue;
+            }
+
+            match node.children {
+                SphereTreeChildren::Leaf(part_id) => {
+                    shape.map_part_at(part_id, &mut |part_pos, part| {
+                        let toi = match part_pos {
+                            Some(pos) => part.cast_ray(pos, ray, best_toi, solid),
+                            None => part.cast_local_ray(ray, best_toi, solid),
+                        };
+
+                        if let Some(toi) = toi {
+                            if toi <= best_toi {
+                                best_toi = toi;
+                                best = Some((part_id, toi));
+                            }
+                        }
+                    });
+                }
+                SphereTreeChildren::Internal(left, right) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Projects a point on the parts of `shape`, and returns the id of the closest part
+    /// together with the projection.
+    ///
+    /// The point is expressed in the local-space of `shape`, which must be the composite shape
+    /// this tree was built from. Subtrees are skipped when their bounding sphere is farther
+    /// from the point than the best projection found so far.
+    pub fn closest_point(
+        &self,
+        shape: &dyn SimdCompositeShape,
+        point: Vector,
+        solid: bool,
+    ) -> Option<(u32, PointProjection)> {
+        let mut best = None;
+        let mut best_dist = Real::MAX;
+        let mut stack = vec![];
+
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+
+        // The smallest distance between the point and anything inside of the sphere.
+        let sphere_dist =
+            |sphere: &BoundingSphere| (point.distance(sphere.center) - sphere.radius).max(0.0);
+
+        while let Some(id) = stack.pop() {
+            let node = &self.nodes[id as usize];
+
+            if sphere_dist(&node.bounding_sphere) >= best_dist {
+                continue;
+            }
+
+            match node.children {
+                SphereTreeChildren::Leaf(part_id) => {
+                    shape.map_part_at(part_id, &mut |part_pos, part| {
+                        let proj = match part_pos {
+                            Some(pos) => part.project_point(pos, point, solid),
+                            None => part.project_local_point(point, solid),
+                        };
+                        let dist = point.distance(proj.point);
+
+                        if dist < best_dist {
+                            best_dist = dist;
+                            best = Some((part_id, proj));
+                        }
+                    });
+                }
+                SphereTreeChildren::Internal(left, right) => {
+                    // Visit the closest child first.
+                    let left_dist = sphere_dist(&self.nodes[left as usize].bounding_sphere);
+                    let right_dist = sphere_dist(&self.nodes[right as usize].bounding_sphere);
+
+                    if left_dist <= right_dist {
+                        stack.push(right);
+                        stack.push(left);
+                    } else {
+                        stack.push(left);
+                        stack.push(right);
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
@@ -1,5 +1,5 @@
 use crate::bounding_volume::Aabb;
-use crate::math::{Real, Vector, DIM};
+use crate::math::{Real, Vector, DEFAULT_EPSILON, DIM};
 use crate::query::Ray;
 use crate::shape::Segment;
 use num::{Bounded, Zero};
@@ -60,6 +60,24 @@ impl Aabb {
         self.clip_ray_parameters(ray)
             .map(|clip| Segment::new(ray.point_at(clip.0), ray.point_at(clip.1)))
     }
+
+    /// Computes the parameter where a ray crosses from this Aabb into the `other` Aabb.
+    ///
+    /// This is the parameter where the ray exits `self`, provided that the ray is inside of
+    /// `other` (or enters it) at that point and keeps going through `other` afterwards. This
+    /// handles both overlapping and adjacent Aabbs.
+    /// Returns `None` if the ray doesn't go from `self` to `other`.
+    #[inline]
+    pub fn ray_transition(&self, other: &Aabb, ray: &Ray) -> Option<Real> {
+        let (_, exit1) = self.clip_ray_parameters(ray)?;
+        let (enter2, exit2) = other.clip_ray_parameters(ray)?;
+
+        if enter2 <= exit1 + DEFAULT_EPSILON && exit2 > exit1 {
+            Some(exit1)
+        } else {
+            None
+        }
+    }
 }
 
 /// Computes the segment given by the intersection of a line and an Aabb.
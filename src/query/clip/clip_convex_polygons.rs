@@ -0,0 +1,44 @@
+use crate::math::{self, Isometry, Real, Vector};
+use crate::query::details::clip_halfspace_polygon;
+use crate::shape::ConvexPolygon;
+
+/// Computes the convex polygon at the intersection of two convex polygons.
+///
+/// The vertices of `poly2`, transformed by `pos12`, are clipped by the half-plane behind each
+/// edge of `poly1` with the Sutherland–Hodgman algorithm. The vertices of the result are
+/// expressed in the local-space of `poly1` and in counterclockwise order.
+///
+/// Returns an empty vector if the polygons are disjoint, or if they are only touching so their
+/// intersection has no area.
+pub fn intersection_polygon_2d(
+    pos12: Isometry,
+    poly1: &ConvexPolygon,
+    poly2: &ConvexPolygon,
+) -> Vec<Vector> {
+    let mut result: Vec<_> = poly2
+        .points()
+        .iter()
+        .map(|pt| pos12.transform_point(*pt))
+        .collect();
+    let mut clipped = Vec::with_capacity(result.len() + poly1.points().len());
+
+    for (pt1, normal1) in poly1.points().iter().zip(poly1.normals()) {
+        clip_halfspace_polygon(*pt1, **normal1, &result, &mut clipped);
+        std::mem::swap(&mut result, &mut clipped);
+
+        if result.is_empty() {
+            break;
+        }
+    }
+
+    // Touching polygons leave their shared edge or vertex, which has no area.
+    let twice_area: Real = (0..result.len())
+        .map(|i| math::perp_dot(result[i], result[(i + 1) % result.len()]))
+        .sum();
+
+    if result.len() < 3 || twice_area <= math::DEFAULT_EPSILON {
+        result.clear();
+    }
+
+    result
+}
@@ -1,4 +1,6 @@
 pub use self::clip_aabb_line::clip_aabb_line;
+#[cfg(all(feature = "dim2", feature = "std"))]
+pub use self::clip_convex_polygons::intersection_polygon_2d;
 #[cfg(feature = "std")]
 pub use self::clip_halfspace_polygon::clip_halfspace_polygon;
 pub use self::clip_segment_segment::clip_segment_segment;
@@ -8,6 +10,8 @@ pub use self::clip_segment_segment::clip_segment_segment_with_normal;
 mod clip_aabb_line;
 #[cfg(feature = "std")]
 mod clip_aabb_polygon;
+#[cfg(all(feature = "dim2", feature = "std"))]
+mod clip_convex_polygons;
 #[cfg(feature = "std")]
 mod clip_halfspace_polygon;
 mod clip_segment_segment;
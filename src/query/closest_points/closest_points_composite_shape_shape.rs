@@ -124,7 +124,7 @@ where
                         match pts {
                             Ok(ClosestPoints::WithinMargin(p1, p2)) => {
                                 let p1 = part_pos1.transform_point(p1);
-                                let p2_1 = self.pos12 * p2;
+                                let p2_1 = self.pos12.transform_point(p2);
                                 weights[ii] = p1.distance(p2_1);
                                 results[ii] = Some((part_id, ClosestPoints::WithinMargin(p1, p2)));
                                 mask[ii] = true;
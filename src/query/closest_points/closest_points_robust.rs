@@ -0,0 +1,69 @@
+use crate::math::{Isometry, Real};
+use crate::query::{
+    ClosestPoints, Contact, DefaultQueryDispatcher, QueryDispatcher, TouchStatus, Unsupported,
+};
+use crate::shape::Shape;
+
+/// Computes the closest points between two shapes, with a well-defined witness point when
+/// they barely touch.
+///
+/// At the exact touching configuration, rounding errors can make
+/// [`closest_points()`](crate::query::closest_points) report either an intersection or a tiny
+/// distance, and the witness points are ill-defined. Here, if the signed distance between the
+/// shapes lies within `[-tolerance, tolerance]`, the status is `TouchStatus::Touching` and both
+/// points of the result are the midpoint of the two nearly coincident contact points, so the
+/// same single witness is reported on either side of the touching boundary.
+///
+/// Otherwise, penetrating shapes yield `ClosestPoints::Intersecting` together with their
+/// penetration depth, and separated shapes yield their closest points together with their
+/// distance. The points are expressed in world-space.
+pub fn closest_points_robust(
+    pos1: Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    tolerance: Real,
+) -> Result<(ClosestPoints, TouchStatus), Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+
+    match DefaultQueryDispatcher.contact(pos12, g1, g2, tolerance)? {
+        Some(contact) if contact.dist < -tolerance => Ok((
+            ClosestPoints::Intersecting,
+            TouchStatus::Penetrating(-contact.dist),
+        )),
+        Some(contact) if contact.dist <= tolerance => Ok(touching(pos1, pos2, &contact)),
+        _ => match DefaultQueryDispatcher.closest_points(pos12, g1, g2, Real::MAX)? {
+            ClosestPoints::WithinMargin(p1, p2) => {
+                let p1 = pos1.transform_point(p1);
+                let p2 = pos2.transform_point(p2);
+                Ok((
+                    ClosestPoints::WithinMargin(p1, p2),
+                    TouchStatus::Separated(p1.distance(p2)),
+                ))
+            }
+            // The contact query found the shapes farther apart than `tolerance`, so an
+            // intersection reported here is due to rounding errors at the touching boundary.
+            _ => match DefaultQueryDispatcher.contact(pos12, g1, g2, Real::MAX)? {
+                Some(contact) if contact.dist <= 0.0 => Ok(touching(pos1, pos2, &contact)),
+                Some(contact) => Ok((
+                    ClosestPoints::WithinMargin(
+                        pos1.transform_point(contact.point1),
+                        pos2.transform_point(contact.point2),
+                    ),
+                    TouchStatus::Separated(contact.dist),
+                )),
+                None => Ok((ClosestPoints::Disjoint, TouchStatus::Separated(Real::MAX))),
+            },
+        },
+    }
+}
+
+/// The result for touching shapes, with the midpoint of the contact points as single witness.
+fn touching(pos1: Isometry, pos2: Isometry, contact: &Contact) -> (ClosestPoints, TouchStatus) {
+    let witness =
+        (pos1.transform_point(contact.point1) + pos2.transform_point(contact.point2)) * 0.5;
+    (
+        ClosestPoints::WithinMargin(witness, witness),
+        TouchStatus::Touching,
+    )
+}
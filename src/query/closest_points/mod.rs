@@ -21,6 +21,7 @@ pub use self::closest_points_line_line::{
     closest_points_line_line, closest_points_line_line_parameters,
     closest_points_line_line_parameters_eps,
 };
+pub use self::closest_points_robust::closest_points_robust;
 pub use self::closest_points_segment_segment::{
     closest_points_segment_segment, closest_points_segment_segment_with_locations,
     closest_points_segment_segment_with_locations_nD,
@@ -38,6 +39,7 @@ mod closest_points_cuboid_cuboid;
 mod closest_points_cuboid_triangle;
 mod closest_points_halfspace_support_map;
 mod closest_points_line_line;
+mod closest_points_robust;
 mod closest_points_segment_segment;
 mod closest_points_shape_shape;
 mod closest_points_support_map_support_map;
@@ -1,4 +1,5 @@
-use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::math::{AngVector, Isometry, Real, UnitVector, Vector, DEFAULT_EPSILON, DIM};
+use crate::utils::WBasis;
 use std::mem;
 
 #[cfg(feature = "rkyv")]
@@ -83,4 +84,90 @@ impl Contact {
         self.point1 = pos * self.point1;
         self.normal1 = pos * self.normal1;
     }
+
+    /// Computes the velocity of the second body relative to the first one at the contact point.
+    ///
+    /// The contact points are assumed to be the lever arms of the contact, i.e., to be given
+    /// relative to the center of rotation of their respective bodies, in the same frame as the
+    /// velocities. The result is positive along `normal1` if the bodies are moving apart.
+    #[inline]
+    pub fn relative_velocity(
+        &self,
+        linvel1: Vector,
+        angvel1: AngVector,
+        linvel2: Vector,
+        angvel2: AngVector,
+    ) -> Vector {
+        #[cfg(feature = "dim2")]
+        let (vel1, vel2) = (
+            linvel1 + crate::math::perp(self.point1) * angvel1,
+            linvel2 + crate::math::perp(self.point2) * angvel2,
+        );
+        #[cfg(feature = "dim3")]
+        let (vel1, vel2) = (
+            linvel1 + angvel1.cross(self.point1),
+            linvel2 + angvel2.cross(self.point2),
+        );
+
+        vel2 - vel1
+    }
+
+    /// Computes unit tangents orthogonal to `normal1`, the first one being aligned with the
+    /// tangential part of the relative velocity `rel_vel`.
+    ///
+    /// This gives stable friction directions, e.g., for anisotropic friction or conveyor belts.
+    /// If `rel_vel` is (almost) parallel to the normal, the first tangent is an arbitrary
+    /// direction orthogonal to the normal instead. In 3D, the second tangent is
+    /// `normal1.cross(tangent1)`, so the tangents and the normal form a right-handed basis.
+    #[inline]
+    pub fn tangent_frame_along(&self, rel_vel: Vector) -> [UnitVector; DIM - 1] {
+        let normal = *self.normal1;
+        let tangent_vel = rel_vel - normal * rel_vel.dot(normal);
+
+        let min_length_squared = DEFAULT_EPSILON * rel_vel.length_squared();
+        let tangent1 = if tangent_vel.length_squared() > min_length_squared {
+            UnitVector::new_unchecked(tangent_vel.normalize())
+        } else {
+            UnitVector::new_unchecked(normal.orthonormal_basis()[0])
+        };
+
+        #[cfg(feature = "dim2")]
+        {
+            [tangent1]
+        }
+        #[cfg(feature = "dim3")]
+        {
+            [tangent1, UnitVector::new_unchecked(normal.cross(*tangent1))]
+        }
+    }
+
+    /// Expresses this contact, given in the local-space of the second shape, in the
+    /// local-space of the first shape.
+    ///
+    /// Both points and both normals are transformed by `pos12`, the position of the second
+    /// shape relative to the first one. This is the inverse of [`Self::to_frame2`].
+    #[inline]
+    pub fn to_frame1(&self, pos12: Isometry) -> Self {
+        self.transformed(pos12)
+    }
+
+    /// Expresses this contact, given in the local-space of the first shape, in the
+    /// local-space of the second shape.
+    ///
+    /// Both points and both normals are transformed by the inverse of `pos12`, the position
+    /// of the second shape relative to the first one. This is the inverse of [`Self::to_frame1`].
+    #[inline]
+    pub fn to_frame2(&self, pos12: Isometry) -> Self {
+        self.transformed(pos12.inverse())
+    }
+
+    fn transformed(&self, pos: Isometry) -> Self {
+        Contact {
+            point1: pos.transform_point(self.point1),
+            point2: pos.transform_point(self.point2),
+            normal1: pos * self.normal1,
+            normal2: pos * self.normal2,
+            dist: self.dist,
+        }
+    }
 }
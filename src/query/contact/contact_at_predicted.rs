@@ -0,0 +1,55 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::{Contact, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// Computes one pair of contact points between two shapes at the poses they reach after
+/// translating with constant velocities during `dt`.
+///
+/// The contact is computed at the predicted poses, but its points and normals are given in
+/// world-space at the current poses `pos1` and `pos2`, i.e., attached to the shapes as they
+/// are now. Its `dist` is the predicted separation distance. This is the input expected by
+/// speculative-contact solvers which generate contacts before integrating the positions.
+///
+/// Returns `None` if the objects at their predicted poses are separated by a distance greater
+/// than `prediction`.
+pub fn contact_at_predicted(
+    pos1: Isometry,
+    vel1: Vector,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    vel2: Vector,
+    g2: &dyn Shape,
+    dt: Real,
+    prediction: Real,
+) -> Result<Option<Contact>, Unsupported> {
+    let predicted1 = Isometry {
+        translation: pos1.translation + vel1 * dt,
+        rotation: pos1.rotation,
+    };
+    let predicted2 = Isometry {
+        translation: pos2.translation + vel2 * dt,
+        rotation: pos2.rotation,
+    };
+    let pos12 = predicted1.inv_mul(predicted2);
+
+    let finite_inputs = pos12.is_finite() && !prediction.is_nan();
+    debug_assert!(
+        finite_inputs,
+        "contact_at_predicted: non-finite shape positions."
+    );
+    if !finite_inputs {
+        return Ok(None);
+    }
+
+    let result = DefaultQueryDispatcher.contact(pos12, g1, g2, prediction)?;
+
+    Ok(result.map(|contact| {
+        Contact::new(
+            pos1.transform_point(contact.point1),
+            pos2.transform_point(contact.point2),
+            pos1.rotation * contact.normal1,
+            pos2.rotation * contact.normal2,
+            contact.dist,
+        )
+    }))
+}
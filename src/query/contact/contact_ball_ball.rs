@@ -14,7 +14,7 @@ pub fn contact_ball_ball(
     let r1 = b1.radius;
     let r2 = b2.radius;
     let center2_1 = pos12.translation;
-    let distance_squared = center2_1.length();
+    let distance_squared = center2_1.length_squared();
     let sum_radius = r1 + r2;
     let sum_radius_with_error = sum_radius + prediction;
 
@@ -0,0 +1,58 @@
+use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::query::{Contact, PointQuery};
+use crate::shape::{Ball, Capsule};
+
+/// Contact between a ball and a capsule.
+///
+/// The ball center is projected on the segment of the capsule, and the normal goes from that
+/// projection toward the ball center. If the ball center lies on the segment, the normal is an
+/// arbitrary direction orthogonal to the segment.
+pub fn contact_ball_capsule(
+    pos12: Isometry,
+    ball: &Ball,
+    capsule: &Capsule,
+    prediction: Real,
+) -> Option<Contact> {
+    let center1 = pos12.inverse_transform_point(Vector::ZERO);
+    let proj = capsule.segment.project_local_point(center1, true).point;
+    let (normal2, center_dist) = match UnitVector::new_and_length(center1 - proj) {
+        Ok(normal_and_dist) => normal_and_dist,
+        Err(_) => (segment_orthogonal_dir(capsule), 0.0),
+    };
+    let dist = center_dist - ball.radius - capsule.radius;
+
+    if dist > prediction {
+        return None;
+    }
+
+    let normal1 = -(pos12.rotation * normal2);
+    let point1 = *normal1 * ball.radius;
+    let point2 = proj + *normal2 * capsule.radius;
+
+    Some(Contact::new(point1, point2, normal1, normal2, dist))
+}
+
+/// Contact between a capsule and a ball.
+///
+/// See [`contact_ball_capsule`] for details.
+pub fn contact_capsule_ball(
+    pos12: Isometry,
+    capsule: &Capsule,
+    ball: &Ball,
+    prediction: Real,
+) -> Option<Contact> {
+    contact_ball_capsule(pos12.inverse(), ball, capsule, prediction).map(Contact::flipped)
+}
+
+/// An arbitrary direction orthogonal to the segment of the capsule.
+fn segment_orthogonal_dir(capsule: &Capsule) -> UnitVector {
+    let dir = capsule.segment.scaled_direction();
+
+    #[cfg(feature = "dim2")]
+    let orth = crate::math::perp(dir).try_normalize();
+    #[cfg(feature = "dim3")]
+    let orth = dir.try_normalize().map(|dir| dir.any_orthonormal_vector());
+
+    // A zero-length segment is a ball, so any direction works.
+    orth.map(UnitVector::new_unchecked).unwrap_or(UnitVector::X)
+}
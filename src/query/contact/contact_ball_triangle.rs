@@ -0,0 +1,164 @@
+use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::query::{Contact, PointQueryWithLocation};
+use crate::shape::{Ball, Triangle, TrianglePointLocation};
+
+#[cfg(feature = "dim3")]
+use crate::shape::FeatureId;
+
+/// The smallest cosine of the angle between the direction from a triangle to a ball center and
+/// the face normal for the contact normal to be snapped to the face normal.
+#[cfg(feature = "dim3")]
+const FACE_NORMAL_MIN_COS: Real = 0.9999;
+
+/// Contact between a ball and a triangle.
+///
+/// See [`contact_triangle_ball`] for details about the contact normal.
+#[inline]
+pub fn contact_ball_triangle(
+    pos12: Isometry,
+    ball1: &Ball,
+    triangle2: &Triangle,
+    prediction: Real,
+) -> Option<Contact> {
+    contact_triangle_ball(pos12.inverse(), triangle2, ball1, prediction).map(|c| c.flipped())
+}
+
+/// Contact between a triangle and a ball.
+///
+/// The contact point on the triangle is the projection of the center of the ball, taking the
+/// Voronoi regions of the face, edges, and vertices of the triangle into account. In 3D, the
+/// contact normal is the face normal if the center is over the face, and the direction from
+/// the projection to the center if it is closest to an edge or a vertex. That direction is
+/// still snapped to the face normal if they only differ by rounding errors, e.g., if the ball
+/// is right above an edge, so coplanar triangles sharing that edge yield the same normal.
+#[inline]
+pub fn contact_triangle_ball(
+    pos12: Isometry,
+    triangle1: &Triangle,
+    ball2: &Ball,
+    prediction: Real,
+) -> Option<Contact> {
+    let (point1, _, dpt, normal1) = project_ball_center(pos12, triangle1);
+    make_contact(pos12, point1, dpt, normal1, ball2, prediction)
+}
+
+/// Contact between a ball and a triangle, ignoring the internal edges shared with the given
+/// adjacent triangles.
+///
+/// See [`contact_triangle_ball_with_adjacent_normals`] for details.
+#[cfg(feature = "dim3")]
+#[inline]
+pub fn contact_ball_triangle_with_adjacent_normals(
+    pos12: Isometry,
+    ball1: &Ball,
+    triangle2: &Triangle,
+    adjacent_normals2: &[Option<UnitVector>; 3],
+    prediction: Real,
+) -> Option<Contact> {
+    contact_triangle_ball_with_adjacent_normals(
+        pos12.inverse(),
+        triangle2,
+        adjacent_normals2,
+        ball1,
+        prediction,
+    )
+    .map(|c| c.flipped())
+}
+
+/// Contact between a triangle and a ball, ignoring the internal edges shared with the given
+/// adjacent triangles.
+///
+/// The `i`-th element of `adjacent_normals1` is the normal of the triangle sharing the `i`-th
+/// edge (AB, BC, then CA) of `triangle1`, if any. The contact normal is computed as by
+/// [`contact_triangle_ball`], then corrected with [`Triangle::correct_internal_edge_normal`] so
+/// a ball rolling across the edge between two coplanar triangles isn’t pushed back by that
+/// edge. The contact distance is measured along the corrected normal.
+#[cfg(feature = "dim3")]
+#[inline]
+pub fn contact_triangle_ball_with_adjacent_normals(
+    pos12: Isometry,
+    triangle1: &Triangle,
+    adjacent_normals1: &[Option<UnitVector>; 3],
+    ball2: &Ball,
+    prediction: Real,
+) -> Option<Contact> {
+    let (point1, location, dpt, normal1) = project_ball_center(pos12, triangle1);
+    let feature1 = match location {
+        TrianglePointLocation::OnVertex(i) => FeatureId::Vertex(i),
+        TrianglePointLocation::OnEdge(i, _) => FeatureId::Edge(i),
+        TrianglePointLocation::OnFace(i, _) => FeatureId::Face(i),
+        TrianglePointLocation::OnSolid => FeatureId::Unknown,
+    };
+    let normal1 = triangle1.correct_internal_edge_normal(feature1, normal1, adjacent_normals1);
+    make_contact(pos12, point1, dpt, normal1, ball2, prediction)
+}
+
+/// Projects the center of the ball on `triangle1`.
+///
+/// Returns the projected point, its location, the vector from the projected point to the
+/// center, and the contact normal.
+fn project_ball_center(
+    pos12: Isometry,
+    triangle1: &Triangle,
+) -> (Vector, TrianglePointLocation, Vector, UnitVector) {
+    let center2_1 = pos12.translation;
+    let (proj, location) = triangle1.project_local_point_and_get_location(center2_1, false);
+    let dpt = center2_1 - proj.point;
+
+    let normal1 = match UnitVector::new(dpt) {
+        // In 2D, the center is inside of the triangle.
+        Ok(dir1) if proj.is_inside => -dir1,
+        Ok(dir1) => dir1,
+        Err(_) => triangle1.normal().unwrap_or(UnitVector::Y),
+    };
+
+    #[cfg(feature = "dim3")]
+    let normal1 = snap_to_face_normal(triangle1, location, dpt, normal1);
+
+    (proj.point, location, dpt, normal1)
+}
+
+/// Builds the contact with the ball, if its distance along `normal1` is smaller than
+/// `prediction`.
+fn make_contact(
+    pos12: Isometry,
+    point1: Vector,
+    dpt: Vector,
+    normal1: UnitVector,
+    ball2: &Ball,
+    prediction: Real,
+) -> Option<Contact> {
+    let dist = dpt.dot(*normal1) - ball2.radius;
+
+    if dist <= prediction {
+        let normal2 = pos12.rotation.inverse() * -normal1;
+        let point2 = *normal2 * ball2.radius;
+        return Some(Contact::new(point1, point2, normal1, normal2, dist));
+    }
+
+    None
+}
+
+/// Replaces `normal1` by the normal of the face of `triangle1` on the side of `dpt`, if the
+/// projection is on the face or if both normals are almost equal.
+#[cfg(feature = "dim3")]
+fn snap_to_face_normal(
+    triangle1: &Triangle,
+    location: TrianglePointLocation,
+    dpt: Vector,
+    normal1: UnitVector,
+) -> UnitVector {
+    let face_normal = match triangle1.normal() {
+        Ok(face_normal) if dpt.dot(*face_normal) < 0.0 => -face_normal,
+        Ok(face_normal) => face_normal,
+        Err(_) => return normal1,
+    };
+
+    if matches!(location, TrianglePointLocation::OnFace(..))
+        || normal1.dot(*face_normal) >= FACE_NORMAL_MIN_COS
+    {
+        face_normal
+    } else {
+        normal1
+    }
+}
@@ -0,0 +1,43 @@
+use crate::math::{Isometry, Real, UnitVector, DEFAULT_EPSILON};
+use crate::query::details::{
+    closest_points_segment_segment_with_locations, contact_support_map_support_map,
+};
+use crate::query::Contact;
+use crate::shape::Capsule;
+
+/// Contact between two capsules.
+///
+/// The contact is computed from the closest points between the capsules’ segments, shifted
+/// by their radii. If the segments intersect, no normal can be deduced from these closest
+/// points so the EPA algorithm is used instead.
+pub fn contact_capsule_capsule(
+    pos12: Isometry,
+    capsule1: &Capsule,
+    capsule2: &Capsule,
+    prediction: Real,
+) -> Option<Contact> {
+    let (loc1, loc2) =
+        closest_points_segment_segment_with_locations(pos12, &capsule1.segment, &capsule2.segment);
+    let seg2_1 = capsule2.segment.transformed(pos12);
+    let p1 = capsule1.segment.point_at(&loc1);
+    let p2_1 = seg2_1.point_at(&loc2);
+
+    if let Ok((normal1, center_dist)) = UnitVector::new_and_length(p2_1 - p1) {
+        if center_dist > DEFAULT_EPSILON {
+            let dist = center_dist - capsule1.radius - capsule2.radius;
+
+            if dist > prediction {
+                return None;
+            }
+
+            let normal2 = pos12.rotation.inverse() * -normal1;
+            let point1 = p1 + *normal1 * capsule1.radius;
+            let point2 = capsule2.segment.point_at(&loc2) + *normal2 * capsule2.radius;
+
+            return Some(Contact::new(point1, point2, normal1, normal2, dist));
+        }
+    }
+
+    // The segments intersect.
+    contact_support_map_support_map(pos12, capsule1, capsule2, prediction)
+}
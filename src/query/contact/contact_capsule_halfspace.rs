@@ -0,0 +1,51 @@
+use crate::math::{Isometry, Real};
+use crate::query::Contact;
+use crate::shape::{Capsule, HalfSpace};
+use arrayvec::ArrayVec;
+
+/// Contacts between a capsule and a halfspace.
+///
+/// The deepest point of the capsule is always on one of the spheres centered at the
+/// endpoints of its segment. One contact is generated for each endpoint closer than
+/// `prediction` to the halfspace, so a capsule lying on the halfspace has two contacts. The
+/// contacts are sorted by increasing distance.
+pub fn contact_capsule_halfspace(
+    pos12: Isometry,
+    capsule: &Capsule,
+    halfspace: &HalfSpace,
+    prediction: Real,
+) -> ArrayVec<Contact, 2> {
+    let normal1 = pos12 * -halfspace.normal;
+    let normal2 = halfspace.normal;
+    let mut contacts = ArrayVec::new();
+
+    for pt in [capsule.segment.a, capsule.segment.b] {
+        let dist = (pt - pos12.translation).dot(-*normal1) - capsule.radius;
+
+        if dist <= prediction {
+            let point1 = pt + *normal1 * capsule.radius;
+            let point2 = pos12.inverse_transform_point(point1 + *normal1 * dist);
+            contacts.push(Contact::new(point1, point2, normal1, normal2, dist));
+        }
+    }
+
+    if contacts.len() == 2 && contacts[1].dist < contacts[0].dist {
+        contacts.swap(0, 1);
+    }
+
+    contacts
+}
+
+/// Contacts between a halfspace and a capsule.
+///
+/// See [`contact_capsule_halfspace`] for details.
+pub fn contact_halfspace_capsule(
+    pos12: Isometry,
+    halfspace: &HalfSpace,
+    capsule: &Capsule,
+    prediction: Real,
+) -> ArrayVec<Contact, 2> {
+    let mut contacts = contact_capsule_halfspace(pos12.inverse(), capsule, halfspace, prediction);
+    contacts.iter_mut().for_each(|c| c.flip());
+    contacts
+}
@@ -0,0 +1,69 @@
+use crate::math::{real_consts, Isometry, Real, Vector};
+use crate::query::Contact;
+use crate::shape::{Cone, HalfSpace};
+use arrayvec::ArrayVec;
+
+/// The number of points sampled on the base circle of a cone resting on a halfspace.
+const NUM_BASE_POINTS: usize = 8;
+
+/// Contacts between a cone and a halfspace.
+///
+/// The base circle of the cone is sampled with points evenly distributed around its rim,
+/// starting with the rim point deepest inside of the halfspace. One contact is generated for
+/// each of these points, and for the apex, closer than `prediction` to the halfspace. So a cone
+/// standing on its apex has one contact, a cone standing on its base has one contact for each
+/// base point, and a tilted cone has a single rim point or an arc of the rim (plus the apex if
+/// it lies on its side). The contacts are sorted by increasing distance.
+pub fn contact_cone_halfspace(
+    pos12: Isometry,
+    cone: &Cone,
+    halfspace: &HalfSpace,
+    prediction: Real,
+) -> ArrayVec<Contact, { NUM_BASE_POINTS + 1 }> {
+    let normal1 = pos12 * -halfspace.normal;
+    let normal2 = halfspace.normal;
+    let axis = cone.apex_direction();
+    let mut contacts = ArrayVec::new();
+
+    let mut push_contact = |pt: Vector| {
+        let dist = (pt - pos12.translation).dot(-*normal1);
+
+        if dist <= prediction {
+            let point2 = pos12.inverse_transform_point(pt + *normal1 * dist);
+            contacts.push(Contact::new(pt, point2, normal1, normal2, dist));
+        }
+    };
+
+    push_contact(axis * cone.half_height);
+
+    // The rim direction pointing the deepest into the halfspace. If the base is parallel to
+    // the halfspace boundary, all the rim points are equally deep and any direction works.
+    let u = (*normal1 - axis * normal1.dot(axis))
+        .try_normalize()
+        .unwrap_or_else(|| axis.any_orthonormal_vector());
+    let v = axis.cross(u);
+    let base_center = -axis * cone.half_height;
+
+    for k in 0..NUM_BASE_POINTS {
+        let angle = k as Real * real_consts::TAU / NUM_BASE_POINTS as Real;
+        let (sin, cos) = angle.sin_cos();
+        push_contact(base_center + (u * cos + v * sin) * cone.radius);
+    }
+
+    contacts.sort_unstable_by(|a, b| a.dist.total_cmp(&b.dist));
+    contacts
+}
+
+/// Contacts between a halfspace and a cone.
+///
+/// See [`contact_cone_halfspace`] for details.
+pub fn contact_halfspace_cone(
+    pos12: Isometry,
+    halfspace: &HalfSpace,
+    cone: &Cone,
+    prediction: Real,
+) -> ArrayVec<Contact, { NUM_BASE_POINTS + 1 }> {
+    let mut contacts = contact_cone_halfspace(pos12.inverse(), cone, halfspace, prediction);
+    contacts.iter_mut().for_each(|c| c.flip());
+    contacts
+}
@@ -6,6 +6,9 @@ use crate::shape::Shape;
 ///
 /// Returns `None` if the objects are separated by a distance greater than `prediction`.
 /// The result is given in world-space.
+///
+/// The positions must be finite and `prediction` must be non-negative and finite. This is
+/// checked with a debug assertion, and `None` is returned in release mode if they aren't.
 pub fn contact(
     pos1: Isometry,
     g1: &dyn Shape,
@@ -14,6 +17,16 @@ pub fn contact(
     prediction: Real,
 ) -> Result<Option<Contact>, Unsupported> {
     let pos12 = pos1.inv_mul(pos2);
+
+    let valid_inputs = pos12.is_finite() && prediction >= 0.0 && prediction.is_finite();
+    debug_assert!(
+        valid_inputs,
+        "contact: the shape positions must be finite, and the prediction non-negative and finite."
+    );
+    if !valid_inputs {
+        return Ok(None);
+    }
+
     let mut result = DefaultQueryDispatcher.contact(pos12, g1, g2, prediction);
 
     if let Ok(Some(contact)) = &mut result {
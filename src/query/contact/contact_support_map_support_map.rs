@@ -1,10 +1,14 @@
 use crate::math::{Isometry, Real, UnitVector};
 use crate::query::epa::EPA;
 use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
-use crate::query::Contact;
+use crate::query::{Contact, QueryWorkspace};
 use crate::shape::SupportMap;
 
 /// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// If the shapes are penetrating, the contact normal and penetration depth are computed with
+/// the EPA algorithm, and the returned contact has a negative `dist`. If EPA fails, which may
+/// happen with degenerate configurations, `None` is returned.
 pub fn contact_support_map_support_map<G1: ?Sized, G2: ?Sized>(
     pos12: Isometry,
     g1: &G1,
@@ -43,6 +47,57 @@ pub fn contact_support_map_support_map_with_params<G1: ?Sized, G2: ?Sized>(
     simplex: &mut VoronoiSimplex,
     init_dir: Option<UnitVector>,
 ) -> GJKResult
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    contact_with_buffers(
+        pos12,
+        g1,
+        g2,
+        prediction,
+        simplex,
+        &mut EPA::new(),
+        init_dir,
+    )
+}
+
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// Same as [`contact_support_map_support_map_with_params`], except that the buffers of
+/// `workspace` are reused, so the EPA buffers are only allocated once across calls.
+pub fn contact_support_map_support_map_with_workspace<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+    prediction: Real,
+    workspace: &mut QueryWorkspace,
+    init_dir: Option<UnitVector>,
+) -> GJKResult
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    contact_with_buffers(
+        pos12,
+        g1,
+        g2,
+        prediction,
+        &mut workspace.simplex,
+        &mut workspace.epa,
+        init_dir,
+    )
+}
+
+fn contact_with_buffers<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+    prediction: Real,
+    simplex: &mut VoronoiSimplex,
+    epa: &mut EPA,
+    init_dir: Option<UnitVector>,
+) -> GJKResult
 where
     G1: SupportMap,
     G2: SupportMap,
@@ -66,11 +121,11 @@ where
     }
 
     // The point is inside of the CSO: use the fallback algorithm
-    let mut epa = EPA::new();
     if let Some((p1, p2, n)) = epa.closest_points(pos12, g1, g2, simplex) {
         return GJKResult::ClosestPoints(p1, p2, n);
     }
 
-    // Everything failed
+    // EPA failed (this may happen with degenerate configurations). Report no contact rather
+    // than a made-up penetration.
     GJKResult::NoIntersection(UnitVector::X)
 }
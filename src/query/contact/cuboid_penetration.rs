@@ -0,0 +1,59 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Real, Vector};
+use crate::query::sat;
+use crate::shape::Cuboid;
+
+/// Computes the penetration of an oriented cuboid into an axis-aligned box, e.g., a voxel.
+///
+/// Returns the unit direction along which the cuboid has to be moved to stop penetrating
+/// `voxel_aabb`, and the penetration depth along that direction. Returns `None` if the
+/// cuboid and the box are not penetrating.
+///
+/// This relies on the separating axis theorem, which is exact for pairs of boxes, and is
+/// much cheaper than running EPA through [`contact`](crate::query::contact).
+pub fn cuboid_penetration(
+    pos: Isometry,
+    cuboid: &Cuboid,
+    voxel_aabb: &Aabb,
+) -> Option<(Vector, Real)> {
+    let voxel = Cuboid::new(voxel_aabb.half_extents());
+    let voxel_pos = Isometry::from_translation(voxel_aabb.center());
+    let pos12 = voxel_pos.inv_mul(pos);
+    let pos21 = pos12.inverse();
+
+    // Separating axes from the voxel's faces. These are expressed in world-space since the
+    // voxel isn't rotated, and point from the voxel toward the cuboid.
+    let (mut best_sep, mut best_dir) =
+        sat::cuboid_cuboid_find_local_separating_normal_oneway(&voxel, cuboid, pos12);
+    if best_sep >= 0.0 {
+        return None;
+    }
+
+    // Separating axes from the cuboid's faces, pointing from the cuboid toward the voxel.
+    let (sep2, dir2) =
+        sat::cuboid_cuboid_find_local_separating_normal_oneway(cuboid, &voxel, pos21);
+    if sep2 >= 0.0 {
+        return None;
+    }
+
+    if sep2 > best_sep {
+        best_sep = sep2;
+        best_dir = pos.rotation * -dir2;
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        let (sep3, dir3) =
+            sat::cuboid_cuboid_find_local_separating_edge_twoway(&voxel, cuboid, pos12);
+        if sep3 >= 0.0 {
+            return None;
+        }
+
+        if sep3 > best_sep {
+            best_sep = sep3;
+            best_dir = dir3;
+        }
+    }
+
+    Some((best_dir, -best_sep))
+}
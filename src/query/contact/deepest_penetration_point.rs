@@ -0,0 +1,29 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// Computes the point of `g1` that penetrates the deepest into `g2`.
+///
+/// Returns the point, expressed in the local-space of `g1`, together with the penetration depth,
+/// or `None` if the shapes are not penetrating. The point is the one of `g1` farthest along the
+/// contact normal `normal1`, as given by [`contact`](crate::query::contact). If `g1` has a
+/// support map, that point is recomputed as its support point so it lies exactly on a vertex
+/// when the deepest feature is a single vertex, e.g., the corner of a box stabbing into another
+/// shape.
+pub fn deepest_penetration_point(
+    pos12: Isometry,
+    g1: &dyn Shape,
+    g2: &dyn Shape,
+) -> Result<Option<(Vector, Real)>, Unsupported> {
+    let contact = match DefaultQueryDispatcher.contact(pos12, g1, g2, 0.0)? {
+        Some(contact) if contact.dist < 0.0 => contact,
+        _ => return Ok(None),
+    };
+
+    let point1 = match g1.as_support_map() {
+        Some(g1) => g1.local_support_point_toward(contact.normal1),
+        None => contact.point1,
+    };
+
+    Ok(Some((point1, -contact.dist)))
+}
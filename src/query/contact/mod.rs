@@ -1,14 +1,26 @@
 //! Implementation details of the `contact` and `contacts` functions.
 
 pub use self::contact::Contact;
+pub use self::contact_at_predicted::contact_at_predicted;
 pub use self::contact_ball_ball::contact_ball_ball;
+pub use self::contact_ball_capsule::{contact_ball_capsule, contact_capsule_ball};
 pub use self::contact_ball_convex_polyhedron::{
     contact_ball_convex_polyhedron, contact_convex_polyhedron_ball,
 };
+pub use self::contact_ball_triangle::{contact_ball_triangle, contact_triangle_ball};
+#[cfg(feature = "dim3")]
+pub use self::contact_ball_triangle::{
+    contact_ball_triangle_with_adjacent_normals, contact_triangle_ball_with_adjacent_normals,
+};
+#[cfg(feature = "std")]
+pub use self::contact_capsule_capsule::contact_capsule_capsule;
+pub use self::contact_capsule_halfspace::{contact_capsule_halfspace, contact_halfspace_capsule};
 #[cfg(feature = "std")]
 pub use self::contact_composite_shape_shape::{
     contact_composite_shape_shape, contact_shape_composite_shape,
 };
+#[cfg(feature = "dim3")]
+pub use self::contact_cone_halfspace::{contact_cone_halfspace, contact_halfspace_cone};
 pub use self::contact_cuboid_cuboid::contact_cuboid_cuboid;
 pub use self::contact_halfspace_support_map::{
     contact_halfspace_support_map, contact_support_map_halfspace,
@@ -17,15 +29,32 @@ pub use self::contact_shape_shape::contact;
 #[cfg(feature = "std")] // TODO: doesn’t work without std because of EPA
 pub use self::contact_support_map_support_map::{
     contact_support_map_support_map, contact_support_map_support_map_with_params,
+    contact_support_map_support_map_with_workspace,
 };
+pub use self::cuboid_penetration::cuboid_penetration;
+pub use self::deepest_penetration_point::deepest_penetration_point;
+pub use self::mtv::mtv;
+pub use self::touch_status::{touch_status, TouchStatus};
 
 mod contact;
+mod contact_at_predicted;
 mod contact_ball_ball;
+mod contact_ball_capsule;
 mod contact_ball_convex_polyhedron;
+mod contact_ball_triangle;
+#[cfg(feature = "std")]
+mod contact_capsule_capsule;
+mod contact_capsule_halfspace;
 #[cfg(feature = "std")]
 mod contact_composite_shape_shape;
+#[cfg(feature = "dim3")]
+mod contact_cone_halfspace;
 mod contact_cuboid_cuboid;
 mod contact_halfspace_support_map;
 mod contact_shape_shape;
 #[cfg(feature = "std")] // TODO: doesn’t work without std because of EPA
 mod contact_support_map_support_map;
+mod cuboid_penetration;
+mod deepest_penetration_point;
+mod mtv;
+mod touch_status;
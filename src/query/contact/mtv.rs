@@ -0,0 +1,22 @@
+use crate::math::{Isometry, Vector};
+use crate::query::{self, Unsupported};
+use crate::shape::Shape;
+
+/// Computes the minimum translational vector (MTV) between two penetrating shapes.
+///
+/// This is the smallest translation that needs to be applied to `g1` so that it just
+/// touches `g2` without penetrating it. It is equal to the opposite of the contact normal
+/// `normal1` multiplied by the penetration depth.
+///
+/// Returns `None` if the shapes are not penetrating. The result is given in world-space.
+pub fn mtv(
+    pos1: Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+) -> Result<Option<Vector>, Unsupported> {
+    let contact = query::contact(pos1, g1, pos2, g2, 0.0)?;
+    Ok(contact
+        .filter(|contact| contact.dist < 0.0)
+        .map(|contact| *contact.normal1 * contact.dist))
+}
@@ -0,0 +1,39 @@
+use crate::math::{Isometry, Real};
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// Whether two shapes are separated, exactly touching, or penetrating.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum TouchStatus {
+    /// The shapes are separated by the given positive distance.
+    Separated(Real),
+    /// The shapes are touching, i.e., their distance is within the touching tolerance.
+    Touching,
+    /// The shapes are penetrating with the given positive penetration depth.
+    Penetrating(Real),
+}
+
+/// Determines if two shapes are separated, touching, or penetrating.
+///
+/// The shapes are considered touching if their signed distance lies within
+/// `[-tolerance, tolerance]`. This lets resting contacts be distinguished from both small
+/// gaps and small penetrations, which [`distance`](crate::query::distance) and
+/// [`contact`](crate::query::contact) both report as (nearly) zero distances.
+pub fn touch_status(
+    pos1: Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    tolerance: Real,
+) -> Result<TouchStatus, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+
+    match DefaultQueryDispatcher.contact(pos12, g1, g2, tolerance)? {
+        Some(contact) if contact.dist < -tolerance => Ok(TouchStatus::Penetrating(-contact.dist)),
+        Some(contact) if contact.dist <= tolerance => Ok(TouchStatus::Touching),
+        Some(contact) => Ok(TouchStatus::Separated(contact.dist)),
+        None => DefaultQueryDispatcher
+            .distance(pos12, g1, g2)
+            .map(TouchStatus::Separated),
+    }
+}
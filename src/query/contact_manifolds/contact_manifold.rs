@@ -260,6 +260,57 @@ impl<ManifoldData, ContactData: Default + Copy> ContactManifold<ManifoldData, Co
         }
     }
 
+    /// Copy data associated to contacts from the manifold computed at the `previous` frame to
+    /// the new contacts in `self`, e.g., to warm-start a constraints solver with the impulses
+    /// of the previous frame.
+    ///
+    /// Each contact of `self` is paired with the contact of `previous` with the same
+    /// feature-ids. Contacts with unknown feature-ids are paired instead with the closest
+    /// contact of `previous` if both their local points are closer than `dist_threshold`.
+    /// Each contact of `previous` is carried to at most one contact of `self`, and the
+    /// contacts of `self` without any match have their data reset to its default value.
+    pub fn match_and_carry<PreviousManifoldData>(
+        &mut self,
+        previous: &ContactManifold<PreviousManifoldData, ContactData>,
+        dist_threshold: Real,
+    ) {
+        let sq_threshold = dist_threshold * dist_threshold;
+        let mut carried = vec![false; previous.points.len()];
+
+        for contact in &mut self.points {
+            contact.data = ContactData::default();
+
+            let has_known_features = !contact.fid1.is_unknown() || !contact.fid2.is_unknown();
+            let mut best_match = None;
+
+            if has_known_features {
+                best_match = (0..previous.points.len()).find(|i| {
+                    let old = &previous.points[*i];
+                    !carried[*i] && old.fid1 == contact.fid1 && old.fid2 == contact.fid2
+                });
+            } else {
+                let mut best_sq_dist = sq_threshold;
+
+                for (i, old) in previous.points.iter().enumerate() {
+                    let sq_dist1 = contact.local_p1.distance_squared(old.local_p1);
+                    let sq_dist2 = contact.local_p2.distance_squared(old.local_p2);
+                    let sq_dist = sq_dist1.max(sq_dist2);
+
+                    if !carried[i] && sq_dist < best_sq_dist {
+                        best_sq_dist = sq_dist;
+                        best_match = Some(i);
+                    }
+                }
+            }
+
+            if let Some(i) = best_match {
+                // Transfer the tracked data.
+                carried[i] = true;
+                contact.data = previous.points[i].data;
+            }
+        }
+    }
+
     /// Removes all the contacts from `self`.
     pub fn clear(&mut self) {
         self.points.clear();
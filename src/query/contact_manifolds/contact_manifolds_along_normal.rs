@@ -0,0 +1,106 @@
+use crate::math::{Isometry, Real, UnitVector};
+use crate::query::{ContactManifold, TrackedContact, Unsupported};
+use crate::shape::{PackedFeatureId, PolygonalFeature, Shape};
+
+/// Computes a contact manifold between two shapes along a contact normal imposed by the caller.
+///
+/// This is useful for solvers constraining the contact normal, e.g., keeping it vertical in a
+/// 2.5D game, instead of using the axis of minimum penetration. `normal1` is expressed in the
+/// local-space of `g1` and points toward `g2`. The support feature of `g1` along `normal1` is
+/// the reference feature: the support feature of `g2` along the opposite direction is clipped
+/// against it, and the distance of each contact is measured along `normal1`. If these features
+/// can't be clipped against each other, e.g., if they are both vertices, the manifold contains
+/// the single pair of support points instead.
+///
+/// Returns `Err(Unsupported)` if one of the shapes doesn't implement
+/// [`PolygonalFeatureMap`](crate::shape::PolygonalFeatureMap), and `Ok(None)` if the shapes
+/// are separated by a distance greater than `prediction` along `normal1`. As with
+/// [`contact_manifold()`](crate::query::contact_manifold), the contact points and normals of
+/// the result are expressed in the local-space of each shape.
+pub fn contact_manifold_along_normal(
+    pos12: Isometry,
+    g1: &dyn Shape,
+    g2: &dyn Shape,
+    normal1: UnitVector,
+    prediction: Real,
+) -> Result<Option<ContactManifold<(), ()>>, Unsupported> {
+    let ((pfm1, border_radius1), (pfm2, border_radius2)) =
+        match (g1.as_polygonal_feature_map(), g2.as_polygonal_feature_map()) {
+            (Some(pfm1), Some(pfm2)) => (pfm1, pfm2),
+            _ => return Err(Unsupported),
+        };
+
+    let normal2 = pos12.rotation.inverse() * -normal1;
+    let total_prediction = prediction + border_radius1 + border_radius2;
+    let mut feature1 = PolygonalFeature::default();
+    let mut feature2 = PolygonalFeature::default();
+    pfm1.local_support_feature(normal1, &mut feature1);
+    pfm2.local_support_feature(normal2, &mut feature2);
+
+    // Express both features in the local-space of `g1` so the clipping is done with an identity
+    // relative position.
+    for pt in &mut feature2.vertices[..feature2.num_vertices] {
+        *pt = pos12.transform_point(*pt);
+    }
+
+    let mut manifold = ContactManifold::new();
+
+    if can_clip(&feature1, &feature2) {
+        PolygonalFeature::contacts(
+            Isometry::IDENTITY,
+            Isometry::IDENTITY,
+            *normal1,
+            -*normal1,
+            &feature1,
+            &feature2,
+            total_prediction,
+            &mut manifold,
+            false,
+        );
+
+        for contact in &mut manifold.points {
+            contact.local_p2 = pos12.inverse_transform_point(contact.local_p2);
+        }
+    } else {
+        let pt1 = pfm1.local_support_point(*normal1);
+        let pt2 = pfm2.local_support_point(*normal2);
+        manifold.points.push(TrackedContact::new(
+            pt1,
+            pt2,
+            PackedFeatureId::UNKNOWN,
+            PackedFeatureId::UNKNOWN,
+            (pos12.transform_point(pt2) - pt1).dot(*normal1),
+        ));
+    }
+
+    // Adjust points to take the radius into account.
+    for contact in &mut manifold.points {
+        contact.local_p1 += *normal1 * border_radius1;
+        contact.local_p2 += *normal2 * border_radius2;
+        contact.dist -= border_radius1 + border_radius2;
+    }
+
+    manifold.points.retain(|contact| contact.dist <= prediction);
+    manifold.local_n1 = *normal1;
+    manifold.local_n2 = *normal2;
+
+    if manifold.points.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(manifold))
+    }
+}
+
+/// Can [`PolygonalFeature::contacts`] generate contacts between these two features?
+fn can_clip(feature1: &PolygonalFeature, feature2: &PolygonalFeature) -> bool {
+    #[cfg(feature = "dim2")]
+    {
+        feature1.num_vertices == 2 || feature2.num_vertices == 2
+    }
+    #[cfg(feature = "dim3")]
+    {
+        feature1.num_vertices > 2
+            || feature2.num_vertices > 2
+            || (feature1.num_vertices == 2 && feature2.num_vertices == 2)
+    }
+}
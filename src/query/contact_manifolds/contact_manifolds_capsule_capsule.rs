@@ -1,4 +1,3 @@
-#[cfg(feature = "dim2")]
 use crate::approx::AbsDiffEq;
 use crate::math::UnitVector;
 use crate::math::{Isometry, Real};
@@ -130,6 +129,10 @@ pub fn contact_manifold_capsule_capsule<'a, ManifoldData, ContactData>(
 }
 
 /// Computes the contact manifold between two capsules.
+///
+/// If the capsule axes are nearly parallel and their projections overlap, two contact
+/// points are generated in order to stabilize resting contacts. Otherwise, a single contact
+/// point between the closest points of the inner segments is generated.
 #[cfg(feature = "dim3")]
 pub fn contact_manifold_capsule_capsule<'a, ManifoldData, ContactData>(
     pos12: Isometry,
@@ -148,34 +151,72 @@ pub fn contact_manifold_capsule_capsule<'a, ManifoldData, ContactData>(
             (seg2_1.a, seg2_1.b),
         );
 
+    // We do this clone to perform contact tracking and transfer impulses.
+    // FIXME: find a more efficient way of doing this.
+    let old_manifold_points = manifold.points.clone();
+    manifold.clear();
+
     let bcoords1 = loc1.barycentric_coordinates();
     let bcoords2 = loc2.barycentric_coordinates();
     let local_p1 = seg1.a * bcoords1[0] + seg1.b * bcoords1[1];
     let local_p2_1 = seg2_1.a * bcoords2[0] + seg2_1.b * bcoords2[1];
 
     let local_n1 = UnitVector::new(local_p2_1 - local_p1).unwrap_or(UnitVector::Y);
-    let dist = (local_p2_1 - local_p1).dot(*local_n1) - capsule1.radius - capsule2.radius;
+    let dist = (local_p2_1 - local_p1).dot(*local_n1);
 
-    if dist <= prediction {
-        let local_n2 = pos12.rotation.inverse() * -local_n1;
-        let fid = PackedFeatureId::face(0);
-        let contact = TrackedContact::new(
-            local_p1 + *local_n1 * capsule1.radius,
-            pos12.inverse_transform_point(local_p2_1) + *local_n2 * capsule2.radius,
-            fid,
-            fid,
-            dist,
-        );
+    if dist > prediction + capsule1.radius + capsule2.radius {
+        // No contact within tolerance.
+        return;
+    }
+
+    let local_n2 = pos12.rotation.inverse() * -local_n1;
+    let fid = PackedFeatureId::face(0);
+    let contact = TrackedContact::new(
+        local_p1,
+        pos12.inverse_transform_point(local_p2_1),
+        fid,
+        fid,
+        dist,
+    );
+    manifold.points.push(contact);
+
+    manifold.local_n1 = *local_n1;
+    manifold.local_n2 = *local_n2;
+
+    if let (Some(dir1), Some(dir2)) = (seg1.direction(), seg2_1.direction()) {
+        if dir1.dot(*dir2).abs() >= crate::utils::COS_FRAC_PI_8
+            && dir1.dot(*local_n1).abs() < crate::utils::SIN_FRAC_PI_8
+        {
+            // Capsules axes are almost parallel and are almost perpendicular to the normal.
+            // Find a second contact point.
+            if let Some((clip_a, clip_b)) =
+                crate::query::details::clip_segment_segment((seg1.a, seg1.b), (seg2_1.a, seg2_1.b))
+            {
+                let clip =
+                    if (clip_a.0 - local_p1).length_squared() > Real::default_epsilon() * 100.0 {
+                        clip_a
+                    } else {
+                        clip_b
+                    };
 
-        if manifold.points.len() != 0 {
-            manifold.points[0].copy_geometry_from(contact);
-        } else {
-            manifold.points.push(contact);
+                if (clip.0 - local_p1).length_squared() > Real::default_epsilon() * 100.0 {
+                    manifold.points.push(TrackedContact::new(
+                        clip.0,
+                        pos12.inverse_transform_point(clip.1),
+                        PackedFeatureId::face(1),
+                        PackedFeatureId::face(1),
+                        (clip.1 - clip.0).dot(*local_n1),
+                    ));
+                }
+            }
         }
+    }
 
-        manifold.local_n1 = *local_n1;
-        manifold.local_n2 = *local_n2;
-    } else {
-        manifold.clear();
+    for point in &mut manifold.points {
+        point.local_p1 += manifold.local_n1 * capsule1.radius;
+        point.local_p2 += manifold.local_n2 * capsule2.radius;
+        point.dist -= capsule1.radius + capsule2.radius;
     }
+
+    manifold.match_contacts(&old_manifold_points);
 }
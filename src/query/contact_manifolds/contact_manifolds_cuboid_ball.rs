@@ -0,0 +1,86 @@
+use crate::math::{Isometry, Real, Vector, DIM};
+use crate::query::contact_manifolds::contact_manifold_convex_ball;
+use crate::query::{ContactManifold, TrackedContact};
+use crate::shape::{Ball, Cuboid, FeatureId};
+
+/// Computes the contact manifold between a cuboid and a ball.
+///
+/// If `contact_radius` is zero, this generates the same single contact point as
+/// [`contact_manifold_convex_ball`]. Otherwise, if the ball rests on a face of the cuboid, the
+/// single contact point is replaced by a ring of contact points at a distance `contact_radius`
+/// from it, approximating a contact patch. The ring is only generated if it lies entirely on
+/// the face.
+pub fn contact_manifold_cuboid_ball<ManifoldData, ContactData>(
+    pos12: Isometry,
+    cuboid1: &Cuboid,
+    ball2: &Ball,
+    prediction: Real,
+    contact_radius: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    flipped: bool,
+) where
+    ContactData: Default + Copy,
+{
+    if contact_radius <= 0.0 {
+        contact_manifold_convex_ball(pos12, cuboid1, ball2, prediction, manifold, flipped);
+        return;
+    }
+
+    let old_points = manifold.points.clone();
+    contact_manifold_convex_ball(pos12, cuboid1, ball2, prediction, manifold, flipped);
+
+    if manifold.points.len() != 1 {
+        return;
+    }
+
+    let contact = manifold.points[0];
+    let (local_p1, local_p2, fid1, fid2) = if flipped {
+        (
+            contact.local_p2,
+            contact.local_p1,
+            contact.fid2,
+            contact.fid1,
+        )
+    } else {
+        (
+            contact.local_p1,
+            contact.local_p2,
+            contact.fid1,
+            contact.fid2,
+        )
+    };
+
+    let face_axis = match fid1.unpack() {
+        FeatureId::Face(face) => face as usize % DIM,
+        _ => return,
+    };
+
+    // Check that the ring fits on the face.
+    for i in 0..DIM {
+        if i != face_axis && local_p1[i].abs() + contact_radius > cuboid1.half_extents[i] {
+            return;
+        }
+    }
+
+    let rot21 = pos12.rotation.inverse();
+    manifold.clear();
+
+    for i in (0..DIM).filter(|i| *i != face_axis) {
+        for sign in [1.0, -1.0] {
+            let mut offset1 = Vector::ZERO;
+            offset1[i] = contact_radius * sign;
+            let offset2 = rot21 * offset1;
+
+            manifold.points.push(TrackedContact::flipped(
+                local_p1 + offset1,
+                local_p2 + offset2,
+                fid1,
+                fid2,
+                contact.dist,
+                flipped,
+            ));
+        }
+    }
+
+    manifold.match_contacts_using_positions(&old_points, contact_radius * 0.5);
+}
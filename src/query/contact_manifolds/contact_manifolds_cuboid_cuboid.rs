@@ -1,6 +1,4 @@
-#[cfg(feature = "dim2")]
-use crate::math::Vector;
-use crate::math::{Isometry, Real};
+use crate::math::{Isometry, Real, Vector, DIM};
 use crate::query::{sat, ContactManifold};
 use crate::shape::{Cuboid, PolygonalFeature, Shape};
 
@@ -24,6 +22,30 @@ pub fn contact_manifold_cuboid_cuboid<'a, ManifoldData, ContactData: Default + C
     cuboid2: &'a Cuboid,
     prediction: Real,
     manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) {
+    contact_manifold_cuboid_cuboid_with_normal_snapping(
+        pos12, cuboid1, cuboid2, prediction, 0.0, manifold,
+    )
+}
+
+/// Computes the contact manifold between two cuboids, snapping its normal to a face normal.
+///
+/// If the angle between the contact normal and the closest face normal of `cuboid1` is at
+/// most `max_snap_angle` (in radians), that face normal is used as the contact normal instead.
+/// Otherwise, the closest face normal of `cuboid2` is used if it is within the same tolerance.
+/// This keeps the normal of resting boxes constant under small rotations, instead of switching
+/// between the face normals of both boxes, or edge-edge normals, from one frame to the next.
+pub fn contact_manifold_cuboid_cuboid_with_normal_snapping<
+    'a,
+    ManifoldData,
+    ContactData: Default + Copy,
+>(
+    pos12: Isometry,
+    cuboid1: &'a Cuboid,
+    cuboid2: &'a Cuboid,
+    prediction: Real,
+    max_snap_angle: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
 ) {
     if manifold.try_update_contacts(pos12) {
         return;
@@ -76,6 +98,10 @@ pub fn contact_manifold_cuboid_cuboid<'a, ManifoldData, ContactData: Default + C
         best_sep = sep3;
     }
 
+    if max_snap_angle > 0.0 {
+        best_sep.1 = snap_to_face_normal(pos12, pos21, best_sep.1, max_snap_angle);
+    }
+
     // We do this clone to perform contact tracking and transfer impulses.
     // FIXME: find a more efficient way of doing this.
     let old_manifold_points = manifold.points.clone();
@@ -98,3 +124,42 @@ pub fn contact_manifold_cuboid_cuboid<'a, ManifoldData, ContactData: Default + C
     // Transfer impulses.
     manifold.match_contacts(&old_manifold_points);
 }
+
+/// The face normal of a cuboid closest to the unit vector `dir`, both expressed in the
+/// local-space of the cuboid, and the cosine of the angle between them.
+fn closest_face_normal(dir: Vector) -> (Vector, Real) {
+    let mut best = 0;
+
+    for i in 1..DIM {
+        if dir[i].abs() > dir[best].abs() {
+            best = i;
+        }
+    }
+
+    let mut normal = Vector::ZERO;
+    normal[best] = dir[best].signum();
+    (normal, dir[best].abs())
+}
+
+/// Snaps `normal1`, expressed in the local-space of the first cuboid, to the closest face
+/// normal of the first cuboid, or else of the second cuboid, if it is within `max_angle`.
+fn snap_to_face_normal(
+    pos12: Isometry,
+    pos21: Isometry,
+    normal1: Vector,
+    max_angle: Real,
+) -> Vector {
+    let min_cos = max_angle.cos();
+
+    let (face1, cos1) = closest_face_normal(normal1);
+    if cos1 >= min_cos {
+        return face1;
+    }
+
+    let (face2, cos2) = closest_face_normal(pos21 * -normal1);
+    if cos2 >= min_cos {
+        return pos12 * -face2;
+    }
+
+    normal1
+}
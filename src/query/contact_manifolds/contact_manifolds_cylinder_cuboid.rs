@@ -0,0 +1,252 @@
+use crate::math::{Isometry, Real, Vector, DEFAULT_EPSILON};
+use crate::query::gjk::{GJKResult, VoronoiSimplex};
+use crate::query::{self, ContactManifold, TrackedContact};
+use crate::shape::{Cuboid, Cylinder, CylinderFeature, PackedFeatureId, Shape};
+use crate::MinMaxIndex;
+use arrayvec::ArrayVec;
+
+/// The maximum number of vertices of the cuboid face clipped by the sides of a cylinder cap:
+/// each of the cap edges can add one vertex to the four vertices of the face.
+const MAX_CLIPPED_VERTICES: usize = 4 + CylinderFeature::NUM_CAP_VERTICES;
+
+/// The distance under which the contacts of the previous manifold are matched with the new
+/// ones when the prediction distance is smaller.
+///
+/// Contacts are matched by position since the feature ids of a cylinder don't identify a
+/// single contact. Without this lower bound, a zero prediction would never match any contact.
+const MIN_CONTACT_MATCHING_DISTANCE: Real = 1.0e-3;
+
+/// Computes the contact manifold between a cylinder and a cuboid represented as `Shape`
+/// trait-objects.
+pub fn contact_manifold_cylinder_cuboid_shapes<ManifoldData, ContactData>(
+    pos12: Isometry,
+    shape1: &dyn Shape,
+    shape2: &dyn Shape,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+) where
+    ContactData: Default + Copy,
+{
+    if let (Some(cylinder1), Some(cuboid2)) = (shape1.as_cylinder(), shape2.as_cuboid()) {
+        contact_manifold_cylinder_cuboid(pos12, cylinder1, cuboid2, prediction, manifold, false);
+    } else if let (Some(cuboid1), Some(cylinder2)) = (shape1.as_cuboid(), shape2.as_cylinder()) {
+        contact_manifold_cylinder_cuboid(
+            pos12.inverse(),
+            cylinder2,
+            cuboid1,
+            prediction,
+            manifold,
+            true,
+        );
+    }
+}
+
+/// Computes the contact manifold between a cylinder and a cuboid.
+///
+/// The contact normal is computed with GJK/EPA, and the feature of the cylinder supporting it
+/// along that normal is classified with [`Cylinder::support_feature`]:
+/// - If it is a cap, the cap polygon is intersected with the support face of the cuboid,
+///   giving up to one contact per vertex of the intersection, e.g., for a cylinder standing
+///   on a box.
+/// - If it is a lateral segment or a segment of the rim, that segment is clipped by the
+///   support face of the cuboid, giving up to two contacts, e.g., for a cylinder lying on a box.
+///
+/// Only the contacts closer than `prediction` are kept. If none remains, e.g., for a vertex or
+/// an edge of the cuboid touching the cylinder, the single contact found by GJK/EPA is used.
+/// If `flipped` is `true`, the cylinder is the second shape of the resulting manifold.
+pub fn contact_manifold_cylinder_cuboid<ManifoldData, ContactData>(
+    pos12: Isometry,
+    cylinder1: &Cylinder,
+    cuboid2: &Cuboid,
+    prediction: Real,
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    flipped: bool,
+) where
+    ContactData: Default + Copy,
+{
+    let contact = query::details::contact_support_map_support_map_with_params(
+        pos12,
+        cylinder1,
+        cuboid2,
+        prediction,
+        &mut VoronoiSimplex::new(),
+        None,
+    );
+
+    let old_manifold_points = manifold.points.clone();
+    manifold.clear();
+
+    let (p1, p2_1, dir) = match contact {
+        GJKResult::ClosestPoints(p1, p2_1, dir) => (p1, p2_1, dir),
+        _ => return,
+    };
+
+    let local_n1 = *dir;
+    let local_n2 = pos12.rotation.inverse() * -local_n1;
+
+    // The support face of the cuboid, expressed in the local-space of the cylinder.
+    let face2_feature = cuboid2.support_face(local_n2);
+    let face2 = face2_feature.vertices.map(|pt| pos12.transform_point(pt));
+    let iamax = local_n2.abs().max_index();
+    let mut local_face_normal2 = Vector::ZERO;
+    local_face_normal2[iamax] = local_n2[iamax].signum();
+    let face2_normal = pos12.rotation * local_face_normal2;
+
+    let feature1 = cylinder1.support_feature(local_n1);
+    let fid1 = cylinder_feature_id(cylinder1, &feature1, local_n1);
+    let fid2 = face2_feature.fid;
+    let mut push_contact = |pt1: Vector, pt2: Vector, dist: Real| {
+        if dist <= prediction {
+            manifold.points.push(TrackedContact::flipped(
+                pt1,
+                pos12.inverse_transform_point(pt2),
+                fid1,
+                fid2,
+                dist,
+                flipped,
+            ));
+        }
+    };
+
+    match feature1 {
+        CylinderFeature::Cap(cap) => {
+            // Intersect the cuboid face with the prism extruding the cap along the normal.
+            let mut clipped: ArrayVec<Vector, MAX_CLIPPED_VERTICES> = face2.into_iter().collect();
+
+            for (center, normal) in side_planes(&cap, local_n1) {
+                clipped = clip_polygon_halfspace(&clipped, center, normal);
+            }
+
+            for pt2 in clipped {
+                let dist = (pt2 - cap[0]).dot(local_n1);
+                push_contact(pt2 - local_n1 * dist, pt2, dist);
+            }
+        }
+        CylinderFeature::Lateral(segment) | CylinderFeature::Rim(segment) => {
+            // Clip the segment with the prism extruding the cuboid face along the normal, then
+            // project it on the face along the normal.
+            let mut clipped = Some([segment.a, segment.b]);
+
+            for (center, normal) in side_planes(&face2, local_n1) {
+                clipped = clipped.and_then(|seg| clip_segment_halfspace(seg, center, normal));
+            }
+
+            let denom = local_n1.dot(face2_normal);
+
+            if let Some(seg) = clipped.filter(|_| denom < -DEFAULT_EPSILON) {
+                for pt1 in seg {
+                    let dist = (face2[0] - pt1).dot(face2_normal) / denom;
+                    push_contact(pt1, pt1 + local_n1 * dist, dist);
+                }
+            }
+        }
+    }
+
+    if manifold.points.is_empty() {
+        manifold.points.push(TrackedContact::flipped(
+            p1,
+            pos12.inverse_transform_point(p2_1),
+            fid1,
+            fid2,
+            (p2_1 - p1).dot(local_n1),
+            flipped,
+        ));
+    }
+
+    if flipped {
+        manifold.local_n1 = local_n2;
+        manifold.local_n2 = local_n1;
+    } else {
+        manifold.local_n1 = local_n1;
+        manifold.local_n2 = local_n2;
+    }
+
+    // Transfer impulses.
+    manifold.match_contacts_using_positions(
+        &old_manifold_points,
+        prediction.max(MIN_CONTACT_MATCHING_DISTANCE),
+    );
+}
+
+/// The id of the feature of `cylinder` supporting it along `dir`, following the numbering of
+/// its `PolygonalFeatureMap` implementation: `0` for the curved part, `9` and `19` for the
+/// bottom and top caps, and `2` and `12` for an edge of their rims.
+fn cylinder_feature_id(
+    cylinder: &Cylinder,
+    feature: &CylinderFeature,
+    dir: Vector,
+) -> PackedFeatureId {
+    let top = cylinder.axis.to_y_up(dir).y > 0.0;
+
+    match feature {
+        CylinderFeature::Lateral(_) => PackedFeatureId::face(0),
+        CylinderFeature::Cap(_) => PackedFeatureId::face(if top { 19 } else { 9 }),
+        CylinderFeature::Rim(_) => PackedFeatureId::edge(if top { 12 } else { 2 }),
+    }
+}
+
+/// Clips the convex `polygon` with the half-space delimited by the plane with the given
+/// `center` and outward `normal`.
+fn clip_polygon_halfspace(
+    polygon: &[Vector],
+    center: Vector,
+    normal: Vector,
+) -> ArrayVec<Vector, MAX_CLIPPED_VERTICES> {
+    let mut result = ArrayVec::new();
+
+    for (i, &pt) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let d = (pt - center).dot(normal);
+        let d_next = (next - center).dot(normal);
+
+        // Rounding errors on a nearly flat polygon may give more vertices than it can have in
+        // theory. The extra ones are dropped.
+        if d <= 0.0 {
+            let _ = result.try_push(pt);
+        }
+
+        if (d <= 0.0) != (d_next <= 0.0) {
+            let _ = result.try_push(pt + (next - pt) * (d / (d - d_next)));
+        }
+    }
+
+    result
+}
+
+/// The planes containing `dir` and each edge of the convex `polygon`, as pairs of a point and
+/// of the normal pointing outside of the polygon.
+fn side_planes(polygon: &[Vector], dir: Vector) -> impl Iterator<Item = (Vector, Vector)> + '_ {
+    let centroid = polygon.iter().copied().sum::<Vector>() / polygon.len() as Real;
+
+    (0..polygon.len()).map(move |i| {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        let normal = (b - a).cross(dir).normalize_or_zero();
+
+        if normal.dot(centroid - a) > 0.0 {
+            (a, -normal)
+        } else {
+            (a, normal)
+        }
+    })
+}
+
+/// Clips the segment `seg` with the half-space delimited by the plane with the given `center`
+/// and outward `normal`. Returns `None` if the segment is entirely outside of the half-space.
+fn clip_segment_halfspace(seg: [Vector; 2], center: Vector, normal: Vector) -> Option<[Vector; 2]> {
+    let d0 = (seg[0] - center).dot(normal);
+    let d1 = (seg[1] - center).dot(normal);
+
+    if d0 > 0.0 && d1 > 0.0 {
+        None
+    } else if d0 > 0.0 || d1 > 0.0 {
+        let cut = seg[0] + (seg[1] - seg[0]) * (d0 / (d0 - d1));
+        Some(if d0 > 0.0 {
+            [cut, seg[1]]
+        } else {
+            [seg[0], cut]
+        })
+    } else {
+        Some(seg)
+    }
+}
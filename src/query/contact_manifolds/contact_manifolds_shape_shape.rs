@@ -0,0 +1,50 @@
+use crate::math::{Isometry, Real};
+use crate::query::{
+    ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, QueryDispatcher,
+    TrackedContact, Unsupported,
+};
+use crate::shape::{PackedFeatureId, Shape};
+
+/// Computes a contact manifold between two shapes.
+///
+/// Pairs of shapes with a dedicated manifold generator (balls, cuboids, capsules, half-spaces,
+/// polygonal feature maps, etc.) produce a full manifold. Other pairs fall back to a manifold
+/// containing the single contact point computed by [`contact()`](crate::query::contact).
+///
+/// Returns `None` if the objects are separated by a distance greater than `prediction`.
+/// Unlike [`contact()`](crate::query::contact), the contact points and normals of the
+/// result are expressed in the local-space of each shape.
+pub fn contact_manifold(
+    pos1: Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    prediction: Real,
+) -> Result<Option<ContactManifold<(), ()>>, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    let mut manifold = ContactManifold::new();
+
+    if DefaultQueryDispatcher
+        .contact_manifold_convex_convex(pos12, g1, g2, prediction, &mut manifold)
+        .is_err()
+    {
+        // No specialized manifold generator for this pair, fallback to a single point.
+        if let Some(contact) = DefaultQueryDispatcher.contact(pos12, g1, g2, prediction)? {
+            manifold.local_n1 = *contact.normal1;
+            manifold.local_n2 = *contact.normal2;
+            manifold.points.push(TrackedContact::new(
+                contact.point1,
+                contact.point2,
+                PackedFeatureId::UNKNOWN,
+                PackedFeatureId::UNKNOWN,
+                contact.dist,
+            ));
+        }
+    }
+
+    if manifold.points.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(manifold))
+    }
+}
@@ -8,6 +8,12 @@ use crate::query::query_dispatcher::PersistentQueryDispatcher;
 use crate::query::ContactManifold;
 use crate::shape::{Shape, TriMesh};
 
+#[cfg(feature = "dim3")]
+use crate::{
+    math::UnitVector,
+    shape::{TriMeshFlags, Triangle},
+};
+
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "rkyv",
@@ -194,6 +200,19 @@ pub fn contact_manifolds_trimesh_shape<ManifoldData, ContactData>(
             let _ = dispatcher
                 .contact_manifold_convex_convex(pos12, &triangle1, shape2, prediction, manifold);
         }
+
+        #[cfg(feature = "dim3")]
+        if trimesh1.flags().contains(TriMeshFlags::FIX_INTERNAL_EDGES) {
+            if let Some(adjacent_normals) = trimesh1.triangle_adjacent_normals(*triangle_id) {
+                correct_internal_edge_normal(
+                    pos12,
+                    &triangle1,
+                    &adjacent_normals,
+                    manifold,
+                    flipped,
+                );
+            }
+        }
     }
 
     /*
@@ -209,6 +228,43 @@ pub fn contact_manifolds_trimesh_shape<ManifoldData, ContactData>(
     );
 }
 
+/// Corrects the normal of a manifold involving `triangle1` so it doesn’t point toward the
+/// triangles adjacent to it.
+///
+/// The correction is based on the feature of the triangle touched by the deepest contact. Only
+/// the normals are modified, not the contact points.
+#[cfg(feature = "dim3")]
+fn correct_internal_edge_normal<ManifoldData, ContactData>(
+    pos12: Isometry,
+    triangle1: &Triangle,
+    adjacent_normals1: &[Option<UnitVector>; 3],
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    flipped: bool,
+) where
+    ContactData: Default + Copy,
+{
+    let (fid1, local_n1) = match manifold.find_deepest_contact() {
+        Some(deepest) if flipped => (deepest.fid2, manifold.local_n2),
+        Some(deepest) => (deepest.fid1, manifold.local_n1),
+        None => return,
+    };
+    let normal1 = match UnitVector::new(local_n1) {
+        Ok(normal1) => normal1,
+        Err(_) => return,
+    };
+    let corrected =
+        *triangle1.correct_internal_edge_normal(fid1.unpack(), normal1, adjacent_normals1);
+    let other = pos12.rotation.inverse() * -corrected;
+
+    if flipped {
+        manifold.local_n1 = other;
+        manifold.local_n2 = corrected;
+    } else {
+        manifold.local_n1 = corrected;
+        manifold.local_n2 = other;
+    }
+}
+
 impl WorkspaceData for TriMeshShapeContactManifoldsWorkspace {
     fn as_typed_workspace_data(&self) -> TypedWorkspaceData {
         TypedWorkspaceData::TriMeshShapeContactManifoldsWorkspace(self)
@@ -1,4 +1,5 @@
 pub use self::contact_manifold::{ContactManifold, TrackedContact};
+pub use self::contact_manifolds_along_normal::contact_manifold_along_normal;
 pub use self::contact_manifolds_ball_ball::{
     contact_manifold_ball_ball, contact_manifold_ball_ball_shapes,
 };
@@ -13,12 +14,18 @@ pub use self::contact_manifolds_convex_ball::{
 // pub use self::contact_manifolds_cuboid_capsule::{
 //     contact_manifold_cuboid_capsule, contact_manifold_cuboid_capsule_shapes,
 // };
+pub use self::contact_manifolds_cuboid_ball::contact_manifold_cuboid_ball;
 pub use self::contact_manifolds_cuboid_cuboid::{
     contact_manifold_cuboid_cuboid, contact_manifold_cuboid_cuboid_shapes,
+    contact_manifold_cuboid_cuboid_with_normal_snapping,
 };
 pub use self::contact_manifolds_cuboid_triangle::{
     contact_manifold_cuboid_triangle, contact_manifold_cuboid_triangle_shapes,
 };
+#[cfg(feature = "dim3")]
+pub use self::contact_manifolds_cylinder_cuboid::{
+    contact_manifold_cylinder_cuboid, contact_manifold_cylinder_cuboid_shapes,
+};
 pub use self::contact_manifolds_halfspace_pfm::{
     contact_manifold_halfspace_pfm, contact_manifold_halfspace_pfm_shapes,
 };
@@ -29,12 +36,14 @@ pub use self::contact_manifolds_heightfield_shape::{
 pub use self::contact_manifolds_pfm_pfm::{
     contact_manifold_pfm_pfm, contact_manifold_pfm_pfm_shapes,
 };
+pub use self::contact_manifolds_shape_shape::contact_manifold;
 pub use self::contact_manifolds_trimesh_shape::{
     contact_manifolds_trimesh_shape, contact_manifolds_trimesh_shape_shapes,
 };
 pub use self::contact_manifolds_workspace::{
     ContactManifoldsWorkspace, TypedWorkspaceData, WorkspaceData,
 };
+pub use self::reduce_contacts::reduce_contacts;
 
 use {
     self::contact_manifolds_composite_shape_composite_shape::CompositeShapeCompositeShapeContactManifoldsWorkspace,
@@ -46,18 +55,24 @@ use {
 };
 
 mod contact_manifold;
+mod contact_manifolds_along_normal;
 mod contact_manifolds_ball_ball;
 mod contact_manifolds_capsule_capsule;
 mod contact_manifolds_convex_ball;
 // mod contact_manifolds_cuboid_capsule;
 mod contact_manifolds_composite_shape_composite_shape;
 mod contact_manifolds_composite_shape_shape;
+mod contact_manifolds_cuboid_ball;
 mod contact_manifolds_cuboid_cuboid;
 mod contact_manifolds_cuboid_triangle;
+#[cfg(feature = "dim3")]
+mod contact_manifolds_cylinder_cuboid;
 mod contact_manifolds_halfspace_pfm;
 mod contact_manifolds_heightfield_composite_shape;
 mod contact_manifolds_heightfield_shape;
 mod contact_manifolds_pfm_pfm;
+mod contact_manifolds_shape_shape;
 mod contact_manifolds_trimesh_shape;
 mod contact_manifolds_workspace;
 mod internal_edges_fixer;
+mod reduce_contacts;
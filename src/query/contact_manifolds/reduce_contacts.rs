@@ -0,0 +1,117 @@
+use crate::math::{Real, Vector};
+use crate::query::ContactManifold;
+
+/// Removes contacts from `manifold` until at most `max_points` remain.
+///
+/// The deepest contact is always kept. The other contacts are selected to maximize the
+/// spatial spread of the reduced manifold: the contact farthest from the deepest one, then
+/// (in 3D) the contact maximizing the area of the triangle formed with the first two, and
+/// the contact lying the farthest outside of that triangle. Any additional contact is the
+/// one farthest from all the contacts kept so far.
+pub fn reduce_contacts<ManifoldData, ContactData: Default + Copy>(
+    manifold: &mut ContactManifold<ManifoldData, ContactData>,
+    max_points: usize,
+) {
+    let num_points = manifold.points.len();
+
+    if num_points <= max_points {
+        return;
+    }
+
+    if max_points == 0 {
+        manifold.clear();
+        return;
+    }
+
+    let pts: Vec<Vector> = manifold.points.iter().map(|pt| pt.local_p1).collect();
+    let mut kept = vec![false; num_points];
+    let mut selected: Vec<usize> = Vec::with_capacity(max_points);
+
+    // The deepest contact.
+    let mut deepest = 0;
+    for (i, pt) in manifold.points.iter().enumerate() {
+        if pt.dist < manifold.points[deepest].dist {
+            deepest = i;
+        }
+    }
+
+    kept[deepest] = true;
+    selected.push(deepest);
+
+    // Selects the point not kept yet maximizing `score`.
+    let select_best = |kept: &[bool], score: &dyn Fn(Vector) -> Real| {
+        let mut best = None;
+        let mut best_score = -Real::MAX;
+
+        for (i, pt) in pts.iter().enumerate() {
+            if !kept[i] {
+                let s = score(*pt);
+                if s > best_score {
+                    best_score = s;
+                    best = Some(i);
+                }
+            }
+        }
+
+        best
+    };
+
+    #[cfg(feature = "dim3")]
+    if max_points >= 3 {
+        let a = pts[deepest];
+
+        if let Some(ib) = select_best(&kept, &|p| (p - a).length_squared()) {
+            kept[ib] = true;
+            selected.push(ib);
+            let b = pts[ib];
+
+            if let Some(ic) = select_best(&kept, &|p| (b - a).cross(p - a).length_squared()) {
+                kept[ic] = true;
+                selected.push(ic);
+                let c = pts[ic];
+                let n = (b - a).cross(c - a);
+
+                if max_points >= 4 {
+                    // The point the farthest outside of the triangle `abc`, i.e., with the most
+                    // negative signed area with any of its edges.
+                    let outside = |p: Vector| {
+                        let ab = (b - a).cross(p - a).dot(n);
+                        let bc = (c - b).cross(p - b).dot(n);
+                        let ca = (a - c).cross(p - c).dot(n);
+                        -ab.min(bc).min(ca)
+                    };
+
+                    if let Some(id) = select_best(&kept, &outside) {
+                        kept[id] = true;
+                        selected.push(id);
+                    }
+                }
+            }
+        }
+    }
+
+    // Fill the remaining slots with the points farthest from the ones already kept.
+    while selected.len() < max_points {
+        let min_dist = |p: Vector| {
+            selected
+                .iter()
+                .map(|i| pts[*i].distance_squared(p))
+                .fold(Real::MAX, Real::min)
+        };
+
+        match select_best(&kept, &min_dist) {
+            Some(i) => {
+                kept[i] = true;
+                selected.push(i);
+            }
+            None => break,
+        }
+    }
+
+    let mut i = 0;
+    manifold.points.retain(|_| {
+        let keep = kept[i];
+        i += 1;
+        keep
+    });
+}
@@ -147,6 +147,14 @@ impl QueryDispatcher for DefaultQueryDispatcher {
 
         if let (Some(b1), Some(b2)) = (ball1, ball2) {
             Ok(query::details::contact_ball_ball(pos12, b1, b2, prediction))
+        } else if let (Some(b1), Some(c2)) = (ball1, shape2.as_capsule()) {
+            Ok(query::details::contact_ball_capsule(
+                pos12, b1, c2, prediction,
+            ))
+        } else if let (Some(c1), Some(b2)) = (shape1.as_capsule(), ball2) {
+            Ok(query::details::contact_capsule_ball(
+                pos12, c1, b2, prediction,
+            ))
         // } else if let (Some(c1), Some(c2)) = (shape1.as_cuboid(), shape2.as_cuboid()) {
         //     Ok(query::details::contact_cuboid_cuboid(
         //         pos12, c1, c2, prediction,
@@ -173,7 +181,12 @@ impl QueryDispatcher for DefaultQueryDispatcher {
             ))
         } else {
             #[cfg(feature = "std")]
-            if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
+            if let (Some(c1), Some(c2)) = (shape1.as_capsule(), shape2.as_capsule()) {
+                return Ok(query::details::contact_capsule_capsule(
+                    pos12, c1, c2, prediction,
+                ));
+            } else if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map())
+            {
                 return Ok(query::details::contact_support_map_support_map(
                     pos12, s1, s2, prediction,
                 ));
@@ -570,6 +583,10 @@ where
             (ShapeType::Triangle, ShapeType::Cuboid) | (ShapeType::Cuboid, ShapeType::Triangle) => {
                 contact_manifold_cuboid_triangle_shapes(pos12, shape1, shape2, prediction, manifold)
             }
+            #[cfg(feature = "dim3")]
+            (ShapeType::Cylinder, ShapeType::Cuboid) | (ShapeType::Cuboid, ShapeType::Cylinder) => {
+                contact_manifold_cylinder_cuboid_shapes(pos12, shape1, shape2, prediction, manifold)
+            }
             (ShapeType::HalfSpace, _) => {
                 if let Some((pfm2, border_radius2)) = shape2.as_polygonal_feature_map() {
                     contact_manifold_halfspace_pfm(
@@ -0,0 +1,40 @@
+use crate::math::{Isometry, Real};
+use crate::query::{self, Unsupported};
+use crate::shape::Shape;
+
+/// Finds the pair of shapes closest to each other among a set of shapes.
+///
+/// Returns the indices of both shapes in `shapes`, and their distance, which is `0.0` if they
+/// are touching or penetrating. Returns `None` if `shapes` contains less than two shapes.
+///
+/// The bounding spheres of the shapes are used to skip the pairs that cannot be closer than the
+/// best pair found so far before running the exact distance computation.
+pub fn closest_pair(
+    shapes: &[(Isometry, &dyn Shape)],
+) -> Result<Option<(usize, usize, Real)>, Unsupported> {
+    let mut result = None;
+    let mut best_dist = Real::MAX;
+
+    for (i, (pos1, g1)) in shapes.iter().enumerate() {
+        let sphere1 = g1.compute_bounding_sphere(*pos1);
+
+        for (j, (pos2, g2)) in shapes.iter().enumerate().skip(i + 1) {
+            let sphere2 = g2.compute_bounding_sphere(*pos2);
+            let lower_bound =
+                sphere1.center.distance(sphere2.center) - sphere1.radius - sphere2.radius;
+
+            if lower_bound >= best_dist {
+                continue;
+            }
+
+            if let Some(dist) = query::distance_within(*pos1, *g1, *pos2, *g2, best_dist)? {
+                if result.is_none() || dist < best_dist {
+                    best_dist = dist;
+                    result = Some((i, j, dist));
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
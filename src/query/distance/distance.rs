@@ -1,11 +1,14 @@
 use crate::math::{Isometry, Real};
 
-use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::query::{ClosestPoints, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
 use crate::shape::Shape;
 
 /// Computes the minimum distance separating two shapes.
 ///
 /// Returns `0.0` if the objects are touching or penetrating.
+///
+/// The positions must be finite. This is checked with a debug assertion, and `NaN` is
+/// returned in release mode if they aren't.
 pub fn distance(
     pos1: Isometry,
     g1: &dyn Shape,
@@ -13,5 +16,34 @@ pub fn distance(
     g2: &dyn Shape,
 ) -> Result<Real, Unsupported> {
     let pos12 = pos1.inv_mul(pos2);
+
+    debug_assert!(pos12.is_finite(), "distance: non-finite shape positions.");
+    if !pos12.is_finite() {
+        return Ok(Real::NAN);
+    }
+
     DefaultQueryDispatcher.distance(pos12, g1, g2)
 }
+
+/// Computes the minimum distance separating two shapes, if it is smaller than `max_dist`.
+///
+/// Returns `None` if the shapes are separated by a distance larger than `max_dist`. Unlike
+/// [`distance`], the computation can stop as soon as the shapes are proven to be too far
+/// apart, which makes it cheaper for rejecting distant pairs.
+/// Returns `Some(0.0)` if the objects are touching or penetrating.
+pub fn distance_within(
+    pos1: Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    max_dist: Real,
+) -> Result<Option<Real>, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    let dist = match DefaultQueryDispatcher.closest_points(pos12, g1, g2, max_dist)? {
+        ClosestPoints::Intersecting => Some(0.0),
+        ClosestPoints::WithinMargin(pt1, pt2) => Some(pt1.distance(pos12.transform_point(pt2))),
+        ClosestPoints::Disjoint => None,
+    };
+
+    Ok(dist)
+}
@@ -0,0 +1,23 @@
+use crate::math::{Isometry, Real};
+use crate::query::PointQuery;
+use crate::shape::{Ball, Triangle};
+
+/// Distance between a ball and a triangle.
+///
+/// Returns `0.0` if they intersect.
+#[inline]
+pub fn distance_ball_triangle(pos12: Isometry, ball1: &Ball, triangle2: &Triangle) -> Real {
+    distance_triangle_ball(pos12.inverse(), triangle2, ball1)
+}
+
+/// Distance between a triangle and a ball.
+///
+/// The center of the ball is projected on the triangle, which selects the Voronoi region of
+/// the face, edge, or vertex closest to it, and the radius of the ball is subtracted from the
+/// distance to this projection. Returns `0.0` if they intersect.
+#[inline]
+pub fn distance_triangle_ball(pos12: Isometry, triangle1: &Triangle, ball2: &Ball) -> Real {
+    let center2_1 = pos12.translation;
+    let proj = triangle1.project_local_point(center2_1, true);
+    (proj.point.distance(center2_1) - ball2.radius).max(0.0)
+}
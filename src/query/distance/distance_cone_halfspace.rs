@@ -0,0 +1,25 @@
+use crate::math::{Isometry, Real};
+use crate::shape::{Cone, HalfSpace};
+
+/// Distance between a cone and a halfspace.
+///
+/// The deepest point of the cone along the halfspace normal is either its apex or a point of
+/// its base rim, so the distance is computed analytically from both candidates.
+pub fn distance_cone_halfspace(pos12: Isometry, cone: &Cone, halfspace: &HalfSpace) -> Real {
+    // The halfspace normal and the position of its boundary plane in the cone's local-space.
+    let normal = pos12.rotation * halfspace.normal;
+    let plane_offset = normal.dot(pos12.translation);
+    // The normal in the frame where the cone's principal axis is the `y` axis.
+    let normal = cone.to_y_up(*normal);
+
+    let apex_height = normal.y * cone.half_height;
+    let rim_height = -normal.y * cone.half_height
+        - cone.radius * (normal.x * normal.x + normal.z * normal.z).sqrt();
+
+    (apex_height.min(rim_height) - plane_offset).max(0.0)
+}
+
+/// Distance between a halfspace and a cone.
+pub fn distance_halfspace_cone(pos12: Isometry, halfspace: &HalfSpace, cone: &Cone) -> Real {
+    distance_cone_halfspace(pos12.inverse(), cone, halfspace)
+}
@@ -0,0 +1,45 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::details;
+use crate::query::gjk::{GJKResult, VoronoiSimplex};
+use crate::shape::{Segment, SupportMap};
+
+/// Distance and witness points between a support-mapped shape and the segment `[a, b]`.
+///
+/// The segment endpoints are expressed in the local-space of the second shape, positioned
+/// relative to `g` by `pos12`. Returns the distance together with the closest point on `g`,
+/// expressed in its local-space, and the closest point on the segment, expressed in the
+/// local-space of the segment.
+///
+/// If the segment intersects `g`, the distance is zero and the witness points are the
+/// deepest points of the penetration instead.
+pub fn distance_support_map_segment<G: ?Sized + SupportMap>(
+    pos12: Isometry,
+    g: &G,
+    a: Vector,
+    b: Vector,
+) -> (Real, Vector, Vector) {
+    // The segment is handled by GJK as a degenerate support-mapped shape.
+    let segment = Segment::new(a, b);
+
+    match details::closest_points_support_map_support_map_with_params(
+        pos12,
+        g,
+        &segment,
+        Real::MAX,
+        &mut VoronoiSimplex::new(),
+        None,
+    ) {
+        GJKResult::ClosestPoints(pt1, pt2, _) => {
+            (pt1.distance(pt2), pt1, pos12.inverse_transform_point(pt2))
+        }
+        _ => {
+            if let Some(contact) =
+                details::contact_support_map_support_map(pos12, g, &segment, Real::MAX)
+            {
+                (0.0, contact.point1, contact.point2)
+            } else {
+                (0.0, pos12.transform_point(a), a)
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::math::{Isometry, Real, UnitVector, Vector};
-use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
+use crate::math::{Isometry, Real, UnitVector};
+use crate::query::gjk::{self, CSOPoint, GJKInitDir, GJKResult, VoronoiSimplex};
+use crate::query::QueryError;
 use crate::shape::SupportMap;
 
 use num::Bounded;
@@ -14,25 +15,86 @@ where
     G1: SupportMap,
     G2: SupportMap,
 {
-    distance_support_map_support_map_with_params(pos12, g1, g2, &mut VoronoiSimplex::new(), None)
+    distance_support_map_support_map_with_params(
+        pos12,
+        g1,
+        g2,
+        &mut VoronoiSimplex::new(),
+        GJKInitDir::TranslationDelta,
+    )
 }
 
 /// Distance between support-mapped shapes.
 ///
 /// This allows a more fine grained control other the underlying GJK algorigtm.
+/// `init_dir` selects the initial search direction of GJK. An `Option<Vector>` is accepted as
+/// well, where `None` is [`GJKInitDir::TranslationDelta`].
+/// `pos12` must be finite. This is checked with a debug assertion, and `NaN` is returned in
+/// release mode if it isn't.
 pub fn distance_support_map_support_map_with_params<G1: ?Sized, G2: ?Sized>(
     pos12: Isometry,
     g1: &G1,
     g2: &G2,
     simplex: &mut VoronoiSimplex,
-    init_dir: Option<Vector>,
+    init_dir: impl Into<GJKInitDir>,
 ) -> Real
 where
     G1: SupportMap,
     G2: SupportMap,
 {
-    // FIXME: or m2.translation - m1.translation ?
-    let dir = init_dir.unwrap_or_else(|| -pos12.translation);
+    debug_assert!(pos12.is_finite(), "GJK: non-finite shape positions.");
+
+    match try_distance_support_map_support_map_with_params(pos12, g1, g2, simplex, init_dir) {
+        Ok(dist) => dist,
+        // Non-finite inputs are rejected before running GJK, which would otherwise not
+        // converge and stop at its iteration limit.
+        Err(QueryError::NonFiniteInput) => Real::NAN,
+        Err(_) => 0.0, // FIXME: GJK did not converge.
+    }
+}
+
+/// Distance between support-mapped shapes, reporting a failure of the GJK algorithm.
+///
+/// Unlike [`distance_support_map_support_map`], this returns `Err(QueryError::NotConverged)`
+/// instead of `0.0` if GJK reaches its iteration limit.
+pub fn try_distance_support_map_support_map<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+) -> Result<Real, QueryError>
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    try_distance_support_map_support_map_with_params(
+        pos12,
+        g1,
+        g2,
+        &mut VoronoiSimplex::new(),
+        GJKInitDir::TranslationDelta,
+    )
+}
+
+/// Distance between support-mapped shapes, reporting a failure of the GJK algorithm.
+///
+/// See [`distance_support_map_support_map_with_params`] for details about the parameters.
+/// Returns `Err(QueryError::NonFiniteInput)` if `pos12` isn't finite.
+pub fn try_distance_support_map_support_map_with_params<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+    simplex: &mut VoronoiSimplex,
+    init_dir: impl Into<GJKInitDir>,
+) -> Result<Real, QueryError>
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    if !pos12.is_finite() {
+        return Err(QueryError::NonFiniteInput);
+    }
+
+    let dir = init_dir.into().direction(pos12, g1, g2);
 
     if let Ok(dir) = UnitVector::new(dir) {
         simplex.reset(CSOPoint::from_shapes(pos12, g1, g2, dir));
@@ -41,9 +103,65 @@ where
     }
 
     match gjk::closest_points(pos12, g1, g2, Real::max_value(), true, simplex) {
-        GJKResult::Intersection => 0.0,
-        GJKResult::ClosestPoints(p1, p2, _) => p1.distance(p2),
+        GJKResult::Intersection => Ok(0.0),
+        GJKResult::ClosestPoints(p1, p2, _) => Ok(p1.distance(p2)),
+        GJKResult::Proximity(_) => unreachable!(),
+        // With an infinite `max_dist`, this is only returned when reaching the iteration limit.
+        GJKResult::NoIntersection(_) => Err(QueryError::NotConverged),
+    }
+}
+
+/// Distance between support-mapped shapes, if it is smaller than `max_dist`.
+///
+/// Returns `None` as soon as the GJK algorithm proves that the shapes are separated by a
+/// distance larger than `max_dist`.
+pub fn distance_support_map_support_map_within<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+    max_dist: Real,
+) -> Option<Real>
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    distance_support_map_support_map_within_with_params(
+        pos12,
+        g1,
+        g2,
+        max_dist,
+        &mut VoronoiSimplex::new(),
+        GJKInitDir::TranslationDelta,
+    )
+}
+
+/// Distance between support-mapped shapes, if it is smaller than `max_dist`.
+///
+/// See [`distance_support_map_support_map_with_params`] for details about the parameters.
+pub fn distance_support_map_support_map_within_with_params<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+    max_dist: Real,
+    simplex: &mut VoronoiSimplex,
+    init_dir: impl Into<GJKInitDir>,
+) -> Option<Real>
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    let dir = init_dir.into().direction(pos12, g1, g2);
+    simplex.reset(CSOPoint::from_shapes(
+        pos12,
+        g1,
+        g2,
+        UnitVector::new(dir).unwrap_or(UnitVector::X),
+    ));
+
+    match gjk::closest_points(pos12, g1, g2, max_dist, true, simplex) {
+        GJKResult::Intersection => Some(0.0),
+        GJKResult::ClosestPoints(p1, p2, _) => Some(p1.distance(p2)),
+        GJKResult::NoIntersection(_) => None,
         GJKResult::Proximity(_) => unreachable!(),
-        GJKResult::NoIntersection(_) => 0.0, // FIXME: GJK did not converge.
     }
 }
@@ -0,0 +1,86 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::PointQuery;
+use crate::shape::{ConvexPolygon, Segment};
+
+/// Computes the symmetric Hausdorff distance between the boundaries of two convex polygons.
+///
+/// This is the largest distance between a point on the boundary of one polygon and the
+/// boundary of the other polygon, i.e., the largest of the two directed Hausdorff distances
+/// computed by [`directed_hausdorff_distance_2d`].
+pub fn hausdorff_distance_2d(
+    pos12: Isometry,
+    poly1: &ConvexPolygon,
+    poly2: &ConvexPolygon,
+) -> Real {
+    directed_hausdorff_distance_2d(pos12, poly1, poly2).max(directed_hausdorff_distance_2d(
+        pos12.inverse(),
+        poly2,
+        poly1,
+    ))
+}
+
+/// Computes the directed Hausdorff distance from the boundary of `poly1` to the boundary of
+/// `poly2`.
+///
+/// This is the largest distance between a point on the boundary of `poly1` and the boundary of
+/// `poly2`. Along an edge of `poly1`, the distance to the boundary of `poly2` is convex outside
+/// of `poly2`, and is the minimum of the distances to the lines supporting its edges inside of
+/// it. So the maximum is reached either at a vertex of `poly1`, or where an edge of `poly1`
+/// is equidistant to the lines supporting two edges of `poly2`. All these candidates are tested.
+pub fn directed_hausdorff_distance_2d(
+    pos12: Isometry,
+    poly1: &ConvexPolygon,
+    poly2: &ConvexPolygon,
+) -> Real {
+    let points2: Vec<_> = poly2
+        .points()
+        .iter()
+        .map(|pt| pos12.transform_point(*pt))
+        .collect();
+    let normals2: Vec<_> = poly2
+        .normals()
+        .iter()
+        .map(|n| pos12.rotation * **n)
+        .collect();
+    let points1 = poly1.points();
+    let mut result: Real = 0.0;
+
+    // The signed distance from `pt` to the line supporting the `j`-th edge of `poly2`, positive
+    // on the inner side of that line.
+    let line_dist = |j: usize, pt: Vector| (points2[j] - pt).dot(normals2[j]);
+
+    for (i, a) in points1.iter().enumerate() {
+        let b = points1[(i + 1) % points1.len()];
+        result = result.max(distance_to_boundary(&points2, *a));
+
+        for j in 0..points2.len() {
+            for k in j + 1..points2.len() {
+                let da = line_dist(j, *a) - line_dist(k, *a);
+                let db = line_dist(j, b) - line_dist(k, b);
+
+                if da != db {
+                    let t = da / (da - db);
+
+                    if (0.0..=1.0).contains(&t) {
+                        let pt = *a + (b - *a) * t;
+                        result = result.max(distance_to_boundary(&points2, pt));
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// The distance between `pt` and the boundary of the polygon with the given vertices.
+fn distance_to_boundary(points: &[Vector], pt: Vector) -> Real {
+    let mut result = Real::MAX;
+
+    for (i, a) in points.iter().enumerate() {
+        let segment = Segment::new(*a, points[(i + 1) % points.len()]);
+        result = result.min(segment.distance_to_local_point(pt, false));
+    }
+
+    result
+}
@@ -1,30 +1,48 @@
 //! Implementation details of the `distance` function.
 
-pub use self::distance::distance;
+pub use self::closest_pair::closest_pair;
+pub use self::distance::{distance, distance_within};
 pub use self::distance_ball_ball::distance_ball_ball;
 pub use self::distance_ball_convex_polyhedron::{
     distance_ball_convex_polyhedron, distance_convex_polyhedron_ball,
 };
+pub use self::distance_ball_triangle::{distance_ball_triangle, distance_triangle_ball};
 #[cfg(feature = "std")]
 pub use self::distance_composite_shape_shape::{
     distance_composite_shape_shape, distance_shape_composite_shape,
     CompositeShapeAgainstAnyDistanceVisitor,
 };
+#[cfg(feature = "dim3")]
+pub use self::distance_cone_halfspace::{distance_cone_halfspace, distance_halfspace_cone};
 pub use self::distance_cuboid_cuboid::distance_cuboid_cuboid;
 pub use self::distance_halfspace_support_map::{
     distance_halfspace_support_map, distance_support_map_halfspace,
 };
 pub use self::distance_segment_segment::distance_segment_segment;
+#[cfg(feature = "std")]
+pub use self::distance_support_map_segment::distance_support_map_segment;
 pub use self::distance_support_map_support_map::{
     distance_support_map_support_map, distance_support_map_support_map_with_params,
+    distance_support_map_support_map_within, distance_support_map_support_map_within_with_params,
+    try_distance_support_map_support_map, try_distance_support_map_support_map_with_params,
 };
+#[cfg(all(feature = "dim2", feature = "std"))]
+pub use self::hausdorff_distance_2d::{directed_hausdorff_distance_2d, hausdorff_distance_2d};
 
+mod closest_pair;
 mod distance;
 mod distance_ball_ball;
 mod distance_ball_convex_polyhedron;
+mod distance_ball_triangle;
 #[cfg(feature = "std")]
 mod distance_composite_shape_shape;
+#[cfg(feature = "dim3")]
+mod distance_cone_halfspace;
 mod distance_cuboid_cuboid;
 mod distance_halfspace_support_map;
 mod distance_segment_segment;
+#[cfg(feature = "std")]
+mod distance_support_map_segment;
 mod distance_support_map_support_map;
+#[cfg(all(feature = "dim2", feature = "std"))]
+mod hausdorff_distance_2d;
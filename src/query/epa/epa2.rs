@@ -6,11 +6,12 @@ use std::collections::BinaryHeap;
 use num::Bounded;
 
 use crate::math::{Isometry, Real, UnitVector, Vector};
-use crate::query::gjk::{self, CSOPoint, ConstantOrigin, VoronoiSimplex};
+use crate::query::gjk::{self, CSOPoint, ConstantOrigin, SupportCache, VoronoiSimplex};
+use crate::query::QueryError;
 use crate::shape::SupportMap;
 use crate::utils;
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 struct FaceId {
     id: usize,
     neg_dist: Real,
@@ -48,7 +49,7 @@ impl Ord for FaceId {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Copy, Clone, Debug)]
 struct Face {
     pts: [usize; 2],
     normal: UnitVector,
@@ -108,9 +109,12 @@ impl Face {
 }
 
 /// The Expanding Polytope Algorithm in 2D.
+#[derive(Clone, Debug, Default)]
 pub struct EPA {
     vertices: Vec<CSOPoint>,
     faces: Vec<Face>,
+    // Indices of the slots of `faces` that are no longer referenced and can be reused.
+    free_faces: Vec<usize>,
     heap: BinaryHeap<FaceId>,
 }
 
@@ -120,6 +124,7 @@ impl EPA {
         EPA {
             vertices: Vec::new(),
             faces: Vec::new(),
+            free_faces: Vec::new(),
             heap: BinaryHeap::new(),
         }
     }
@@ -127,9 +132,30 @@ impl EPA {
     fn reset(&mut self) {
         self.vertices.clear();
         self.faces.clear();
+        self.free_faces.clear();
         self.heap.clear();
     }
 
+    /// Marks the slot of the face `id` as no longer referenced, so it can be reused.
+    fn free_face(&mut self, id: usize) {
+        debug_assert!(
+            !self.free_faces.contains(&id),
+            "EPA: a face slot was freed twice."
+        );
+        self.free_faces.push(id);
+    }
+
+    /// Stores `face`, reusing the slot of a face that is no longer referenced if possible.
+    fn push_face(&mut self, face: Face) -> usize {
+        if let Some(id) = self.free_faces.pop() {
+            self.faces[id] = face;
+            id
+        } else {
+            self.faces.push(face);
+            self.faces.len() - 1
+        }
+    }
+
     /// Projects the origin on boundary the given shape.
     ///
     /// The origin is assumed to be inside of the shape. If it is outside, use
@@ -152,10 +178,31 @@ impl EPA {
             .map(|(p, _, _)| p)
     }
 
+    /// An estimate of the size of the CSO of `g1` and `g2`, used to scale tolerances.
+    ///
+    /// Because the origin is inside of the CSO, the CSO support points along the coordinate
+    /// axes are at a distance from the origin of the same order of magnitude as the CSO extents.
+    fn characteristic_length<G1: ?Sized, G2: ?Sized>(pos12: Isometry, g1: &G1, g2: &G2) -> Real
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        [
+            UnitVector::X,
+            UnitVector::Y,
+            UnitVector::NEG_X,
+            UnitVector::NEG_Y,
+        ]
+        .into_iter()
+        .map(|dir| CSOPoint::from_shapes(pos12, g1, g2, dir).point.length())
+        .fold(0.0, Real::max)
+    }
+
     /// Projects the origin on a shape using the EPA algorithm.
     ///
     /// The origin is assumed to be located inside of the shape.
     /// Returns `None` if the EPA fails to converge or if `g1` and `g2` are not penetrating.
+    /// `pos12` must be finite, which is checked with a debug assertion.
     pub fn closest_points<G1: ?Sized, G2: ?Sized>(
         &mut self,
         pos12: Isometry,
@@ -167,8 +214,88 @@ impl EPA {
         G1: SupportMap,
         G2: SupportMap,
     {
+        debug_assert!(pos12.is_finite(), "EPA: non-finite shape positions.");
+        self.try_closest_points(pos12, g1, g2, simplex).ok()
+    }
+
+    /// Projects the origin on a shape using the EPA algorithm, reporting why it failed if it did.
+    ///
+    /// The origin is assumed to be located inside of the shape. Unlike
+    /// [`Self::closest_points`], this distinguishes non-finite inputs, non-convergence, and
+    /// degenerate configurations (including `g1` and `g2` not penetrating).
+    pub fn try_closest_points<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        simplex: &VoronoiSimplex,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError>
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        // Support directions are rotated into the local-space of `g2` in each iteration.
+        let rot21 = pos12.rotation.inverse();
+        self.try_closest_points_with_support(pos12, g1, g2, simplex, |n| {
+            CSOPoint::from_local_dirs(pos12, g1, g2, *n, rot21 * -*n)
+        })
+    }
+
+    /// Same as [`Self::try_closest_points`], but reuses the support points cached in `caches`
+    /// for the support directions of `g1` and `g2` respectively, if they are within
+    /// `cone_tolerance` (in radians) of a previous one.
+    ///
+    /// The caches are expressed in the local-space of their shape, so they can be reused across
+    /// calls with the same shapes, even if `pos12` changes. The result is an approximation whose
+    /// accuracy depends on `cone_tolerance`, as described in [`SupportCache`].
+    pub fn try_closest_points_with_support_caches<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        simplex: &VoronoiSimplex,
+        caches: &mut [SupportCache; 2],
+        cone_tolerance: Real,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError>
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        let rot21 = pos12.rotation.inverse();
+        let [cache1, cache2] = caches;
+        self.try_closest_points_with_support(pos12, g1, g2, simplex, |n| {
+            let dir2 = UnitVector::new_unchecked(rot21 * -*n);
+            CSOPoint::new(
+                cache1.support_or_cached(g1, n, cone_tolerance),
+                pos12.transform_point(cache2.support_or_cached(g2, dir2, cone_tolerance)),
+            )
+        })
+    }
+
+    /// Runs the EPA, evaluating the support point of the CSO along each face normal with
+    /// `support`.
+    fn try_closest_points_with_support<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        simplex: &VoronoiSimplex,
+        support: impl FnMut(UnitVector) -> CSOPoint,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError>
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        if !pos12.is_finite() {
+            return Err(QueryError::NonFiniteInput);
+        }
+
         let _eps: Real = crate::math::DEFAULT_EPSILON;
+        // Tolerance on cosines, which doesn't depend on the scale of the shapes.
         let _eps_tol = _eps * 100.0;
+        // Scale the tolerance on distances by the size of the CSO so that the convergence
+        // criteria remain meaningful for shapes far from unit scale.
+        let _dist_tol = _eps_tol * Self::characteristic_length(pos12, g1, g2);
 
         self.reset();
 
@@ -191,12 +318,12 @@ impl EPA {
             let orig1 = self.vertices[0].orig1;
             for _ in 0..MAX_ITERS {
                 let supp1 = g1.local_support_point(*n);
-                if let Ok(tangent) = UnitVector::new_with_min(supp1 - orig1, _eps_tol) {
+                if let Ok(tangent) = UnitVector::new_with_min(supp1 - orig1, _dist_tol) {
                     if n.dot(*tangent) < _eps_tol {
                         break;
                     }
 
-                    n = UnitVector::new_unchecked(Vector::new(-tangent.y, tangent.x));
+                    n = utils::perpendicular(tangent);
                 } else {
                     break;
                 }
@@ -206,18 +333,18 @@ impl EPA {
             let orig2 = self.vertices[0].orig2;
             for _ in 0..MAX_ITERS {
                 let supp2 = g2.support_point(pos12, *-n);
-                if let Ok(tangent) = UnitVector::new_with_min(supp2 - orig2, _eps_tol) {
+                if let Ok(tangent) = UnitVector::new_with_min(supp2 - orig2, _dist_tol) {
                     if (-n).dot(*tangent) < _eps_tol {
                         break;
                     }
 
-                    n = UnitVector::new_unchecked(Vector::new(-tangent.y, tangent.x));
+                    n = utils::perpendicular(tangent);
                 } else {
                     break;
                 }
             }
 
-            return Some((Vector::ZERO, Vector::ZERO, n));
+            return Ok((Vector::ZERO, Vector::ZERO, n));
         } else if simplex.dimension() == 2 {
             let dp1 = self.vertices[1] - self.vertices[0];
             let dp2 = self.vertices[2] - self.vertices[0];
@@ -240,17 +367,26 @@ impl EPA {
 
             if proj_is_inside1 {
                 let dist1 = self.faces[0].normal.dot(self.vertices[0].point);
-                self.heap.push(FaceId::new(0, -dist1)?);
+                self.heap
+                    .push(FaceId::new(0, -dist1).ok_or(QueryError::Degenerate)?);
+            } else {
+                self.free_face(0);
             }
 
             if proj_is_inside2 {
                 let dist2 = self.faces[1].normal.dot(self.vertices[1].point);
-                self.heap.push(FaceId::new(1, -dist2)?);
+                self.heap
+                    .push(FaceId::new(1, -dist2).ok_or(QueryError::Degenerate)?);
+            } else {
+                self.free_face(1);
             }
 
             if proj_is_inside3 {
                 let dist3 = self.faces[2].normal.dot(self.vertices[2].point);
-                self.heap.push(FaceId::new(2, -dist3)?);
+                self.heap
+                    .push(FaceId::new(2, -dist3).ok_or(QueryError::Degenerate)?);
+            } else {
+                self.free_face(2);
             }
         } else {
             let pts1 = [0, 1];
@@ -272,10 +408,24 @@ impl EPA {
             let dist1 = self.faces[0].normal.dot(self.vertices[0].point);
             let dist2 = self.faces[1].normal.dot(self.vertices[1].point);
 
-            self.heap.push(FaceId::new(0, dist1)?);
-            self.heap.push(FaceId::new(1, dist2)?);
+            self.heap
+                .push(FaceId::new(0, dist1).ok_or(QueryError::Degenerate)?);
+            self.heap
+                .push(FaceId::new(1, dist2).ok_or(QueryError::Degenerate)?);
         }
 
+        self.expand(_dist_tol, support)
+    }
+
+    /// Expands the polytope until its face closest to the origin lies on the CSO boundary, with
+    /// `support` evaluating the support point of the CSO along each face normal.
+    ///
+    /// The heap must contain at least one face.
+    fn expand(
+        &mut self,
+        dist_tol: Real,
+        mut support: impl FnMut(UnitVector) -> CSOPoint,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError> {
         let mut niter = 0;
         let mut max_dist = Real::max_value();
         let mut best_face_id = *self.heap.peek().unwrap();
@@ -285,29 +435,40 @@ impl EPA {
          */
         while let Some(face_id) = self.heap.pop() {
             // Create new faces.
-            let face = self.faces[face_id.id].clone();
+            let face = self.faces[face_id.id];
 
             if face.deleted {
+                self.free_face(face_id.id);
                 continue;
             }
 
-            let cso_point = CSOPoint::from_shapes(pos12, g1, g2, face.normal);
+            let cso_point = support(face.normal);
             let support_point_id = self.vertices.len();
             self.vertices.push(cso_point);
 
             let candidate_max_dist = cso_point.point.dot(*face.normal);
 
+            // The expanded face is replaced by the new faces below, so its slot can be reused
+            // unless it is needed to compute the final result.
             if candidate_max_dist < max_dist {
+                // The initial best face is the top of the heap, which is already freed if it
+                // was deleted.
+                if best_face_id.id != face_id.id && !self.faces[best_face_id.id].deleted {
+                    self.free_face(best_face_id.id);
+                }
+
                 best_face_id = face_id;
                 max_dist = candidate_max_dist;
+            } else {
+                self.free_face(face_id.id);
             }
 
             let curr_dist = -face_id.neg_dist;
 
-            if max_dist - curr_dist < _eps_tol {
+            if max_dist - curr_dist < dist_tol {
                 let best_face = &self.faces[best_face_id.id];
                 let cpts = best_face.closest_points(&self.vertices);
-                return Some((cpts.0, cpts.1, best_face.normal));
+                return Ok((cpts.0, cpts.1, best_face.normal));
             }
 
             let pts1 = [face.pts[0], support_point_id];
@@ -318,33 +479,32 @@ impl EPA {
                 Face::new(&self.vertices, pts2),
             ];
 
-            for f in new_faces.iter() {
-                if f.1 {
-                    let dist = f.0.normal.dot(f.0.proj);
-                    if dist < curr_dist {
-                        // FIXME: if we reach this point, there were issues due to
-                        // numerical errors.
-                        let cpts = f.0.closest_points(&self.vertices);
-                        return Some((cpts.0, cpts.1, f.0.normal));
-                    }
-
-                    if !f.0.deleted {
-                        self.heap.push(FaceId::new(self.faces.len(), -dist)?);
+            for (new_face, proj_is_inside) in new_faces {
+                if proj_is_inside {
+                    // On a convex CSO, the new faces can't be closer to the origin than the
+                    // face they replace. A smaller distance is only caused by rounding errors
+                    // on near-degenerate polytopes, so it is clamped to keep the lower bound
+                    // monotonic instead of stopping with the normal of this ill-conditioned
+                    // face. The face is still expanded later so the polytope stays closed.
+                    let dist = new_face.normal.dot(new_face.proj).max(curr_dist);
+
+                    if !new_face.deleted {
+                        let id = self.push_face(new_face);
+                        self.heap
+                            .push(FaceId::new(id, -dist).ok_or(QueryError::Degenerate)?);
                     }
                 }
-
-                self.faces.push(f.0.clone());
             }
 
             niter += 1;
             if niter > 10000 {
-                return None;
+                return Err(QueryError::NotConverged);
             }
         }
 
         let best_face = &self.faces[best_face_id.id];
         let cpts = best_face.closest_points(&self.vertices);
-        Some((cpts.0, cpts.1, best_face.normal))
+        Ok((cpts.0, cpts.1, best_face.normal))
     }
 }
 
@@ -374,3 +534,74 @@ fn project_origin(a: Vector, b: Vector) -> Option<(Vector, [Real; 2])> {
         Some((res, [1.0 - position_on_segment, position_on_segment]))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Ball;
+
+    #[test]
+    fn faces_are_reclaimed_during_expansion() {
+        // The CSO of a ball is round, so the EPA needs many expansions to converge.
+        let ball = Ball::new(1.0);
+        let point = Vector::new(0.1, 0.2);
+        let m = Isometry::new(-point, Default::default());
+        let dir = UnitVector::new(-m.translation).unwrap();
+        let mut simplex = VoronoiSimplex::new();
+        simplex.reset(CSOPoint::from_shapes(
+            m.inverse(),
+            &ball,
+            &ConstantOrigin,
+            dir,
+        ));
+        assert!(gjk::project_origin(m, &ball, &mut simplex).is_none());
+
+        let mut epa = EPA::new();
+        let proj = epa.project_origin(m, &ball, &simplex).unwrap();
+        assert!((proj.length() - 1.0).abs() < 1.0e-2);
+
+        // Each expansion adds one vertex, replaces one face by at most two new ones, and
+        // only the best face found so far is kept aside.
+        let num_expansions = epa.vertices.len() - (simplex.dimension() + 1);
+        assert!(num_expansions > 3);
+        assert!(epa.faces.len() <= epa.vertices.len() + 1);
+    }
+
+    #[test]
+    fn deleted_initial_best_face_is_freed_once() {
+        // The CSO is a unit disk. The initial polytope is a triangle, plus a degenerate face
+        // at the top of the heap which seeds the best face.
+        let ball = Ball::new(1.0);
+        let pos12 = Isometry::IDENTITY;
+        let mut epa = EPA::new();
+
+        for dir in [Vector::X, Vector::Y, Vector::new(-1.0, -1.0), Vector::X] {
+            let dir = UnitVector::new(dir).unwrap();
+            epa.vertices
+                .push(CSOPoint::from_shapes(pos12, &ball, &ConstantOrigin, dir));
+        }
+
+        let degenerate = Face::new_with_proj(&epa.vertices, Vector::ZERO, [1.0, 0.0], [0, 3]);
+        assert!(degenerate.deleted);
+        epa.faces.push(degenerate);
+        epa.heap.push(FaceId::new(0, 0.0).unwrap());
+
+        for pts in [[0, 1], [1, 2], [2, 0]] {
+            let (face, proj_is_inside) = Face::new(&epa.vertices, pts);
+            assert!(proj_is_inside);
+            let dist = face.normal.dot(face.proj);
+            epa.faces.push(face);
+            epa.heap
+                .push(FaceId::new(epa.faces.len() - 1, -dist).unwrap());
+        }
+
+        // Freeing the degenerate face twice would make two new faces share the same slot.
+        let (proj, _, _) = epa.expand(pos12, &ball, &ConstantOrigin, 1.0e-4).unwrap();
+        assert!((proj.length() - 1.0).abs() < 1.0e-2);
+
+        let mut free_faces = epa.free_faces.clone();
+        free_faces.sort_unstable();
+        free_faces.dedup();
+        assert_eq!(free_faces.len(), epa.free_faces.len());
+    }
+}
@@ -1,15 +1,15 @@
 //! Three-dimensional penetration depth queries using the Expanding Polytope Algorithm.
 
 use crate::math::{Isometry, Real, UnitVector, Vector};
-use crate::query::gjk::{self, CSOPoint, ConstantOrigin, VoronoiSimplex};
-use crate::query::PointQueryWithLocation;
+use crate::query::gjk::{self, CSOPoint, ConstantOrigin, SupportCache, VoronoiSimplex};
+use crate::query::{PointQueryWithLocation, QueryError};
 use crate::shape::{SupportMap, Triangle, TrianglePointLocation};
 use crate::utils;
 use num::Bounded;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 struct FaceId {
     id: usize,
     neg_dist: Real,
@@ -65,11 +65,11 @@ impl Face {
     ) -> Self {
         let normal;
 
-        if let Ok(n) = utils::ccw_face_normal([
+        if let Ok(n) = utils::triangle_normal(
             vertices[pts[0]].point,
             vertices[pts[1]].point,
             vertices[pts[2]].point,
-        ]) {
+        ) {
             normal = n;
         } else {
             // This is a bit of a hack for degenerate faces.
@@ -146,6 +146,7 @@ impl Face {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
 struct SilhouetteEdge {
     face_id: usize,
     opp_pt_id: usize,
@@ -158,6 +159,7 @@ impl SilhouetteEdge {
 }
 
 /// The Expanding Polytope Algorithm in 3D.
+#[derive(Clone, Debug, Default)]
 pub struct EPA {
     vertices: Vec<CSOPoint>,
     faces: Vec<Face>,
@@ -208,6 +210,7 @@ impl EPA {
     ///
     /// The origin is assumed to be located inside of the shape.
     /// Returns `None` if the EPA fails to converge or if `g1` and `g2` are not penetrating.
+    /// `pos12` must be finite, which is checked with a debug assertion.
     pub fn closest_points<G1: ?Sized, G2: ?Sized>(
         &mut self,
         pos12: Isometry,
@@ -219,6 +222,82 @@ impl EPA {
         G1: SupportMap,
         G2: SupportMap,
     {
+        debug_assert!(pos12.is_finite(), "EPA: non-finite shape positions.");
+        self.try_closest_points(pos12, g1, g2, simplex).ok()
+    }
+
+    /// Projects the origin on a shape using the EPA algorithm, reporting why it failed if it did.
+    ///
+    /// The origin is assumed to be located inside of the shape. Unlike
+    /// [`Self::closest_points`], this distinguishes non-finite inputs, non-convergence, and
+    /// degenerate configurations (including `g1` and `g2` not penetrating).
+    pub fn try_closest_points<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        simplex: &VoronoiSimplex,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError>
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        // Support directions are rotated into the local-space of `g2` in each iteration.
+        let rot21 = pos12.rotation.inverse();
+        self.try_closest_points_with_support(pos12, g1, g2, simplex, |n| {
+            CSOPoint::from_local_dirs(pos12, g1, g2, *n, rot21 * -*n)
+        })
+    }
+
+    /// Same as [`Self::try_closest_points`], but reuses the support points cached in `caches`
+    /// for the support directions of `g1` and `g2` respectively, if they are within
+    /// `cone_tolerance` (in radians) of a previous one.
+    ///
+    /// The caches are expressed in the local-space of their shape, so they can be reused across
+    /// calls with the same shapes, even if `pos12` changes. The result is an approximation whose
+    /// accuracy depends on `cone_tolerance`, as described in [`SupportCache`].
+    pub fn try_closest_points_with_support_caches<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        simplex: &VoronoiSimplex,
+        caches: &mut [SupportCache; 2],
+        cone_tolerance: Real,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError>
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        let rot21 = pos12.rotation.inverse();
+        let [cache1, cache2] = caches;
+        self.try_closest_points_with_support(pos12, g1, g2, simplex, |n| {
+            let dir2 = UnitVector::new_unchecked(rot21 * -*n);
+            CSOPoint::new(
+                cache1.support_or_cached(g1, n, cone_tolerance),
+                pos12.transform_point(cache2.support_or_cached(g2, dir2, cone_tolerance)),
+            )
+        })
+    }
+
+    /// Runs the EPA, evaluating the support point of the CSO along each face normal with
+    /// `support`.
+    fn try_closest_points_with_support<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        simplex: &VoronoiSimplex,
+        mut support: impl FnMut(UnitVector) -> CSOPoint,
+    ) -> Result<(Vector, Vector, UnitVector), QueryError>
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        if !pos12.is_finite() {
+            return Err(QueryError::NonFiniteInput);
+        }
+
         let _eps = crate::math::DEFAULT_EPSILON;
         let _eps_tol = _eps * 100.0;
 
@@ -234,7 +313,7 @@ impl EPA {
         if simplex.dimension() == 0 {
             let mut n = Vector::ZERO;
             n[1] = 1.0;
-            return Some((Vector::ZERO, Vector::ZERO, UnitVector::new_unchecked(n)));
+            return Ok((Vector::ZERO, Vector::ZERO, UnitVector::new_unchecked(n)));
         } else if simplex.dimension() == 3 {
             let dp1 = self.vertices[1] - self.vertices[0];
             let dp2 = self.vertices[2] - self.vertices[0];
@@ -266,22 +345,26 @@ impl EPA {
 
             if proj_inside1 {
                 let dist1 = self.faces[0].normal.dot(self.vertices[0].point);
-                self.heap.push(FaceId::new(0, -dist1)?);
+                self.heap
+                    .push(FaceId::new(0, -dist1).ok_or(QueryError::Degenerate)?);
             }
 
             if proj_inside2 {
                 let dist2 = self.faces[1].normal.dot(self.vertices[1].point);
-                self.heap.push(FaceId::new(1, -dist2)?);
+                self.heap
+                    .push(FaceId::new(1, -dist2).ok_or(QueryError::Degenerate)?);
             }
 
             if proj_inside3 {
                 let dist3 = self.faces[2].normal.dot(self.vertices[2].point);
-                self.heap.push(FaceId::new(2, -dist3)?);
+                self.heap
+                    .push(FaceId::new(2, -dist3).ok_or(QueryError::Degenerate)?);
             }
 
             if proj_inside4 {
                 let dist4 = self.faces[3].normal.dot(self.vertices[3].point);
-                self.heap.push(FaceId::new(3, -dist4)?);
+                self.heap
+                    .push(FaceId::new(3, -dist4).ok_or(QueryError::Degenerate)?);
             }
         } else {
             if simplex.dimension() == 1 {
@@ -303,8 +386,10 @@ impl EPA {
             self.faces.push(face1);
             self.faces.push(face2);
 
-            self.heap.push(FaceId::new(0, 0.0)?);
-            self.heap.push(FaceId::new(1, 0.0)?);
+            self.heap
+                .push(FaceId::new(0, 0.0).ok_or(QueryError::Degenerate)?);
+            self.heap
+                .push(FaceId::new(1, 0.0).ok_or(QueryError::Degenerate)?);
         }
 
         let mut niter = 0;
@@ -322,7 +407,7 @@ impl EPA {
                 continue;
             }
 
-            let cso_point = CSOPoint::from_shapes(pos12, g1, g2, face.normal);
+            let cso_point = support(face.normal);
             let support_point_id = self.vertices.len();
             self.vertices.push(cso_point);
 
@@ -338,7 +423,7 @@ impl EPA {
             if max_dist - curr_dist < _eps_tol {
                 let best_face = &self.faces[best_face_id.id];
                 let points = best_face.closest_points(&self.vertices);
-                return Some((points.0, points.1, best_face.normal));
+                return Ok((points.0, points.1, best_face.normal));
             }
 
             self.faces[face_id.id].deleted = true;
@@ -355,7 +440,7 @@ impl EPA {
 
             if self.silhouette.len() == 0 {
                 // FIXME: Something went very wrong because we failed to extract a silhouette…
-                return None;
+                return Err(QueryError::Degenerate);
             }
 
             for edge in &self.silhouette {
@@ -385,10 +470,11 @@ impl EPA {
                             // FIXME: if we reach this point, there were issues due to
                             // numerical errors.
                             let points = face.closest_points(&self.vertices);
-                            return Some((points.0, points.1, face.normal));
+                            return Ok((points.0, points.1, face.normal));
                         }
 
-                        self.heap.push(FaceId::new(new_face_id, -dist)?);
+                        self.heap
+                            .push(FaceId::new(new_face_id, -dist).ok_or(QueryError::Degenerate)?);
                     }
                 }
             }
@@ -396,7 +482,7 @@ impl EPA {
             if first_new_face_id == self.faces.len() {
                 // Something went very wrong because all the edges
                 // from the silhouette belonged to deleted faces.
-                return None;
+                return Err(QueryError::Degenerate);
             }
 
             self.faces[first_new_face_id].adj[2] = self.faces.len() - 1;
@@ -407,13 +493,13 @@ impl EPA {
 
             niter += 1;
             if niter > 10000 {
-                return None;
+                return Err(QueryError::NotConverged);
             }
         }
 
         let best_face = &self.faces[best_face_id.id];
         let points = best_face.closest_points(&self.vertices);
-        Some((points.0, points.1, best_face.normal))
+        Ok((points.0, points.1, best_face.normal))
     }
 
     fn compute_silhouette(&mut self, point: usize, id: usize, opp_pt_id: usize) {
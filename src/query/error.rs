@@ -12,3 +12,31 @@ impl fmt::Display for Unsupported {
 
 #[cfg(feature = "std")]
 impl std::error::Error for Unsupported {}
+
+/// Error indicating that an iterative query algorithm failed to compute its result.
+///
+/// This is distinct from the geometric outcomes of a query (e.g., the shapes not
+/// intersecting) and signals that the returned value, if any, can't be trusted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum QueryError {
+    /// Some of the query inputs, e.g. the shape positions, are not finite.
+    NonFiniteInput,
+    /// The algorithm reached its iteration limit before converging.
+    NotConverged,
+    /// The algorithm reached a degenerate configuration, typically because of numerical
+    /// errors, or because its preconditions were not met.
+    Degenerate,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::NonFiniteInput => f.pad("query inputs are not finite"),
+            QueryError::NotConverged => f.pad("query algorithm did not converge"),
+            QueryError::Degenerate => f.pad("query algorithm reached a degenerate configuration"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QueryError {}
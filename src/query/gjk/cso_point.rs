@@ -73,8 +73,29 @@ impl CSOPoint {
         G1: SupportMap,
         G2: SupportMap,
     {
-        let sp1 = g1.local_support_point(*dir);
-        let sp2 = g2.support_point(pos12, *-dir);
+        Self::from_local_dirs(pos12, g1, g2, *dir, pos12.rotation.inverse() * *-dir)
+    }
+
+    /// Computes the support point of the CSO of `g1` and `g2` from support directions already
+    /// expressed in the local-space of each shape.
+    ///
+    /// The support point of `g1` is computed toward `dir1`, and `local_dir2` must be `-dir1`
+    /// expressed in the local-space of `g2`, i.e., `pos12.rotation.inverse() * -dir1`. Loops
+    /// computing many support points can invert `pos12.rotation` once and call this instead of
+    /// [`Self::from_shapes`], which inverts it on each call.
+    pub fn from_local_dirs<G1: ?Sized, G2: ?Sized>(
+        pos12: Isometry,
+        g1: &G1,
+        g2: &G2,
+        dir1: Vector,
+        local_dir2: Vector,
+    ) -> Self
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        let sp1 = g1.local_support_point(dir1);
+        let sp2 = pos12.transform_point(g2.local_support_point(local_dir2));
 
         CSOPoint::new(sp1, sp2)
     }
@@ -1,6 +1,6 @@
 //! The Gilbert–Johnson–Keerthi distance algorithm.
 
-use crate::math::{Isometry, Real, UnitVector, Vector, DIM};
+use crate::math::{AnyVector, Isometry, Real, UnitVector, Vector, DIM};
 use crate::query::gjk::{CSOPoint, ConstantOrigin, VoronoiSimplex};
 use crate::query::{self, Ray};
 use crate::shape::SupportMap;
@@ -29,6 +29,68 @@ pub enum GJKResult {
     NoIntersection(UnitVector),
 }
 
+/// Strategy used to choose the initial search direction of the GJK algorithm.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum GJKInitDir {
+    /// The direction from the origin of the second shape to the origin of the first shape,
+    /// i.e., `-pos12.translation`.
+    #[default]
+    TranslationDelta,
+    /// The direction from the center of the second shape to the center of the first shape.
+    ///
+    /// The centers are the centers of the local AABBs of the shapes, computed with `2 * DIM`
+    /// support function evaluations per shape. This is a better seed than
+    /// [`GJKInitDir::TranslationDelta`] for shapes lying far from their local origin.
+    CenterDelta,
+    /// A custom direction expressed in the local-space of the first shape, e.g., the separating
+    /// axis found by a previous query.
+    Custom(Vector),
+}
+
+impl From<Option<Vector>> for GJKInitDir {
+    /// Maps `None` to [`GJKInitDir::TranslationDelta`] and `Some(dir)` to
+    /// [`GJKInitDir::Custom`], matching the former `init_dir: Option<Vector>` parameters.
+    fn from(dir: Option<Vector>) -> Self {
+        match dir {
+            Some(dir) => GJKInitDir::Custom(dir),
+            None => GJKInitDir::TranslationDelta,
+        }
+    }
+}
+
+impl GJKInitDir {
+    /// The initial search direction, expressed in the local-space of `g1`.
+    ///
+    /// The returned vector isn't normalized and may be zero.
+    pub fn direction<G1: ?Sized, G2: ?Sized>(self, pos12: Isometry, g1: &G1, g2: &G2) -> Vector
+    where
+        G1: SupportMap,
+        G2: SupportMap,
+    {
+        match self {
+            GJKInitDir::TranslationDelta => -pos12.translation,
+            GJKInitDir::CenterDelta => {
+                support_map_center(g1) - pos12.transform_point(support_map_center(g2))
+            }
+            GJKInitDir::Custom(dir) => dir,
+        }
+    }
+}
+
+/// The center of the local AABB of a support-mapped shape.
+fn support_map_center<G: ?Sized + SupportMap>(g: &G) -> Vector {
+    let mut center = Vector::ZERO;
+
+    for i in 0..DIM {
+        let axis = Vector::ith(i, 1.0);
+        let max = g.local_support_point(axis)[i];
+        let min = g.local_support_point(-axis)[i];
+        center[i] = (min + max) / 2.0;
+    }
+
+    center
+}
+
 /// The absolute tolerence used by the GJK algorithm.
 pub const EPS_TOLERANCE: Real = 10.0 * crate::math::DEFAULT_EPSILON;
 
@@ -103,6 +165,8 @@ where
     let mut max_bound = Real::max_value();
     let mut dir;
     let mut niter = 0;
+    // Support directions are rotated into the local-space of `g2` in each iteration.
+    let rot21 = pos12.rotation.inverse();
 
     loop {
         let old_max_bound = max_bound;
@@ -124,7 +188,7 @@ where
             }
         }
 
-        let cso_point = CSOPoint::from_shapes(pos12, g1, g2, dir);
+        let cso_point = CSOPoint::from_local_dirs(pos12, g1, g2, *dir, rot21 * -*dir);
         let min_bound = -dir.dot(cso_point.point);
 
         assert!(min_bound.is_finite());
@@ -255,6 +319,7 @@ where
     let mut dir;
     let mut niter = 0;
     let mut last_chance = false;
+    let rot21 = pos12.rotation.inverse();
 
     loop {
         let old_max_bound = max_bound;
@@ -271,7 +336,7 @@ where
             last_chance = true;
             CSOPoint::single_point(proj + curr_ray.origin)
         } else {
-            CSOPoint::from_shapes(pos12, g1, g2, dir)
+            CSOPoint::from_local_dirs(pos12, g1, g2, *dir, rot21 * -*dir)
         };
 
         if last_chance && ltoi > 0.0 {
@@ -7,10 +7,12 @@ pub use self::voronoi_simplex2::VoronoiSimplex;
 pub use self::voronoi_simplex3::VoronoiSimplex;
 pub use gjk::*;
 pub use special_support_maps::*;
+pub use support_cache::{SupportCache, SUPPORT_CACHE_CAPACITY};
 
 mod cso_point;
 mod gjk;
 mod special_support_maps;
+mod support_cache;
 #[cfg(feature = "dim2")]
 mod voronoi_simplex2;
 #[cfg(feature = "dim3")]
@@ -0,0 +1,87 @@
+use crate::math::{Real, UnitVector, Vector};
+use crate::shape::SupportMap;
+use arrayvec::ArrayVec;
+
+/// The maximum number of support points remembered by a [`SupportCache`].
+pub const SUPPORT_CACHE_CAPACITY: usize = 8;
+
+/// A cache of the support points recently computed for a single shape.
+///
+/// Algorithms like EPA query the support function of a shape along many nearby directions. The
+/// cache returns the support point computed for a previous direction if the new direction lies
+/// within a small cone around it, skipping the support function evaluation. See
+/// [`EPA::try_closest_points_with_support_caches`](crate::query::epa::EPA::try_closest_points_with_support_caches).
+///
+/// A cached support point is only an approximation: for a direction forming an angle `θ` with
+/// the cached one, the returned point can be behind the exact support point by up to
+/// `4 * r * sin(θ / 2)` along the new direction, where `r` is the radius of a bounding sphere
+/// of the shape centered at its local origin. Polytopes often have the same support vertex for
+/// both directions, in which case the result is exact.
+///
+/// All the directions and points are expressed in the local-space of the shape. The cache must
+/// be [cleared](Self::clear) before being used with another shape.
+#[derive(Clone, Debug, Default)]
+pub struct SupportCache {
+    entries: ArrayVec<(UnitVector, Vector), SUPPORT_CACHE_CAPACITY>,
+    next: usize,
+    hits: usize,
+    misses: usize,
+}
+
+impl SupportCache {
+    /// Creates an empty support cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the support point of `shape` along `dir`, or a cached one computed for a
+    /// direction forming an angle smaller than `cone_tolerance` (in radians) with `dir`.
+    ///
+    /// On a cache miss, the support point is computed and replaces the oldest cache entry if
+    /// the cache is full.
+    pub fn support_or_cached<S: ?Sized + SupportMap>(
+        &mut self,
+        shape: &S,
+        dir: UnitVector,
+        cone_tolerance: Real,
+    ) -> Vector {
+        let min_cos = cone_tolerance.cos();
+
+        if let Some((_, pt)) = self
+            .entries
+            .iter()
+            .filter(|(cached_dir, _)| cached_dir.dot(*dir) >= min_cos)
+            .max_by(|a, b| a.0.dot(*dir).total_cmp(&b.0.dot(*dir)))
+        {
+            self.hits += 1;
+            return *pt;
+        }
+
+        self.misses += 1;
+        let pt = shape.local_support_point_toward(dir);
+
+        if self.entries.is_full() {
+            self.entries[self.next] = (dir, pt);
+            self.next = (self.next + 1) % SUPPORT_CACHE_CAPACITY;
+        } else {
+            self.entries.push((dir, pt));
+        }
+
+        pt
+    }
+
+    /// The number of queries answered with a cached support point.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of queries that required evaluating the support function.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Removes all the cached support points and resets the hit and miss counters.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -18,6 +18,12 @@ pub struct VoronoiSimplex {
     dim: usize,
 }
 
+impl Default for VoronoiSimplex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl VoronoiSimplex {
     /// Creates a new empty simplex.
     pub fn new() -> VoronoiSimplex {
@@ -324,6 +330,31 @@ impl VoronoiSimplex {
         self.prev_dim
     }
 
+    /// A measure, in `[0, 1]`, of how well-conditioned the last projection of the origin on this
+    /// simplex is.
+    ///
+    /// This is the ratio between the smallest and the largest barycentric coordinate computed by
+    /// the last call to `project_origin_and_reduce`. Values close to zero mean that one of the
+    /// vertices barely contributes to the projection, i.e., that it lies very close to a smaller
+    /// sub-simplex. This happens in nearly degenerate configurations, where results computed
+    /// by GJK are less reliable. Returns `1.0` if this simplex is a single point, or if it
+    /// has dimension 3 and contains the origin.
+    pub fn conditioning(&self) -> Real {
+        if self.dim == 0 || self.dim == 3 {
+            return 1.0;
+        }
+
+        let coords = &self.proj[..self.dim + 1];
+        let min = coords.iter().copied().fold(Real::MAX, Real::min);
+        let max = coords.iter().copied().fold(0.0, Real::max);
+
+        if max > 0.0 {
+            min / max
+        } else {
+            0.0
+        }
+    }
+
     /// The maximum squared length of the vertices of this simplex.
     pub fn max_sq_len(&self) -> Real {
         let mut max_sq_len = 0.0;
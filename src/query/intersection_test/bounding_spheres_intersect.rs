@@ -0,0 +1,20 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Real};
+use crate::shape::Shape;
+
+/// Tests whether the bounding spheres of two shapes are closer than `margin`.
+///
+/// This is a cheap conservative pre-filter: if it returns `false` then the shapes cannot be
+/// closer than `margin` and a precise query like [`intersection_test()`](crate::query::intersection_test)
+/// or [`contact()`](crate::query::contact) can be skipped.
+pub fn bounding_spheres_intersect(
+    pos1: Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    margin: Real,
+) -> bool {
+    let sphere1 = g1.compute_bounding_sphere(pos1).loosened(margin);
+    let sphere2 = g2.compute_bounding_sphere(pos2);
+    sphere1.intersects(&sphere2)
+}
@@ -0,0 +1,48 @@
+use crate::math::{Isometry, Real, UnitVector};
+use crate::query::{ClosestPoints, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// Tests whether `shape` fits through the gap between two obstacles while moving along
+/// `path_dir`.
+///
+/// The gap is the vector between the closest points of the two obstacles, without its component
+/// along `path_dir`. The shape, oriented like its local frame, passes through if its width
+/// along the gap is smaller than the length of the gap. The width is exact for support-mapped
+/// shapes, and is computed from the local [`Aabb`](crate::bounding_volume::Aabb) of other
+/// shapes, which can overestimate it.
+///
+/// Returns `false` if the obstacles intersect, or if they are lined up along `path_dir` so no
+/// gap crosses the path.
+pub fn can_pass_through(
+    shape: &dyn Shape,
+    path_dir: UnitVector,
+    pos1: Isometry,
+    obstacle1: &dyn Shape,
+    pos2: Isometry,
+    obstacle2: &dyn Shape,
+) -> Result<bool, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    let (pt1, pt2) =
+        match DefaultQueryDispatcher.closest_points(pos12, obstacle1, obstacle2, Real::MAX)? {
+            ClosestPoints::WithinMargin(pt1, pt2) => {
+                (pos1.transform_point(pt1), pos2.transform_point(pt2))
+            }
+            ClosestPoints::Intersecting | ClosestPoints::Disjoint => return Ok(false),
+        };
+
+    let delta = pt2 - pt1;
+    let gap = delta - *path_dir * delta.dot(*path_dir);
+    let (gap_dir, gap_length) = match UnitVector::new_and_length(gap) {
+        Ok(gap_dir_and_length) => gap_dir_and_length,
+        Err(_) => return Ok(false),
+    };
+
+    let width = if let Some(support_map) = shape.as_support_map() {
+        (support_map.local_support_point(*gap_dir) - support_map.local_support_point(-*gap_dir))
+            .dot(*gap_dir)
+    } else {
+        shape.compute_local_aabb().extents().dot(gap_dir.abs())
+    };
+
+    Ok(width < gap_length)
+}
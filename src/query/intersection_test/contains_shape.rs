@@ -0,0 +1,36 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{AnyVector, Isometry, Vector, DIM};
+use crate::shape::Shape;
+
+/// Tests whether `g2` lies entirely inside of `g1`.
+///
+/// Unlike [`intersection_test()`](crate::query::intersection_test), this returns `false` if
+/// `g2` pokes outside of `g1`, even when they overlap. The test is exact when `g1` is a
+/// [`Cuboid`](crate::shape::Cuboid) and `g2` is a support-mapped shape: the support points of
+/// `g2` along each face normal of the cuboid must not go beyond that face.
+///
+/// Otherwise the test is conservative: it checks that the bounding sphere of `g2` is inside
+/// of the inscribed sphere of `g1`, so it might return `false` for a contained shape. It
+/// always returns `false` if `g1` doesn't provide an inscribed sphere.
+pub fn contains_shape(pos1: Isometry, g1: &dyn Shape, pos2: Isometry, g2: &dyn Shape) -> bool {
+    let pos12 = pos1.inv_mul(pos2);
+
+    if let (Some(cuboid), Some(support_map2)) = (g1.as_cuboid(), g2.as_support_map()) {
+        for i in 0..DIM {
+            let axis = Vector::ith(i, 1.0);
+
+            let max = support_map2.support_point(pos12, axis)[i];
+            let min = support_map2.support_point(pos12, -axis)[i];
+
+            if max > cuboid.half_extents[i] || min < -cuboid.half_extents[i] {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    g1.local_inscribed_sphere().map_or(false, |inscribed| {
+        inscribed.contains(&g2.compute_bounding_sphere(pos12))
+    })
+}
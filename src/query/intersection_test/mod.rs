@@ -1,5 +1,8 @@
 //! Implementation details of the `intersection_test` function.
 
+pub use self::bounding_spheres_intersect::bounding_spheres_intersect;
+pub use self::can_pass_through::can_pass_through;
+pub use self::contains_shape::contains_shape;
 pub use self::intersection_test::intersection_test;
 pub use self::intersection_test_ball_ball::intersection_test_ball_ball;
 pub use self::intersection_test_ball_point_query::{
@@ -27,6 +30,9 @@ pub use self::intersection_test_halfspace_support_map::{
 pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map;
 pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map_with_params;
 
+mod bounding_spheres_intersect;
+mod can_pass_through;
+mod contains_shape;
 mod intersection_test;
 mod intersection_test_ball_ball;
 mod intersection_test_ball_point_query;
@@ -4,11 +4,36 @@
 //! The most general methods provided by this module are:
 //!
 //! * [`closest_points()`] to compute the closest points between two shapes.
+//! * [`closest_points_robust()`] to compute the closest points between two shapes, with a single well-defined witness point when they barely touch.
 //! * [`distance()`] to compute the distance between two shapes.
+//! * [`distance_within()`] to compute the distance between two shapes, unless they are too far apart.
+//! * [`closest_pair()`] to find the two closest shapes among a set of shapes.
+//! * `hausdorff_distance_2d()` to compute the Hausdorff distance between the boundaries of two convex polygons (2D only).
 //! * [`contact()`] to compute one pair of contact points between two shapes, including penetrating contact.
+//! * [`contact_at_predicted()`] to compute the contact between two shapes at the poses they reach after translating for a timestep.
+//! * [`mtv()`] to compute the minimum translation separating two penetrating shapes.
+//! * [`deepest_penetration_point()`] to find the point of a shape penetrating the deepest into another.
+//! * [`cuboid_penetration()`] to compute the penetration of an oriented cuboid into an axis-aligned box, e.g., a voxel.
+//! * [`touch_status()`] to distinguish exactly touching shapes from separated or penetrating ones.
+//! * [`contact_manifold()`] to compute a full contact manifold between two shapes.
+//! * [`contact_manifold_along_normal()`] to compute a contact manifold along a contact normal imposed by the caller.
 //! * [`intersection_test()`] to determine if two shapes are intersecting or not.
+//! * [`bounding_spheres_intersect()`] to cheaply reject pairs of shapes before a precise query.
+//! * [`contains_shape()`] to determine if a shape lies entirely inside of another.
+//! * [`can_pass_through()`] to determine if a shape fits through the gap between two obstacles.
+//! * `intersection_polygon_2d()` to compute the polygon where two convex polygons overlap (2D only).
+//! * [`swept_ball_closest_point()`] to find the point of the volume swept by a moving ball that is the closest to a target.
 //! * [`time_of_impact()`] to determine when two shapes undergoing translational motions hit for the first time.
+//! * [`cast_shape_single()`] to sweep a convex shape against another one and get the witness points and normal of the hit.
+//! * [`toi_ball_ball()`] to determine when two balls moving with constant velocities hit, in closed form.
+//! * [`directional_distance()`] to determine how far a shape has to move along a direction to touch another.
+//! * [`max_safe_velocity()`] to clamp the velocity of a shape so it does not tunnel through obstacles in one step.
+//! * [`required_margin()`] to compute how much bounding volumes must be enlarged not to miss collisions at a given speed.
+//! * [`time_of_separation()`] to determine when two overlapping shapes undergoing translational motions stop overlapping.
+//! * [`time_to_leave_aabb()`] to determine when a translating shape first pokes outside of an `Aabb` region.
 //! * [`nonlinear_time_of_impact()`] to determine when two shapes undergoing continuous rigid motions hit for the first time.
+//! * [`time_of_impact_path()`] to determine when a shape following an arbitrary path hits another one for the first time.
+//! * [`time_of_impact_pivot()`] to determine the angle at which a shape rotating about a pivot, e.g., a door, hits another one for the first time.
 //!
 //! Ray-casting and point-projection can be achieved by importing traits:
 //!
@@ -25,24 +50,45 @@
 //! * `[shape1]` is the type of the first shape passed to the function, e.g., `ball`, or `halfspace`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 //! * `[shape2]` is the type of the second shape passed to the function, e.g., `ball`, or `halfspace`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 
-pub use self::closest_points::{closest_points, ClosestPoints};
-pub use self::contact::{contact, Contact};
+#[cfg(all(feature = "dim2", feature = "std"))]
+pub use self::clip::intersection_polygon_2d;
+pub use self::closest_points::{closest_points, closest_points_robust, ClosestPoints};
+pub use self::contact::{
+    contact, contact_at_predicted, cuboid_penetration, deepest_penetration_point, mtv,
+    touch_status, Contact, TouchStatus,
+};
 #[cfg(feature = "std")]
 pub use self::contact_manifolds::{
-    ContactManifold, ContactManifoldsWorkspace, TrackedContact, TypedWorkspaceData, WorkspaceData,
+    contact_manifold, contact_manifold_along_normal, ContactManifold, ContactManifoldsWorkspace,
+    TrackedContact, TypedWorkspaceData, WorkspaceData,
 };
 pub use self::default_query_dispatcher::DefaultQueryDispatcher;
-pub use self::distance::distance;
-pub use self::error::Unsupported;
-pub use self::intersection_test::intersection_test;
-pub use self::nonlinear_time_of_impact::{nonlinear_time_of_impact, NonlinearRigidMotion};
-pub use self::point::{PointProjection, PointQuery, PointQueryWithLocation};
+pub use self::distance::{closest_pair, distance, distance_within};
+#[cfg(all(feature = "dim2", feature = "std"))]
+pub use self::distance::{directed_hausdorff_distance_2d, hausdorff_distance_2d};
+pub use self::error::{QueryError, Unsupported};
+pub use self::intersection_test::{
+    bounding_spheres_intersect, can_pass_through, contains_shape, intersection_test,
+};
+pub use self::nonlinear_time_of_impact::{
+    nonlinear_time_of_impact, time_of_impact_ca, time_of_impact_path, time_of_impact_pivot,
+    NonlinearRigidMotion,
+};
+pub use self::point::{
+    swept_ball_closest_point, PointProjection, PointQuery, PointQueryWithLocation, PosedPointQuery,
+};
 #[cfg(feature = "std")]
 pub use self::query_dispatcher::PersistentQueryDispatcher;
 pub use self::query_dispatcher::{QueryDispatcher, QueryDispatcherChain};
+pub use self::query_workspace::QueryWorkspace;
+#[cfg(feature = "dim2")]
+pub use self::ray::ray_polygon_boundary_intersections;
 pub use self::ray::{Ray, RayCast, RayIntersection, SimdRay};
 pub use self::split::{IntersectResult, SplitResult};
-pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
+pub use self::time_of_impact::{
+    cast_shape_single, directional_distance, max_safe_velocity, required_margin, time_of_impact,
+    time_of_separation, time_to_leave_aabb, toi_ball_ball, ShapeCastHit, TOIStatus, TOI,
+};
 
 mod clip;
 pub mod closest_points;
@@ -59,6 +105,7 @@ mod intersection_test;
 mod nonlinear_time_of_impact;
 pub mod point;
 mod query_dispatcher;
+mod query_workspace;
 mod ray;
 pub mod sat;
 mod split;
@@ -11,6 +11,9 @@ pub use self::nonlinear_time_of_impact::nonlinear_time_of_impact;
 pub use self::nonlinear_time_of_impact_support_map_support_map::{
     nonlinear_time_of_impact_support_map_support_map, NonlinearTOIMode,
 };
+pub use self::time_of_impact_ca::time_of_impact_ca;
+pub use self::time_of_impact_path::time_of_impact_path;
+pub use self::time_of_impact_pivot::time_of_impact_pivot;
 
 #[cfg(feature = "std")]
 mod nonlinear_time_of_impact_composite_shape_shape;
@@ -18,3 +21,6 @@ mod nonlinear_time_of_impact_composite_shape_shape;
 mod nonlinear_rigid_motion;
 mod nonlinear_time_of_impact;
 mod nonlinear_time_of_impact_support_map_support_map;
+mod time_of_impact_ca;
+mod time_of_impact_path;
+mod time_of_impact_pivot;
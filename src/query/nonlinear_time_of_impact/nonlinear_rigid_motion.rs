@@ -58,7 +58,8 @@ impl NonlinearRigidMotion {
     fn set_start(&mut self, new_start: Isometry) {
         // NOTE: we need to adjust the local_center so that the angular
         // velocity is still expressed wrt. the original center.
-        self.local_center = new_start.inverse_transform_point(self.start * self.local_center);
+        self.local_center =
+            new_start.inverse_transform_point(self.start.transform_point(self.local_center));
         self.start = new_start;
     }
 
@@ -79,7 +80,7 @@ impl NonlinearRigidMotion {
     pub fn append_translation(&self, tra: Vector) -> Self {
         let mut result = self.clone();
         result.set_start(Isometry {
-            translation: result.start * tra,
+            translation: result.start.translation + tra,
             rotation: result.start.rotation,
         });
         result
@@ -114,13 +115,14 @@ impl NonlinearRigidMotion {
 
     /// Computes the position at time `t` of a rigid-body following the motion described by `self`.
     pub fn position_at_time(&self, t: Real) -> Isometry {
-        let center = self.start * self.local_center;
+        // Rotate around the world-space center, then translate.
+        let center = self.start.transform_point(self.local_center);
         let iso = Isometry::new(self.linvel * t, self.angvel * t);
         (Isometry {
-            translation: iso * center,
+            translation: center + iso.translation,
             rotation: iso.rotation,
         }) * Isometry {
-            translation: self.start * -center,
+            translation: self.start.translation - center,
             rotation: self.start.rotation,
         }
     }
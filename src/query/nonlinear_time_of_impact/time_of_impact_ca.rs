@@ -0,0 +1,98 @@
+use crate::math::{AngVector, Isometry, Real, UnitVector, Vector};
+use crate::query::time_of_impact::{conservative_advancement, ConservativeAdvancement};
+use crate::query::{
+    self, DefaultQueryDispatcher, NonlinearRigidMotion, QueryDispatcher, TOIStatus, Unsupported,
+    TOI,
+};
+use crate::shape::Shape;
+
+/// Computes the smallest time of impact of two convex shapes under translational and rotational
+/// movements, using conservative advancement.
+///
+/// Each shape moves with a constant linear velocity and rotates with a constant angular
+/// velocity around its local origin. At each step, the shapes are advanced by the largest time
+/// step that can't make them penetrate: the approach speed along the current separating
+/// direction is bounded by the linear relative velocity, plus the angular speed of each shape
+/// times the radius of its bounding sphere around its local origin. This bound only holds for
+/// convex shapes, so `Err(Unsupported)` is returned if one of the shapes isn't known to be
+/// convex. If the advancement doesn't converge, the remaining motion is checked with
+/// [`query::nonlinear_time_of_impact`].
+///
+/// Returns `None` if the shapes don't touch before `max_toi`. If the shapes are penetrating at
+/// the time 0, a time of impact of `0.0` with the `TOIStatus::Penetrating` status is returned.
+pub fn time_of_impact_ca(
+    pos1: Isometry,
+    vel1: Vector,
+    omega1: AngVector,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    vel2: Vector,
+    omega2: AngVector,
+    g2: &dyn Shape,
+    max_toi: Real,
+) -> Result<Option<TOI>, Unsupported> {
+    if !g1.is_convex() || !g2.is_convex() {
+        return Err(Unsupported);
+    }
+
+    let tolerance = query::gjk::EPS_TOLERANCE * 100.0;
+    let motion1 = NonlinearRigidMotion::new(pos1, Vector::ZERO, vel1, omega1);
+    let motion2 = NonlinearRigidMotion::new(pos2, Vector::ZERO, vel2, omega2);
+
+    // Bound the displacement of any point of the shapes due to their rotations.
+    let angular_bound = angular_speed(omega1) * max_distance_from_origin(g1)
+        + angular_speed(omega2) * max_distance_from_origin(g2);
+
+    let closest_points = |toi: Real| {
+        let pos12 = motion1
+            .position_at_time(toi)
+            .inv_mul(motion2.position_at_time(toi));
+        DefaultQueryDispatcher
+            .closest_points(pos12, g1, g2, Real::MAX)
+            .map(|res| (pos12, res))
+    };
+    let max_approach_speed = |toi: Real, normal1: UnitVector| {
+        let world_normal = motion1.position_at_time(toi).rotation * *normal1;
+        (vel1 - vel2).dot(world_normal) + angular_bound
+    };
+
+    match conservative_advancement(
+        max_toi,
+        tolerance,
+        UnitVector::X,
+        closest_points,
+        max_approach_speed,
+    )? {
+        ConservativeAdvancement::Impact(toi) => Ok(Some(toi)),
+        ConservativeAdvancement::NoImpact => Ok(None),
+        ConservativeAdvancement::InitialPenetration => {
+            let contact = DefaultQueryDispatcher.contact(pos1.inv_mul(pos2), g1, g2, Real::MAX)?;
+            Ok(contact.map(|contact| TOI {
+                toi: 0.0,
+                witness1: contact.point1,
+                witness2: contact.point2,
+                normal1: contact.normal1,
+                normal2: contact.normal2,
+                status: TOIStatus::Penetrating,
+            }))
+        }
+        ConservativeAdvancement::Unresolved(safe_toi) => {
+            query::nonlinear_time_of_impact(&motion1, g1, &motion2, g2, safe_toi, max_toi, true)
+        }
+    }
+}
+
+fn max_distance_from_origin(g: &dyn Shape) -> Real {
+    let sphere = g.compute_local_bounding_sphere();
+    sphere.center().length() + sphere.radius()
+}
+
+#[cfg(feature = "dim2")]
+fn angular_speed(omega: AngVector) -> Real {
+    omega.abs()
+}
+
+#[cfg(feature = "dim3")]
+fn angular_speed(omega: AngVector) -> Real {
+    omega.length()
+}
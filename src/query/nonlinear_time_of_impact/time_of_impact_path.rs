@@ -0,0 +1,95 @@
+use crate::math::{AngVector, Isometry, Real, Vector};
+use crate::query::{
+    self, DefaultQueryDispatcher, NonlinearRigidMotion, QueryDispatcher, TOIStatus, Unsupported,
+    TOI,
+};
+use crate::shape::Shape;
+
+/// Computes the smallest time of impact of a shape following an arbitrary path with a
+/// static shape.
+///
+/// The first shape is located at `path(t)` at the time `t`, for `t` in `[0, max_t]`. This is
+/// useful for bodies with scripted motions, e.g., moving platforms following a spline. The
+/// path is split into `steps` substeps of equal duration. Within each substep, the motion is
+/// approximated by a rigid motion with constant linear and angular velocities interpolating
+/// the poses at the substep's endpoints, and checked with [`query::nonlinear_time_of_impact`].
+/// Increasing `steps` makes this approximation more accurate.
+///
+/// Returns `None` if the shapes don't touch before `max_t`. If the shapes are penetrating at
+/// the time 0, a time of impact of `0.0` with the `TOIStatus::Penetrating` status is returned.
+/// If `max_t` is not positive, only the shapes at the time 0 are checked, and shapes exactly
+/// touching at that time yield the `TOIStatus::Converged` status.
+pub fn time_of_impact_path(
+    path: &dyn Fn(Real) -> Isometry,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    max_t: Real,
+    steps: usize,
+) -> Result<Option<TOI>, Unsupported> {
+    if max_t <= 0.0 {
+        // The path is reduced to its starting pose, so there is no motion to subdivide.
+        let pos12 = path(0.0).inv_mul(pos2);
+        let contact = DefaultQueryDispatcher.contact(pos12, g1, g2, 0.0)?;
+        return Ok(contact.map(|contact| TOI {
+            toi: 0.0,
+            witness1: contact.point1,
+            witness2: contact.point2,
+            normal1: contact.normal1,
+            normal2: contact.normal2,
+            // Shapes exactly touching at the time 0 are not penetrating.
+            status: if contact.dist < 0.0 {
+                TOIStatus::Penetrating
+            } else {
+                TOIStatus::Converged
+            },
+        }));
+    }
+
+    let steps = steps.max(1);
+    let dt = max_t / steps as Real;
+    let motion2 = NonlinearRigidMotion::constant_position(pos2);
+    let mut start = path(0.0);
+
+    for i in 0..steps {
+        let t0 = i as Real * dt;
+        let end = path(t0 + dt);
+        let motion1 = NonlinearRigidMotion::new(
+            start,
+            Vector::ZERO,
+            (end.translation - start.translation) / dt,
+            angular_displacement(start, end) / dt,
+        );
+
+        if let Some(mut toi) =
+            query::nonlinear_time_of_impact(&motion1, g1, &motion2, g2, 0.0, dt, true)?
+        {
+            toi.toi += t0;
+            return Ok(Some(toi));
+        }
+
+        start = end;
+    }
+
+    Ok(None)
+}
+
+/// The rotation taking the orientation of `start` to the orientation of `end`, as an angle in
+/// 2D, or as a scaled axis in 3D.
+#[cfg(feature = "dim2")]
+fn angular_displacement(start: Isometry, end: Isometry) -> AngVector {
+    (end.rotation * start.rotation.inverse()).as_radians()
+}
+
+/// The rotation taking the orientation of `start` to the orientation of `end`, as an angle in
+/// 2D, or as a scaled axis in 3D.
+#[cfg(feature = "dim3")]
+fn angular_displacement(start: Isometry, end: Isometry) -> AngVector {
+    let delta = (end.rotation * start.rotation.inverse()).0;
+    // Take the shortest of the two rotations represented by the quaternion.
+    if delta.w < 0.0 {
+        (-delta).to_scaled_axis()
+    } else {
+        delta.to_scaled_axis()
+    }
+}
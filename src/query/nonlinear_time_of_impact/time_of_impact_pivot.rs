@@ -0,0 +1,55 @@
+use crate::math::{AngVector, Isometry, Real, Vector};
+use crate::query::{self, NonlinearRigidMotion, Unsupported, TOI};
+use crate::shape::Shape;
+
+/// Computes the smallest angle of impact of a shape rotating about a fixed pivot with a static
+/// shape.
+///
+/// This is useful for hinged bodies like doors or turrets. The first shape, initially at
+/// `pos1`, rotates about the world-space point `pivot`, around the direction of `omega`: in
+/// 2D, it turns counterclockwise if `omega` is positive, and in 3D, `omega` is the rotation
+/// axis. The magnitude of `omega` is ignored. This rotation is checked with
+/// [`query::nonlinear_time_of_impact`] at a unit angular speed, so times are angles.
+///
+/// Returns `None` if the shapes don't touch before the first shape has turned by `max_angle`,
+/// or if `omega` is zero. Otherwise, the `toi` field of the result is the angle (not the time)
+/// at which the shapes first touch. If the shapes are penetrating at the angle 0, an angle of
+/// `0.0` with the `TOIStatus::Penetrating` status is returned.
+pub fn time_of_impact_pivot(
+    pos1: Isometry,
+    pivot: Vector,
+    omega: AngVector,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    max_angle: Real,
+) -> Result<Option<TOI>, Unsupported> {
+    let axis = match rotation_axis(omega) {
+        Some(axis) => axis,
+        None => return Ok(None),
+    };
+
+    let motion1 = NonlinearRigidMotion::new(
+        pos1,
+        pos1.inverse_transform_point(pivot),
+        Vector::ZERO,
+        axis,
+    );
+    let motion2 = NonlinearRigidMotion::constant_position(pos2);
+
+    query::nonlinear_time_of_impact(&motion1, g1, &motion2, g2, 0.0, max_angle, true)
+}
+
+#[cfg(feature = "dim2")]
+fn rotation_axis(omega: AngVector) -> Option<AngVector> {
+    if omega == 0.0 {
+        None
+    } else {
+        Some(omega.signum())
+    }
+}
+
+#[cfg(feature = "dim3")]
+fn rotation_axis(omega: AngVector) -> Option<AngVector> {
+    omega.try_normalize()
+}
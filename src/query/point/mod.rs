@@ -9,6 +9,8 @@ pub use self::point_composite_shape::{
 pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation};
 #[cfg(feature = "std")] // TODO: can’t be used without std because of EPA
 pub use self::point_support_map::local_point_projection_on_support_map;
+pub use self::posed_point_query::PosedPointQuery;
+pub use self::swept_ball_closest_point::swept_ball_closest_point;
 
 mod point_aabb;
 mod point_ball;
@@ -31,3 +33,5 @@ mod point_support_map;
 #[cfg(feature = "dim3")]
 mod point_tetrahedron;
 mod point_triangle;
+mod posed_point_query;
+mod swept_ball_closest_point;
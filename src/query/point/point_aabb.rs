@@ -1,9 +1,39 @@
 use crate::bounding_volume::Aabb;
-use crate::math::{Real, Vector, DIM};
+use crate::math::{Real, UnitVector, Vector, DIM};
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::FeatureId;
 
 impl Aabb {
+    /// Computes the point of this `Aabb` closest to `pt`.
+    ///
+    /// This clamps each coordinate of `pt` to `[self.mins, self.maxs]`. Points located inside of
+    /// this `Aabb` are left unchanged.
+    #[inline]
+    pub fn project_point(&self, pt: Vector) -> Vector {
+        pt.clamp(self.mins, self.maxs)
+    }
+
+    /// Computes the point of this `Aabb` closest to `pt`, as well as the outward normal of
+    /// this `Aabb` at that point.
+    ///
+    /// If `pt` is inside of this `Aabb` and `solid` is `true`, then `pt` itself is returned
+    /// without any normal. If `solid` is `false`, `pt` is projected on the closest face of
+    /// this `Aabb` and the normal of that face is returned. The normal is also `None` if the
+    /// point lies exactly on the boundary of this `Aabb`.
+    pub fn closest_point_and_normal(
+        &self,
+        pt: Vector,
+        solid: bool,
+    ) -> (Vector, Option<UnitVector>) {
+        let (inside, proj, shift) = self.do_project_local_point(pt, solid);
+
+        if inside {
+            (proj, UnitVector::new(shift).ok())
+        } else {
+            (proj, UnitVector::new(-shift).ok())
+        }
+    }
+
     fn do_project_local_point(&self, pt: Vector, solid: bool) -> (bool, Vector, Vector) {
         let mins_pt = self.mins - pt;
         let pt_maxs = pt - self.maxs;
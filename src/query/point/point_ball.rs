@@ -1,4 +1,4 @@
-use crate::math::{Real, Vector};
+use crate::math::{Real, UnitVector, Vector};
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{Ball, FeatureId};
 
@@ -33,8 +33,57 @@ impl PointQuery for Ball {
         }
     }
 
+    #[inline]
+    fn distance_gradient(&self, pt: Vector) -> Vector {
+        // The gradient is not defined at the center, pick an arbitrary direction there.
+        pt.try_normalize().unwrap_or(Vector::Y)
+    }
+
     #[inline]
     fn contains_local_point(&self, pt: Vector) -> bool {
         pt.length_squared() <= self.radius * self.radius
     }
 }
+
+impl Ball {
+    /// Projects the local point `pt` on the part of the boundary of this ball lying on the
+    /// positive side of the plane passing through `plane_point` with normal `plane_normal`.
+    ///
+    /// Returns `None` if the ball lies entirely on the negative side of the plane.
+    pub fn project_local_point_in_halfspace(
+        &self,
+        pt: Vector,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vector> {
+        let normal = *plane_normal;
+        let offset = plane_point.dot(normal);
+
+        if offset > self.radius {
+            return None;
+        }
+
+        let proj = pt.try_normalize().unwrap_or(normal) * self.radius;
+
+        if proj.dot(normal) >= offset {
+            return Some(proj);
+        }
+
+        // The closest point lies on the circle where the plane cuts the ball.
+        let center = normal * offset;
+        let circle_radius = (self.radius * self.radius - offset * offset)
+            .max(0.0)
+            .sqrt();
+        let tangent = pt - normal * pt.dot(normal);
+        #[cfg(feature = "dim2")]
+        let dir = tangent
+            .try_normalize()
+            .unwrap_or_else(|| crate::math::perp(normal));
+        #[cfg(feature = "dim3")]
+        let dir = tangent
+            .try_normalize()
+            .unwrap_or_else(|| normal.any_orthonormal_vector());
+
+        Some(center + dir * circle_radius)
+    }
+}
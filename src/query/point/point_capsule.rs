@@ -7,6 +7,11 @@ use crate::utils::WBasis;
 impl PointQuery for Capsule {
     #[inline]
     fn project_local_point(&self, pt: Vector, solid: bool) -> PointProjection {
+        if let Some(seg) = self.as_segment() {
+            // A zero-radius capsule has no interior to project onto.
+            return seg.project_local_point(pt, solid);
+        }
+
         let seg = Segment::new(self.segment.a, self.segment.b);
         let proj = seg.project_local_point(pt, solid);
         let dproj = pt - proj.point;
@@ -44,4 +49,18 @@ impl PointQuery for Capsule {
     fn project_local_point_and_get_feature(&self, pt: Vector) -> (PointProjection, FeatureId) {
         (self.project_local_point(pt, false), FeatureId::Face(0))
     }
+
+    #[inline]
+    fn distance_gradient(&self, pt: Vector) -> Vector {
+        let seg = Segment::new(self.segment.a, self.segment.b);
+        let proj = seg.project_local_point(pt, true);
+
+        if let Ok(dir) = UnitVector::new(pt - proj.point) {
+            *dir
+        } else {
+            // The point lies on the segment: the projection picks an arbitrary
+            // direction from which the boundary point is the closest.
+            (self.project_local_point(pt, false).point - pt).normalize_or_zero()
+        }
+    }
 }
@@ -1,7 +1,12 @@
 use crate::bounding_volume::Aabb;
-use crate::math::{Real, Vector};
+#[cfg(feature = "std")]
+use crate::math::UnitVector;
+use crate::math::{AnyVector, Real, Vector};
+#[cfg(feature = "std")]
+use crate::query::details::clip_halfspace_polygon;
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{Cuboid, FeatureId};
+use crate::MinMaxIndex;
 
 impl PointQuery for Cuboid {
     #[inline]
@@ -31,4 +36,106 @@ impl PointQuery for Cuboid {
         let ur = self.half_extents;
         Aabb::new(dl, ur).contains_local_point(pt)
     }
+
+    #[inline]
+    fn distance_gradient(&self, pt: Vector) -> Vector {
+        let outside = pt - pt.clamp(-self.half_extents, self.half_extents);
+
+        if outside != Vector::ZERO {
+            outside.normalize()
+        } else {
+            // Inside, the gradient is the normal of the closest face.
+            let i = (self.half_extents - pt.abs()).min_index();
+            Vector::ith(i, pt[i].signum())
+        }
+    }
+}
+
+impl Cuboid {
+    /// Projects the local point `pt` on the part of the boundary of this cuboid lying on the
+    /// positive side of the plane passing through `plane_point` with normal `plane_normal`.
+    ///
+    /// Returns `None` if the cuboid lies entirely on the negative side of the plane.
+    #[cfg(feature = "std")]
+    pub fn project_local_point_in_halfspace(
+        &self,
+        pt: Vector,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vector> {
+        let mut best = None;
+        let mut best_dist = Real::MAX;
+        let mut clipped_face = Vec::new();
+
+        // Project on each face clipped by the half-space, and keep the closest projection.
+        for face_normal in Cuboid::face_normals() {
+            let face = self.support_face(*face_normal);
+            clip_halfspace_polygon(
+                plane_point,
+                -*plane_normal,
+                &face.vertices[..face.num_vertices],
+                &mut clipped_face,
+            );
+
+            if let Some(proj) = project_on_planar_convex_polygon(&clipped_face, *face_normal, pt) {
+                let dist = proj.distance_squared(pt);
+
+                if dist < best_dist {
+                    best_dist = dist;
+                    best = Some(proj);
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// Projects `pt` on the convex polygon with the given vertices, lying on a plane with the given
+/// normal.
+#[cfg(feature = "std")]
+fn project_on_planar_convex_polygon(
+    vertices: &[Vector],
+    _normal: Vector,
+    pt: Vector,
+) -> Option<Vector> {
+    #[cfg(feature = "dim3")]
+    if vertices.len() >= 3 {
+        let proj = pt - _normal * (pt - vertices[0]).dot(_normal);
+        let side = |i: usize| {
+            let a = vertices[i];
+            let b = vertices[(i + 1) % vertices.len()];
+            (b - a).cross(proj - a).dot(_normal)
+        };
+        let inside = (0..vertices.len()).all(|i| side(i) >= 0.0)
+            || (0..vertices.len()).all(|i| side(i) <= 0.0);
+
+        if inside {
+            return Some(proj);
+        }
+    }
+
+    // The projection lies on the boundary of the polygon.
+    let mut best = None;
+    let mut best_dist = Real::MAX;
+
+    for i in 0..vertices.len() {
+        let a = vertices[i];
+        let ab = vertices[(i + 1) % vertices.len()] - a;
+        let length_squared = ab.length_squared();
+        let t = if length_squared > 0.0 {
+            ((pt - a).dot(ab) / length_squared).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let proj = a + ab * t;
+        let dist = proj.distance_squared(pt);
+
+        if dist < best_dist {
+            best_dist = dist;
+            best = Some(proj);
+        }
+    }
+
+    best
 }
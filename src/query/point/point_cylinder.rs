@@ -4,9 +4,10 @@ use crate::math::{UnitVector2, Vector};
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{Cylinder, FeatureId};
 
-impl PointQuery for Cylinder {
-    #[inline]
-    fn project_local_point(&self, pt: Vector, solid: bool) -> PointProjection {
+impl Cylinder {
+    /// Projects a point expressed in the frame where the principal axis of `self` is the
+    /// `y` axis.
+    fn project_local_point_y_up(&self, pt: Vector, solid: bool) -> PointProjection {
         // Project on the basis.
         let (mut dir_from_basis_center, planar_dist_from_basis_center) =
             UnitVector2::new_and_length(pt.xz()).unwrap();
@@ -68,6 +69,15 @@ impl PointQuery for Cylinder {
             }
         }
     }
+}
+
+impl PointQuery for Cylinder {
+    #[inline]
+    fn project_local_point(&self, pt: Vector, solid: bool) -> PointProjection {
+        let mut proj = self.project_local_point_y_up(self.axis.to_y_up(pt), solid);
+        proj.point = self.axis.from_y_up(proj.point);
+        proj
+    }
 
     #[inline]
     fn project_local_point_and_get_feature(&self, pt: Vector) -> (PointProjection, FeatureId) {
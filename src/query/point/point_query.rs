@@ -1,4 +1,4 @@
-use crate::math::{Isometry, Real, Vector};
+use crate::math::{Isometry, Real, Vector, DEFAULT_EPSILON, DIM};
 use crate::shape::FeatureId;
 
 #[cfg(feature = "rkyv")]
@@ -29,7 +29,7 @@ impl PointProjection {
     pub fn transform_by(&self, pos: Isometry) -> Self {
         PointProjection {
             is_inside: self.is_inside,
-            point: pos * self.point,
+            point: pos.transform_point(self.point),
         }
     }
 }
@@ -86,6 +86,27 @@ pub trait PointQuery {
         }
     }
 
+    /// Computes the gradient of the signed distance between `self` and a point.
+    ///
+    /// This is the unit outward normal of `self` at the point of its boundary closest to `pt`.
+    /// The default implementation approximates it with central finite differences of
+    /// [`Self::distance_to_local_point`].
+    ///
+    /// The point is assumed to be expressed in the local-space of `self`.
+    fn distance_gradient(&self, pt: Vector) -> Vector {
+        let eps = DEFAULT_EPSILON.sqrt();
+        let mut gradient = Vector::ZERO;
+
+        for i in 0..DIM {
+            let mut shift = Vector::ZERO;
+            shift[i] = eps;
+            gradient[i] = self.distance_to_local_point(pt + shift, false)
+                - self.distance_to_local_point(pt - shift, false);
+        }
+
+        gradient.normalize_or_zero()
+    }
+
     /// Tests if the given point is inside of `self`.
     fn contains_local_point(&self, pt: Vector) -> bool {
         self.project_local_point(pt, true).is_inside
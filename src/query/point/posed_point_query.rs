@@ -0,0 +1,53 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::{PointProjection, PointQuery};
+
+/// A shape with a fixed position, for running many point queries in world-space.
+///
+/// The inverse of the shape’s position is computed once on construction instead of once per
+/// query, which makes it more efficient than [`PointQuery::project_point`] or
+/// [`PointQuery::contains_point`] when testing a large number of points against the same
+/// posed shape.
+#[derive(Copy, Clone, Debug)]
+pub struct PosedPointQuery<'a, S: ?Sized> {
+    /// The shape being queried.
+    pub shape: &'a S,
+    /// The position of the shape.
+    pub pos: Isometry,
+    /// The inverse of the position of the shape.
+    pub inv_pos: Isometry,
+}
+
+impl<'a, S: ?Sized + PointQuery> PosedPointQuery<'a, S> {
+    /// Wraps `shape`, positioned at `pos`, for repeated world-space point queries.
+    pub fn new(pos: Isometry, shape: &'a S) -> Self {
+        Self {
+            shape,
+            pos,
+            inv_pos: pos.inverse(),
+        }
+    }
+
+    /// Tests if the world-space point `pt` is inside of the shape.
+    #[inline]
+    pub fn contains(&self, pt: Vector) -> bool {
+        self.shape
+            .contains_local_point(self.inv_pos.transform_point(pt))
+    }
+
+    /// Projects the world-space point `pt` on the shape.
+    ///
+    /// The resulting projection is expressed in world-space.
+    #[inline]
+    pub fn project(&self, pt: Vector, solid: bool) -> PointProjection {
+        self.shape
+            .project_local_point(self.inv_pos.transform_point(pt), solid)
+            .transform_by(self.pos)
+    }
+
+    /// Computes the distance between the world-space point `pt` and the shape.
+    #[inline]
+    pub fn distance(&self, pt: Vector, solid: bool) -> Real {
+        self.shape
+            .distance_to_local_point(self.inv_pos.transform_point(pt), solid)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::math::{Real, Vector};
+use crate::query::PointQuery;
+use crate::shape::Segment;
+
+/// Computes the point of the volume swept by a ball moving along a segment that is the closest
+/// to `target`.
+///
+/// The ball has the given `radius` and its center moves from `a` to `b`, so the swept volume is
+/// the capsule with the segment `[a, b]` as its axis. This volume is solid: if `target` lies
+/// inside of it, `target` itself is returned.
+pub fn swept_ball_closest_point(a: Vector, b: Vector, radius: Real, target: Vector) -> Vector {
+    // The center of the ball at the time it gets the closest to the target.
+    let center = Segment::new(a, b).project_local_point(target, true).point;
+    let dpt = target - center;
+    let dist = dpt.length();
+
+    if dist <= radius {
+        target
+    } else {
+        center + dpt * (radius / dist)
+    }
+}
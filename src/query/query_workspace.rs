@@ -0,0 +1,34 @@
+use crate::query::gjk::VoronoiSimplex;
+
+#[cfg(feature = "std")]
+use crate::query::epa::EPA;
+
+/// Buffers used by the GJK and EPA algorithms, reusable across queries.
+///
+/// The functions computing a contact between two support-mapped shapes allocate new EPA buffers
+/// for each penetrating pair. A solver processing many pairs at once can instead create a
+/// single `QueryWorkspace` and pass it to
+/// [`contact_support_map_support_map_with_workspace`](crate::query::details::contact_support_map_support_map_with_workspace),
+/// so that the EPA buffers are allocated only once. Its `simplex` can also be passed to the
+/// `*_with_params` functions.
+#[derive(Debug, Clone, Default)]
+pub struct QueryWorkspace {
+    /// The simplex used by the GJK algorithm.
+    pub simplex: VoronoiSimplex,
+    /// The polytope buffers used by the EPA algorithm.
+    #[cfg(feature = "std")]
+    pub epa: EPA,
+}
+
+impl QueryWorkspace {
+    /// Creates a new workspace.
+    ///
+    /// The EPA buffers are empty until they are first needed by a penetration query.
+    pub fn new() -> Self {
+        Self {
+            simplex: VoronoiSimplex::new(),
+            #[cfg(feature = "std")]
+            epa: EPA::new(),
+        }
+    }
+}
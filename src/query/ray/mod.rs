@@ -8,6 +8,8 @@ pub use self::ray_composite_shape::{
     RayCompositeShapeToiAndNormalBestFirstVisitor, RayCompositeShapeToiBestFirstVisitor,
 };
 pub use self::ray_halfspace::{line_toi_with_halfspace, ray_toi_with_halfspace};
+#[cfg(feature = "dim2")]
+pub use self::ray_polygon_boundary::ray_polygon_boundary_intersections;
 pub use self::ray_support_map::local_ray_intersection_with_support_map_with_params;
 #[cfg(feature = "dim3")]
 pub use self::ray_triangle::local_ray_intersection_with_triangle;
@@ -23,6 +25,8 @@ mod ray_composite_shape;
 mod ray_cuboid;
 mod ray_halfspace;
 mod ray_heightfield;
+#[cfg(feature = "dim2")]
+mod ray_polygon_boundary;
 mod ray_round_shape;
 mod ray_support_map;
 mod ray_triangle;
@@ -1,6 +1,7 @@
 //! Traits and structure needed to cast rays.
 
 use crate::math::{Isometry, Real, Vector};
+use crate::query::PointQuery;
 use crate::shape::FeatureId;
 
 #[cfg(feature = "rkyv")]
@@ -32,7 +33,7 @@ impl Ray {
     /// Transforms this ray by the given isometry.
     #[inline]
     pub fn transform_by(&self, m: Isometry) -> Self {
-        Self::new(m * self.origin, m * self.dir)
+        Self::new(m.transform_point(self.origin), m * self.dir)
     }
 
     /// Transforms this ray by the inverse of the given isometry.
@@ -132,6 +133,42 @@ pub trait RayCast {
         solid: bool,
     ) -> Option<RayIntersection>;
 
+    /// Computes the time of impact between this shape and a ray, ignoring any hit occurring
+    /// before `min_toi`.
+    ///
+    /// This is useful for rays starting on (or very close to) the surface of the shape, where
+    /// the immediate surface hit must be skipped. The ray is cast as if it started at
+    /// `ray.point_at(min_toi)`, so the `solid` flag applies to that shifted origin: if it lies
+    /// inside of the shape and `solid` is `true`, the returned time of impact is `min_toi`;
+    /// if `solid` is `false`, the exit point of the shape is returned instead.
+    fn cast_local_ray_with_range(
+        &self,
+        ray: &Ray,
+        min_toi: Real,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<Real> {
+        if min_toi > max_toi {
+            return None;
+        }
+
+        let shifted_ray = Ray::new(ray.point_at(min_toi), ray.dir);
+        self.cast_local_ray(&shifted_ray, max_toi - min_toi, solid)
+            .map(|toi| toi + min_toi)
+    }
+
+    /// Tests whether the origin of the ray lies inside of this shape.
+    ///
+    /// This is the case where a solid ray cast returns a time of impact of zero, but it is
+    /// answered without actually casting the ray.
+    #[inline]
+    fn origin_inside(&self, ray: &Ray) -> bool
+    where
+        Self: PointQuery,
+    {
+        self.contains_local_point(ray.origin)
+    }
+
     /// Tests whether a ray intersects this transformed shape.
     #[inline]
     fn intersects_local_ray(&self, ray: &Ray, max_toi: Real) -> bool {
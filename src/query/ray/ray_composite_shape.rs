@@ -1,10 +1,12 @@
 use crate::bounding_volume::SimdAabb;
-use crate::math::{Real, SimdBool, SimdReal, SIMD_WIDTH};
+use crate::math::{Isometry, Real, SimdBool, SimdReal, SIMD_WIDTH};
 use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
+use crate::query::visitors::RayIntersectionsVisitor;
 use crate::query::{Ray, RayCast, RayIntersection, SimdRay};
 use crate::shape::{Compound, FeatureId, Polyline, TriMesh, TypedSimdCompositeShape};
 use crate::utils::DefaultStorage;
 use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
+use std::cmp::Ordering;
 
 impl RayCast for TriMesh {
     #[inline]
@@ -23,21 +25,80 @@ impl RayCast for TriMesh {
         max_toi: Real,
         solid: bool,
     ) -> Option<RayIntersection> {
-        let mut visitor =
-            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+        self.cast_local_ray_and_get_part(ray, max_toi, solid)
+            .map(|(_, res)| res)
+    }
+}
 
-        self.qbvh()
-            .traverse_best_first(&mut visitor)
-            .map(|(_, (best, mut res))| {
-                // We hit a backface.
-                // NOTE: we need this for `TriMesh::is_backface` to work properly.
-                if res.feature == FeatureId::Face(1) {
-                    res.feature = FeatureId::Face(best + self.indices().len() as u32)
-                } else {
-                    res.feature = FeatureId::Face(best);
-                }
-                res
-            })
+impl TriMesh {
+    /// Computes the time of impact and normal between this mesh and a ray, as well as the
+    /// index of the triangle hit by the ray.
+    pub fn cast_local_ray_and_get_part(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        cast_local_ray_on_composite_shape(self, ray, max_toi, solid)
+            .map(|(best, res)| (best, self.with_triangle_feature(best, res)))
+    }
+
+    /// Computes the time of impact and normal between this transformed mesh and a ray, as well
+    /// as the index of the triangle hit by the ray.
+    pub fn cast_ray_and_get_part(
+        &self,
+        m: Isometry,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_and_get_part(&ls_ray, max_toi, solid)
+            .map(|(part_id, inter)| (part_id, inter.transform_by(m)))
+    }
+
+    /// Computes all the intersections between this mesh and a ray, together with the
+    /// index of the triangle hit, sorted by increasing time of impact.
+    pub fn cast_local_ray_all(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(u32, RayIntersection)> {
+        let mut hits = cast_local_ray_all_on_composite_shape(self, ray, max_toi, solid);
+        for (tri_id, res) in &mut hits {
+            *res = self.with_triangle_feature(*tri_id, *res);
+        }
+        hits
+    }
+
+    /// Computes all the intersections between this transformed mesh and a ray, together
+    /// with the index of the triangle hit, sorted by increasing time of impact.
+    pub fn cast_ray_all(
+        &self,
+        m: Isometry,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_all(&ls_ray, max_toi, solid)
+            .into_iter()
+            .map(|(part_id, inter)| (part_id, inter.transform_by(m)))
+            .collect()
+    }
+
+    /// Replaces the feature of a ray intersection with one of the triangle `tri_id` by a
+    /// feature identifying that triangle in this mesh.
+    fn with_triangle_feature(&self, tri_id: u32, mut res: RayIntersection) -> RayIntersection {
+        // We hit a backface.
+        // NOTE: we need this for `TriMesh::is_backface` to work properly.
+        if res.feature == FeatureId::Face(1) {
+            res.feature = FeatureId::Face(tri_id + self.indices().len() as u32)
+        } else {
+            res.feature = FeatureId::Face(tri_id);
+        }
+        res
     }
 }
 
@@ -58,12 +119,62 @@ impl RayCast for Polyline {
         max_toi: Real,
         solid: bool,
     ) -> Option<RayIntersection> {
-        let mut visitor =
-            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+        self.cast_local_ray_and_get_part(ray, max_toi, solid)
+            .map(|(_, res)| res)
+    }
+}
 
-        self.qbvh()
-            .traverse_best_first(&mut visitor)
-            .map(|(_, (_, res))| res)
+impl Polyline {
+    /// Computes the time of impact and normal between this polyline and a ray, as well as the
+    /// index of the segment hit by the ray.
+    pub fn cast_local_ray_and_get_part(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        cast_local_ray_on_composite_shape(self, ray, max_toi, solid)
+    }
+
+    /// Computes the time of impact and normal between this transformed polyline and a ray, as
+    /// well as the index of the segment hit by the ray.
+    pub fn cast_ray_and_get_part(
+        &self,
+        m: Isometry,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_and_get_part(&ls_ray, max_toi, solid)
+            .map(|(part_id, inter)| (part_id, inter.transform_by(m)))
+    }
+
+    /// Computes all the intersections between this polyline and a ray, together with the
+    /// index of the segment hit, sorted by increasing time of impact.
+    pub fn cast_local_ray_all(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(u32, RayIntersection)> {
+        cast_local_ray_all_on_composite_shape(self, ray, max_toi, solid)
+    }
+
+    /// Computes all the intersections between this transformed polyline and a ray, together
+    /// with the index of the segment hit, sorted by increasing time of impact.
+    pub fn cast_ray_all(
+        &self,
+        m: Isometry,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_all(&ls_ray, max_toi, solid)
+            .into_iter()
+            .map(|(part_id, inter)| (part_id, inter.transform_by(m)))
+            .collect()
     }
 }
 
@@ -84,13 +195,112 @@ impl RayCast for Compound {
         max_toi: Real,
         solid: bool,
     ) -> Option<RayIntersection> {
-        let mut visitor =
-            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, solid);
+        self.cast_local_ray_and_get_part(ray, max_toi, solid)
+            .map(|(_, res)| res)
+    }
+}
 
-        self.qbvh()
-            .traverse_best_first(&mut visitor)
-            .map(|(_, (_, res))| res)
+impl Compound {
+    /// Computes the time of impact and normal between this compound shape and a ray, as well
+    /// as the index of the sub-shape hit by the ray.
+    pub fn cast_local_ray_and_get_part(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        cast_local_ray_on_composite_shape(self, ray, max_toi, solid)
     }
+
+    /// Computes the time of impact and normal between this transformed compound shape and a
+    /// ray, as well as the index of the sub-shape hit by the ray.
+    pub fn cast_ray_and_get_part(
+        &self,
+        m: Isometry,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Option<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_and_get_part(&ls_ray, max_toi, solid)
+            .map(|(part_id, inter)| (part_id, inter.transform_by(m)))
+    }
+
+    /// Computes all the intersections between this compound shape and a ray, together with the
+    /// index of the sub-shape hit, sorted by increasing time of impact.
+    pub fn cast_local_ray_all(
+        &self,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(u32, RayIntersection)> {
+        cast_local_ray_all_on_composite_shape(self, ray, max_toi, solid)
+    }
+
+    /// Computes all the intersections between this transformed compound shape and a ray, together
+    /// with the index of the sub-shape hit, sorted by increasing time of impact.
+    pub fn cast_ray_all(
+        &self,
+        m: Isometry,
+        ray: &Ray,
+        max_toi: Real,
+        solid: bool,
+    ) -> Vec<(u32, RayIntersection)> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_all(&ls_ray, max_toi, solid)
+            .into_iter()
+            .map(|(part_id, inter)| (part_id, inter.transform_by(m)))
+            .collect()
+    }
+}
+
+fn cast_local_ray_on_composite_shape<S>(
+    shape: &S,
+    ray: &Ray,
+    max_toi: Real,
+    solid: bool,
+) -> Option<(S::PartId, RayIntersection)>
+where
+    S: TypedSimdCompositeShape<QbvhStorage = DefaultStorage>,
+{
+    let mut visitor =
+        RayCompositeShapeToiAndNormalBestFirstVisitor::new(shape, ray, max_toi, solid);
+
+    shape
+        .typed_qbvh()
+        .traverse_best_first(&mut visitor)
+        .map(|(_, res)| res)
+}
+
+fn cast_local_ray_all_on_composite_shape<S>(
+    shape: &S,
+    ray: &Ray,
+    max_toi: Real,
+    solid: bool,
+) -> Vec<(S::PartId, RayIntersection)>
+where
+    S: TypedSimdCompositeShape<QbvhStorage = DefaultStorage>,
+{
+    let mut hits = Vec::new();
+    let mut callback = |part_id: &S::PartId| {
+        shape.map_typed_part_at(*part_id, |part_pos, part_shape| {
+            let result = if let Some(part_pos) = part_pos {
+                part_shape.cast_ray_and_get_normal(part_pos, ray, max_toi, solid)
+            } else {
+                part_shape.cast_local_ray_and_get_normal(ray, max_toi, solid)
+            };
+
+            if let Some(result) = result {
+                hits.push((*part_id, result));
+            }
+        });
+        true
+    };
+
+    let mut visitor = RayIntersectionsVisitor::new(ray, max_toi, &mut callback);
+    shape.typed_qbvh().traverse_depth_first(&mut visitor);
+    hits.sort_by(|a, b| a.1.toi.partial_cmp(&b.1.toi).unwrap_or(Ordering::Equal));
+    hits
 }
 
 /*
@@ -0,0 +1,68 @@
+use crate::math::{perp, Real, Vector, DEFAULT_EPSILON};
+use crate::query::Ray;
+use arrayvec::ArrayVec;
+
+/// Computes the parameters of all the points where `ray` crosses the boundary of a convex
+/// polygon.
+///
+/// The polygon vertices can be given in clockwise or counterclockwise order. Unlike
+/// [`RayCast::cast_local_ray`](crate::query::RayCast::cast_local_ray), which only returns the
+/// first hit, this returns up to two hits sorted by increasing parameter: the entry and exit
+/// points of the ray, ignoring the ones before the ray origin. A ray grazing a single vertex
+/// of the polygon results in only one hit.
+pub fn ray_polygon_boundary_intersections(ray: &Ray, vertices: &[Vector]) -> ArrayVec<Real, 2> {
+    let mut result = ArrayVec::new();
+
+    if vertices.len() < 3 {
+        return result;
+    }
+
+    // Orientation of the polygon, used to make the edge normals point outward.
+    let mut twice_area = 0.0;
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        twice_area += a.perp_dot(b);
+    }
+    let orientation: Real = if twice_area < 0.0 { -1.0 } else { 1.0 };
+
+    // Clip the ray line against the halfplane of each edge (Cyrus-Beck).
+    let mut t_enter = -Real::MAX;
+    let mut t_exit = Real::MAX;
+
+    for (i, a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        let edge = b - *a;
+        let normal = -perp(edge) * orientation;
+        let num = normal.dot(*a - ray.origin);
+        let denom = normal.dot(ray.dir);
+
+        if denom == 0.0 {
+            if num < 0.0 {
+                // The ray is parallel to this edge and outside of the polygon.
+                return result;
+            }
+        } else {
+            let t = num / denom;
+
+            if denom < 0.0 {
+                t_enter = t_enter.max(t);
+            } else {
+                t_exit = t_exit.min(t);
+            }
+        }
+    }
+
+    if t_enter > t_exit || t_exit < 0.0 {
+        return result;
+    }
+
+    if t_enter >= 0.0 {
+        result.push(t_enter);
+    }
+
+    if t_exit - t_enter > DEFAULT_EPSILON * t_exit.abs().max(1.0) {
+        result.push(t_exit);
+    }
+
+    result
+}
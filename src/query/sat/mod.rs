@@ -1,21 +1,29 @@
 //! Application of the Separating-Axis-Theorem (SAT).
 
+pub use self::sat_cuboid_capsule::*;
 pub use self::sat_cuboid_cuboid::*;
 pub use self::sat_cuboid_point::*;
 pub use self::sat_cuboid_segment::*;
 pub use self::sat_cuboid_support_map::*;
 pub use self::sat_cuboid_triangle::*;
+#[cfg(all(feature = "dim2", feature = "std"))]
+pub use self::sat_polygon_polygon::*;
+#[cfg(all(feature = "dim3", feature = "std"))]
+pub use self::sat_polyhedron_polyhedron::*;
 pub use self::sat_support_map_support_map::*;
 #[cfg(feature = "dim3")]
 pub use self::sat_triangle_segment::*;
-// pub use self::sat_polygon_polygon::*;
 
+mod sat_cuboid_capsule;
 mod sat_cuboid_cuboid;
 mod sat_cuboid_point;
 mod sat_cuboid_segment;
 mod sat_cuboid_support_map;
 mod sat_cuboid_triangle;
+#[cfg(all(feature = "dim2", feature = "std"))]
+mod sat_polygon_polygon;
+#[cfg(all(feature = "dim3", feature = "std"))]
+mod sat_polyhedron_polyhedron;
 mod sat_support_map_support_map;
 #[cfg(feature = "dim3")]
 mod sat_triangle_segment;
-// mod sat_polygon_polygon;
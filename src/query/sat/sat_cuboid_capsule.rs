@@ -0,0 +1,80 @@
+use crate::math::{Isometry, Real, Vector, DIM};
+use crate::shape::{Capsule, Cuboid};
+
+/// Computes the separation distance between a cuboid and a capsule without relying on GJK.
+///
+/// The separation between the cuboid and the capsule's segment is the largest separation along
+/// a set of candidate axes: the face normals of the cuboid, the segment direction crossed with
+/// the cuboid edges (in 3D) or the segment normal (in 2D), and the directions joining the
+/// closest points between each segment endpoint and the cuboid, and between each cuboid vertex
+/// and the segment. These include the direction joining the closest points of both shapes, so
+/// the result is exact when the shapes are separated. The capsule radius is then subtracted.
+///
+/// Returns the separation together with the separating direction pointing from the cuboid
+/// toward the capsule, expressed in the local-space of the cuboid. A negative separation
+/// approximates the penetration depth.
+pub fn cuboid_capsule_separation(
+    pos12: Isometry,
+    cuboid1: &Cuboid,
+    capsule2: &Capsule,
+) -> (Real, Vector) {
+    let half_extents = cuboid1.half_extents;
+    let a = pos12.transform_point(capsule2.segment.a);
+    let b = pos12.transform_point(capsule2.segment.b);
+    let dir = b - a;
+
+    let mut best = (-Real::MAX, Vector::Y);
+    let mut test_axis = |axis: Vector| {
+        // Degenerate axes are skipped. Any other axis gives a lower bound of the distance.
+        if let Some(axis) = axis.try_normalize() {
+            let box_extent = axis.abs().dot(half_extents);
+            let (proj_a, proj_b) = (a.dot(axis), b.dot(axis));
+            let separation_pos = proj_a.min(proj_b) - box_extent;
+            let separation_neg = -proj_a.max(proj_b) - box_extent;
+
+            if separation_pos >= separation_neg {
+                if separation_pos > best.0 {
+                    best = (separation_pos, axis);
+                }
+            } else if separation_neg > best.0 {
+                best = (separation_neg, -axis);
+            }
+        }
+    };
+
+    for axis in Vector::AXES {
+        test_axis(axis);
+    }
+
+    #[cfg(feature = "dim2")]
+    test_axis(crate::math::perp(dir));
+    #[cfg(feature = "dim3")]
+    for axis in Vector::AXES {
+        test_axis(axis.cross(dir));
+    }
+
+    // Segment endpoints against their closest points on the cuboid.
+    for pt in [a, b] {
+        test_axis(pt - pt.clamp(-half_extents, half_extents));
+    }
+
+    // Cuboid vertices against their closest points on the segment.
+    let sq_len = dir.length_squared();
+    for i in 0..1 << DIM {
+        let mut vertex = half_extents;
+        for k in 0..DIM {
+            if i & (1 << k) != 0 {
+                vertex[k] = -vertex[k];
+            }
+        }
+
+        let t = if sq_len != 0.0 {
+            ((vertex - a).dot(dir) / sq_len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        test_axis(a + dir * t - vertex);
+    }
+
+    (best.0 - capsule2.radius, best.1)
+}
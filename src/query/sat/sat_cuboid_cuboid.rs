@@ -1,4 +1,4 @@
-use crate::math::{AnyVector, Isometry, Real, Vector, DIM};
+use crate::math::{Isometry, Real, Vector, DIM};
 use crate::shape::{Cuboid, SupportMap};
 
 /// Computes the separation of two cuboids along `axis1`.
@@ -14,7 +14,7 @@ pub fn cuboid_cuboid_compute_separation_wrt_local_line(
     let axis2 = pos12.rotation.inverse() * -axis1;
     let local_pt1 = cuboid1.local_support_point(axis1);
     let local_pt2 = cuboid2.local_support_point(axis2);
-    let pt2 = pos12 * local_pt2;
+    let pt2 = pos12.transform_point(local_pt2);
     let separation = (pt2 - local_pt1).dot(axis1);
     (separation, axis1)
 }
@@ -81,22 +81,52 @@ pub fn cuboid_cuboid_find_local_separating_normal_oneway(
     cuboid2: &Cuboid,
     pos12: Isometry,
 ) -> (Real, Vector) {
+    let (separation, dir, _, _) =
+        cuboid_cuboid_find_local_separating_normal_oneway_with_witnesses(cuboid1, cuboid2, pos12);
+    (separation, dir)
+}
+
+/// Finds the best separating normal between two cuboids, as well as the witness points on
+/// each cuboid.
+///
+/// Only the normals from `cuboid1` are tested. Returns the separation, the separating normal
+/// expressed in the local-space of `cuboid1`, a point on the face of `cuboid1` orthogonal to
+/// this normal expressed in the local-space of `cuboid1`, and the support point of `cuboid2`
+/// in the opposite direction expressed in the local-space of `cuboid2`.
+pub fn cuboid_cuboid_find_local_separating_normal_oneway_with_witnesses(
+    cuboid1: &Cuboid,
+    cuboid2: &Cuboid,
+    pos12: Isometry,
+) -> (Real, Vector, Vector, Vector) {
     let mut best_separation = -Real::MAX;
     let mut best_dir = Vector::ZERO;
+    let mut best_pt1 = Vector::ZERO;
+    let mut best_pt2 = Vector::ZERO;
+
+    let face_normals = Cuboid::face_normals();
 
     for i in 0..DIM {
-        let sign = (1.0 as Real).copysign(pos12.translation[i]);
-        let axis1 = Vector::ith(i, sign);
+        // Only test the face of `cuboid1` on the side of the center of `cuboid2`.
+        let face_id = if pos12.translation[i].is_sign_negative() {
+            i + DIM
+        } else {
+            i
+        };
+        let axis1 = *face_normals[face_id];
         let axis2 = pos12.rotation.inverse() * -axis1;
         let local_pt2 = cuboid2.local_support_point(axis2);
-        let pt2 = pos12 * local_pt2;
-        let separation = pt2[i] * sign - cuboid1.half_extents[i];
+        let pt2 = pos12.transform_point(local_pt2);
+        let separation = pt2.dot(axis1) - cuboid1.half_extents[i];
 
         if separation > best_separation {
             best_separation = separation;
             best_dir = axis1;
+            // Project `pt2` on the face of `cuboid1` orthogonal to `axis1`.
+            best_pt1 = pt2.clamp(-cuboid1.half_extents, cuboid1.half_extents);
+            best_pt1[i] = cuboid1.half_extents[i] * axis1[i];
+            best_pt2 = local_pt2;
         }
     }
 
-    (best_separation, best_dir)
+    (best_separation, best_dir, best_pt1, best_pt2)
 }
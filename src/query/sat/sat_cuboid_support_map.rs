@@ -1,5 +1,5 @@
 use crate::math::UnitVector;
-use crate::math::{AnyVector, Isometry, Real, Vector, DIM};
+use crate::math::{Isometry, Real, Vector, DIM};
 use crate::shape::{Cuboid, SupportMap};
 
 /// Computes the separation between a cuboid an a convex shape implementing the `SupportMap` trait,
@@ -75,16 +75,13 @@ pub fn cuboid_support_map_find_local_separating_normal_oneway<S: SupportMap>(
     let mut best_separation = -Real::MAX;
     let mut best_dir = Vector::ZERO;
 
-    for i in 0..DIM {
-        for sign in &[-1.0, 1.0] {
-            let axis1 = Vector::ith(i, *sign);
-            let pt2 = shape2.support_point_toward(pos12, UnitVector::new_unchecked(-axis1));
-            let separation = pt2[i] * *sign - cube1.half_extents[i];
+    for (face_id, axis1) in Cuboid::face_normals().into_iter().enumerate() {
+        let pt2 = shape2.support_point_toward(pos12, -axis1);
+        let separation = pt2.dot(*axis1) - cube1.half_extents[face_id % DIM];
 
-            if separation > best_separation {
-                best_separation = separation;
-                best_dir = axis1;
-            }
+        if separation > best_separation {
+            best_separation = separation;
+            best_dir = *axis1;
         }
     }
 
@@ -0,0 +1,114 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::details::clip_segment_segment_with_normal;
+use crate::shape::ConvexPolygon;
+use crate::utils;
+
+/// Computes the largest separation between the edges of `poly1` and the vertices of `poly2`.
+///
+/// Returns the separation, the index of the edge of `poly1` realizing it, and the index of the
+/// vertex of `poly2` the deepest along the normal of that edge. A positive separation means
+/// that the polygons are disjoint.
+pub fn polygon_polygon_compute_separation_features(
+    poly1: &ConvexPolygon,
+    poly2: &ConvexPolygon,
+    pos12: Isometry,
+) -> (Real, usize, usize) {
+    let mut best = (-Real::MAX, 0, 0);
+
+    for (i, (pt1, normal1)) in poly1.points().iter().zip(poly1.normals()).enumerate() {
+        let local_normal2 = pos12.rotation.inverse() * **normal1;
+        let j = utils::point_cloud_support_point_id(-local_normal2, poly2.points());
+        let pt2 = pos12.transform_point(poly2.points()[j]);
+        let separation = (pt2 - *pt1).dot(**normal1);
+
+        if separation > best.0 {
+            best = (separation, i, j);
+        }
+    }
+
+    best
+}
+
+/// Computes the minimum translation vector and contact points between two penetrating convex
+/// polygons using the Separating-Axis-Theorem.
+///
+/// The edge normals of both polygons are tested. The edge with the smallest penetration becomes
+/// the reference edge, and the edge of the other polygon most opposed to it is clipped against
+/// its extent to obtain the contact points.
+///
+/// Returns `None` if the polygons are disjoint. Otherwise, returns the axis pointing from
+/// `poly1` toward `poly2` along which `poly2` must be moved by the returned penetration depth
+/// to separate them, and the two contact points lying on the incident edge. Both points are
+/// equal if only one of them penetrates the reference polygon. Everything is expressed in the
+/// local-space of `poly1`.
+pub fn polygon_polygon_sat(
+    poly1: &ConvexPolygon,
+    poly2: &ConvexPolygon,
+    pos12: Isometry,
+) -> Option<(Vector, Real, [Vector; 2])> {
+    let pos21 = pos12.inverse();
+
+    let sep1 = polygon_polygon_compute_separation_features(poly1, poly2, pos12);
+    if sep1.0 > 0.0 {
+        return None;
+    }
+
+    let sep2 = polygon_polygon_compute_separation_features(poly2, poly1, pos21);
+    if sep2.0 > 0.0 {
+        return None;
+    }
+
+    // Express everything in the frame of the reference polygon, i.e., the one owning the edge
+    // with the smallest penetration.
+    let flipped = sep2.0 > sep1.0;
+    let (reference, incident, pos_ri, (separation, ref_edge, deepest), pos_1r) = if flipped {
+        (poly2, poly1, pos21, sep2, pos12)
+    } else {
+        (poly1, poly2, pos12, sep1, Isometry::IDENTITY)
+    };
+
+    let ref_normal = *reference.normals()[ref_edge];
+    let ref_a = reference.points()[ref_edge];
+    let ref_b = reference.points()[(ref_edge + 1) % reference.points().len()];
+
+    // The incident edge is the one with the normal most opposed to the reference normal.
+    let local_ref_normal = pos_ri.rotation.inverse() * ref_normal;
+    let inc_edge = (0..incident.normals().len())
+        .min_by(|i, j| {
+            let di = incident.normals()[*i].dot(local_ref_normal);
+            let dj = incident.normals()[*j].dot(local_ref_normal);
+            di.total_cmp(&dj)
+        })
+        .unwrap_or(0);
+    let inc_a = pos_ri.transform_point(incident.points()[inc_edge]);
+    let inc_b = pos_ri.transform_point(incident.points()[(inc_edge + 1) % incident.points().len()]);
+
+    // Fall back to the deepest vertex if clipping yields no penetrating point.
+    let mut points = [pos_ri.transform_point(incident.points()[deepest]); 2];
+
+    if let Some((clip_a, clip_b)) =
+        clip_segment_segment_with_normal((ref_a, ref_b), (inc_a, inc_b), ref_normal)
+    {
+        let mut num_points = 0;
+
+        for pt in [clip_a.1, clip_b.1] {
+            if (pt - ref_a).dot(ref_normal) <= 0.0 {
+                points[num_points] = pt;
+                num_points += 1;
+            }
+        }
+
+        if num_points == 1 {
+            points[1] = points[0];
+        }
+    }
+
+    let normal = pos_1r.rotation * ref_normal;
+    let axis = if flipped { -normal } else { normal };
+
+    Some((
+        axis,
+        -separation,
+        points.map(|pt| pos_1r.transform_point(pt)),
+    ))
+}
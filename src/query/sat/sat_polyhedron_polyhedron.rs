@@ -0,0 +1,258 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::details::{
+    clip_halfspace_polygon, closest_points_segment_segment_with_locations_nD,
+};
+use crate::shape::{ConvexPolyhedron, Segment, SupportMap};
+use approx::AbsDiffEq;
+
+/// Computes the largest separation between the faces of `poly1` and the vertices of `poly2`.
+///
+/// Returns the separation, and the index of the face of `poly1` realizing it. A positive
+/// separation means that the polyhedra are disjoint.
+pub fn polyhedron_polyhedron_compute_separation_features(
+    poly1: &ConvexPolyhedron,
+    poly2: &ConvexPolyhedron,
+    pos12: Isometry,
+) -> (Real, usize) {
+    let mut best = (-Real::MAX, 0);
+
+    for (i, face) in poly1.faces().iter().enumerate() {
+        let normal1 = *face.normal;
+        let pt1 = face_points(poly1, i).next().unwrap_or(Vector::ZERO);
+        let local_normal2 = pos12.rotation.inverse() * -normal1;
+        let pt2 = pos12.transform_point(poly2.local_support_point(local_normal2));
+        let separation = (pt2 - pt1).dot(normal1);
+
+        if separation > best.0 {
+            best = (separation, i);
+        }
+    }
+
+    best
+}
+
+/// Finds the best separating axis among the cross products of the edges of both polyhedra.
+///
+/// Only the pairs of edges building a face of the Minkowski difference of both polyhedra are
+/// tested, i.e., the pairs of edges whose arcs intersect on the Gauss map. Along the axis
+/// orthogonal to such a pair, these edges are the support features of their polyhedra, so the
+/// separation is measured between the edges themselves and other pairs are rejected without
+/// computing any support point.
+///
+/// Returns the separation and the axis realizing it, oriented from `poly1` toward `poly2` and
+/// expressed in the local-space of `poly1`, as well as the indices of the two edges it is
+/// orthogonal to. Pairs of parallel edges are ignored.
+pub fn polyhedron_polyhedron_find_local_separating_edge_twoway(
+    poly1: &ConvexPolyhedron,
+    poly2: &ConvexPolyhedron,
+    pos12: Isometry,
+) -> (Real, Vector, [usize; 2]) {
+    let mut best = (-Real::MAX, Vector::ZERO, [0; 2]);
+
+    // The direction, a vertex, and the opposite of the normals of the adjacent faces of each
+    // edge of `poly2`, expressed in the local-space of `poly1`.
+    let edges2: Vec<_> = poly2
+        .edges()
+        .iter()
+        .enumerate()
+        .filter_map(|(j, edge2)| {
+            let c = face_normal(poly2, edge2.faces.x)?;
+            let d = face_normal(poly2, edge2.faces.y)?;
+            Some((
+                j,
+                pos12.rotation * *edge2.dir,
+                pos12.transform_point(poly2.points()[edge2.vertices.x as usize]),
+                pos12.rotation * -c,
+                pos12.rotation * -d,
+            ))
+        })
+        .collect();
+
+    for (i, edge1) in poly1.edges().iter().enumerate() {
+        let (a, b) = match (
+            face_normal(poly1, edge1.faces.x),
+            face_normal(poly1, edge1.faces.y),
+        ) {
+            (Some(a), Some(b)) => (a, b),
+            _ => continue,
+        };
+        let pt1 = poly1.points()[edge1.vertices.x as usize];
+
+        for (j, dir2, pt2, c, d) in &edges2 {
+            if !is_minkowski_face(a, b, *c, *d) {
+                continue;
+            }
+
+            let axis1 = edge1.dir.cross(*dir2);
+            let norm1 = axis1.length();
+
+            if norm1 <= Real::default_epsilon() {
+                continue;
+            }
+
+            // The axis lies on the arc between the normals of the faces adjacent to `edge1`.
+            let axis1 = if axis1.dot(a + b) < 0.0 {
+                -axis1 / norm1
+            } else {
+                axis1 / norm1
+            };
+            let separation = (*pt2 - pt1).dot(axis1);
+
+            if separation > best.0 {
+                best = (separation, axis1, [i, *j]);
+            }
+        }
+    }
+
+    best
+}
+
+/// Do the arcs `a, b` and `c, d` intersect on the Gauss map?
+///
+/// The arcs are the normals of the faces adjacent to an edge of the first polyhedron, and the
+/// opposite of the normals of the faces adjacent to an edge of the second polyhedron. They
+/// intersect if these edges build a face of the Minkowski difference of both polyhedra.
+fn is_minkowski_face(a: Vector, b: Vector, c: Vector, d: Vector) -> bool {
+    let bxa = b.cross(a);
+    let dxc = d.cross(c);
+
+    let cba = c.dot(bxa);
+    let dba = d.dot(bxa);
+    let adc = a.dot(dxc);
+    let bdc = b.dot(dxc);
+
+    // The arcs must cross each other's planes and lie on the same hemisphere.
+    cba * dba < 0.0 && adc * bdc < 0.0 && cba * bdc > 0.0
+}
+
+/// Computes the minimum translation vector and contact points between two penetrating convex
+/// polyhedra using the Separating-Axis-Theorem.
+///
+/// This is the generalization of the cuboid-cuboid SAT to arbitrary convex polyhedra. The
+/// face normals of both polyhedra and the cross products of all their pairs of edges are
+/// tested, so this is quadratic in the number of edges. If the best axis is a face normal, the
+/// face of the other polyhedron most opposed to it is clipped against the side planes of that
+/// reference face, keeping only the points penetrating it. If the best axis is an edge-edge
+/// axis, the single contact point is the point of the edge of `poly2` closest to the edge of
+/// `poly1`.
+///
+/// Returns `None` if the polyhedra are disjoint. Otherwise, returns the axis pointing from
+/// `poly1` toward `poly2` along which `poly2` must be moved by the returned penetration depth
+/// to separate them, and the contact points lying on the incident feature. Everything is
+/// expressed in the local-space of `poly1`.
+pub fn polyhedron_polyhedron_sat(
+    poly1: &ConvexPolyhedron,
+    poly2: &ConvexPolyhedron,
+    pos12: Isometry,
+) -> Option<(Vector, Real, Vec<Vector>)> {
+    let pos21 = pos12.inverse();
+
+    let sep1 = polyhedron_polyhedron_compute_separation_features(poly1, poly2, pos12);
+    if sep1.0 > 0.0 {
+        return None;
+    }
+
+    let sep2 = polyhedron_polyhedron_compute_separation_features(poly2, poly1, pos21);
+    if sep2.0 > 0.0 {
+        return None;
+    }
+
+    let sep3 = polyhedron_polyhedron_find_local_separating_edge_twoway(poly1, poly2, pos12);
+    if sep3.0 > 0.0 {
+        return None;
+    }
+
+    if sep3.0 > sep1.0 && sep3.0 > sep2.0 {
+        let [e1, e2] = sep3.2;
+        let edge1 = poly1.edges()[e1].vertices;
+        let edge2 = poly2.edges()[e2].vertices;
+        let seg1 = (
+            poly1.points()[edge1.x as usize],
+            poly1.points()[edge1.y as usize],
+        );
+        let seg2 = Segment::new(
+            pos12.transform_point(poly2.points()[edge2.x as usize]),
+            pos12.transform_point(poly2.points()[edge2.y as usize]),
+        );
+        let (_, loc2) = closest_points_segment_segment_with_locations_nD(seg1, (seg2.a, seg2.b));
+
+        return Some((sep3.1, -sep3.0, vec![seg2.point_at(&loc2)]));
+    }
+
+    // Express everything in the frame of the reference polyhedron, i.e., the one owning the
+    // face with the smallest penetration.
+    let flipped = sep2.0 > sep1.0;
+    let (reference, incident, pos_ri, (separation, ref_face), pos_1r) = if flipped {
+        (poly2, poly1, pos21, sep2, pos12)
+    } else {
+        (poly1, poly2, pos12, sep1, Isometry::IDENTITY)
+    };
+
+    let ref_normal = *reference.faces()[ref_face].normal;
+    let ref_points: Vec<_> = face_points(reference, ref_face).collect();
+    let ref_center = ref_points.iter().copied().sum::<Vector>() / ref_points.len() as Real;
+
+    // The incident face is the one with the normal most opposed to the reference normal.
+    let local_ref_normal = pos_ri.rotation.inverse() * ref_normal;
+    let inc_face = (0..incident.faces().len())
+        .min_by(|i, j| {
+            let di = incident.faces()[*i].normal.dot(local_ref_normal);
+            let dj = incident.faces()[*j].normal.dot(local_ref_normal);
+            di.total_cmp(&dj)
+        })
+        .unwrap_or(0);
+    let mut polygon: Vec<_> = face_points(incident, inc_face)
+        .map(|pt| pos_ri.transform_point(pt))
+        .collect();
+
+    // Clip the incident face against the side planes of the reference face.
+    let mut clipped = Vec::with_capacity(polygon.len());
+    for (k, a) in ref_points.iter().enumerate() {
+        let b = ref_points[(k + 1) % ref_points.len()];
+        let mut side_normal = (b - *a).cross(ref_normal);
+
+        if side_normal.dot(*a - ref_center) < 0.0 {
+            side_normal = -side_normal;
+        }
+
+        clip_halfspace_polygon(*a, side_normal, &polygon, &mut clipped);
+        std::mem::swap(&mut polygon, &mut clipped);
+    }
+
+    let mut points: Vec<_> = polygon
+        .into_iter()
+        .filter(|pt| (*pt - ref_points[0]).dot(ref_normal) <= 0.0)
+        .collect();
+
+    // Fall back to the deepest vertex if clipping yields no penetrating point.
+    if points.is_empty() {
+        points.push(pos_ri.transform_point(incident.local_support_point(-local_ref_normal)));
+    }
+
+    let normal = pos_1r.rotation * ref_normal;
+    let axis = if flipped { -normal } else { normal };
+
+    Some((
+        axis,
+        -separation,
+        points
+            .into_iter()
+            .map(|pt| pos_1r.transform_point(pt))
+            .collect(),
+    ))
+}
+
+/// The vertices of the `face`-th face of `poly`, in the order they appear on its boundary.
+fn face_points(poly: &ConvexPolyhedron, face: usize) -> impl Iterator<Item = Vector> + '_ {
+    let face = &poly.faces()[face];
+    let first = face.first_vertex_or_edge as usize;
+    let last = first + face.num_vertices_or_edges as usize;
+    poly.vertices_adj_to_face()[first..last]
+        .iter()
+        .map(|vid| poly.points()[*vid as usize])
+}
+
+/// The normal of the `face`-th face of `poly`, if it exists.
+fn face_normal(poly: &ConvexPolyhedron, face: u32) -> Option<Vector> {
+    poly.faces().get(face as usize).map(|face| *face.normal)
+}
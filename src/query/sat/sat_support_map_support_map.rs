@@ -14,3 +14,27 @@ pub fn support_map_support_map_compute_separation(
     let p2 = sm2.support_point_toward(pos12, -dir1);
     (p2 - p1).dot(*dir1)
 }
+
+/// Computes the largest separation between two convex shapes implementing the `SupportMap`
+/// trait, along the line with direction `dir1`.
+///
+/// Both orientations `dir1` and `-dir1` of the line are tested, and the largest separation is
+/// returned. A positive value indicates that the shapes are separated along that line.
+pub fn support_map_max_separation<G1: ?Sized, G2: ?Sized>(
+    pos12: Isometry,
+    g1: &G1,
+    g2: &G2,
+    dir1: UnitVector,
+) -> Real
+where
+    G1: SupportMap,
+    G2: SupportMap,
+{
+    let separation = |dir1: UnitVector| {
+        let p1 = g1.local_support_point_toward(dir1);
+        let p2 = g2.support_point_toward(pos12, -dir1);
+        (p2 - p1).dot(*dir1)
+    };
+
+    separation(dir1).max(separation(-dir1))
+}
@@ -0,0 +1,95 @@
+use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::query::time_of_impact::{conservative_advancement, ConservativeAdvancement};
+use crate::query::{self, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// The result of casting a shape against another shape with [`cast_shape_single`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShapeCastHit {
+    /// The time at which the shapes touch.
+    pub toi: Real,
+    /// The world-space normal of the hit, pointing from the first shape toward the second one.
+    pub normal: UnitVector,
+    /// The world-space witness point on the first shape at the time of impact.
+    pub witness1: Vector,
+    /// The world-space witness point on the second shape at the time of impact.
+    pub witness2: Vector,
+}
+
+/// Casts the convex shape `g1`, initially at `pos1` and moving with the velocity `vel1`, against
+/// the static convex shape `g2` at `pos2`.
+///
+/// This is the shape counterpart of [`RayCast::cast_ray`](crate::query::RayCast::cast_ray). The
+/// first shape is moved with conservative advancement until it touches the second shape, and
+/// the witness points and normal of the hit are the ones of the contact between both shapes at
+/// that time.
+///
+/// Returns `Err(Unsupported)` if one of the shapes isn't known to be convex, and `None` if the
+/// shapes don't touch before `max_toi`. If the shapes are penetrating at the time 0, the hit is
+/// at the time 0, with the witness points and normal of their penetration.
+pub fn cast_shape_single(
+    pos1: Isometry,
+    vel1: Vector,
+    g1: &dyn Shape,
+    pos2: Isometry,
+    g2: &dyn Shape,
+    max_toi: Real,
+) -> Result<Option<ShapeCastHit>, Unsupported> {
+    if !g1.is_convex() || !g2.is_convex() {
+        return Err(Unsupported);
+    }
+
+    let tolerance = query::gjk::EPS_TOLERANCE * 100.0;
+    let pos1_at = |toi: Real| Isometry {
+        translation: pos1.translation + vel1 * toi,
+        rotation: pos1.rotation,
+    };
+    let closest_points = |toi: Real| {
+        let pos12 = pos1_at(toi).inv_mul(pos2);
+        DefaultQueryDispatcher
+            .closest_points(pos12, g1, g2, Real::MAX)
+            .map(|res| (pos12, res))
+    };
+    // Both shapes are convex, so they can't get closer faster than the velocity of the first
+    // shape along the direction separating them.
+    let max_approach_speed = |_: Real, normal1: UnitVector| vel1.dot(pos1.rotation * *normal1);
+    let normal1 = UnitVector::new(pos1.rotation.inverse() * vel1).unwrap_or(UnitVector::X);
+
+    let toi = match conservative_advancement(
+        max_toi,
+        tolerance,
+        normal1,
+        closest_points,
+        max_approach_speed,
+    )? {
+        ConservativeAdvancement::Impact(toi) => toi.toi,
+        ConservativeAdvancement::NoImpact => return Ok(None),
+        ConservativeAdvancement::InitialPenetration => 0.0,
+        ConservativeAdvancement::Unresolved(safe_toi) => {
+            // Finish with a linear sweep from the last configuration known to be disjoint.
+            match query::time_of_impact(
+                pos1_at(safe_toi),
+                vel1,
+                g1,
+                pos2,
+                Vector::ZERO,
+                g2,
+                max_toi - safe_toi,
+                true,
+            )? {
+                Some(toi) => safe_toi + toi.toi,
+                None => return Ok(None),
+            }
+        }
+    };
+
+    let pos1 = pos1_at(toi);
+    let contact = DefaultQueryDispatcher.contact(pos1.inv_mul(pos2), g1, g2, Real::MAX)?;
+
+    Ok(contact.map(|contact| ShapeCastHit {
+        toi,
+        normal: pos1.rotation * contact.normal1,
+        witness1: pos1.transform_point(contact.point1),
+        witness2: pos2.transform_point(contact.point2),
+    }))
+}
@@ -0,0 +1,89 @@
+use crate::math::{Isometry, Real, UnitVector};
+use crate::query::{ClosestPoints, TOIStatus, Unsupported, TOI};
+
+/// The outcome of [`conservative_advancement`].
+pub(crate) enum ConservativeAdvancement {
+    /// The shapes touch at the given time of impact.
+    Impact(TOI),
+    /// The shapes don't touch before the maximum time of impact.
+    NoImpact,
+    /// The shapes are penetrating at the time 0.
+    InitialPenetration,
+    /// The advancement stopped before finding a contact, because of rounding errors or because
+    /// it ran out of iterations. The shapes are known to be disjoint until the given time.
+    Unresolved(Real),
+}
+
+/// Advances the time by steps that can't make two shapes penetrate, until they touch.
+///
+/// `closest_points(t)` returns the relative position `pos12` of the shapes at the time `t`,
+/// and their closest points in the local-space of each shape. `max_approach_speed(t, normal1)`
+/// bounds the speed at which the shapes can get closer at the time `t`, given the direction
+/// `normal1` from the closest point of the first shape toward the second shape, expressed in
+/// the local-space of the first shape. A non-positive bound means the shapes can't touch
+/// anymore.
+///
+/// `normal1` is used as the contact normal if the closest points are too close to each other
+/// to define one. The shapes are considered touching once their distance is smaller than
+/// `tolerance`.
+pub(crate) fn conservative_advancement(
+    max_toi: Real,
+    tolerance: Real,
+    mut normal1: UnitVector,
+    mut closest_points: impl FnMut(Real) -> Result<(Isometry, ClosestPoints), Unsupported>,
+    mut max_approach_speed: impl FnMut(Real, UnitVector) -> Real,
+) -> Result<ConservativeAdvancement, Unsupported> {
+    const MAX_ITERATIONS: usize = 100;
+
+    let mut toi = 0.0;
+    let mut safe_toi = 0.0;
+
+    for _ in 0..MAX_ITERATIONS {
+        let (pos12, closest_points) = closest_points(toi)?;
+
+        match closest_points {
+            ClosestPoints::WithinMargin(p1, p2) => {
+                let p2_1 = pos12.transform_point(p2);
+                let dist = p1.distance(p2_1);
+                normal1 = UnitVector::new(p2_1 - p1).unwrap_or(normal1);
+
+                if dist <= tolerance {
+                    return Ok(ConservativeAdvancement::Impact(TOI {
+                        toi,
+                        witness1: p1,
+                        witness2: p2,
+                        normal1,
+                        normal2: pos12.rotation.inverse() * -normal1,
+                        status: TOIStatus::Converged,
+                    }));
+                }
+
+                safe_toi = toi;
+
+                let approach_speed = max_approach_speed(toi, normal1);
+                if approach_speed <= 0.0 {
+                    return Ok(ConservativeAdvancement::NoImpact);
+                }
+
+                // The shapes can't get closer than `dist` during this step. Stop a bit before
+                // that so we don't end up penetrating because of rounding errors.
+                toi += (dist - tolerance * 0.5) / approach_speed;
+
+                if toi > max_toi {
+                    return Ok(ConservativeAdvancement::NoImpact);
+                }
+            }
+            ClosestPoints::Intersecting => {
+                if toi == 0.0 {
+                    return Ok(ConservativeAdvancement::InitialPenetration);
+                }
+
+                // Numerical errors made us step slightly past the contact.
+                break;
+            }
+            ClosestPoints::Disjoint => return Ok(ConservativeAdvancement::NoImpact),
+        }
+    }
+
+    Ok(ConservativeAdvancement::Unresolved(safe_toi))
+}
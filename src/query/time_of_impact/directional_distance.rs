@@ -0,0 +1,21 @@
+use crate::math::{Isometry, Real, UnitVector};
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::Shape;
+
+/// Computes the distance `g2` has to travel along `dir` to touch `g1`.
+///
+/// Both `pos12` and `dir` are expressed in the local-space of `g1`. This is a time of impact
+/// with a unit velocity along `dir`, which makes it suitable for anisotropic queries like
+/// resolving penetrations along the vertical axis only.
+///
+/// Returns `Some(0.0)` if the shapes are already touching or penetrating, and `None` if `g2`
+/// never touches `g1` while moving along `dir`.
+pub fn directional_distance(
+    pos12: Isometry,
+    g1: &dyn Shape,
+    g2: &dyn Shape,
+    dir: UnitVector,
+) -> Result<Option<Real>, Unsupported> {
+    let toi = DefaultQueryDispatcher.time_of_impact(pos12, *dir, g1, g2, Real::MAX, true)?;
+    Ok(toi.map(|toi| toi.toi))
+}
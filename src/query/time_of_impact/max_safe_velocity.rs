@@ -0,0 +1,41 @@
+use crate::math::{Isometry, Real, Vector};
+use crate::query::{self, Unsupported};
+use crate::shape::Shape;
+
+/// Clamps the velocity `vel` of `shape` so it does not tunnel through any of the `obstacles`
+/// during a step of duration `dt`.
+///
+/// The obstacles are static and given with their world-space positions. The returned velocity
+/// is `vel` scaled by the fraction of the step elapsed before the earliest time of impact, so
+/// that moving with it for `dt` brings the shape exactly in contact with the first obstacle it
+/// hits. Obstacles already penetrating `shape` do not prevent it from moving away from them.
+pub fn max_safe_velocity(
+    pos: Isometry,
+    vel: Vector,
+    shape: &dyn Shape,
+    obstacles: &[(Isometry, &dyn Shape)],
+    dt: Real,
+) -> Result<Vector, Unsupported> {
+    if dt <= 0.0 {
+        return Ok(vel);
+    }
+
+    let mut min_toi = dt;
+
+    for (obstacle_pos, obstacle) in obstacles {
+        if let Some(toi) = query::time_of_impact(
+            pos,
+            vel,
+            shape,
+            *obstacle_pos,
+            Vector::ZERO,
+            *obstacle,
+            min_toi,
+            false,
+        )? {
+            min_toi = min_toi.min(toi.toi);
+        }
+    }
+
+    Ok(vel * (min_toi / dt))
+}
@@ -1,15 +1,27 @@
 //! Implementation details of the `time_of_impact` function.
 
+pub use self::cast_shape_single::{cast_shape_single, ShapeCastHit};
+pub(crate) use self::conservative_advancement::{
+    conservative_advancement, ConservativeAdvancement,
+};
+pub use self::directional_distance::directional_distance;
+pub use self::max_safe_velocity::max_safe_velocity;
+pub use self::required_margin::required_margin;
 pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
 pub use self::time_of_impact_ball_ball::time_of_impact_ball_ball;
 pub use self::time_of_impact_halfspace_support_map::{
     time_of_impact_halfspace_support_map, time_of_impact_support_map_halfspace,
 };
+pub use self::time_of_impact_point_support_map::time_of_impact_point_support_map;
+pub use self::time_of_separation::time_of_separation;
+pub use self::time_to_leave_aabb::time_to_leave_aabb;
+pub use self::toi_ball_ball::toi_ball_ball;
 #[cfg(feature = "std")]
 pub use self::{
     time_of_impact_composite_shape_shape::{
-        time_of_impact_composite_shape_shape, time_of_impact_shape_composite_shape,
-        TOICompositeShapeShapeBestFirstVisitor,
+        time_of_impact_composite_shape_shape,
+        time_of_impact_composite_shape_shape_with_conservative_advancement,
+        time_of_impact_shape_composite_shape, TOICompositeShapeShapeBestFirstVisitor,
     },
     time_of_impact_heightfield_shape::{
         time_of_impact_heightfield_shape, time_of_impact_shape_heightfield,
@@ -17,6 +29,11 @@ pub use self::{
     time_of_impact_support_map_support_map::time_of_impact_support_map_support_map,
 };
 
+mod cast_shape_single;
+mod conservative_advancement;
+mod directional_distance;
+mod max_safe_velocity;
+mod required_margin;
 mod time_of_impact;
 mod time_of_impact_ball_ball;
 #[cfg(feature = "std")]
@@ -24,5 +41,9 @@ mod time_of_impact_composite_shape_shape;
 mod time_of_impact_halfspace_support_map;
 #[cfg(feature = "std")]
 mod time_of_impact_heightfield_shape;
+mod time_of_impact_point_support_map;
 #[cfg(feature = "std")]
 mod time_of_impact_support_map_support_map;
+mod time_of_separation;
+mod time_to_leave_aabb;
+mod toi_ball_ball;
@@ -0,0 +1,11 @@
+use crate::math::Real;
+
+/// The distance travelled during a step of duration `dt` by a shape moving at `max_speed`.
+///
+/// This is the margin by which bounding volumes must be enlarged for the broad-phase not to
+/// miss any collision during that step. See [`Shape::is_ccd_safe`](crate::shape::Shape::is_ccd_safe)
+/// to check whether a shape may tunnel through others at that speed.
+#[inline]
+pub fn required_margin(max_speed: Real, dt: Real) -> Real {
+    max_speed * dt
+}
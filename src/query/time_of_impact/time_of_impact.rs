@@ -1,4 +1,4 @@
-use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::math::{Isometry, Real, UnitVector, Vector, DEFAULT_EPSILON};
 use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
 use crate::shape::Shape;
 
@@ -19,7 +19,10 @@ pub enum TOIStatus {
     Failed,
     /// The two shape already overlap at the time 0.
     ///
-    /// The witness points and normals provided by the `TOI` will have undefined values.
+    /// For support-mapped shapes, the witness points and normals provided by the `TOI` are the
+    /// ones of the penetration contact, so `normal1` is the direction along which the second
+    /// shape should be pushed out of the first one. For other shapes, they may have undefined
+    /// values.
     Penetrating,
 }
 
@@ -80,6 +83,13 @@ impl TOI {
 /// distance smaller or equal to `distance`.
 ///
 /// Returns `0.0` if the objects are touching or penetrating.
+///
+/// If the relative velocity of the shapes is zero, they either already touch at the time 0 or
+/// never will: this returns a time of impact of `0.0` with the `TOIStatus::Penetrating` status
+/// if the shapes are touching and `stop_at_penetration` is `true`, and `None` otherwise.
+///
+/// The positions and velocities must be finite and `max_toi` must not be `NaN`. This is
+/// checked with a debug assertion, and `None` is returned in release mode if they aren't.
 pub fn time_of_impact(
     pos1: Isometry,
     vel1: Vector,
@@ -92,5 +102,30 @@ pub fn time_of_impact(
 ) -> Result<Option<TOI>, Unsupported> {
     let pos12 = pos1.inv_mul(pos2);
     let vel12 = pos1.rotation.inverse() * (vel2 - vel1);
+
+    let finite_inputs = pos12.is_finite() && vel12.is_finite() && !max_toi.is_nan();
+    debug_assert!(
+        finite_inputs,
+        "time_of_impact: non-finite positions or velocities."
+    );
+    if !finite_inputs {
+        return Ok(None);
+    }
+
+    if vel12.length_squared() <= DEFAULT_EPSILON * DEFAULT_EPSILON {
+        // Casting a zero-length sweep is degenerate, so test the overlap directly instead. Shape
+        // pairs without contact support, e.g., involving a heightfield, still use the sweep.
+        if let Ok(contact) = DefaultQueryDispatcher.contact(pos12, g1, g2, 0.0) {
+            return Ok(contact.filter(|_| stop_at_penetration).map(|contact| TOI {
+                toi: 0.0,
+                witness1: contact.point1,
+                witness2: contact.point2,
+                normal1: contact.normal1,
+                normal2: contact.normal2,
+                status: TOIStatus::Penetrating,
+            }));
+        }
+    }
+
     DefaultQueryDispatcher.time_of_impact(pos12, vel12, g1, g2, max_toi, stop_at_penetration)
 }
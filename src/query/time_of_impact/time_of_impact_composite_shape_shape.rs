@@ -1,6 +1,8 @@
 use crate::bounding_volume::SimdAabb;
-use crate::math::{Isometry, Real, SimdBool, SimdReal, SimdVector, Vector, SIMD_WIDTH};
+use crate::math::{Isometry, Real, SimdBool, SimdReal, SimdVector, UnitVector, Vector, SIMD_WIDTH};
 use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
+use crate::query::details::closest_points_composite_shape_shape;
+use crate::query::time_of_impact::{conservative_advancement, ConservativeAdvancement};
 use crate::query::{QueryDispatcher, Ray, SimdRay, TOI};
 use crate::shape::{Shape, TypedSimdCompositeShape};
 use crate::utils::DefaultStorage;
@@ -34,6 +36,81 @@ where
         .map(|res| res.1 .1)
 }
 
+/// Time Of Impact of a composite shape with any other shape, under translational movement,
+/// computed with conservative advancement.
+///
+/// Instead of sweeping the whole relative motion at once, the second shape is advanced along
+/// `vel12` by steps that never exceed the current distance between both shapes. No part of
+/// the composite shape can be skipped by a single step, so this cannot tunnel through thin
+/// features even if the relative motion is large. If the advancement doesn't converge, e.g.,
+/// because the second shape slides along the composite shape, the remaining motion is checked
+/// with [`time_of_impact_composite_shape_shape`] from the last configuration where both shapes
+/// are known to be disjoint.
+///
+/// The shapes are considered touching once their distance is smaller than `tolerance`.
+pub fn time_of_impact_composite_shape_shape_with_conservative_advancement<D: ?Sized, G1: ?Sized>(
+    dispatcher: &D,
+    pos12: Isometry,
+    vel12: Vector,
+    g1: &G1,
+    g2: &dyn Shape,
+    max_toi: Real,
+    tolerance: Real,
+    stop_at_penetration: bool,
+) -> Option<TOI>
+where
+    D: QueryDispatcher,
+    G1: TypedSimdCompositeShape<QbvhStorage = DefaultStorage>,
+{
+    let speed = vel12.length();
+    // If the closest points are too close to get a reliable normal, the second shape is
+    // hitting the first one in the direction of the relative motion.
+    let normal1 = UnitVector::new(-vel12).unwrap_or(UnitVector::X);
+    let closest_points = |toi: Real| {
+        let mut pos = pos12;
+        pos.translation += vel12 * toi;
+        Ok((
+            pos,
+            closest_points_composite_shape_shape(dispatcher, pos, g1, g2, Real::MAX),
+        ))
+    };
+    // The closest points of a composite shape change as the shapes move, so the steps can't
+    // rely on their approach speed: no point of the second shape moves faster than `speed`.
+    let max_approach_speed = |_: Real, _: UnitVector| speed;
+
+    let safe_toi = match conservative_advancement(
+        max_toi,
+        tolerance,
+        normal1,
+        closest_points,
+        max_approach_speed,
+    )
+    .ok()?
+    {
+        ConservativeAdvancement::Impact(toi) => return Some(toi),
+        ConservativeAdvancement::NoImpact => return None,
+        // Let the linear sweep decide whether the penetration must be reported.
+        ConservativeAdvancement::InitialPenetration => 0.0,
+        ConservativeAdvancement::Unresolved(safe_toi) => safe_toi,
+    };
+
+    let mut pos = pos12;
+    pos.translation += vel12 * safe_toi;
+    time_of_impact_composite_shape_shape(
+        dispatcher,
+        pos,
+        vel12,
+        g1,
+        g2,
+        max_toi - safe_toi,
+        stop_at_penetration,
+    )
+    .map(|mut toi| {
+        toi.toi += safe_toi;
+        toi
+    })
+}
+
 /// Time Of Impact of any shape with a composite shape, under translational movement.
 pub fn time_of_impact_shape_composite_shape<D: ?Sized, G2: ?Sized>(
     dispatcher: &D,
@@ -0,0 +1,50 @@
+use crate::math::{Isometry, Real, UnitVector, Vector};
+use crate::query::details::local_ray_intersection_with_support_map_with_params;
+use crate::query::gjk::VoronoiSimplex;
+use crate::query::{Ray, TOIStatus, TOI};
+use crate::shape::SupportMap;
+
+/// Time of impact between a moving point and a support-mapped shape.
+///
+/// The point is located at the origin of the first local-space, and `g2` is positioned at
+/// `pos12` relative to it. The point moves with the velocity `point_vel` relative to `g2`,
+/// expressed in the first local-space. This is a specialization of the general time of impact
+/// between support-mapped shapes that reduces to a single ray cast.
+pub fn time_of_impact_point_support_map<G2: ?Sized>(
+    pos12: Isometry,
+    point_vel: Vector,
+    g2: &G2,
+    max_toi: Real,
+) -> Option<TOI>
+where
+    G2: SupportMap,
+{
+    let local_ray = Ray::new(
+        pos12.inverse_transform_point(Vector::ZERO),
+        pos12.rotation.inverse() * point_vel,
+    );
+    let mut simplex = VoronoiSimplex::new();
+    let inter = local_ray_intersection_with_support_map_with_params(
+        g2,
+        &mut simplex,
+        &local_ray,
+        max_toi,
+        true,
+    )?;
+
+    let normal2 = UnitVector::new(inter.normal).unwrap_or(UnitVector::X);
+    let status = if inter.toi == 0.0 {
+        TOIStatus::Penetrating
+    } else {
+        TOIStatus::Converged
+    };
+
+    Some(TOI {
+        toi: inter.toi,
+        witness1: Vector::ZERO,
+        witness2: local_ray.point_at(inter.toi),
+        normal1: pos12.rotation * -normal2,
+        normal2,
+        status,
+    })
+}
@@ -38,6 +38,29 @@ where
                         status: TOIStatus::Penetrating,
                     })
                 }
+            } else if toi.is_zero() {
+                // The shapes overlap initially: the ray-cast normal is arbitrary in that case,
+                // so report the penetration normal computed by EPA instead. This lets the caller
+                // push the shapes apart along the shallowest separation direction. If EPA fails,
+                // fall back to the ray-cast result so the penetration is still reported.
+                match details::contact_support_map_support_map(pos12, g1, g2, Real::MAX) {
+                    Some(contact) => Some(TOI {
+                        toi,
+                        normal1: contact.normal1,
+                        normal2: contact.normal2,
+                        witness1: contact.point1,
+                        witness2: contact.point2,
+                        status: TOIStatus::Penetrating,
+                    }),
+                    None => Some(TOI {
+                        toi,
+                        normal1: UnitVector::new_unchecked(normal1),
+                        normal2: UnitVector::new_unchecked(pos12.rotation.inverse() * -normal1),
+                        witness1,
+                        witness2: pos12.inverse_transform_point(witness2),
+                        status: TOIStatus::Penetrating,
+                    }),
+                }
             } else {
                 Some(TOI {
                     toi,
@@ -45,11 +68,7 @@ where
                     normal2: UnitVector::new_unchecked(pos12.rotation.inverse() * -normal1),
                     witness1,
                     witness2: pos12.inverse_transform_point(witness2),
-                    status: if toi.is_zero() {
-                        TOIStatus::Penetrating
-                    } else {
-                        TOIStatus::Converged
-                    },
+                    status: TOIStatus::Converged,
                 })
             }
         },
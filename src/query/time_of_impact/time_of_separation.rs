@@ -0,0 +1,61 @@
+use crate::math::{Isometry, Real, Vector, DEFAULT_EPSILON};
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, TOIStatus, Unsupported};
+use crate::shape::Shape;
+
+/// Computes the time at which `g2`, translating with the velocity `vel12`, stops overlapping
+/// with `g1`.
+///
+/// Both `pos12` and `vel12` are expressed in the local-space of `g1`. This is the inverse of a
+/// time of impact, useful for detecting when a constraint between two overlapping shapes can
+/// be released. It is computed by casting `g2` backward in time, from a position where both
+/// shapes are known to be disjoint, back to the last time they touch.
+///
+/// The shapes must be convex. Along a straight motion, convex shapes stop overlapping only once,
+/// but non-convex shapes can separate and then touch again, e.g., a ball passing through the
+/// arms of a U-shaped compound. Then, the result is the time of the last separation before
+/// `max_toi` instead of the first one.
+///
+/// Returns `Some(0.0)` if the shapes are not overlapping initially, and `None` if they are
+/// still overlapping at `max_toi`, including if `vel12` is zero.
+pub fn time_of_separation(
+    pos12: Isometry,
+    vel12: Vector,
+    g1: &dyn Shape,
+    g2: &dyn Shape,
+    max_toi: Real,
+) -> Result<Option<Real>, Unsupported> {
+    if !DefaultQueryDispatcher.intersection_test(pos12, g1, g2)? {
+        return Ok(Some(0.0));
+    }
+
+    let speed_squared = vel12.length_squared();
+
+    if speed_squared <= DEFAULT_EPSILON * DEFAULT_EPSILON {
+        return Ok(None);
+    }
+
+    // The shapes are disjoint once their bounding spheres are, which gives an upper bound of the
+    // separation time to start the backward cast from. The spheres are slightly enlarged so the
+    // shapes are strictly disjoint at that time.
+    let bs1 = g1.compute_local_bounding_sphere();
+    let bs2 = g2.compute_bounding_sphere(pos12);
+    let dcenter = bs2.center - bs1.center;
+    let radius = (bs1.radius + bs2.radius) * 1.01 + DEFAULT_EPSILON;
+    let b = dcenter.dot(vel12);
+    let c = dcenter.length_squared() - radius * radius;
+    let discriminant = (b * b - speed_squared * c).max(0.0);
+    let horizon = ((-b + discriminant.sqrt()) / speed_squared).clamp(0.0, max_toi);
+
+    let end_pos12 = Isometry {
+        translation: pos12.translation + vel12 * horizon,
+        rotation: pos12.rotation,
+    };
+
+    match DefaultQueryDispatcher.time_of_impact(end_pos12, -vel12, g1, g2, horizon, true)? {
+        // Still overlapping at the end of the time interval.
+        Some(toi) if toi.status == TOIStatus::Penetrating => Ok(None),
+        Some(toi) => Ok(Some(horizon - toi.toi)),
+        // Only possible because of rounding errors: the shapes barely touch at the time 0.
+        None => Ok(Some(0.0)),
+    }
+}
@@ -0,0 +1,40 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Real, Vector, DIM};
+use crate::shape::Shape;
+
+/// Computes the time at which the `Aabb` of `shape` first pokes outside of `region`.
+///
+/// The shape at the position `pos` is translated with the constant velocity `vel`. Its
+/// `Aabb` leaves `region` as soon as one of its faces crosses the corresponding face of
+/// `region`, which is computed independently for each axis.
+///
+/// Returns `Some(0.0)` if the `Aabb` of the shape is not already inside of `region`, and
+/// `None` if it does not leave `region` before `max_toi`.
+pub fn time_to_leave_aabb(
+    pos: Isometry,
+    vel: Vector,
+    shape: &dyn Shape,
+    region: &Aabb,
+    max_toi: Real,
+) -> Option<Real> {
+    let aabb = shape.compute_aabb(pos);
+    let mut toi = Real::MAX;
+
+    for i in 0..DIM {
+        if aabb.mins[i] < region.mins[i] || aabb.maxs[i] > region.maxs[i] {
+            return Some(0.0);
+        }
+
+        if vel[i] > 0.0 {
+            toi = toi.min((region.maxs[i] - aabb.maxs[i]) / vel[i]);
+        } else if vel[i] < 0.0 {
+            toi = toi.min((region.mins[i] - aabb.mins[i]) / vel[i]);
+        }
+    }
+
+    if toi <= max_toi {
+        Some(toi)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,47 @@
+use crate::math::{Real, Vector};
+
+/// Computes the first time at which two balls moving with constant velocities touch.
+///
+/// The center of the second ball relative to the first one is `center12`, and its velocity
+/// relative to the first one is `vel12`. This solves the quadratic equation giving the time at
+/// which the distance between both centers becomes `r1 + r2`, without building any shape.
+///
+/// Returns `Some(0.0)` if the balls already overlap, and `None` if they don’t touch before
+/// `max_toi`.
+#[inline]
+pub fn toi_ball_ball(
+    center12: Vector,
+    vel12: Vector,
+    r1: Real,
+    r2: Real,
+    max_toi: Real,
+) -> Option<Real> {
+    let rsum = r1 + r2;
+    let c = center12.length_squared() - rsum * rsum;
+
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+
+    let b = center12.dot(vel12);
+
+    if b >= 0.0 {
+        // The balls are moving away from each other, or aren’t moving at all.
+        return None;
+    }
+
+    let a = vel12.length_squared();
+    let discr = b * b - a * c;
+
+    if discr < 0.0 {
+        return None;
+    }
+
+    let toi = (-b - discr.sqrt()) / a;
+
+    if toi <= max_toi {
+        Some(toi)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,60 @@
+use crate::bounding_volume::SimdAabb;
+use crate::math::{Real, SimdReal, SimdVector, Vector, SIMD_WIDTH};
+use crate::partitioning::{SimdVisitStatus, SimdVisitor};
+use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
+use std::marker::PhantomData;
+
+/// Spatial partitioning structure visitor collecting nodes that may intersect a given ball.
+pub struct BallIntersectionsVisitor<'a, T, F> {
+    simd_center: SimdVector,
+    simd_radius: SimdReal,
+    /// Callback executed for each leaf which Aabb intersects the ball.
+    callback: &'a mut F,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T, F> BallIntersectionsVisitor<'a, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    /// Creates a new `BallIntersectionsVisitor`.
+    #[inline]
+    pub fn new(
+        center: Vector,
+        radius: Real,
+        callback: &'a mut F,
+    ) -> BallIntersectionsVisitor<'a, T, F> {
+        BallIntersectionsVisitor {
+            simd_center: SimdVector::splat(center),
+            simd_radius: SimdReal::splat(radius),
+            callback,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, F> SimdVisitor<T, SimdAabb> for BallIntersectionsVisitor<'a, T, F>
+where
+    F: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn visit(&mut self, bv: &SimdAabb, b: Option<[Option<&T>; SIMD_WIDTH]>) -> SimdVisitStatus {
+        let mask = bv
+            .distance_to_local_point(self.simd_center)
+            .simd_le(self.simd_radius);
+
+        if let Some(data) = b {
+            let bitmask = mask.bitmask();
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) != 0
+                    && data[ii].is_some()
+                    && !(self.callback)(data[ii].unwrap())
+                {
+                    return SimdVisitStatus::ExitEarly;
+                }
+            }
+        }
+
+        SimdVisitStatus::MaybeContinue(mask)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::bounding_volume::SimdAabb;
+use crate::math::{Real, SimdBool, SimdReal, SimdVector, Vector, SIMD_WIDTH};
+use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
+use crate::shape::SimdCompositeShape;
+use crate::utils::WeightedValue;
+use simba::simd::{SimdBool as _, SimdPartialOrd, SimdValue};
+use std::collections::BinaryHeap;
+
+/// Best-first traversal visitor collecting the `k` parts of a composite shape closest to a point.
+///
+/// The traversal itself doesn’t return anything: once it is done, the parts found are retrieved
+/// with [`KNearestVisitor::into_nearest`].
+pub struct KNearestVisitor<'a, S: 'a> {
+    shape: &'a S,
+    point: Vector,
+    simd_point: SimdVector,
+    solid: bool,
+    k: usize,
+    // A max-heap of the closest parts found so far, with the farthest one on top.
+    nearest: BinaryHeap<WeightedValue<u32>>,
+}
+
+impl<'a, S> KNearestVisitor<'a, S> {
+    /// Initializes a visitor collecting the `k` parts of `shape` closest to `point`.
+    ///
+    /// The `point` is expressed in the local-space of `shape`.
+    pub fn new(shape: &'a S, point: Vector, k: usize, solid: bool) -> Self {
+        KNearestVisitor {
+            shape,
+            point,
+            simd_point: SimdVector::splat(point),
+            solid,
+            k,
+            nearest: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    /// The ids of the closest parts found, with their distance to the point, sorted by
+    /// increasing distance.
+    pub fn into_nearest(self) -> Vec<(u32, Real)> {
+        self.nearest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|entry| (entry.value, entry.cost))
+            .collect()
+    }
+
+    // The distance beyond which parts can be ignored.
+    fn max_dist(&self) -> Real {
+        if self.nearest.len() < self.k {
+            Real::MAX
+        } else {
+            self.nearest
+                .peek()
+                .map(|entry| entry.cost)
+                .unwrap_or(-Real::MAX)
+        }
+    }
+}
+
+impl<'a, S: SimdCompositeShape> SimdBestFirstVisitor<u32, SimdAabb> for KNearestVisitor<'a, S> {
+    type Result = ();
+
+    #[inline]
+    fn visit(
+        &mut self,
+        _: Real,
+        aabb: &SimdAabb,
+        data: Option<[Option<&u32>; SIMD_WIDTH]>,
+    ) -> SimdBestFirstVisitStatus<Self::Result> {
+        if self.k == 0 {
+            return SimdBestFirstVisitStatus::ExitEarly(None);
+        }
+
+        let dist = aabb.distance_to_local_point(self.simd_point);
+        let mask = dist.simd_lt(SimdReal::splat(self.max_dist()));
+
+        if let Some(data) = data {
+            let bitmask = mask.bitmask();
+
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
+                    let part_id = *data[ii].unwrap();
+                    let mut part_dist = Real::MAX;
+
+                    self.shape.map_part_at(part_id, &mut |part_pos, obj| {
+                        let proj = if let Some(part_pos) = part_pos {
+                            obj.project_point(part_pos, self.point, self.solid)
+                        } else {
+                            obj.project_local_point(self.point, self.solid)
+                        };
+
+                        part_dist = self.point.distance(proj.point);
+                    });
+
+                    if part_dist < self.max_dist() {
+                        self.nearest.push(WeightedValue::new(part_id, part_dist));
+
+                        if self.nearest.len() > self.k {
+                            let _ = self.nearest.pop();
+                        }
+                    }
+                }
+            }
+
+            // The leaves are not reported to the traversal so it doesn’t stop at the first one.
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: dist,
+                mask: SimdBool::splat(false),
+                results: [None; SIMD_WIDTH],
+            }
+        } else {
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: dist,
+                mask,
+                results: [None; SIMD_WIDTH],
+            }
+        }
+    }
+}
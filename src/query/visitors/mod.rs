@@ -3,6 +3,8 @@
 #[cfg(feature = "std")]
 pub use self::aabb_sets_interferences_collector::AabbSetsInterferencesCollector;
 #[cfg(feature = "std")]
+pub use self::ball_intersections_visitor::BallIntersectionsVisitor;
+#[cfg(feature = "std")]
 pub use self::bounding_volume_intersections_simultaneous_visitor::BoundingVolumeIntersectionsSimultaneousVisitor;
 #[cfg(feature = "std")]
 pub use self::bounding_volume_intersections_visitor::BoundingVolumeIntersectionsVisitor;
@@ -10,6 +12,8 @@ pub use self::bounding_volume_intersections_visitor::BoundingVolumeIntersections
 pub use self::composite_closest_point_visitor::CompositeClosestPointVisitor;
 pub use self::composite_point_containment_test::CompositePointContainmentTest;
 #[cfg(feature = "std")]
+pub use self::k_nearest_visitor::KNearestVisitor;
+#[cfg(feature = "std")]
 pub use self::point_intersections_visitor::PointIntersectionsVisitor;
 #[cfg(feature = "std")]
 pub use self::ray_intersections_visitor::RayIntersectionsVisitor;
@@ -17,6 +21,8 @@ pub use self::ray_intersections_visitor::RayIntersectionsVisitor;
 #[cfg(feature = "std")]
 mod aabb_sets_interferences_collector;
 #[cfg(feature = "std")]
+mod ball_intersections_visitor;
+#[cfg(feature = "std")]
 mod bounding_volume_intersections_simultaneous_visitor;
 #[cfg(feature = "std")]
 mod bounding_volume_intersections_visitor;
@@ -24,6 +30,8 @@ mod bounding_volume_intersections_visitor;
 mod composite_closest_point_visitor;
 mod composite_point_containment_test;
 #[cfg(feature = "std")]
+mod k_nearest_visitor;
+#[cfg(feature = "std")]
 mod point_intersections_visitor;
 #[cfg(feature = "std")]
 mod ray_intersections_visitor;
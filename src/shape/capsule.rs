@@ -1,5 +1,6 @@
-use crate::math::{Isometry, Real, Rotation, UnitVector, Vector};
+use crate::math::{Isometry, Real, Rotation, SymmetricEigen, UnitVector, Vector, DEFAULT_EPSILON};
 use crate::shape::{Segment, SupportMap};
+use crate::utils;
 
 #[cfg(feature = "std")]
 use either::Either;
@@ -51,6 +52,101 @@ impl Capsule {
         Self { segment, radius }
     }
 
+    /// Creates a capsule centered at the origin and aligned with the `y` axis, together with the
+    /// isometry placing its endpoints at the world-space points `a` and `b`.
+    ///
+    /// Transforming the returned capsule by the returned isometry yields a capsule with the
+    /// segment `[a, b]`.
+    pub fn from_endpoints(a: Vector, b: Vector, radius: Real) -> (Isometry, Self) {
+        let length = (b - a).length();
+
+        if length == 0.0 {
+            let capsule = Self::new(Vector::ZERO, Vector::ZERO, radius);
+            return (Isometry::from_translation(a), capsule);
+        }
+
+        // Use the orientation closest to `Y` so the rotation is never a half-turn. The local
+        // endpoints are swapped instead.
+        let mut dir = (b - a) / length;
+        let flipped = dir.y < 0.0;
+        if flipped {
+            dir = -dir;
+        }
+
+        #[cfg(feature = "dim2")]
+        let rotation = Rotation::from_rotation_arc_colinear(Vector::Y, dir);
+        #[cfg(feature = "dim3")]
+        let rotation =
+            Rotation::from_rotation_arc_colinear(Vector::Y, dir).unwrap_or(Rotation::IDENTITY);
+
+        let half_axis = Vector::Y * (length / 2.0);
+        let capsule = if flipped {
+            Self::new(half_axis, -half_axis, radius)
+        } else {
+            Self::new(-half_axis, half_axis, radius)
+        };
+        let pos = Isometry {
+            translation: (a + b) / 2.0,
+            rotation,
+        };
+
+        (pos, capsule)
+    }
+
+    /// Fits a capsule enclosing all the given points, together with the isometry placing it.
+    ///
+    /// The capsule axis is the principal axis of the point set, computed from its covariance
+    /// matrix. The radius is the largest distance between a point and that axis, and the segment
+    /// is the shortest one such that the capsule still encloses every point. The returned capsule
+    /// and isometry are the ones given by [`Self::from_endpoints`].
+    ///
+    /// # Panics
+    /// Panics if `points` is empty.
+    pub fn from_points(points: &[Vector]) -> (Isometry, Self) {
+        assert!(
+            !points.is_empty(),
+            "Cannot fit a capsule to an empty set of points."
+        );
+
+        let (center, cov) = utils::center_cov(points);
+        let eigen = SymmetricEigen::new(cov).reverse();
+        let axis = eigen
+            .eigenvectors
+            .x_axis
+            .try_normalize()
+            .unwrap_or(Vector::Y);
+
+        let radius = points
+            .iter()
+            .map(|pt| {
+                let dpt = *pt - center;
+                (dpt - axis * dpt.dot(axis)).length()
+            })
+            .fold(0.0, Real::max);
+
+        // Each point is enclosed if the segment reaches within `sqrt(radius² - dist²)` of its
+        // projection on the axis, where `dist` is its distance to the axis.
+        let mut min_end = Real::MIN;
+        let mut max_start = Real::MAX;
+
+        for pt in points {
+            let dpt = *pt - center;
+            let t = dpt.dot(axis);
+            let dist_sq = (dpt - axis * t).length_squared();
+            let half_span = (radius * radius - dist_sq).max(0.0).sqrt();
+            min_end = min_end.max(t - half_span);
+            max_start = max_start.min(t + half_span);
+        }
+
+        if max_start >= min_end {
+            // A single point of the axis is close enough to all the points.
+            let mid = center + axis * ((max_start + min_end) / 2.0);
+            Self::from_endpoints(mid, mid, radius)
+        } else {
+            Self::from_endpoints(center + axis * max_start, center + axis * min_end, radius)
+        }
+    }
+
     /// The height of this capsule.
     pub fn height(&self) -> Real {
         (self.segment.b - self.segment.a).length()
@@ -66,9 +162,25 @@ impl Capsule {
         (self.segment.a + self.segment.b) / 2.0
     }
 
+    /// The segment of this capsule if its radius is zero, up to `DEFAULT_EPSILON`.
+    ///
+    /// A capsule with a zero radius collapses to its principal axis, so geometric queries on it
+    /// can be performed on the returned [`Segment`] instead.
+    pub fn as_segment(&self) -> Option<Segment> {
+        if self.radius <= DEFAULT_EPSILON {
+            Some(self.segment)
+        } else {
+            None
+        }
+    }
+
     /// Creates a new capsule equal to `self` with all its endpoints transformed by `pos`.
     pub fn transform_by(&self, pos: Isometry) -> Self {
-        Self::new(pos * self.segment.a, pos * self.segment.b, self.radius)
+        Self::new(
+            pos.transform_point(self.segment.a),
+            pos.transform_point(self.segment.b),
+            self.radius,
+        )
     }
 
     /// The transformation such that `t * Y` is collinear with `b - a` and `t * origin` equals
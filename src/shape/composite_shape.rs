@@ -1,6 +1,10 @@
+#[cfg(feature = "std")]
+use crate::bounding_volume::Aabb;
 use crate::math::Isometry;
 use crate::partitioning::{GenericQbvh, IndexedData, Qbvh, QbvhStorage};
 use crate::shape::Shape;
+#[cfg(feature = "std")]
+use crate::utils::hashmap::HashMap;
 use crate::utils::DefaultStorage;
 
 /// Trait implemented by shapes composed of multiple simpler shapes.
@@ -14,6 +18,72 @@ pub trait SimdCompositeShape {
 
     /// Gets the acceleration structure of the composite shape.
     fn qbvh(&self) -> &Qbvh<u32>;
+
+    /// Iterates through the ids of all the parts of this composite shape, together with their
+    /// AABBs transformed by `pos`.
+    ///
+    /// The AABBs are the ones stored in the leaves of the acceleration structure, so they may be
+    /// slightly larger than the tightest AABBs of the transformed parts.
+    fn parts_with_aabb(&self, pos: Isometry) -> impl Iterator<Item = (u32, Aabb)> + '_
+    where
+        Self: Sized,
+    {
+        let qbvh = self.qbvh();
+        qbvh.iter_data().filter_map(move |(node_id, part_id)| {
+            Some((*part_id, qbvh.node_aabb(node_id)?.transform_by(pos)))
+        })
+    }
+}
+
+/// Trait implemented by composite shapes whose parts can be removed.
+///
+/// A removed part is ignored by all the subsequent queries, but it is kept in the shape so the
+/// ids of the other parts don’t change until [`Self::compact`] is called.
+#[cfg(feature = "std")]
+pub trait CompactableCompositeShape: SimdCompositeShape {
+    /// Removes the part with the id `part_id` from the acceleration structure of this shape.
+    ///
+    /// Returns `false` if the part was already removed or doesn’t exist.
+    fn remove_part(&mut self, part_id: u32) -> bool;
+
+    /// Deletes the removed parts and rebuilds this shape.
+    ///
+    /// The remaining parts are renumbered contiguously, keeping their relative order. Returns
+    /// the map from the old id of each remaining part to its new id, which can be used to update
+    /// external references to these parts.
+    fn compact(&mut self) -> HashMap<u32, u32>;
+}
+
+/// Removes the leaf `part_id` from `qbvh`, returning `false` if it was already removed or
+/// doesn’t exist.
+#[cfg(feature = "std")]
+pub(crate) fn remove_qbvh_part(qbvh: &mut Qbvh<u32>, part_id: u32) -> bool {
+    let is_attached = qbvh
+        .raw_proxies()
+        .get(part_id as usize)
+        .map_or(false, |proxy| !proxy.is_detached());
+
+    is_attached && qbvh.remove(part_id).is_some()
+}
+
+/// The ids of the `num_parts` parts of a composite shape that weren’t removed from `qbvh`, in
+/// increasing order, together with the map from each of them to its position in that list.
+#[cfg(feature = "std")]
+pub(crate) fn remaining_parts(
+    qbvh: &Qbvh<u32>,
+    num_parts: usize,
+) -> (Vec<usize>, HashMap<u32, u32>) {
+    let mut remaining = Vec::with_capacity(num_parts);
+    let mut remap = HashMap::default();
+
+    for (old_id, proxy) in qbvh.raw_proxies().iter().enumerate().take(num_parts) {
+        if !proxy.is_detached() {
+            let _ = remap.insert(old_id as u32, remaining.len() as u32);
+            remaining.push(old_id);
+        }
+    }
+
+    (remaining, remap)
 }
 
 pub trait TypedSimdCompositeShape {
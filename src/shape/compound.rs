@@ -5,11 +5,15 @@
 use crate::bounding_volume::{Aabb, BoundingSphere, BoundingVolume};
 use crate::math::Isometry;
 use crate::partitioning::Qbvh;
+use crate::shape::composite_shape;
+use crate::shape::{
+    CompactableCompositeShape, Shape, SharedShape, SimdCompositeShape, TypedSimdCompositeShape,
+};
 #[cfg(feature = "dim2")]
 use crate::shape::{ConvexPolygon, TriMesh, Triangle};
-use crate::shape::{Shape, SharedShape, SimdCompositeShape, TypedSimdCompositeShape};
 #[cfg(feature = "dim2")]
 use crate::transformation::hertel_mehlhorn;
+use crate::utils::hashmap::HashMap;
 use crate::utils::DefaultStorage;
 
 /// A compound shape with an aabb bounding volume.
@@ -135,6 +139,29 @@ impl SimdCompositeShape for Compound {
     }
 }
 
+impl CompactableCompositeShape for Compound {
+    /// Removes the `part_id`-th shape from the acceleration structure of this compound shape.
+    ///
+    /// The [`Aabb`] of this compound isn’t shrunk until [`Self::compact`] is called.
+    fn remove_part(&mut self, part_id: u32) -> bool {
+        composite_shape::remove_qbvh_part(&mut self.qbvh, part_id)
+    }
+
+    /// Deletes the shapes removed with [`Self::remove_part`] and rebuilds this compound shape.
+    ///
+    /// # Panics
+    /// Panics if all the shapes of this compound were removed.
+    fn compact(&mut self) -> HashMap<u32, u32> {
+        let (remaining, remap) = composite_shape::remaining_parts(&self.qbvh, self.shapes.len());
+        let shapes = remaining
+            .into_iter()
+            .map(|i| self.shapes[i].clone())
+            .collect();
+        *self = Compound::new(shapes);
+        remap
+    }
+}
+
 impl TypedSimdCompositeShape for Compound {
     type PartShape = dyn Shape;
     type PartId = u32;
@@ -1,7 +1,7 @@
 //! Support mapping based Cone shape.
 
-use crate::math::{Real, Vector};
-use crate::shape::SupportMap;
+use crate::math::{Matrix3, Real, Rotation, Vector};
+use crate::shape::{CoordinateAxis, SupportMap};
 
 #[cfg(feature = "std")]
 use either::Either;
@@ -9,9 +9,14 @@ use either::Either;
 #[cfg(feature = "rkyv")]
 use rkyv::{bytecheck, CheckBytes};
 
-/// Cone shape with its principal axis aligned with the `y` axis.
+/// Cone shape with its principal axis aligned with one of the coordinate axes.
+///
+/// The principal axis is the `y` axis unless specified otherwise with [`Cone::with_axis`].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "bytemuck",
+    derive(bytemuck::CheckedBitPattern, bytemuck::NoUninit, bytemuck::Zeroable)
+)]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, CheckBytes),
@@ -25,6 +30,15 @@ pub struct Cone {
     pub half_height: Real,
     /// The base radius of the cone.
     pub radius: Real,
+    /// The principal axis of the cone.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub axis: CoordinateAxis,
+    /// The side of the principal axis the apex of the cone points toward.
+    ///
+    /// The apex points toward the negative side of [`Self::axis`] if this is negative, and
+    /// toward its positive side otherwise.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub apex_sign: Real,
 }
 
 impl Cone {
@@ -34,12 +48,75 @@ impl Cone {
     /// * `half_height` - the half length of the cone along the `y` axis.
     /// * `radius` - the length of the cone along all other axis.
     pub fn new(half_height: Real, radius: Real) -> Cone {
+        Self::with_axis(half_height, radius, CoordinateAxis::Y)
+    }
+
+    /// Creates a new cone with its principal axis aligned with `axis`.
+    ///
+    /// # Arguments:
+    /// * `half_height` - the half length of the cone along `axis`.
+    /// * `radius` - the length of the cone along all other axis.
+    /// * `axis` - the principal axis of the cone, pointing from its base toward its apex.
+    pub fn with_axis(half_height: Real, radius: Real, axis: CoordinateAxis) -> Cone {
         Cone {
             half_height,
             radius,
+            axis,
+            apex_sign: 1.0,
+        }
+    }
+
+    /// `-1.0` if the apex points toward the negative side of the principal axis, `1.0` otherwise.
+    #[inline]
+    fn flip(&self) -> Real {
+        (1.0 as Real).copysign(self.apex_sign)
+    }
+
+    /// The unit vector pointing from the base of this cone toward its apex.
+    ///
+    /// This is either the unit vector along [`Self::axis`] or its opposite, e.g., after the cone was mirrored across
+    /// the plane normal to its principal axis.
+    #[inline]
+    pub fn apex_direction(&self) -> Vector {
+        self.axis.unit_vector() * self.flip()
+    }
+
+    /// This cone with its apex pointing toward the opposite direction.
+    #[inline]
+    pub(crate) fn flipped(&self) -> Self {
+        Cone {
+            apex_sign: -self.flip(),
+            ..*self
         }
     }
 
+    /// Expresses `v` in the frame where the apex of this cone points toward `+y`.
+    #[inline]
+    pub(crate) fn to_y_up(&self, v: Vector) -> Vector {
+        let v = self.axis.to_y_up(v);
+        let flip = self.flip();
+        Vector::new(v.x, v.y * flip, v.z * flip)
+    }
+
+    /// Inverse of [`Self::to_y_up`].
+    #[inline]
+    pub(crate) fn from_y_up(&self, v: Vector) -> Vector {
+        let flip = self.flip();
+        self.axis
+            .from_y_up(Vector::new(v.x, v.y * flip, v.z * flip))
+    }
+
+    /// The rotation applied by [`Self::from_y_up`].
+    #[inline]
+    pub(crate) fn rotation_from_y_up(&self) -> Rotation {
+        Matrix3::from_cols(
+            self.from_y_up(Vector::X),
+            self.from_y_up(Vector::Y),
+            self.from_y_up(Vector::Z),
+        )
+        .into()
+    }
+
     /// Computes a scaled version of this cone.
     ///
     /// If the scaling factor is non-uniform, then it can’t be represented as
@@ -53,9 +130,10 @@ impl Cone {
         scale: Vector,
         nsubdivs: u32,
     ) -> Option<Either<Self, super::ConvexPolyhedron>> {
-        // NOTE: if the y scale is negative, the result cone points downwards,
-        //       which can’t be represented with this Cone (without a transform).
-        if scale.x != scale.z || scale.y < 0.0 {
+        // The scaling factors along the principal axis and the two other axes.
+        let scale_y_up = self.axis.to_y_up(scale);
+
+        if scale_y_up.x != scale_y_up.z {
             // The scaled shape isn’t a cone.
             let (mut vtx, idx) = self.to_trimesh(nsubdivs);
             vtx.iter_mut().for_each(|pt| *pt = *pt * scale);
@@ -63,10 +141,13 @@ impl Cone {
                 vtx, &idx,
             )?))
         } else {
-            Some(Either::Left(Self::new(
-                self.half_height * scale.y,
-                self.radius * scale.x,
-            )))
+            // A negative scale along the principal axis makes the apex point the other way.
+            Some(Either::Left(Cone {
+                half_height: self.half_height * scale_y_up.y.abs(),
+                radius: self.radius * scale_y_up.x,
+                axis: self.axis,
+                apex_sign: self.flip() * scale_y_up.y.signum(),
+            }))
         }
     }
 }
@@ -74,6 +155,7 @@ impl Cone {
 impl SupportMap for Cone {
     #[inline]
     fn local_support_point(&self, dir: Vector) -> Vector {
+        let dir = self.to_y_up(dir);
         let mut vres = dir;
 
         vres[1] = 0.0;
@@ -92,6 +174,6 @@ impl SupportMap for Cone {
             }
         }
 
-        vres
+        self.from_y_up(vres)
     }
 }
@@ -0,0 +1,202 @@
+use crate::math::{Real, Vector};
+use crate::shape::{Shape, TypedShape};
+
+#[cfg(feature = "std")]
+use crate::math::Isometry;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+use crate::utils::Array2;
+
+/// A 64-bits FNV-1a hasher.
+///
+/// This is used instead of `std::hash::Hasher` implementations because its output must be
+/// identical on every platform, and every version of the standard library.
+struct ContentHasher(u64);
+
+impl ContentHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        for byte in i.to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write_u32(i as u32);
+        self.write_u32((i >> 32) as u32);
+    }
+
+    fn write_real(&mut self, x: Real) {
+        // Make `0.0` and `-0.0` hash identically since they compare equal.
+        let x = if x == 0.0 { 0.0 } else { x };
+        self.write_u32(x.to_bits());
+    }
+
+    fn write_vector(&mut self, v: Vector) {
+        for x in v.to_array() {
+            self.write_real(x);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn write_isometry(&mut self, pos: Isometry) {
+        self.write_vector(pos.translation);
+        #[cfg(feature = "dim2")]
+        {
+            self.write_real(pos.rotation.cos);
+            self.write_real(pos.rotation.sin);
+        }
+        #[cfg(feature = "dim3")]
+        for x in pos.rotation.to_array() {
+            self.write_real(x);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn write_vectors(&mut self, vs: &[Vector]) {
+        self.write_u64(vs.len() as u64);
+        for v in vs {
+            self.write_vector(*v);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn write_indices<const N: usize>(&mut self, indices: &[[u32; N]]) {
+        self.write_u64(indices.len() as u64);
+        for idx in indices.iter().flatten() {
+            self.write_u32(*idx);
+        }
+    }
+}
+
+/// Computes the content hash of `shape`, as described by [`Shape::content_hash`].
+pub(crate) fn content_hash(shape: &(impl Shape + ?Sized)) -> u64 {
+    let mut hasher = ContentHasher::new();
+    hasher.write_u32(shape.shape_type() as u32);
+
+    match shape.as_typed_shape() {
+        TypedShape::Ball(s) => hasher.write_real(s.radius),
+        TypedShape::Cuboid(s) => hasher.write_vector(s.half_extents),
+        TypedShape::Capsule(s) => {
+            hasher.write_vector(s.segment.a);
+            hasher.write_vector(s.segment.b);
+            hasher.write_real(s.radius);
+        }
+        TypedShape::Segment(s) => {
+            hasher.write_vector(s.a);
+            hasher.write_vector(s.b);
+        }
+        TypedShape::Triangle(s) => {
+            hasher.write_vector(s.a);
+            hasher.write_vector(s.b);
+            hasher.write_vector(s.c);
+        }
+        #[cfg(feature = "std")]
+        TypedShape::TriMesh(s) => {
+            hasher.write_vectors(s.vertices());
+            hasher.write_indices(s.indices());
+            hasher.write_u32(s.flags().bits() as u32);
+        }
+        #[cfg(feature = "std")]
+        TypedShape::Polyline(s) => {
+            hasher.write_vectors(s.vertices());
+            hasher.write_indices(s.indices());
+        }
+        TypedShape::HalfSpace(s) => hasher.write_vector(*s.normal),
+        #[cfg(feature = "std")]
+        TypedShape::HeightField(s) => {
+            let heights = s.heights();
+            hasher.write_vector(s.scale());
+
+            #[cfg(feature = "dim2")]
+            {
+                hasher.write_u64(heights.len() as u64);
+                for i in 0..heights.len() {
+                    hasher.write_real(heights[i]);
+                }
+            }
+            #[cfg(feature = "dim3")]
+            {
+                let statuses = s.cells_statuses();
+                hasher.write_u64(heights.nrows() as u64);
+                hasher.write_u64(heights.ncols() as u64);
+                for i in 0..heights.nrows() {
+                    for j in 0..heights.ncols() {
+                        hasher.write_real(heights.get(i, j));
+                    }
+                }
+                for i in 0..statuses.nrows() {
+                    for j in 0..statuses.ncols() {
+                        hasher.write_u32(statuses.get(i, j).bits() as u32);
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "std")]
+        TypedShape::Compound(s) => {
+            hasher.write_u64(s.shapes().len() as u64);
+            for (pos, part) in s.shapes() {
+                hasher.write_isometry(*pos);
+                hasher.write_u64(part.content_hash());
+            }
+        }
+        #[cfg(feature = "dim2")]
+        #[cfg(feature = "std")]
+        TypedShape::ConvexPolygon(s) => hasher.write_vectors(s.points()),
+        #[cfg(feature = "dim3")]
+        #[cfg(feature = "std")]
+        TypedShape::ConvexPolyhedron(s) => hasher.write_vectors(s.points()),
+        #[cfg(feature = "dim3")]
+        TypedShape::Cylinder(s) => {
+            hasher.write_real(s.half_height);
+            hasher.write_real(s.radius);
+            hasher.write_u32(s.axis as u32);
+        }
+        #[cfg(feature = "dim3")]
+        TypedShape::Cone(s) => {
+            hasher.write_real(s.half_height);
+            hasher.write_real(s.radius);
+            hasher.write_vector(s.apex_direction());
+        }
+        TypedShape::RoundCuboid(s) => {
+            hasher.write_u64(s.inner_shape.content_hash());
+            hasher.write_real(s.border_radius);
+        }
+        TypedShape::RoundTriangle(s) => {
+            hasher.write_u64(s.inner_shape.content_hash());
+            hasher.write_real(s.border_radius);
+        }
+        #[cfg(feature = "dim3")]
+        TypedShape::RoundCylinder(s) => {
+            hasher.write_u64(s.inner_shape.content_hash());
+            hasher.write_real(s.border_radius);
+        }
+        #[cfg(feature = "dim3")]
+        TypedShape::RoundCone(s) => {
+            hasher.write_u64(s.inner_shape.content_hash());
+            hasher.write_real(s.border_radius);
+        }
+        #[cfg(feature = "dim3")]
+        #[cfg(feature = "std")]
+        TypedShape::RoundConvexPolyhedron(s) => {
+            hasher.write_u64(s.inner_shape.content_hash());
+            hasher.write_real(s.border_radius);
+        }
+        #[cfg(feature = "dim2")]
+        #[cfg(feature = "std")]
+        TypedShape::RoundConvexPolygon(s) => {
+            hasher.write_u64(s.inner_shape.content_hash());
+            hasher.write_real(s.border_radius);
+        }
+        TypedShape::Custom(id) => hasher.write_u32(id),
+    }
+
+    hasher.0
+}
@@ -0,0 +1,80 @@
+use crate::math::{Matrix3, Rotation, Vector};
+
+#[cfg(feature = "rkyv")]
+use rkyv::{bytecheck, CheckBytes};
+
+/// One of the coordinate axes, used as the symmetry axis of shapes of revolution.
+///
+/// Shapes like [`Cylinder`](crate::shape::Cylinder) and [`Cone`](crate::shape::Cone) are
+/// modeled with the `Y` axis as their symmetry axis. Another axis maps the `Y`-aligned model
+/// to the chosen axis with a cyclic permutation of the coordinates, which is a rotation.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "bytemuck",
+    derive(bytemuck::CheckedBitPattern, bytemuck::NoUninit, bytemuck::Zeroable)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, CheckBytes),
+    archive(as = "Self")
+)]
+#[cfg_attr(feature = "cuda", derive(cust_core::DeviceCopy))]
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Hash, Default)]
+#[repr(u32)]
+pub enum CoordinateAxis {
+    /// The `X` axis.
+    X = 0,
+    /// The `Y` axis.
+    #[default]
+    Y = 1,
+    /// The `Z` axis.
+    Z = 2,
+}
+
+impl CoordinateAxis {
+    /// The index of this axis, i.e., `0` for `X`, `1` for `Y`, and `2` for `Z`.
+    #[inline]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The unit vector along this axis.
+    #[inline]
+    pub fn unit_vector(self) -> Vector {
+        match self {
+            CoordinateAxis::X => Vector::X,
+            CoordinateAxis::Y => Vector::Y,
+            CoordinateAxis::Z => Vector::Z,
+        }
+    }
+
+    /// Expresses `v` in the frame where this axis becomes the `Y` axis.
+    ///
+    /// This is the cyclic permutation `zxy` for `X`, the identity for `Y`, and `yzx` for `Z`.
+    /// It is computed by indexing rather than by branching on the axis.
+    #[inline]
+    pub fn to_y_up(self, v: Vector) -> Vector {
+        let i = self.index();
+        let v = v.to_array();
+        Vector::new(v[(i + 2) % 3], v[i], v[(i + 1) % 3])
+    }
+
+    /// Inverse of [`Self::to_y_up`]: maps `v` so that the `Y` axis becomes this axis.
+    #[inline]
+    pub fn from_y_up(self, v: Vector) -> Vector {
+        let i = self.index();
+        let v = v.to_array();
+        Vector::new(v[(4 - i) % 3], v[(5 - i) % 3], v[(3 - i) % 3])
+    }
+
+    /// The rotation applied by [`Self::from_y_up`].
+    #[inline]
+    pub fn rotation_from_y_up(self) -> Rotation {
+        Matrix3::from_cols(
+            self.from_y_up(Vector::X),
+            self.from_y_up(Vector::Y),
+            self.from_y_up(Vector::Z),
+        )
+        .into()
+    }
+}
@@ -1,7 +1,9 @@
 //! Support mapping based Cuboid shape.
 
 use crate::math::UnitVector;
-use crate::math::Vector;
+#[cfg(feature = "std")]
+use crate::math::{Isometry, Real};
+use crate::math::{Vector, DIM};
 #[cfg(feature = "dim3")]
 use crate::shape::Segment;
 use crate::shape::{FeatureId, PackedFeatureId, PolygonalFeature, SupportMap};
@@ -43,6 +45,78 @@ impl Cuboid {
         }
     }
 
+    /// Splits this cuboid into a regular grid of `counts[i]` cells along each axis `i`.
+    ///
+    /// Returns, for each cell, its pose relative to this cuboid's local frame together with
+    /// its shape. The cells tile this cuboid exactly without overlapping. They are ordered with
+    /// the cell index along the `X` axis varying the fastest.
+    ///
+    /// # Panics
+    /// Panics if any element of `counts` is zero.
+    #[cfg(feature = "std")]
+    pub fn subdivide(&self, counts: [usize; DIM]) -> Vec<(Isometry, Cuboid)> {
+        assert!(
+            counts.iter().all(|count| *count > 0),
+            "A cuboid cannot be subdivided into zero cells along an axis."
+        );
+
+        let mut cell_half_extents = self.half_extents;
+        for i in 0..DIM {
+            cell_half_extents[i] /= counts[i] as Real;
+        }
+
+        let num_cells = counts.iter().product();
+        let mut cells = Vec::with_capacity(num_cells);
+
+        for cell_id in 0..num_cells {
+            let mut remainder = cell_id;
+            let mut center = -self.half_extents;
+
+            for i in 0..DIM {
+                let k = remainder % counts[i];
+                remainder /= counts[i];
+                center[i] += cell_half_extents[i] * (2 * k + 1) as Real;
+            }
+
+            cells.push((
+                Isometry::from_translation(center),
+                Cuboid::new(cell_half_extents),
+            ));
+        }
+
+        cells
+    }
+
+    /// The outward normals of the faces of any cuboid, ordered by face id.
+    ///
+    /// The normal at index `i` is the normal of the face `FeatureId::Face(i)`, i.e., `+X, +Y`
+    /// followed by `-X, -Y`.
+    #[cfg(feature = "dim2")]
+    pub fn face_normals() -> [UnitVector; 2 * DIM] {
+        [
+            UnitVector::X,
+            UnitVector::Y,
+            UnitVector::NEG_X,
+            UnitVector::NEG_Y,
+        ]
+    }
+
+    /// The outward normals of the faces of any cuboid, ordered by face id.
+    ///
+    /// The normal at index `i` is the normal of the face `FeatureId::Face(i)`, i.e., `+X, +Y, +Z`
+    /// followed by `-X, -Y, -Z`.
+    #[cfg(feature = "dim3")]
+    pub fn face_normals() -> [UnitVector; 2 * DIM] {
+        [
+            UnitVector::X,
+            UnitVector::Y,
+            UnitVector::Z,
+            UnitVector::NEG_X,
+            UnitVector::NEG_Y,
+            UnitVector::NEG_Z,
+        ]
+    }
+
     /// Return the id of the vertex of this cuboid with a normal that maximizes
     /// the dot product with `dir`.
     #[cfg(feature = "dim2")]
@@ -334,6 +408,22 @@ impl SupportMap for Cuboid {
     fn local_support_point(&self, dir: Vector) -> Vector {
         dir.copy_sign_to(self.half_extents).into()
     }
+
+    /// The support point along with its vertex id, where the `i`-th bit of the id is set if
+    /// the `i`-th coordinate of the vertex is negative.
+    #[inline]
+    fn local_support_feature(&self, dir: Vector) -> (Vector, FeatureId) {
+        let vertex = self.local_support_point(dir);
+        let mut vid = 0;
+
+        for i in 0..DIM {
+            if vertex[i].is_sign_negative() {
+                vid |= 1 << i;
+            }
+        }
+
+        (vertex, FeatureId::Vertex(vid))
+    }
 }
 
 /*
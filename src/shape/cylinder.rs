@@ -1,7 +1,7 @@
 //! Support mapping based Cylinder shape.
 
-use crate::math::{Real, Vector};
-use crate::shape::SupportMap;
+use crate::math::{real_consts, Real, Vector, DEFAULT_EPSILON};
+use crate::shape::{CoordinateAxis, Segment, SupportMap};
 
 #[cfg(feature = "std")]
 use either::Either;
@@ -9,9 +9,14 @@ use either::Either;
 #[cfg(feature = "rkyv")]
 use rkyv::{bytecheck, CheckBytes};
 
-/// Cylinder shape with its principal axis aligned with the `y` axis.
+/// Cylinder shape with its principal axis aligned with one of the coordinate axes.
+///
+/// The principal axis is the `y` axis unless specified otherwise with [`Cylinder::with_axis`].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    feature = "bytemuck",
+    derive(bytemuck::CheckedBitPattern, bytemuck::NoUninit, bytemuck::Zeroable)
+)]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, CheckBytes),
@@ -25,6 +30,9 @@ pub struct Cylinder {
     pub half_height: Real,
     /// The radius fo the cylinder.
     pub radius: Real,
+    /// The principal axis of the cylinder.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub axis: CoordinateAxis,
 }
 
 impl Cylinder {
@@ -34,11 +42,22 @@ impl Cylinder {
     /// * `half_height` - the half length of the cylinder along the `y` axis.
     /// * `radius` - the length of the cylinder along all other axis.
     pub fn new(half_height: Real, radius: Real) -> Cylinder {
+        Self::with_axis(half_height, radius, CoordinateAxis::Y)
+    }
+
+    /// Creates a new cylinder with its principal axis aligned with `axis`.
+    ///
+    /// # Arguments:
+    /// * `half_height` - the half length of the cylinder along `axis`.
+    /// * `radius` - the length of the cylinder along all other axis.
+    /// * `axis` - the principal axis of the cylinder.
+    pub fn with_axis(half_height: Real, radius: Real, axis: CoordinateAxis) -> Cylinder {
         assert!(half_height.is_sign_positive() && radius.is_sign_positive());
 
         Cylinder {
             half_height,
             radius,
+            axis,
         }
     }
 
@@ -55,7 +74,10 @@ impl Cylinder {
         scale: Vector,
         nsubdivs: u32,
     ) -> Option<Either<Self, super::ConvexPolyhedron>> {
-        if scale.x != scale.z {
+        // The scaling factors along the principal axis and the two other axes.
+        let scale_y_up = self.axis.to_y_up(scale);
+
+        if scale_y_up.x != scale_y_up.z {
             // The scaled shape isn’t a cylinder.
             let (mut vtx, idx) = self.to_trimesh(nsubdivs);
             vtx.iter_mut().for_each(|pt| *pt = *pt * scale);
@@ -63,27 +85,87 @@ impl Cylinder {
                 vtx, &idx,
             )?))
         } else {
-            Some(Either::Left(Self::new(
-                self.half_height * scale.y,
-                self.radius * scale.x,
+            Some(Either::Left(Self::with_axis(
+                self.half_height * scale_y_up.y,
+                self.radius * scale_y_up.x,
+                self.axis,
             )))
         }
     }
 }
 
+/// The feature of a [`Cylinder`] supporting it along a given direction.
+///
+/// See [`Cylinder::support_feature`].
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum CylinderFeature {
+    /// One of the flat caps, approximated by a regular polygon inscribed in the cap's rim.
+    ///
+    /// The vertices are given in counterclockwise order when seen from outside of the cylinder.
+    Cap([Vector; CylinderFeature::NUM_CAP_VERTICES]),
+    /// A segment on the curved part of the cylinder, parallel to its principal axis.
+    Lateral(Segment),
+    /// A segment approximating the rim circle of one of the caps near the support point.
+    ///
+    /// This is the edge of the [`CylinderFeature::Cap`] polygon centered on the support point.
+    Rim(Segment),
+}
+
+impl CylinderFeature {
+    /// The number of vertices of the polygon approximating a cylinder cap.
+    pub const NUM_CAP_VERTICES: usize = 8;
+}
+
+impl Cylinder {
+    /// Computes the feature of this cylinder supporting it along `dir`, for contact clipping.
+    ///
+    /// Returns a cap if `dir` is aligned with the principal axis, a lateral segment if `dir`
+    /// is orthogonal to the principal axis, and a segment of the rim circle otherwise.
+    pub fn support_feature(&self, dir: Vector) -> CylinderFeature {
+        let eps = DEFAULT_EPSILON.sqrt();
+        let dir = self.axis.to_y_up(dir).normalize_or_zero();
+        let radial_len = (dir.x * dir.x + dir.z * dir.z).sqrt();
+        let angle = if radial_len > 0.0 {
+            dir.z.atan2(dir.x)
+        } else {
+            0.0
+        };
+        let rim_pt = |y: Real, angle: Real| {
+            let (sin, cos) = angle.sin_cos();
+            self.axis
+                .from_y_up(Vector::new(cos * self.radius, y, sin * self.radius))
+        };
+
+        if dir.y.abs() <= eps {
+            return CylinderFeature::Lateral(Segment::new(
+                rim_pt(-self.half_height, angle),
+                rim_pt(self.half_height, angle),
+            ));
+        }
+
+        // Seen from outside, the cap vertices are counterclockwise with decreasing
+        // angles on the top cap and increasing angles on the bottom cap.
+        let y = self.half_height.copysign(dir.y);
+        let step = -real_consts::TAU / (CylinderFeature::NUM_CAP_VERTICES as Real) * dir.y.signum();
+        let cap_vertex = |k: usize| rim_pt(y, angle + (k as Real - 0.5) * step);
+
+        if radial_len <= eps {
+            CylinderFeature::Cap(core::array::from_fn(cap_vertex))
+        } else {
+            CylinderFeature::Rim(Segment::new(cap_vertex(0), cap_vertex(1)))
+        }
+    }
+}
+
 impl SupportMap for Cylinder {
     fn local_support_point(&self, dir: Vector) -> Vector {
+        let dir = self.axis.to_y_up(dir);
         let mut vres = dir;
 
         vres[1] = 0.0;
-        vres = vres.normalize();
-
-        if vres != Vector::ZERO {
-            vres = vres * self.radius;
-        }
-
+        vres = vres.normalize_or_zero() * self.radius;
         vres[1] = self.half_height.copysign(dir[1]);
 
-        vres
+        self.axis.from_y_up(vres)
     }
 }
@@ -0,0 +1,102 @@
+//! Trait for shapes that can be mirrored across a coordinate plane.
+
+use crate::math::{Vector, DIM};
+use crate::shape::{Ball, Capsule, Cuboid, Segment, Triangle};
+#[cfg(feature = "dim3")]
+use crate::shape::{Cone, Cylinder};
+
+/// Trait of shapes that can be mirrored across a plane orthogonal to one of the coordinate axes
+/// and passing through the local-space origin.
+///
+/// The support point of the mirrored shape along any direction is equal to the mirrored
+/// support point of the original shape along the mirrored direction.
+pub trait Mirrorable: Sized {
+    /// Mirrors this shape across the plane orthogonal to the `axis`-th coordinate axis.
+    ///
+    /// # Panics
+    /// Panics if `axis` is not smaller than the dimension of the space.
+    fn mirrored(&self, axis: usize) -> Self;
+}
+
+/// Negates the `axis`-th component of `pt`.
+#[inline]
+fn mirror_point(pt: Vector, axis: usize) -> Vector {
+    assert!(axis < DIM, "The mirroring axis must be smaller than DIM.");
+    let mut result = pt;
+    result[axis] = -result[axis];
+    result
+}
+
+impl Mirrorable for Ball {
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        assert!(axis < DIM, "The mirroring axis must be smaller than DIM.");
+        *self
+    }
+}
+
+impl Mirrorable for Cuboid {
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        // A cuboid is symmetric wrt. all its principal planes.
+        assert!(axis < DIM, "The mirroring axis must be smaller than DIM.");
+        *self
+    }
+}
+
+impl Mirrorable for Segment {
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        Segment::new(mirror_point(self.a, axis), mirror_point(self.b, axis))
+    }
+}
+
+impl Mirrorable for Triangle {
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        // NOTE: mirroring flips the orientation of the triangle, so we swap two
+        //       vertices to preserve it.
+        Triangle::new(
+            mirror_point(self.a, axis),
+            mirror_point(self.c, axis),
+            mirror_point(self.b, axis),
+        )
+    }
+}
+
+impl Mirrorable for Capsule {
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        Capsule {
+            segment: self.segment.mirrored(axis),
+            radius: self.radius,
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirrorable for Cylinder {
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        // A cylinder is symmetric wrt. all the coordinate planes.
+        assert!(axis < DIM, "The mirroring axis must be smaller than DIM.");
+        *self
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl Mirrorable for Cone {
+    /// Mirrors this cone across a coordinate plane.
+    ///
+    /// Mirroring across the plane normal to the principal axis of the cone flips the direction
+    /// of its apex. Mirroring across any other coordinate plane leaves the cone unchanged.
+    #[inline]
+    fn mirrored(&self, axis: usize) -> Self {
+        assert!(axis < DIM, "The mirroring axis must be smaller than DIM.");
+        if axis == self.axis.index() {
+            self.flipped()
+        } else {
+            *self
+        }
+    }
+}
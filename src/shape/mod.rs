@@ -7,6 +7,7 @@ pub use self::composite_shape::TypedSimdCompositeShape;
 pub use self::cuboid::Cuboid;
 pub use self::feature_id::{FeatureId, PackedFeatureId};
 pub use self::half_space::HalfSpace;
+pub use self::mirrorable::Mirrorable;
 pub use self::polygonal_feature_map::PolygonalFeatureMap;
 pub use self::round_shape::RoundShape;
 pub use self::segment::{Segment, SegmentPointLocation};
@@ -16,11 +17,16 @@ pub(crate) use self::shape::DeserializableTypedShape;
 pub use self::shape::{Shape, ShapeType, TypedShape};
 #[doc(inline)]
 pub use self::support_map::SupportMap;
+#[cfg(all(feature = "std", debug_assertions))]
+pub use self::support_map::{debug_validate_support_map, SupportMapInconsistency};
+pub use self::translated::Translated;
 pub use self::triangle::{Triangle, TriangleOrientation, TrianglePointLocation};
 
 #[cfg(feature = "std")]
 pub use self::{
-    composite_shape::SimdCompositeShape, compound::Compound, polyline::Polyline,
+    composite_shape::{CompactableCompositeShape, SimdCompositeShape},
+    compound::Compound,
+    polyline::Polyline,
     shared_shape::SharedShape,
 };
 
@@ -38,7 +44,9 @@ pub use self::cone::Cone;
 #[cfg(feature = "std")]
 pub use self::convex_polyhedron::ConvexPolyhedron;
 #[cfg(feature = "dim3")]
-pub use self::cylinder::Cylinder;
+pub use self::coordinate_axis::CoordinateAxis;
+#[cfg(feature = "dim3")]
+pub use self::cylinder::{Cylinder, CylinderFeature};
 #[cfg(feature = "dim3")]
 pub use self::heightfield3::*;
 #[cfg(feature = "dim3")]
@@ -73,8 +81,10 @@ mod capsule;
 pub mod composite_shape;
 #[cfg(feature = "std")]
 mod compound;
+mod content_hash;
 mod cuboid;
 mod half_space;
+mod mirrorable;
 #[cfg(feature = "std")]
 mod polyline;
 mod round_shape;
@@ -83,6 +93,7 @@ mod segment;
 pub mod shape;
 #[doc(hidden)]
 pub mod support_map;
+mod translated;
 mod triangle;
 
 #[cfg(feature = "dim2")]
@@ -97,6 +108,8 @@ mod cone;
 #[cfg(feature = "std")]
 mod convex_polyhedron;
 #[cfg(feature = "dim3")]
+mod coordinate_axis;
+#[cfg(feature = "dim3")]
 mod cylinder;
 #[cfg(feature = "dim3")]
 mod heightfield3;
@@ -1,4 +1,4 @@
-use crate::math::{Isometry, Real, Vector};
+use crate::math::{perp, Isometry, Real, Vector};
 #[cfg(feature = "std")]
 use crate::query::{self, ContactManifold, TrackedContact};
 use crate::shape::{PackedFeatureId, Segment};
@@ -88,7 +88,7 @@ impl PolygonalFeature {
     ) {
         let v2_1 = pos12 * vertex2.vertices[0];
         let tangent1 = face1.vertices[1] - face1.vertices[0];
-        let normal1 = Vector::new(-tangent1.y, tangent1.x);
+        let normal1 = perp(tangent1);
         let denom = -normal1.dot(sep_axis1);
         let dist = (face1.vertices[0] - v2_1).dot(normal1) / denom;
         let local_p2 = v2_1;
@@ -46,6 +46,8 @@ impl PolygonalFeatureMap for Cylinder {
         // - Each flat cap of the cylinder is approximated by a square.
         // - The curved-part segment has a feature ID of 0, and its endpoint with negative
         //   `y` coordinate has an ID of 1.
+        // - All the coordinates mentioned here are expressed in the frame where the principal
+        //   axis of the shape is the `y` axis.
         // - The bottom cap has its vertices with feature ID of 1,3,5,7 (in counter-clockwise order
         //   when looking at the cap with an eye looking towards +y).
         // - The bottom cap has its four edge feature IDs of 2,4,6,8, in counter-clockwise order.
@@ -54,6 +56,7 @@ impl PolygonalFeatureMap for Cylinder {
         //   So its vertices have IDs 11,13,15,17, its edges 12,14,16,18, and its face 19.
         // - Note that at all times, one of each cap's vertices are the same as the curved-part
         //   segment endpoints.
+        let dir = self.axis.to_y_up(*dir);
         let dir2 = Vector2::new(dir.x, dir.z)
             .try_normalize()
             .unwrap_or(Vector2::X);
@@ -91,6 +94,10 @@ impl PolygonalFeatureMap for Cylinder {
                 out_features.vids = PackedFeatureId::vertices([11, 13, 15, 17]);
             }
         }
+
+        for vtx in &mut out_features.vertices[..out_features.num_vertices] {
+            *vtx = self.axis.from_y_up(*vtx);
+        }
     }
 }
 
@@ -103,12 +110,15 @@ impl PolygonalFeatureMap for Cone {
         // - The flat cap of the cone is approximated by a square.
         // - The curved-part segment has a feature ID of 0, and its endpoint with negative
         //   `y` coordinate has an ID of 1.
+        // - All the coordinates mentioned here are expressed in the frame where the principal
+        //   axis of the shape is the `y` axis.
         // - The bottom cap has its vertices with feature ID of 1,3,5,7 (in counter-clockwise order
         //   when looking at the cap with an eye looking towards +y).
         // - The bottom cap has its four edge feature IDs of 2,4,6,8, in counter-clockwise order.
         // - The bottom cap has its face feature ID of 9.
         // - Note that at all times, one of the cap's vertices are the same as the curved-part
         //   segment endpoints.
+        let dir = self.to_y_up(*dir);
         let dir2 = Vector2::new(dir.x, dir.z)
             .try_normalize()
             .unwrap_or(Vector2::X);
@@ -138,5 +148,9 @@ impl PolygonalFeatureMap for Cone {
             out_features.num_vertices = 4;
             out_features.vids = PackedFeatureId::vertices([1, 3, 5, 7]);
         }
+
+        for vtx in &mut out_features.vertices[..out_features.num_vertices] {
+            *vtx = self.from_y_up(*vtx);
+        }
     }
 }
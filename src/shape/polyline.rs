@@ -2,9 +2,10 @@ use crate::bounding_volume::Aabb;
 use crate::math::{Isometry, Vector};
 use crate::partitioning::Qbvh;
 use crate::query::{PointProjection, PointQueryWithLocation};
-use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::composite_shape::{self, CompactableCompositeShape, SimdCompositeShape};
 use crate::shape::{FeatureId, Segment, SegmentPointLocation, Shape, TypedSimdCompositeShape};
 
+use crate::utils::hashmap::HashMap;
 use crate::utils::DefaultStorage;
 
 #[derive(Clone)]
@@ -284,6 +285,23 @@ impl SimdCompositeShape for Polyline {
     }
 }
 
+impl CompactableCompositeShape for Polyline {
+    fn remove_part(&mut self, part_id: u32) -> bool {
+        composite_shape::remove_qbvh_part(&mut self.qbvh, part_id)
+    }
+
+    /// Deletes the segments removed with [`Self::remove_part`] and rebuilds this polyline.
+    ///
+    /// The vertex buffer is kept as-is, so the removed segments may leave unused vertices.
+    fn compact(&mut self) -> HashMap<u32, u32> {
+        let (remaining, remap) = composite_shape::remaining_parts(&self.qbvh, self.indices.len());
+        let indices = remaining.into_iter().map(|i| self.indices[i]).collect();
+        let vertices = std::mem::take(&mut self.vertices);
+        *self = Polyline::new(vertices, Some(indices));
+        remap
+    }
+}
+
 impl TypedSimdCompositeShape for Polyline {
     type PartShape = Segment;
     type PartId = u32;
@@ -100,7 +100,7 @@ impl Segment {
     #[cfg(feature = "dim2")]
     pub fn scaled_normal(&self) -> Vector {
         let dir = self.scaled_direction();
-        Vector::new(dir.y, -dir.x)
+        -crate::math::perp(dir)
     }
 
     /// The not-normalized counterclockwise normal of this segment, assuming it lies on the plane
@@ -137,7 +137,24 @@ impl Segment {
 
     /// Applies the isometry `m` to the vertices of this segment and returns the resulting segment.
     pub fn transformed(&self, m: Isometry) -> Self {
-        Segment::new(m * self.a, m * self.b)
+        Segment::new(m.transform_point(self.a), m.transform_point(self.b))
+    }
+
+    /// Splits this segment at the point with parameter `t`, where `0.0` is `self.a` and `1.0` is
+    /// `self.b`.
+    ///
+    /// The first returned segment goes from `self.a` to the split point, and the second one from
+    /// the split point to `self.b`.
+    ///
+    /// # Panics
+    /// Panics if `t` is not in `[0, 1]`.
+    pub fn split_at(&self, t: Real) -> (Segment, Segment) {
+        assert!(
+            (0.0..=1.0).contains(&t),
+            "Segment::split_at: the split parameter must be in [0, 1]."
+        );
+        let pt = self.a * (1.0 - t) + self.b * t;
+        (Segment::new(self.a, pt), Segment::new(pt, self.b))
     }
 
     /// Computes the point at the given location.
@@ -1,7 +1,8 @@
 use crate::bounding_volume::{Aabb, BoundingSphere, BoundingVolume};
 use crate::mass_properties::MassProperties;
-use crate::math::{self, Isometry, Real, UnitVector, Vector};
-use crate::query::{PointQuery, RayCast};
+use crate::math::{self, Isometry, Real, UnitVector, Vector, DIM};
+use crate::query::details::distance_support_map_support_map;
+use crate::query::{PointQuery, Ray, RayCast, Unsupported};
 #[cfg(feature = "serde-serialize")]
 use crate::shape::SharedShape;
 #[cfg(feature = "std")]
@@ -20,9 +21,20 @@ use crate::shape::{ConvexPolyhedron, RoundConvexPolyhedron};
 #[cfg(feature = "dim2")]
 #[cfg(feature = "std")]
 use crate::shape::{ConvexPolygon, RoundConvexPolygon};
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+use crate::{
+    transformation::utils::{convex_outline_section, push_circle, push_circle_outline_indices},
+    utils::WBasis,
+};
 use downcast_rs::{impl_downcast, DowncastSync};
 use num_derive::FromPrimitive;
 
+/// The number of subdivisions of the circles approximating curved cross-sections.
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+const SECTION_NSUBDIVS: u32 = 32;
+
 #[derive(Copy, Clone, Debug, FromPrimitive, PartialEq, Eq, Hash)]
 /// Enum representing the type of a shape.
 pub enum ShapeType {
@@ -283,6 +295,22 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
     fn compute_aabb(&self, position: Isometry) -> Aabb {
         self.compute_local_aabb().transform_by(position)
     }
+    /// Computes the local-space [`Aabb`] of this shape, enlarged by `margin` along each axis.
+    ///
+    /// This gives the same result as `self.compute_local_aabb().loosened(margin)`, but shapes
+    /// defined by a radius or half-extents add the margin to them directly instead of loosening
+    /// their [`Aabb`] afterward. The `margin` must be non-negative.
+    fn local_aabb_loosened(&self, margin: Real) -> Aabb {
+        self.compute_local_aabb().loosened(margin)
+    }
+    /// Computes the [`Aabb`] of this shape for each of the given positions.
+    ///
+    /// Shapes whose world-space [`Aabb`] is their transformed local [`Aabb`] compute all the
+    /// positions in SIMD packets with [`Aabb::transform_by_all`].
+    #[cfg(feature = "std")]
+    fn compute_aabbs(&self, poses: &[Isometry]) -> Vec<Aabb> {
+        poses.iter().map(|pos| self.compute_aabb(*pos)).collect()
+    }
     /// Computes the bounding-sphere of this shape with the given position.
     fn compute_bounding_sphere(&self, position: Isometry) -> BoundingSphere {
         self.compute_local_bounding_sphere().transform_by(position)
@@ -297,6 +325,17 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
     /// Gets the underlying shape as an enum.
     fn as_typed_shape(&self) -> TypedShape;
 
+    /// Computes a fingerprint of this shape, hashing its type tag and its defining parameters.
+    ///
+    /// The hash is computed with a fixed algorithm, so it is identical across runs and platforms,
+    /// and can be used as a key to cache data computed for this shape. Shapes with the same
+    /// type and parameters always have the same hash, while different shapes have different
+    /// hashes with a very high probability. By default, custom shapes only hash their type tag
+    /// and should override this method to hash their parameters too.
+    fn content_hash(&self) -> u64 {
+        crate::shape::content_hash::content_hash(self)
+    }
+
     fn ccd_thickness(&self) -> Real;
 
     // TODO: document this.
@@ -307,6 +346,18 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
     // that angle may result in different contact points".
     fn ccd_angular_thickness(&self) -> Real;
 
+    /// Checks whether this shape can move at `max_speed` during a step of duration `dt` without
+    /// risking to tunnel through other shapes.
+    ///
+    /// This compares the distance travelled during the step, given by [`query::required_margin`],
+    /// to [`Shape::ccd_thickness`]. If this returns `false`, continuous collision detection should
+    /// be enabled for this shape.
+    ///
+    /// [`query::required_margin`]: crate::query::required_margin
+    fn is_ccd_safe(&self, max_speed: Real, dt: Real) -> bool {
+        crate::query::required_margin(max_speed, dt) <= self.ccd_thickness()
+    }
+
     /// Is this shape known to be convex?
     ///
     /// If this returns `true` then `self` is known to be convex.
@@ -340,6 +391,16 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
         None
     }
 
+    /// Computes the principal curvatures of this shape's surface at the given local-space point.
+    ///
+    /// The point is assumed to lie on the surface of this shape. The curvatures are sorted in
+    /// decreasing order. A curvature of zero indicates that the surface is flat along the
+    /// corresponding principal direction. The default implementation assumes the surface is flat
+    /// everywhere, which is only correct for polyhedral shapes (away from their edges and vertices).
+    fn local_curvature_at(&self, _pt_on_surface: Vector) -> [Real; DIM - 1] {
+        [0.0; DIM - 1]
+    }
+
     /// Computes the swept [`Aabb`] of this shape, i.e., the space it would occupy by moving from
     /// the given start position to the given end position.
     fn compute_swept_aabb(&self, start_pos: Isometry, end_pos: Isometry) -> Aabb {
@@ -347,6 +408,254 @@ pub trait Shape: RayCast + PointQuery + DowncastSync {
         let aabb2 = self.compute_aabb(end_pos);
         aabb1.merged(&aabb2)
     }
+
+    /// Tests whether a point moving along the given ray stays inside of this shape for the whole
+    /// sweep, i.e., for every parameter in `[0, max_toi]`.
+    ///
+    /// This returns `false` if the ray origin is outside of the shape, or if the ray crosses the
+    /// boundary of the shape before reaching `ray.point_at(max_toi)`.
+    fn ray_stays_inside(&self, pos: Isometry, ray: &Ray, max_toi: Real) -> bool {
+        // With `solid` set to `false`, a ray starting inside of the shape reports the first
+        // boundary it hits instead of stopping immediately.
+        self.contains_point(pos, ray.origin) && self.cast_ray(pos, ray, max_toi, false).is_none()
+    }
+
+    /// Computes the first point of the boundary of this shape transformed by `pos` hit by a ray
+    /// starting at `from` and going toward `dir`, e.g., for snapping a point onto a surface.
+    ///
+    /// Unlike a nearest-point projection, the point is moved along `dir` only. If `from` is
+    /// inside of the shape, the boundary point hit from the inside is returned. All the
+    /// arguments and the result are expressed in world-space.
+    fn surface_point_along(&self, pos: Isometry, from: Vector, dir: Vector) -> Option<Vector> {
+        let ray = Ray::new(from, dir);
+        self.cast_ray(pos, &ray, Real::MAX, false)
+            .map(|toi| ray.point_at(toi))
+    }
+
+    /// Computes the largest sphere fully contained in this shape, in its local-space.
+    ///
+    /// This is useful for conservative tests determining that a point is definitely inside of
+    /// the shape. Returns `None` for shapes where it isn’t implemented.
+    fn local_inscribed_sphere(&self) -> Option<BoundingSphere> {
+        None
+    }
+
+    /// Computes the distance between this shape transformed by `pos` and the world-space `aabb`.
+    ///
+    /// The `aabb` is treated as a [`Cuboid`] and the distance is computed with the GJK algorithm.
+    /// Returns `0.0` if they are intersecting, and `Err(Unsupported)` if this shape doesn’t have
+    /// a support map.
+    fn distance_to_aabb(&self, pos: Isometry, aabb: &Aabb) -> Result<Real, Unsupported> {
+        let support_map = self.as_support_map().ok_or(Unsupported)?;
+        let cuboid = Cuboid::new(aabb.half_extents());
+        let pos12 = pos.inv_mul(Isometry::from_translation(aabb.center()));
+        Ok(distance_support_map_support_map(
+            pos12,
+            support_map,
+            &cuboid,
+        ))
+    }
+
+    /// Computes the point of this shape that is the farthest from the point `from`, both
+    /// expressed in the local-space of this shape.
+    ///
+    /// For support-mapped shapes, the support function is evaluated repeatedly in the direction
+    /// going from `from` toward the current farthest point, until it no longer moves away. For
+    /// other shapes, this returns the corner of the local [`Aabb`] that is the farthest from
+    /// `from`, which is a conservative approximation that might not lie on the shape.
+    fn farthest_local_point(&self, from: Vector) -> Vector {
+        const MAX_ITERATIONS: usize = 100;
+
+        let aabb = self.compute_local_aabb();
+
+        if let Some(support_map) = self.as_support_map() {
+            let mut dir = aabb.center() - from;
+
+            if dir.length_squared() <= math::DEFAULT_EPSILON {
+                dir = Vector::X;
+            }
+
+            let mut pt = support_map.local_support_point(dir);
+            let mut dist_sq = pt.distance_squared(from);
+
+            // Each support point is at least as far from `from` as the previous one.
+            for _ in 0..MAX_ITERATIONS {
+                let new_pt = support_map.local_support_point(pt - from);
+                let new_dist_sq = new_pt.distance_squared(from);
+
+                if new_dist_sq <= dist_sq * (1.0 + math::DEFAULT_EPSILON) {
+                    break;
+                }
+
+                pt = new_pt;
+                dist_sq = new_dist_sq;
+            }
+
+            pt
+        } else {
+            let center = aabb.center();
+            center + aabb.half_extents().copysign(center - from)
+        }
+    }
+
+    /// Computes the interval `(min, max)` of the projections of this shape, transformed by
+    /// `pos`, onto the world-space `axis`.
+    ///
+    /// For support-mapped shapes, the bounds are the dot products of `axis` with the support
+    /// points along `-axis` and `axis`. For other shapes, the projection of the local [`Aabb`]
+    /// is returned, which is a conservative approximation containing the exact interval.
+    fn project_onto_axis(&self, pos: Isometry, axis: UnitVector) -> (Real, Real) {
+        let axis = *axis;
+
+        if let Some(support_map) = self.as_support_map() {
+            let min = support_map.support_point(pos, -axis).dot(axis);
+            let max = support_map.support_point(pos, axis).dot(axis);
+            (min, max)
+        } else {
+            let aabb = self.compute_local_aabb();
+            let local_axis = pos.rotation.inverse() * axis;
+            let center = pos.transform_point(aabb.center()).dot(axis);
+            let radius = aabb.half_extents().dot(local_axis.abs());
+            (center - radius, center + radius)
+        }
+    }
+
+    /// Tests whether this shape, transformed by `pos`, intersects the convex region bounded by
+    /// the given planes, e.g., a view frustum for culling.
+    ///
+    /// Each plane is given by a unit normal `n` pointing toward the inside of the region and an
+    /// offset `d`, such that the points `x` with `n.dot(x) + d >= 0` are on its inner side. The
+    /// shape is culled only if it lies entirely behind one of the planes, which is tested with
+    /// [`Shape::project_onto_axis`], so it is exact for each plane of a support-mapped shape.
+    /// As with any plane-by-plane test, a shape outside of the region but near one of its
+    /// corners may still be reported as intersecting.
+    fn intersects_frustum(&self, pos: Isometry, planes: &[(UnitVector, Real)]) -> bool {
+        planes
+            .iter()
+            .all(|(normal, offset)| self.project_onto_axis(pos, *normal).1 + offset >= 0.0)
+    }
+
+    /// Computes the smallest signed distance between this shape, transformed by `pos`, and the
+    /// given planes.
+    ///
+    /// The planes follow the same convention as [`Shape::intersects_frustum`]: the points `x`
+    /// with `n.dot(x) + d >= 0` are inside. The signed distance to each plane is the one of the
+    /// point of the shape the farthest behind it, computed with [`Shape::project_onto_axis`]. A
+    /// negative result is how far the shape pokes through the nearest plane, so the shape lies
+    /// inside the convex region bounded by all the planes if the result is nonnegative. Returns
+    /// `Real::MAX` if `planes` is empty.
+    fn min_signed_distance_to_planes(&self, pos: Isometry, planes: &[(UnitVector, Real)]) -> Real {
+        planes
+            .iter()
+            .map(|(normal, offset)| self.project_onto_axis(pos, *normal).0 + offset)
+            .fold(Real::MAX, Real::min)
+    }
+
+    /// Projects the local point `pt` on the part of the boundary of this shape lying on the
+    /// positive side of the plane passing through `plane_point` with normal `plane_normal`.
+    ///
+    /// All the arguments are expressed in the local-space of this shape. Returns `None` if no
+    /// part of the boundary lies on the positive side of the plane, or if this projection isn't
+    /// supported by this shape.
+    fn project_local_point_in_halfspace(
+        &self,
+        _pt: Vector,
+        _plane_point: Vector,
+        _plane_normal: UnitVector,
+    ) -> Option<Vector> {
+        None
+    }
+
+    /// Projects the world-space point `pt` on the part of the boundary of this shape, transformed
+    /// by `pos`, lying on the positive side of the world-space plane passing through
+    /// `plane_point` with normal `plane_normal`.
+    ///
+    /// See [`Shape::project_local_point_in_halfspace`] for details.
+    fn project_point_in_halfspace(
+        &self,
+        pos: Isometry,
+        pt: Vector,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vector> {
+        self.project_local_point_in_halfspace(
+            pos.inverse_transform_point(pt),
+            pos.inverse_transform_point(plane_point),
+            pos.rotation.inverse() * plane_normal,
+        )
+        .map(|proj| pos.transform_point(proj))
+    }
+
+    /// Samples the boundary of this shape as a closed polyline, e.g., for debug rendering.
+    ///
+    /// The points are expressed in the local-space of this shape and ordered counterclockwise.
+    /// The last point is implicitly connected to the first one. Each circular arc of the
+    /// boundary is discretized with `nsubdivs` subdivisions. Shapes without a dedicated
+    /// implementation sample their support map along `nsubdivs` directions, or return an empty
+    /// polyline if they don't have one.
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "std")]
+    fn outline(&self, nsubdivs: u32) -> Vec<Vector> {
+        let mut points: Vec<Vector> = Vec::with_capacity(nsubdivs as usize);
+
+        if let Some(support_map) = self.as_support_map() {
+            let dtheta = math::real_consts::TAU / nsubdivs as Real;
+
+            for i in 0..nsubdivs {
+                let (sin, cos) = (i as Real * dtheta).sin_cos();
+                let pt = support_map.local_support_point(Vector::new(cos, sin));
+
+                // Flat parts of the boundary yield the same support point several times.
+                if points.last() != Some(&pt) {
+                    points.push(pt);
+                }
+            }
+
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+        }
+
+        points
+    }
+
+    /// Computes the cross-section of this shape with the plane passing through `plane_point`
+    /// and orthogonal to `plane_normal`, both expressed in the local-space of this shape.
+    ///
+    /// Returns the vertices of the section polygon, sorted counterclockwise around
+    /// `plane_normal`. Curved boundaries are approximated with line segments. Returns `None` if
+    /// the plane doesn't cross this shape or if this shape doesn't support sectioning.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    fn local_section_with_plane(
+        &self,
+        _plane_point: Vector,
+        _plane_normal: UnitVector,
+    ) -> Option<Vec<Vector>> {
+        None
+    }
+
+    /// Computes the cross-section of this shape transformed by `pos` with the world-space plane
+    /// passing through `plane_point` and orthogonal to `plane_normal`.
+    ///
+    /// See [`Shape::local_section_with_plane`] for details. The returned polygon is expressed
+    /// in world-space.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    fn section_with_plane(
+        &self,
+        pos: Isometry,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vec<Vector>> {
+        let local_point = pos.inverse_transform_point(plane_point);
+        let local_normal = pos.rotation.inverse() * plane_normal;
+        let mut section = self.local_section_with_plane(local_point, local_normal)?;
+        section
+            .iter_mut()
+            .for_each(|pt| *pt = pos.transform_point(*pt));
+        Some(section)
+    }
 }
 
 impl_downcast!(sync Shape);
@@ -590,6 +899,11 @@ impl Shape for Ball {
         self.aabb(position)
     }
 
+    fn local_aabb_loosened(&self, margin: Real) -> Aabb {
+        assert!(margin >= 0.0, "The loosening margin must be positive.");
+        Ball::new(self.radius + margin).local_aabb()
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_ball(density, self.radius)
     }
@@ -623,6 +937,63 @@ impl Shape for Ball {
     fn feature_normal_at_point(&self, _: FeatureId, point: Vector) -> Option<UnitVector> {
         UnitVector::new(point).ok()
     }
+
+    fn local_curvature_at(&self, _pt_on_surface: Vector) -> [Real; DIM - 1] {
+        [1.0 / self.radius; DIM - 1]
+    }
+
+    fn farthest_local_point(&self, from: Vector) -> Vector {
+        // Any point of the surface is the farthest if `from` is at the center.
+        let dir = UnitVector::new(-from).map(|dir| *dir).unwrap_or(Vector::X);
+        dir * self.radius
+    }
+
+    fn local_inscribed_sphere(&self) -> Option<BoundingSphere> {
+        Some(self.local_bounding_sphere())
+    }
+
+    fn project_local_point_in_halfspace(
+        &self,
+        pt: Vector,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vector> {
+        Ball::project_local_point_in_halfspace(self, pt, plane_point, plane_normal)
+    }
+
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "std")]
+    fn outline(&self, nsubdivs: u32) -> Vec<Vector> {
+        self.to_polyline(nsubdivs)
+    }
+
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    fn local_section_with_plane(
+        &self,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vec<Vector>> {
+        let dist = plane_point.dot(*plane_normal);
+
+        if dist.abs() >= self.radius {
+            return None;
+        }
+
+        let center = *plane_normal * dist;
+        let radius = (self.radius * self.radius - dist * dist).sqrt();
+        let [u, v] = (*plane_normal).orthonormal_basis();
+        let dtheta = math::real_consts::TAU / SECTION_NSUBDIVS as Real;
+
+        Some(
+            (0..SECTION_NSUBDIVS)
+                .map(|i| {
+                    let (sin, cos) = (i as Real * dtheta).sin_cos();
+                    center + (u * cos + v * sin) * radius
+                })
+                .collect(),
+        )
+    }
 }
 
 impl Shape for Cuboid {
@@ -643,6 +1014,16 @@ impl Shape for Cuboid {
         self.aabb(position)
     }
 
+    fn local_aabb_loosened(&self, margin: Real) -> Aabb {
+        assert!(margin >= 0.0, "The loosening margin must be positive.");
+        Cuboid::new(self.half_extents + Vector::splat(margin)).local_aabb()
+    }
+
+    #[cfg(feature = "std")]
+    fn compute_aabbs(&self, poses: &[Isometry]) -> Vec<Aabb> {
+        self.local_aabb().transform_by_all(poses)
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_cuboid(density, self.half_extents)
     }
@@ -678,6 +1059,44 @@ impl Shape for Cuboid {
     fn feature_normal_at_point(&self, feature: FeatureId, _point: Vector) -> Option<UnitVector> {
         self.feature_normal(feature)
     }
+
+    fn farthest_local_point(&self, from: Vector) -> Vector {
+        self.half_extents.copysign(-from)
+    }
+
+    fn local_inscribed_sphere(&self) -> Option<BoundingSphere> {
+        Some(BoundingSphere::new(
+            Vector::ZERO,
+            self.half_extents.min_element(),
+        ))
+    }
+
+    #[cfg(feature = "std")]
+    fn project_local_point_in_halfspace(
+        &self,
+        pt: Vector,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vector> {
+        Cuboid::project_local_point_in_halfspace(self, pt, plane_point, plane_normal)
+    }
+
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "std")]
+    fn outline(&self, _nsubdivs: u32) -> Vec<Vector> {
+        self.to_polyline()
+    }
+
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    fn local_section_with_plane(
+        &self,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vec<Vector>> {
+        let (vertices, edges) = self.to_outline();
+        convex_outline_section(&vertices, &edges, plane_point, *plane_normal)
+    }
 }
 
 impl Shape for Capsule {
@@ -698,6 +1117,11 @@ impl Shape for Capsule {
         self.aabb(position)
     }
 
+    fn local_aabb_loosened(&self, margin: Real) -> Aabb {
+        assert!(margin >= 0.0, "The loosening margin must be positive.");
+        Capsule::new(self.segment.a, self.segment.b, self.radius + margin).local_aabb()
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_capsule(density, self.segment.a, self.segment.b, self.radius)
     }
@@ -729,6 +1153,32 @@ impl Shape for Capsule {
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         Some((&self.segment as &dyn PolygonalFeatureMap, self.radius))
     }
+
+    #[cfg(feature = "dim2")]
+    #[cfg(feature = "std")]
+    fn outline(&self, nsubdivs: u32) -> Vec<Vector> {
+        self.to_polyline(nsubdivs)
+    }
+
+    fn local_curvature_at(&self, pt_on_surface: Vector) -> [Real; DIM - 1] {
+        let dir = self.segment.scaled_direction();
+        let t = (pt_on_surface - self.segment.a).dot(dir) / dir.length_squared();
+
+        if t > 0.0 && t < 1.0 {
+            // The point is on the cylindrical (or flat, in 2D) part of the capsule.
+            #[cfg(feature = "dim2")]
+            return [0.0];
+            #[cfg(feature = "dim3")]
+            return [1.0 / self.radius, 0.0];
+        }
+
+        // The point is on one of the hemispherical caps.
+        [1.0 / self.radius; DIM - 1]
+    }
+
+    fn local_inscribed_sphere(&self) -> Option<BoundingSphere> {
+        Some(BoundingSphere::new(self.center(), self.radius))
+    }
 }
 
 impl Shape for Triangle {
@@ -863,6 +1313,10 @@ impl Shape for Compound {
         self.local_aabb().transform_by(position)
     }
 
+    fn compute_aabbs(&self, poses: &[Isometry]) -> Vec<Aabb> {
+        self.local_aabb().transform_by_all(poses)
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_compound(density, self.shapes())
     }
@@ -911,6 +1365,10 @@ impl Shape for Polyline {
         self.aabb(position)
     }
 
+    fn compute_aabbs(&self, poses: &[Isometry]) -> Vec<Aabb> {
+        self.local_aabb().transform_by_all(poses)
+    }
+
     fn mass_properties(&self, _density: Real) -> MassProperties {
         MassProperties::ZERO
     }
@@ -957,6 +1415,10 @@ impl Shape for TriMesh {
         self.aabb(position)
     }
 
+    fn compute_aabbs(&self, poses: &[Isometry]) -> Vec<Aabb> {
+        self.local_aabb().transform_by_all(poses)
+    }
+
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_trimesh(density, self.vertices(), self.indices())
     }
@@ -1004,6 +1466,10 @@ impl Shape for HeightField {
         self.aabb(position)
     }
 
+    fn compute_aabbs(&self, poses: &[Isometry]) -> Vec<Aabb> {
+        self.local_aabb().transform_by_all(poses)
+    }
+
     fn mass_properties(&self, _density: Real) -> MassProperties {
         MassProperties::ZERO
     }
@@ -1167,6 +1633,7 @@ impl Shape for Cylinder {
 
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_cylinder(density, self.half_height, self.radius)
+            .transform_by(Isometry::from_rotation(self.axis.rotation_from_y_up()))
     }
 
     fn is_convex(&self) -> bool {
@@ -1196,6 +1663,53 @@ impl Shape for Cylinder {
     fn as_polygonal_feature_map(&self) -> Option<(&dyn PolygonalFeatureMap, Real)> {
         Some((self as &dyn PolygonalFeatureMap, 0.0))
     }
+
+    fn local_curvature_at(&self, pt_on_surface: Vector) -> [Real; DIM - 1] {
+        let pt_on_surface = self.axis.to_y_up(pt_on_surface);
+        let dist_to_axis =
+            (pt_on_surface.x * pt_on_surface.x + pt_on_surface.z * pt_on_surface.z).sqrt();
+        let dist_to_side = (self.radius - dist_to_axis).abs();
+        let dist_to_cap = (self.half_height - pt_on_surface.y.abs()).abs();
+
+        if dist_to_side < dist_to_cap {
+            [1.0 / self.radius, 0.0]
+        } else {
+            [0.0, 0.0]
+        }
+    }
+
+    fn local_inscribed_sphere(&self) -> Option<BoundingSphere> {
+        Some(BoundingSphere::new(
+            Vector::ZERO,
+            self.radius.min(self.half_height),
+        ))
+    }
+
+    #[cfg(feature = "std")]
+    fn local_section_with_plane(
+        &self,
+        plane_point: Vector,
+        plane_normal: UnitVector,
+    ) -> Option<Vec<Vector>> {
+        // The outline only has a few segments along the lateral surface, so cut the prism
+        // approximating the cylinder instead: its edges are the rims of both caps and one
+        // segment of the lateral surface for each sample of the rims.
+        let n = SECTION_NSUBDIVS;
+        let dtheta = math::real_consts::TAU / n as Real;
+        let mut vertices = Vec::with_capacity(2 * n as usize);
+        push_circle(self.radius, n, dtheta, -self.half_height, &mut vertices);
+        push_circle(self.radius, n, dtheta, self.half_height, &mut vertices);
+        vertices
+            .iter_mut()
+            .for_each(|pt| *pt = self.axis.from_y_up(*pt));
+
+        let mut edges = Vec::with_capacity(3 * n as usize);
+        push_circle_outline_indices(&mut edges, 0..n);
+        push_circle_outline_indices(&mut edges, n..2 * n);
+        edges.extend((0..n).map(|i| [i, i + n]));
+
+        convex_outline_section(&vertices, &edges, plane_point, *plane_normal)
+    }
 }
 
 #[cfg(feature = "dim3")]
@@ -1219,6 +1733,7 @@ impl Shape for Cone {
 
     fn mass_properties(&self, density: Real) -> MassProperties {
         MassProperties::from_cone(density, self.half_height, self.radius)
+            .transform_by(Isometry::from_rotation(self.rotation_from_y_up()))
     }
 
     fn is_convex(&self) -> bool {
@@ -1316,6 +1831,11 @@ macro_rules! impl_shape_for_round_shape(
                 self.inner_shape.aabb(position).loosened(self.border_radius)
             }
 
+            fn local_aabb_loosened(&self, margin: Real) -> Aabb {
+                assert!(margin >= 0.0, "The loosening margin must be positive.");
+                self.inner_shape.local_aabb().loosened(self.border_radius + margin)
+            }
+
             fn mass_properties(&self, density: Real) -> MassProperties {
                 self.inner_shape.mass_properties(density)
             }
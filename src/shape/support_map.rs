@@ -1,6 +1,9 @@
 //! Traits for support mapping based shapes.
 
 use crate::math::{Isometry, UnitVector, Vector};
+#[cfg(all(feature = "std", debug_assertions))]
+use crate::math::{Real, DEFAULT_EPSILON};
+use crate::shape::FeatureId;
 
 /// Traits of convex shapes representable by a support mapping function.
 ///
@@ -18,6 +21,15 @@ pub trait SupportMap {
         self.local_support_point(*dir)
     }
 
+    /// Evaluates the support function of this shape, along with the feature the support point
+    /// belongs to.
+    ///
+    /// By default, the feature is `FeatureId::Unknown`. Polytopes can override this to return
+    /// the id of the vertex that produced the support point, e.g., for feature tracking.
+    fn local_support_feature(&self, dir: Vector) -> (Vector, FeatureId) {
+        (self.local_support_point(dir), FeatureId::Unknown)
+    }
+
     // Evaluates the support function of this shape transformed by `transform`.
     //
     // A support function is a function associating a vector to the shape point which maximizes
@@ -33,3 +45,84 @@ pub trait SupportMap {
         transform.transform_point(self.local_support_point_toward(local_dir))
     }
 }
+
+/// An inconsistency detected by [`debug_validate_support_map`].
+#[cfg(all(feature = "std", debug_assertions))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SupportMapInconsistency {
+    /// The support point returned for `dir` is not the farthest point along `dir`: the support
+    /// point returned for `other_dir` goes further along `dir`.
+    NotMaximal {
+        /// The direction for which the returned support point isn't maximal.
+        dir: Vector,
+        /// The direction whose support point goes further along `dir`.
+        other_dir: Vector,
+    },
+    /// Two evaluations of the support function along `dir` returned different points.
+    NotIdempotent {
+        /// The direction for which the support function isn't idempotent.
+        dir: Vector,
+    },
+}
+
+/// Checks that the support function of `shape` is consistent, along `samples` directions
+/// evenly distributed on the unit circle (in 2D) or sphere (in 3D).
+///
+/// For every pair of sampled directions `d` and `d'`, this checks that
+/// `support(d).dot(d) >= support(d').dot(d)`, i.e., that no other support point goes further
+/// along `d`. It also checks that evaluating the support function twice along the same
+/// direction returns the same point.
+///
+/// Custom [`SupportMap`] implementations violating these properties make GJK and EPA fail in
+/// ways that are hard to debug. This is a quadratic check meant to be run from tests or debug
+/// builds only.
+#[cfg(all(feature = "std", debug_assertions))]
+pub fn debug_validate_support_map<G: ?Sized + SupportMap>(
+    shape: &G,
+    samples: usize,
+) -> Result<(), SupportMapInconsistency> {
+    let dirs: Vec<Vector> = (0..samples).map(|i| sample_direction(i, samples)).collect();
+    let pts: Vec<Vector> = dirs
+        .iter()
+        .map(|dir| shape.local_support_point(*dir))
+        .collect();
+
+    for (dir, pt) in dirs.iter().zip(pts.iter()) {
+        let tolerance = DEFAULT_EPSILON * 1000.0 * (1.0 + pt.abs().max_element());
+
+        if (shape.local_support_point(*dir) - *pt).abs().max_element() > tolerance {
+            return Err(SupportMapInconsistency::NotIdempotent { dir: *dir });
+        }
+
+        let support_value = pt.dot(*dir);
+
+        for (other_dir, other_pt) in dirs.iter().zip(pts.iter()) {
+            if other_pt.dot(*dir) > support_value + tolerance {
+                return Err(SupportMapInconsistency::NotMaximal {
+                    dir: *dir,
+                    other_dir: *other_dir,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(feature = "std", debug_assertions))]
+#[cfg(feature = "dim2")]
+fn sample_direction(i: usize, samples: usize) -> Vector {
+    let angle = crate::math::real_consts::TAU * i as Real / samples as Real;
+    Vector::new(angle.cos(), angle.sin())
+}
+
+#[cfg(all(feature = "std", debug_assertions))]
+#[cfg(feature = "dim3")]
+fn sample_direction(i: usize, samples: usize) -> Vector {
+    // Fibonacci sphere.
+    let golden_angle = crate::math::real_consts::PI * (3.0 - (5.0 as Real).sqrt());
+    let y = 1.0 - 2.0 * (i as Real + 0.5) / samples as Real;
+    let r = (1.0 - y * y).sqrt();
+    let phi = golden_angle * i as Real;
+    Vector::new(phi.cos() * r, y, phi.sin() * r)
+}
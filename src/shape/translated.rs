@@ -0,0 +1,43 @@
+use crate::math::{UnitVector, Vector};
+use crate::shape::SupportMap;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[cfg_attr(feature = "cuda", derive(cust_core::DeviceCopy))]
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+/// A support-mapped shape shifted by a local offset.
+///
+/// Support-mapped primitives like [`Ball`](crate::shape::Ball) are centered at their
+/// local origin. This allows, e.g., off-center balls to be used with GJK and EPA without
+/// having to wrap them into a compound shape.
+pub struct Translated<S> {
+    /// The shape being translated.
+    pub inner_shape: S,
+    /// The offset of the inner shape, in the local-space of this shape.
+    pub offset: Vector,
+}
+
+impl<S> Translated<S> {
+    /// Creates a new shape translating `inner_shape` by `offset`.
+    pub fn new(inner_shape: S, offset: Vector) -> Self {
+        Self {
+            inner_shape,
+            offset,
+        }
+    }
+}
+
+impl<S: SupportMap> SupportMap for Translated<S> {
+    fn local_support_point(&self, dir: Vector) -> Vector {
+        self.inner_shape.local_support_point(dir) + self.offset
+    }
+
+    fn local_support_point_toward(&self, dir: UnitVector) -> Vector {
+        self.inner_shape.local_support_point_toward(dir) + self.offset
+    }
+}
@@ -210,7 +210,7 @@ impl Triangle {
         let mut best_dot = -Real::MAX;
 
         for (i, tangent) in self.edges_scaled_directions().iter().enumerate() {
-            let normal = Vector::new(tangent.y, -tangent.x);
+            let normal = -crate::math::perp(*tangent);
             if let Ok(normal) = UnitVector::new(normal) {
                 let dot = normal.dot(dir);
                 if normal.dot(dir) > best_dot {
@@ -517,6 +517,60 @@ impl Triangle {
         self.normal()
     }
 
+    /// Corrects a contact `normal` on the given `feature` of this triangle so it doesn’t lean
+    /// toward the triangles adjacent to it.
+    ///
+    /// The `i`-th element of `adjacent_normals` is the normal of the triangle sharing the `i`-th
+    /// edge of this triangle (AB, BC, then CA), if any, and must be oriented consistently with
+    /// the normal of this triangle. If `feature` is an edge or a vertex, and `normal` leans
+    /// toward one of the edges it touches more than the normal of the triangle on the other side
+    /// of that edge, that adjacent normal is returned instead. This removes the “ghost”
+    /// collisions of shapes catching on the internal edges of a flat triangle mesh.
+    #[cfg(feature = "dim3")]
+    pub fn correct_internal_edge_normal(
+        &self,
+        feature: FeatureId,
+        normal: UnitVector,
+        adjacent_normals: &[Option<UnitVector>; 3],
+    ) -> UnitVector {
+        let edges = match feature {
+            FeatureId::Edge(i) => [Some(i as usize % 3), None],
+            FeatureId::Vertex(i) => [Some(i as usize % 3), Some((i as usize + 2) % 3)],
+            _ => return normal,
+        };
+        let face_normal = match self.normal() {
+            Ok(face_normal) => face_normal,
+            Err(_) => return normal,
+        };
+        // Contacts on the back of the triangle are corrected with the flipped normals.
+        let back = normal.dot(*face_normal) < 0.0;
+        let pts = self.vertices();
+        let mut result = normal;
+
+        for i in edges.into_iter().flatten() {
+            let adjacent_normal = match adjacent_normals[i] {
+                Some(n) if back => -n,
+                Some(n) => n,
+                None => continue,
+            };
+
+            // The direction orthogonal to the edge, pointing away from this triangle.
+            let a = pts[i];
+            let b = pts[(i + 1) % 3];
+            let mut outward = (b - a).cross(*face_normal);
+
+            if outward.dot(pts[(i + 2) % 3] - a) > 0.0 {
+                outward = -outward;
+            }
+
+            if result.dot(outward) > adjacent_normal.dot(outward) {
+                result = adjacent_normal;
+            }
+        }
+
+        result
+    }
+
     /// The orientation of the triangle, based on its signed area.
     ///
     /// Returns `TriangleOrientation::Degenerate` if the triangle’s area is
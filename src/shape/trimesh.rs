@@ -1,4 +1,6 @@
 use crate::bounding_volume::Aabb;
+#[cfg(all(feature = "dim3", feature = "std"))]
+use crate::math::UnitVector;
 use crate::math::{Isometry, Vector};
 use crate::partitioning::QbvhStorage;
 use crate::partitioning::{GenericQbvh, Qbvh};
@@ -11,7 +13,9 @@ use {crate::shape::Cuboid, crate::shape::HeightFieldStorage, crate::utils::Sorte
 
 #[cfg(feature = "std")]
 use {
-    crate::shape::composite_shape::SimdCompositeShape,
+    crate::math::Real,
+    crate::query::visitors::BallIntersectionsVisitor,
+    crate::shape::composite_shape::{CompactableCompositeShape, SimdCompositeShape},
     crate::utils::hashmap::{Entry, HashMap},
     std::collections::HashSet,
 };
@@ -307,6 +311,13 @@ bitflags::bitflags! {
         /// vertices will be merged. It will no longer be the case in the future once we decouple
         /// the computations.
         const DELETE_DUPLICATE_TRIANGLES = 0b0100_0000;
+        /// If set, the contact normals on the internal edges of the trimesh will be corrected
+        /// using the normals of the adjacent triangles.
+        ///
+        /// This prevents shapes sliding on the trimesh from catching on the edges between its
+        /// triangles. This implies that the half-edge topology will be computed, and only has an
+        /// effect in 3D.
+        const FIX_INTERNAL_EDGES = 0b1000_0000;
     }
 }
 
@@ -452,7 +463,7 @@ impl TriMesh {
         let mut result = Ok(());
         let prev_indices_len = self.indices.len();
 
-        if !flags.contains(TriMeshFlags::HALF_EDGE_TOPOLOGY) {
+        if !flags.intersects(TriMeshFlags::HALF_EDGE_TOPOLOGY | TriMeshFlags::FIX_INTERNAL_EDGES) {
             self.topology = None;
         }
 
@@ -481,7 +492,8 @@ impl TriMesh {
         if difference.intersects(
             TriMeshFlags::HALF_EDGE_TOPOLOGY
                 | TriMeshFlags::CONNECTED_COMPONENTS
-                | TriMeshFlags::DELETE_BAD_TOPOLOGY_TRIANGLES,
+                | TriMeshFlags::DELETE_BAD_TOPOLOGY_TRIANGLES
+                | TriMeshFlags::FIX_INTERNAL_EDGES,
         ) {
             result = self.compute_topology(
                 flags.contains(TriMeshFlags::CONNECTED_COMPONENTS),
@@ -572,6 +584,48 @@ impl TriMesh {
         *self = TriMesh::with_flags(vertices, indices, self.flags);
     }
 
+    /// Removes the `i`-th triangle from the acceleration structure of this triangle mesh.
+    ///
+    /// The triangle is ignored by all the subsequent queries on this mesh, but it is kept in
+    /// the index buffer so the ids of the other triangles don’t change. Call [`Self::compact`]
+    /// to actually delete the removed triangles. Returns `false` if the triangle was already
+    /// removed or doesn’t exist.
+    ///
+    /// The connected components of this mesh are discarded. Its topology and pseudo-normals are
+    /// kept, so they still describe the mesh before the removal until [`Self::compact`]
+    /// recomputes them, except that [`Self::triangle_adjacent_normals`] treats the edges shared
+    /// with removed triangles as boundary edges.
+    pub fn remove_triangle(&mut self, i: u32) -> bool {
+        let removed = super::composite_shape::remove_qbvh_part(&mut self.qbvh, i);
+
+        if removed {
+            self.connected_components = None;
+        }
+
+        removed
+    }
+
+    /// Deletes the triangles removed with [`Self::remove_triangle`] and rebuilds this mesh.
+    ///
+    /// The remaining triangles are renumbered contiguously, keeping their relative order, and
+    /// the acceleration structure as well as the optional data enabled by the flags of this
+    /// mesh are recomputed. Returns the map from the old id of each remaining triangle to its
+    /// new id, which can be used to update external references to these triangles.
+    ///
+    /// # Panics
+    /// Panics if all the triangles of this mesh were removed.
+    pub fn compact(&mut self) -> HashMap<u32, u32> {
+        let (remaining, remap) =
+            super::composite_shape::remaining_parts(&self.qbvh, self.indices.len());
+        let indices = remaining.into_iter().map(|i| self.indices[i]).collect();
+
+        // NOTE: the flags deleting triangles were already applied to the original index
+        //       buffer, so they won’t delete any of the remaining triangles.
+        let vertices = std::mem::take(&mut self.vertices);
+        *self = TriMesh::with_flags(vertices, indices, self.flags);
+        remap
+    }
+
     /// Create a `TriMesh` from a set of points assumed to describe a counter-clockwise non-convex polygon.
     ///
     /// This operation may fail if the input polygon is invalid, e.g. it is non-simple or has zero surface area.
@@ -999,6 +1053,58 @@ impl TriMesh {
             )
         })
     }
+
+    /// Collects into `out` the indices of all the triangles with an Aabb intersecting a ball.
+    ///
+    /// The ball of the given `radius` is centered at `center`, expressed in the same space as
+    /// this mesh placed at `pos`. This test is conservative: a triangle close to the ball but
+    /// not touching it may still be collected if its Aabb intersects the ball.
+    pub fn triangles_in_ball(
+        &self,
+        pos: Isometry,
+        center: Vector,
+        radius: Real,
+        out: &mut Vec<u32>,
+    ) {
+        let local_center = pos.inverse_transform_point(center);
+        let mut callback = |triangle_id: &u32| {
+            out.push(*triangle_id);
+            true
+        };
+        let mut visitor = BallIntersectionsVisitor::new(local_center, radius, &mut callback);
+        let _ = self.qbvh.traverse_depth_first(&mut visitor);
+    }
+
+    /// The normals of the triangles sharing an edge with the `i`-th triangle of this mesh.
+    ///
+    /// The `k`-th element is the normal of the triangle adjacent to the `k`-th edge (AB, BC,
+    /// then CA) of the `i`-th triangle, or `None` if that edge is on the boundary of the mesh or
+    /// the adjacent triangle is degenerate. Returns `None` if the half-edge topology of this mesh
+    /// hasn’t been computed.
+    #[cfg(feature = "dim3")]
+    pub fn triangle_adjacent_normals(&self, i: u32) -> Option<[Option<UnitVector>; 3]> {
+        let topology = self.topology.as_ref()?;
+        let mut half_edge = topology.faces[i as usize].half_edge;
+        let mut result = [None; 3];
+
+        for normal in &mut result {
+            let twin = topology.half_edges[half_edge as usize].twin;
+
+            if twin != u32::MAX {
+                let adjacent = topology.half_edges[twin as usize].face;
+                // Triangles removed with `Self::remove_triangle` are no longer adjacent.
+                let is_removed = self.qbvh.raw_proxies()[adjacent as usize].is_detached();
+
+                if !is_removed {
+                    *normal = self.triangle(adjacent).normal().ok();
+                }
+            }
+
+            half_edge = topology.half_edges[half_edge as usize].next;
+        }
+
+        Some(result)
+    }
 }
 
 impl<Storage: TriMeshStorage> GenericTriMesh<Storage> {
@@ -1138,6 +1244,17 @@ impl From<Cuboid> for TriMesh {
     }
 }
 
+#[cfg(feature = "std")]
+impl CompactableCompositeShape for TriMesh {
+    fn remove_part(&mut self, part_id: u32) -> bool {
+        self.remove_triangle(part_id)
+    }
+
+    fn compact(&mut self) -> HashMap<u32, u32> {
+        TriMesh::compact(self)
+    }
+}
+
 #[cfg(feature = "std")]
 impl SimdCompositeShape for TriMesh {
     fn map_part_at(&self, i: u32, f: &mut dyn FnMut(Option<Isometry>, &dyn Shape)) {
@@ -0,0 +1,17 @@
+use crate::math::Vector;
+use crate::transformation::ear_clipping::triangulate_ear_clipping;
+use crate::transformation::hertel_mehlhorn;
+
+/// Decomposes a simple concave polygon into a set of convex polygons.
+///
+/// The polygon is first triangulated with ear clipping, then adjacent triangles are merged
+/// into convex polygons using the Hertel-Mehlhorn algorithm. The input `vertices` are assumed
+/// to describe a counter-clockwise polygon, and so are each of the output pieces, which makes
+/// them suitable for [`ConvexPolygon::from_convex_polyline`](crate::shape::ConvexPolygon::from_convex_polyline).
+///
+/// Returns `None` if the triangulation fails, e.g., if the polygon is non-simple or has zero
+/// surface area.
+pub fn convex_decomposition(vertices: &[Vector]) -> Option<Vec<Vec<Vector>>> {
+    let indices = triangulate_ear_clipping(vertices)?;
+    Some(hertel_mehlhorn(vertices, &indices))
+}
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::math::Vector2;
+use crate::math::{perp, Vector2};
 use crate::transformation::convex_hull_utils::{indexed_support_point_id, support_point_id};
 
 /// Computes the convex hull of a set of 2d points.
@@ -195,7 +195,7 @@ impl SegmentFacet {
     pub fn new(p1: usize, p2: usize, prev: usize, next: usize, points: &[Vector2]) -> SegmentFacet {
         let p1p2 = points[p2] - points[p1];
 
-        let mut normal = Vector2::new(p1p2.y, -p1p2.x).normalize();
+        let mut normal = -perp(p1p2).normalize();
 
         SegmentFacet {
             valid: normal != Vector2::ZERO,
@@ -1,5 +1,5 @@
 use super::{MeshIntersectionError, TriangleTriangleIntersection, EPS};
-use crate::math::{Isometry, Matrix2, Real, Vector, Vector2};
+use crate::math::{perp, Isometry, Matrix2, Real, Vector, Vector2};
 use crate::query::{visitors::BoundingVolumeIntersectionsSimultaneousVisitor, PointQuery};
 use crate::shape::{FeatureId, TriMesh, Triangle};
 use crate::utils::WBasis;
@@ -329,7 +329,7 @@ impl Triangulation {
                 let ab = b - a;
                 let ap = proj - a;
                 let param = ab.dot(ap) / ab.length_squared();
-                let shift = Vector2::new(ab.y, -ab.x);
+                let shift = -perp(ab);
 
                 // NOTE: if we have intersections exactly on the edge, we nudge
                 //       their projection slightly outside of the triangle. That
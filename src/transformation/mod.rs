@@ -23,6 +23,10 @@ pub mod vhacd;
 /// Voxelization of a 2D polyline or 3D triangle mesh.
 pub mod voxelization;
 
+#[cfg(feature = "dim2")]
+mod convex_decomposition2;
+#[cfg(feature = "dim2")]
+pub use self::convex_decomposition2::convex_decomposition;
 #[cfg(feature = "dim2")]
 pub(crate) mod ear_clipping;
 #[cfg(feature = "dim2")]
@@ -33,6 +33,9 @@ impl RoundCylinder {
             border_nsubdiv * 2 + 1..border_nsubdiv * 2 + 2,
         ];
         utils::apply_revolution(false, false, &circles, nsubdiv, &mut out_vtx, &mut out_idx);
+        out_vtx
+            .iter_mut()
+            .for_each(|pt| *pt = self.inner_shape.axis.from_y_up(*pt));
         (out_vtx, out_idx)
     }
 }
@@ -1,6 +1,6 @@
 use crate::bounding_volume::Aabb;
-use crate::math::Vector3;
-use crate::shape::Cuboid;
+use crate::math::{UnitVector3, Vector3};
+use crate::shape::{Cuboid, Triangle};
 use crate::transformation::utils;
 
 impl Aabb {
@@ -20,6 +20,30 @@ impl Cuboid {
         let (vtx, idx) = unit_cuboid();
         (utils::scaled(vtx, self.half_extents * 2.0), idx)
     }
+
+    /// Iterates through the triangles of the boundary of this cuboid, each paired with the
+    /// outward normal of the face it lies on.
+    pub fn triangles_with_normals(&self) -> impl Iterator<Item = (Triangle, UnitVector3)> {
+        // The normals of the faces of `unit_cuboid`, which are made of two consecutive triangles.
+        const FACE_NORMALS: [UnitVector3; 6] = [
+            UnitVector3::NEG_X,
+            UnitVector3::NEG_Z,
+            UnitVector3::X,
+            UnitVector3::Z,
+            UnitVector3::NEG_Y,
+            UnitVector3::Y,
+        ];
+
+        let (vtx, idx) = self.to_trimesh();
+        idx.into_iter().enumerate().map(move |(i, tri)| {
+            let triangle = Triangle::new(
+                vtx[tri[0] as usize],
+                vtx[tri[1] as usize],
+                vtx[tri[2] as usize],
+            );
+            (triangle, FACE_NORMALS[i / 2])
+        })
+    }
 }
 
 /**
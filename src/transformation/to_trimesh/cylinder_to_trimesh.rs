@@ -9,7 +9,11 @@ impl Cylinder {
         let height = self.half_height * 2.0;
         let scale = Vector3::new(diameter, height, diameter);
         let (vtx, idx) = unit_cylinder(nsubdiv);
-        (utils::scaled(vtx, scale), idx)
+        let vtx = utils::scaled(vtx, scale)
+            .into_iter()
+            .map(|pt| self.axis.from_y_up(pt))
+            .collect();
+        (vtx, idx)
     }
 }
 
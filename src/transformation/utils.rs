@@ -324,3 +324,59 @@ pub fn apply_revolution(
         }
     }
 }
+
+/// Computes the polygon where the plane passing through `plane_point` and orthogonal to
+/// `plane_normal` cuts the convex polyhedron outlined by `vertices` and `edges`.
+///
+/// The vertices of the polygon are sorted counterclockwise around `plane_normal`. Returns `None`
+/// if the plane doesn't cut the polyhedron along a polygon with at least three vertices.
+#[cfg(feature = "dim3")]
+pub fn convex_outline_section(
+    vertices: &[Vector],
+    edges: &[[u32; 2]],
+    plane_point: Vector,
+    plane_normal: Vector,
+) -> Option<Vec<Vector>> {
+    let eps = crate::math::DEFAULT_EPSILON;
+    // Outlines may duplicate vertices, up to rounding errors.
+    let merge_dist = eps.sqrt();
+    let dists: Vec<Real> = vertices
+        .iter()
+        .map(|pt| (*pt - plane_point).dot(plane_normal))
+        .collect();
+    let mut section: Vec<Vector> = vec![];
+    let mut push_unique = |pt: Vector| {
+        if section.iter().all(|other| other.distance(pt) > merge_dist) {
+            section.push(pt);
+        }
+    };
+
+    for (pt, dist) in vertices.iter().zip(dists.iter()) {
+        if dist.abs() <= eps {
+            push_unique(*pt);
+        }
+    }
+
+    for [ia, ib] in edges {
+        let (da, db) = (dists[*ia as usize], dists[*ib as usize]);
+
+        if (da < -eps && db > eps) || (da > eps && db < -eps) {
+            let (a, b) = (vertices[*ia as usize], vertices[*ib as usize]);
+            push_unique(a + (b - a) * (da / (da - db)));
+        }
+    }
+
+    if section.len() < 3 {
+        return None;
+    }
+
+    let center = section.iter().copied().sum::<Vector>() / section.len() as Real;
+    let [u, v] = crate::utils::WBasis::orthonormal_basis(plane_normal);
+    let angle = |pt: &Vector| {
+        let dpt = *pt - center;
+        dpt.dot(v).atan2(dpt.dot(u))
+    };
+    section.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+
+    Some(section)
+}
@@ -0,0 +1,59 @@
+use crate::math::{Real, Vector};
+
+/// Computes the area-weighted centroid of a simple polygon.
+///
+/// The vertices can be given in clockwise or counterclockwise order. Unlike
+/// [`center`](crate::utils::center), this is not biased toward regions of the polygon
+/// with many vertices. Returns the average of the vertices if the polygon has a zero area, and
+/// `None` if there are no vertices.
+#[cfg(feature = "dim2")]
+pub fn polygon_centroid(vertices: &[Vector]) -> Option<Vector> {
+    // Use the first vertex as the origin for better numerical stability.
+    let origin = *vertices.first()?;
+    let mut res = Vector::ZERO;
+    let mut twice_area: Real = 0.0;
+
+    for i in 1..vertices.len() {
+        let a = vertices[i] - origin;
+        let b = vertices[(i + 1) % vertices.len()] - origin;
+        let cross = a.perp_dot(b);
+
+        twice_area += cross;
+        res += (a + b) * cross;
+    }
+
+    if twice_area == 0.0 {
+        Some(crate::utils::center(vertices))
+    } else {
+        Some(origin + res / (3.0 * twice_area))
+    }
+}
+
+/// Computes the volume-weighted centroid of a closed polyhedron.
+///
+/// The faces must all be oriented consistently, either all clockwise or all counterclockwise.
+/// Returns the average of the vertices if the polyhedron has a zero volume, and `None` if there
+/// are no vertices.
+#[cfg(feature = "dim3")]
+pub fn polyhedron_centroid(vertices: &[Vector], faces: &[[u32; 3]]) -> Option<Vector> {
+    // Use the first vertex as the origin for better numerical stability.
+    let origin = *vertices.first()?;
+    let mut res = Vector::ZERO;
+    let mut six_volume: Real = 0.0;
+
+    for face in faces {
+        let a = vertices[face[0] as usize] - origin;
+        let b = vertices[face[1] as usize] - origin;
+        let c = vertices[face[2] as usize] - origin;
+        let vol = a.dot(b.cross(c));
+
+        six_volume += vol;
+        res += (a + b + c) * vol;
+    }
+
+    if six_volume == 0.0 {
+        Some(crate::utils::center(vertices))
+    } else {
+        Some(origin + res / (4.0 * six_volume))
+    }
+}
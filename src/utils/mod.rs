@@ -2,6 +2,10 @@
 
 pub use self::ccw_face_normal::ccw_face_normal;
 pub use self::center::center;
+#[cfg(feature = "dim2")]
+pub use self::centroid::polygon_centroid;
+#[cfg(feature = "dim3")]
+pub use self::centroid::polyhedron_centroid;
 #[cfg(feature = "std")]
 pub use self::deterministic_state::DeterministicState;
 
@@ -11,6 +15,8 @@ pub use self::cleanup::remove_unused_points;
 pub(crate) use self::inv::inv;
 pub use self::isometry_ops::{IsometryOps, IsometryOpt, SimdIsometryOps};
 pub use self::median::median;
+#[cfg(feature = "dim2")]
+pub use self::perpendicular::perpendicular;
 pub use self::point_cloud_support_point::{
     point_cloud_support_point, point_cloud_support_point_id,
 };
@@ -27,6 +33,8 @@ pub use self::segments_intersection::{segments_intersection2d, SegmentsIntersect
 pub(crate) use self::sort::sort2;
 pub(crate) use self::sort::sort3;
 pub use self::sorted_pair::SortedPair;
+#[cfg(feature = "dim3")]
+pub use self::triangle_normal::{triangle_normal, TriangleDegeneracy};
 pub(crate) use self::weighted_value::WeightedValue;
 pub(crate) use self::wops::{simd_swap, WBasis, WCross, WSign};
 
@@ -43,6 +51,7 @@ mod array;
 mod as_bytes;
 mod ccw_face_normal;
 mod center;
+mod centroid;
 #[cfg(feature = "dim3")]
 #[cfg(feature = "std")]
 mod cleanup;
@@ -60,6 +69,8 @@ pub mod hashmap;
 mod inv;
 mod isometry_ops;
 mod median;
+#[cfg(feature = "dim2")]
+mod perpendicular;
 mod point_cloud_support_point;
 mod point_in_poly2d;
 #[cfg(feature = "dim2")]
@@ -69,5 +80,7 @@ mod sdp_matrix;
 mod segments_intersection;
 mod sort;
 mod sorted_pair;
+#[cfg(feature = "dim3")]
+mod triangle_normal;
 mod weighted_value;
 mod wops;
@@ -0,0 +1,9 @@
+use crate::math::{perp, UnitVector};
+
+/// Computes the direction obtained by rotating `dir` by 90 degrees counter-clockwise.
+///
+/// Together with `dir`, the result forms a right-handed orthonormal basis of the plane.
+#[inline]
+pub fn perpendicular(dir: UnitVector) -> UnitVector {
+    UnitVector::new_unchecked(perp(*dir))
+}
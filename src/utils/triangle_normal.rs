@@ -0,0 +1,37 @@
+use crate::math::{UnitVector, Vector};
+use core::fmt;
+
+/// The reason why a triangle doesn't have a well-defined normal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TriangleDegeneracy {
+    /// At least two vertices of the triangle are at the same position.
+    CoincidentPoints,
+    /// The vertices of the triangle are distinct, but lie on the same line.
+    CollinearPoints,
+}
+
+impl fmt::Display for TriangleDegeneracy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CoincidentPoints => f.pad("the triangle has coincident vertices"),
+            Self::CollinearPoints => f.pad("the triangle has collinear vertices"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TriangleDegeneracy {}
+
+/// Computes the normal of the counter-clock-wise triangle `(a, b, c)`.
+///
+/// Unlike [`ccw_face_normal`](crate::utils::ccw_face_normal), the returned error tells whether
+/// the triangle is degenerate because some of its vertices coincide, or because they are
+/// collinear (sliver triangle).
+#[inline]
+pub fn triangle_normal(a: Vector, b: Vector, c: Vector) -> Result<UnitVector, TriangleDegeneracy> {
+    if a == b || a == c || b == c {
+        return Err(TriangleDegeneracy::CoincidentPoints);
+    }
+
+    UnitVector::new((b - a).cross(c - a)).map_err(|_| TriangleDegeneracy::CollinearPoints)
+}